@@ -2,7 +2,7 @@ use iced::{
     Align, Button, Column, Element, Length, Sandbox, Settings, Row, Text, TextInput,
     button, text_input
 };
-use iced_aw::{TabBar, TabLabel};
+use iced_aw::{tab_bar, TabBar, TabLabel};
 
 fn main() -> iced::Result {
     TabBarExample::run(Settings::default())
@@ -12,6 +12,7 @@ fn main() -> iced::Result {
 enum Message {
     TabSelected(usize),
     TabClosed(usize),
+    TabReordered(usize, usize),
     TabLabelInputChanged(String),
     TabContentInputChanged(String),
     NewTab,
@@ -19,6 +20,7 @@ enum Message {
 
 struct TabBarExample {
     active_tab: usize,
+    tab_bar_state: tab_bar::State,
     tab_label_input: text_input::State,
     new_tab_label: String,
     tab_content_input: text_input::State,
@@ -34,6 +36,7 @@ impl Sandbox for TabBarExample {
     fn new() -> Self {
         TabBarExample {
             active_tab: 0,
+            tab_bar_state: tab_bar::State::new(),
             tab_label_input: text_input::State::new(),
             new_tab_label: String::new(),
             tab_content_input: text_input::State::new(),
@@ -62,6 +65,11 @@ impl Sandbox for TabBarExample {
                 };
                 println!("active tab after: {}", self.active_tab);
             }
+            Message::TabReordered(from, to) => {
+                let tab = self.tabs.remove(from);
+                self.tabs.insert(to, tab);
+                self.active_tab = to;
+            }
             Message::TabLabelInputChanged(value) => {
                 self.new_tab_label = value
             },
@@ -124,6 +132,7 @@ impl Sandbox for TabBarExample {
                         }
                     )
                     .on_close(Message::TabClosed)
+                    .reorderable(&mut self.tab_bar_state, Message::TabReordered)
                     .tab_width(Length::Shrink)
                     .spacing(5)
                     .padding(5)
@@ -0,0 +1,107 @@
+//! Accessibility information of the widgets.
+//!
+//! The widgets pass a [`Node`](Node) with their [`Role`](Role), label and
+//! description to the renderer while being drawn. Renderers with an
+//! accessibility backend can announce them to assistive technologies like
+//! screen readers by implementing [`Renderer::announce`](Renderer::announce).
+use iced_native::Rectangle;
+
+/// The role of a widget, telling assistive technologies how to present it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A dialog shown on top of the rest of the user interface, like a
+    /// [`Modal`](crate::native::Modal) or the overlay of a picker.
+    Dialog,
+    /// A group of related elements, like a [`Card`](crate::native::Card).
+    Group,
+    /// A list of tabs, like a [`TabBar`](crate::native::TabBar).
+    TabList,
+}
+
+/// The accessibility information set on a widget.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Accessibility {
+    /// The short name of the widget, e.g. the title of a dialog.
+    pub label: Option<String>,
+    /// The longer description of the widget.
+    pub description: Option<String>,
+}
+
+impl Accessibility {
+    /// Gets the [`Node`](Node) of a widget with the given [`Role`](Role) and
+    /// this accessibility information.
+    #[must_use]
+    pub fn node(&self, role: Role) -> Node<'_> {
+        Node {
+            role,
+            label: self.label.as_deref(),
+            description: self.description.as_deref(),
+        }
+    }
+}
+
+/// The accessibility information of a drawn widget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Node<'a> {
+    /// The role of the widget.
+    pub role: Role,
+    /// The optional label of the widget.
+    pub label: Option<&'a str>,
+    /// The optional description of the widget.
+    pub description: Option<&'a str>,
+}
+
+impl<'a> Node<'a> {
+    /// Creates a new [`Node`](Node) with the given [`Role`](Role), but
+    /// without a label or description.
+    #[must_use]
+    pub const fn new(role: Role) -> Self {
+        Self {
+            role,
+            label: None,
+            description: None,
+        }
+    }
+}
+
+/// The part of a renderer announcing the accessibility information of the
+/// widgets.
+///
+/// The renderers of the widgets with accessibility information require this
+/// trait.
+pub trait Renderer {
+    /// Announces the [`Node`](Node) of a widget drawn within the given bounds.
+    ///
+    /// This does nothing by default.
+    fn announce(&mut self, _node: Node<'_>, _bounds: Rectangle) {}
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Accessibility, Node, Role};
+
+    #[test]
+    fn node_without_information() {
+        assert_eq!(
+            Accessibility::default().node(Role::Dialog),
+            Node::new(Role::Dialog)
+        );
+    }
+
+    #[test]
+    fn node_with_information() {
+        let accessibility = Accessibility {
+            label: Some(String::from("Pick a date")),
+            description: Some(String::from("The start of the holidays")),
+        };
+
+        let node = accessibility.node(Role::Dialog);
+
+        assert_eq!(node.role, Role::Dialog);
+        assert_eq!(node.label, Some("Pick a date"));
+        assert_eq!(node.description, Some("The start of the holidays"));
+    }
+}
@@ -0,0 +1,60 @@
+//! Helper functions for animating the opening and closing of overlays.
+use std::time::{Duration, Instant};
+
+/// Returns the linear progress from `0.0` to `1.0` of an animation with the
+/// given duration that started at `start`.
+///
+/// An animation with a zero duration is always finished.
+pub fn progress(start: Instant, duration: Duration, now: Instant) -> f32 {
+    if duration == Duration::ZERO {
+        return 1.0;
+    }
+
+    let elapsed = now.saturating_duration_since(start);
+
+    (elapsed.as_secs_f32() / duration.as_secs_f32()).min(1.0)
+}
+
+/// Eases the given linear progress out, so the animation starts fast and
+/// slows down towards its end.
+pub fn ease_out_cubic(progress: f32) -> f32 {
+    let inverse = 1.0 - progress.max(0.0).min(1.0);
+
+    1.0 - inverse * inverse * inverse
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{ease_out_cubic, progress};
+
+    #[test]
+    fn progress_is_clamped() {
+        let start = Instant::now();
+        let duration = Duration::from_millis(200);
+
+        assert!(progress(start, duration, start).abs() < f32::EPSILON);
+        assert!(
+            (progress(start, duration, start + Duration::from_millis(100)) - 0.5).abs()
+                < f32::EPSILON
+        );
+        assert!(
+            (progress(start, duration, start + Duration::from_secs(1)) - 1.0).abs() < f32::EPSILON
+        );
+    }
+
+    #[test]
+    fn zero_duration_is_finished() {
+        let start = Instant::now();
+
+        assert!((progress(start, Duration::ZERO, start) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn easing_keeps_the_end_points() {
+        assert!(ease_out_cubic(0.0).abs() < f32::EPSILON);
+        assert!((ease_out_cubic(1.0) - 1.0).abs() < f32::EPSILON);
+        assert!(ease_out_cubic(0.5) > 0.5);
+    }
+}
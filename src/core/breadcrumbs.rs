@@ -0,0 +1,117 @@
+//! Helper functions for collapsing the segments of breadcrumbs.
+
+/// The text shown in place of the collapsed segments.
+pub const ELLIPSIS: &str = "\u{2026}";
+
+/// An item shown by collapsed breadcrumbs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Crumb {
+    /// The segment at the given index.
+    Segment(usize),
+    /// An ellipsis replacing the collapsed segments.
+    Ellipsis,
+}
+
+/// Returns the items of breadcrumbs with segments of the given widths that fit
+/// into the maximum width.
+///
+/// If the segments don't fit, the middle segments are replaced by an ellipsis.
+/// The first and the last segment are always kept, followed by as many
+/// segments before the last one as fit.
+#[allow(clippy::cast_precision_loss)]
+pub fn collapse(
+    widths: &[f32],
+    divider_width: f32,
+    ellipsis_width: f32,
+    max_width: f32,
+) -> Vec<Crumb> {
+    let count = widths.len();
+    let total = widths.iter().sum::<f32>() + divider_width * count.saturating_sub(1) as f32;
+
+    if total <= max_width || count <= 2 {
+        return (0..count).map(Crumb::Segment).collect();
+    }
+
+    // The first segment, the ellipsis and the last segment with their dividers.
+    let mut width = widths[0] + ellipsis_width + widths[count - 1] + 2.0 * divider_width;
+    let mut first_trailing = count - 1;
+
+    while first_trailing > 2 {
+        let next = widths[first_trailing - 1] + divider_width;
+
+        if width + next > max_width {
+            break;
+        }
+
+        width += next;
+        first_trailing -= 1;
+    }
+
+    std::iter::once(Crumb::Segment(0))
+        .chain(std::iter::once(Crumb::Ellipsis))
+        .chain((first_trailing..count).map(Crumb::Segment))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collapse, Crumb};
+
+    /// The widths of the segments used for the tests.
+    const WIDTHS: [f32; 5] = [10.0, 20.0, 30.0, 40.0, 50.0];
+
+    #[test]
+    fn fitting_segments_are_not_collapsed() {
+        let crumbs = collapse(&WIDTHS, 5.0, 10.0, 170.0);
+
+        assert_eq!(crumbs, (0..5).map(Crumb::Segment).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn middle_segments_are_collapsed() {
+        let crumbs = collapse(&WIDTHS, 5.0, 10.0, 100.0);
+
+        assert_eq!(
+            crumbs,
+            vec![Crumb::Segment(0), Crumb::Ellipsis, Crumb::Segment(4)]
+        );
+    }
+
+    #[test]
+    fn trailing_segments_are_kept_if_they_fit() {
+        let crumbs = collapse(&WIDTHS, 5.0, 10.0, 135.0);
+
+        assert_eq!(
+            crumbs,
+            vec![
+                Crumb::Segment(0),
+                Crumb::Ellipsis,
+                Crumb::Segment(3),
+                Crumb::Segment(4)
+            ]
+        );
+    }
+
+    #[test]
+    fn ellipsis_replaces_at_least_one_segment() {
+        let crumbs = collapse(&WIDTHS, 5.0, 10.0, 165.0);
+
+        assert_eq!(
+            crumbs,
+            vec![
+                Crumb::Segment(0),
+                Crumb::Ellipsis,
+                Crumb::Segment(2),
+                Crumb::Segment(3),
+                Crumb::Segment(4)
+            ]
+        );
+    }
+
+    #[test]
+    fn two_segments_are_never_collapsed() {
+        let crumbs = collapse(&WIDTHS[..2], 5.0, 10.0, 0.0);
+
+        assert_eq!(crumbs, vec![Crumb::Segment(0), Crumb::Segment(1)]);
+    }
+}
@@ -1,4 +1,6 @@
 //! Helper functions for calculating the clock
+//!
+//! *This API requires the following crate features to be activated: `time_picker`*
 
 use std::fmt::Display;
 
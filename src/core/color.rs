@@ -1,4 +1,6 @@
-//! Helper functions and structs for picking dates.
+//! Helper functions and structs for picking colors.
+//!
+//! *This API requires the following crate features to be activated: `color_picker`*
 
 use iced_graphics::Color;
 
@@ -23,6 +25,24 @@ impl Hsv {
             value,
         }
     }
+
+    /// The hue of the color in degrees (0 - 359).
+    #[must_use]
+    pub const fn hue(&self) -> u16 {
+        self.hue
+    }
+
+    /// The saturation of the color (0.0 - 1.0).
+    #[must_use]
+    pub const fn saturation(&self) -> f32 {
+        self.saturation
+    }
+
+    /// The value of the color (0.0 - 1.0).
+    #[must_use]
+    pub const fn value(&self) -> f32 {
+        self.value
+    }
 }
 
 /// Creates a string of hexadecimal characters.
@@ -43,6 +63,32 @@ impl HexString for Color {
     }
 }
 
+/// Parses a string of hexadecimal characters into a [`Color`](Color).
+///
+/// The string may start with a `#` and must contain either 6 (`RRGGBB`) or 8
+/// (`RRGGBBAA`) hexadecimal characters.
+#[must_use]
+pub fn parse_hex_string(hex: &str) -> Option<Color> {
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+    if !(hex.len() == 6 || hex.len() == 8) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |index: usize| {
+        u8::from_str_radix(&hex[index..index + 2], 16)
+            .ok()
+            .map(|value| f32::from(value) / 255.0)
+    };
+
+    Some(Color {
+        r: channel(0)?,
+        g: channel(2)?,
+        b: channel(4)?,
+        a: if hex.len() == 8 { channel(6)? } else { 1.0 },
+    })
+}
+
 impl From<Color> for Hsv {
     // https://de.wikipedia.org/wiki/HSV-Farbraum#Umrechnung_RGB_in_HSV/HSL
     fn from(color: Color) -> Self {
@@ -101,7 +147,21 @@ impl From<Hsv> for Color {
 mod tests {
     use iced_graphics::Color;
 
-    use super::Hsv;
+    use super::{parse_hex_string, Hsv};
+
+    #[test]
+    fn hex_string_to_rgba() {
+        assert_eq!(
+            parse_hex_string("#FF8000"),
+            Some(Color::from_rgba(1.0, 128.0 / 255.0, 0.0, 1.0))
+        );
+        assert_eq!(
+            parse_hex_string("ff800000"),
+            Some(Color::from_rgba(1.0, 128.0 / 255.0, 0.0, 0.0))
+        );
+        assert_eq!(parse_hex_string("#FF80"), None);
+        assert_eq!(parse_hex_string("#GG8000"), None);
+    }
 
     #[test]
     fn rgb_to_hsv() {
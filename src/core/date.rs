@@ -1,14 +1,19 @@
 //! Helper functions for calculating dates
+//!
+//! *This API requires the following crate features to be activated: `date_picker`*
 
 use std::fmt::Display;
 
 #[cfg(not(target_arch = "wasm32"))]
-use chrono::{Datelike, Duration, NaiveDate};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
 #[cfg(not(target_arch = "wasm32"))]
 use lazy_static::lazy_static;
 
 /// The date value
-#[derive(Clone, Copy, Debug, Default)]
+///
+/// Dates are ordered chronologically, so they can be used directly as keys of
+/// the model.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Date {
     /// The year value of the date.
     pub year: i32,
@@ -24,6 +29,39 @@ impl Date {
     pub const fn from_ymd(year: i32, month: u32, day: u32) -> Self {
         Self { year, month, day }
     }
+
+    /// The year value of the date.
+    #[must_use]
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// The month value of the date (1 - 12).
+    #[must_use]
+    pub const fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// The day value of the date (1 - 31).
+    #[must_use]
+    pub const fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Formats the date with the given format string.
+    ///
+    /// The format string follows the syntax of
+    /// [`chrono::format::strftime`](chrono::format::strftime), e.g.
+    /// `"%d.%m.%Y"`.
+    ///
+    /// An invalid date, e.g. the [`Default`](Default) one, can't be
+    /// formatted and falls back to the `YYYY-MM-DD` rendering of its fields.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn format(&self, format: &str) -> String {
+        NaiveDate::from_ymd_opt(self.year, self.month, self.day)
+            .map_or_else(|| self.to_string(), |date| date.format(format).to_string())
+    }
 }
 
 impl Display for Date {
@@ -46,6 +84,65 @@ impl From<NaiveDate> for Date {
     }
 }
 
+/// The locale conventions of a calendar.
+///
+/// The default locale starts the week on Monday, uses the English names of
+/// the months and weekdays and formats dates like `2020-05-17`.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Locale {
+    /// The first day of the week, shown in the first column of the calendar.
+    pub first_weekday: Weekday,
+    /// The names of the months, starting with January.
+    pub month_names: [String; 12],
+    /// The labels of the weekdays above the calendar, starting with Monday.
+    pub weekday_labels: [String; 7],
+    /// The [`strftime`](chrono::format::strftime) format of the dates.
+    pub date_format: String,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Locale {
+    /// Gets the name of the month of the given date.
+    #[must_use]
+    pub fn month_name(&self, date: NaiveDate) -> &str {
+        &self.month_names[date.month0() as usize]
+    }
+
+    /// Gets the labels of the weekdays in the order of the columns of the
+    /// calendar, starting with the first day of the week.
+    pub fn weekday_labels(&self) -> impl Iterator<Item = &str> {
+        let first = self.first_weekday.num_days_from_monday() as usize;
+        (0..7).map(move |i| self.weekday_labels[(first + i) % 7].as_str())
+    }
+
+    /// Formats the given date with the date format of this locale.
+    #[must_use]
+    pub fn format(&self, date: Date) -> String {
+        date.format(&self.date_format)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for Locale {
+    fn default() -> Self {
+        let mut month_names: [String; 12] = Default::default();
+        for (month, name) in month_names.iter_mut().enumerate() {
+            *name = month_as_string(NaiveDate::from_ymd(0, month as u32 + 1, 1));
+        }
+
+        let mut weekday_labels: [String; 7] = Default::default();
+        weekday_labels.clone_from_slice(&WEEKDAY_LABELS);
+
+        Self {
+            first_weekday: Weekday::Mon,
+            month_names,
+            weekday_labels,
+            date_format: String::from("%Y-%m-%d"),
+        }
+    }
+}
+
 /// Creates a date with the previous month based on the given date.
 #[cfg(not(target_arch = "wasm32"))]
 #[must_use]
@@ -144,10 +241,29 @@ pub enum IsInMonth {
 #[cfg(not(target_arch = "wasm32"))]
 #[must_use]
 pub fn position_to_day(x: usize, y: usize, year: i32, month: u32) -> (usize, IsInMonth) {
+    position_to_day_with_week_start(x, y, year, month, Weekday::Mon)
+}
+
+/// Calculates the day number at the given position in the calendar table based
+/// on the given year and month, with the weeks starting on the given weekday.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn position_to_day_with_week_start(
+    x: usize,
+    y: usize,
+    year: i32,
+    month: u32,
+    first_weekday: Weekday,
+) -> (usize, IsInMonth) {
     let (x, y) = (x as isize, y as isize);
     let first_day = NaiveDate::from_ymd(year, month, 1);
-    let day_of_week = first_day.weekday().num_days_from_monday() as isize;
-    let day_of_week = if day_of_week == 0 { 7 } else { day_of_week };
+    let day_of_week =
+        (first_day.weekday().num_days_from_monday() + 7 - first_weekday.num_days_from_monday()) % 7;
+    let day_of_week = if day_of_week == 0 {
+        7
+    } else {
+        day_of_week as isize
+    };
 
     let day = (x + 7 * y) + 1 - day_of_week;
 
@@ -167,9 +283,31 @@ pub fn position_to_day(x: usize, y: usize, year: i32, month: u32) -> (usize, IsI
     }
 }
 
+/// Calculates the date at the given position in the calendar table showing the
+/// month of the given date, with the weeks starting on the given weekday.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn position_to_date(x: usize, y: usize, date: NaiveDate, first_weekday: Weekday) -> NaiveDate {
+    let (day, is_in_month) =
+        position_to_day_with_week_start(x, y, date.year(), date.month(), first_weekday);
+
+    match is_in_month {
+        IsInMonth::Previous => pred_month(date)
+            .with_day(day as u32)
+            .expect("Previous month with day should be valid"),
+        IsInMonth::Same => date
+            .with_day(day as u32)
+            .expect("Same month with day should be valid"),
+        IsInMonth::Next => succ_month(date)
+            .with_day(day as u32)
+            .expect("Succeeding month with day should be valid"),
+    }
+}
+
 /// Checks if the given year is a leap year.
 #[cfg(not(target_arch = "wasm32"))]
-const fn is_leap_year(year: i32) -> bool {
+#[must_use]
+pub const fn is_leap_year(year: i32) -> bool {
     let mod4 = year % 4 == 0;
     let mod100 = year % 100 == 0;
     let mod400 = year % 400 == 0;
@@ -179,7 +317,8 @@ const fn is_leap_year(year: i32) -> bool {
 
 /// Gets the number of days the given month in the year has.
 #[cfg(not(target_arch = "wasm32"))]
-const fn num_days_of_month(year: i32, month: u32) -> u32 {
+#[must_use]
+pub const fn num_days_of_month(year: i32, month: u32) -> u32 {
     match month {
         4 | 6 | 9 | 11 => 30,
         2 => {
@@ -265,13 +404,45 @@ lazy_static! {
 #[cfg(test)]
 #[cfg(not(target_arch = "wasm32"))]
 mod tests {
-    use chrono::NaiveDate;
+    use chrono::{NaiveDate, Weekday};
 
     use super::{
-        is_leap_year, num_days_of_month, position_to_day, pred_month, pred_year, succ_month,
-        succ_year, IsInMonth,
+        is_leap_year, num_days_of_month, position_to_date, position_to_day,
+        position_to_day_with_week_start, pred_month, pred_year, succ_month, succ_year, Date,
+        IsInMonth, Locale,
     };
 
+    #[test]
+    fn date_accessors_test() {
+        let date = Date::from_ymd(2020, 5, 17);
+
+        assert_eq!(date.year(), 2020);
+        assert_eq!(date.month(), 5);
+        assert_eq!(date.day(), 17);
+    }
+
+    #[test]
+    fn date_format_test() {
+        let date = Date::from_ymd(2020, 5, 7);
+
+        assert_eq!(date.to_string(), "2020-05-07");
+        assert_eq!(date.format("%d.%m.%Y"), "07.05.2020");
+    }
+
+    #[test]
+    fn invalid_date_format_test() {
+        assert_eq!(Date::default().format("%d.%m.%Y"), "0000-00-00");
+        assert_eq!(Date::from_ymd(2021, 2, 30).format("%d.%m.%Y"), "2021-02-30");
+    }
+
+    #[test]
+    fn date_order_test() {
+        assert!(Date::from_ymd(2019, 12, 31) < Date::from_ymd(2020, 1, 1));
+        assert!(Date::from_ymd(2020, 1, 31) < Date::from_ymd(2020, 2, 1));
+        assert!(Date::from_ymd(2020, 2, 1) < Date::from_ymd(2020, 2, 2));
+        assert_eq!(Date::from_ymd(2020, 2, 2), Date::from_ymd(2020, 2, 2));
+    }
+
     #[test]
     fn pred_month_test() {
         let date = NaiveDate::from_ymd(2020, 5, 6);
@@ -395,6 +566,70 @@ mod tests {
         assert_eq!(is_in_month, IsInMonth::Next);
     }
 
+    #[test]
+    fn position_to_day_with_week_start_test() {
+        let (day, is_in_month) = position_to_day_with_week_start(0, 0, 2020, 12, Weekday::Sun);
+        assert_eq!(day, 29);
+        assert_eq!(is_in_month, IsInMonth::Previous);
+
+        let (day, is_in_month) = position_to_day_with_week_start(2, 0, 2020, 12, Weekday::Sun);
+        assert_eq!(day, 1);
+        assert_eq!(is_in_month, IsInMonth::Same);
+
+        let (day, is_in_month) = position_to_day_with_week_start(0, 0, 2020, 11, Weekday::Sun);
+        assert_eq!(day, 25);
+        assert_eq!(is_in_month, IsInMonth::Previous);
+
+        let (day, is_in_month) = position_to_day_with_week_start(0, 1, 2020, 11, Weekday::Sun);
+        assert_eq!(day, 1);
+        assert_eq!(is_in_month, IsInMonth::Same);
+    }
+
+    #[test]
+    fn locale_test() {
+        let locale = Locale::default();
+        assert_eq!(locale.month_name(NaiveDate::from_ymd(2020, 5, 17)), "May");
+        assert_eq!(locale.format(Date::from_ymd(2020, 5, 7)), "2020-05-07");
+
+        let locale = Locale {
+            first_weekday: Weekday::Sun,
+            date_format: String::from("%d.%m.%Y"),
+            ..Locale::default()
+        };
+        let labels: Vec<&str> = locale.weekday_labels().collect();
+        assert_eq!(labels, vec!["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"]);
+        assert_eq!(locale.format(Date::from_ymd(2020, 5, 7)), "07.05.2020");
+    }
+
+    #[test]
+    fn position_to_date_test() {
+        let date = NaiveDate::from_ymd(2020, 12, 15);
+
+        assert_eq!(
+            position_to_date(0, 0, date, Weekday::Mon),
+            NaiveDate::from_ymd(2020, 11, 30)
+        );
+        assert_eq!(
+            position_to_date(1, 0, date, Weekday::Mon),
+            NaiveDate::from_ymd(2020, 12, 1)
+        );
+        assert_eq!(
+            position_to_date(3, 4, date, Weekday::Mon),
+            NaiveDate::from_ymd(2020, 12, 31)
+        );
+        assert_eq!(
+            position_to_date(6, 5, date, Weekday::Mon),
+            NaiveDate::from_ymd(2021, 1, 10)
+        );
+
+        let date = NaiveDate::from_ymd(2021, 3, 31);
+
+        assert_eq!(
+            position_to_date(0, 0, date, Weekday::Mon),
+            NaiveDate::from_ymd(2021, 2, 22)
+        );
+    }
+
     #[test]
     fn is_leap_year_test() {
         assert_eq!(is_leap_year(2020), true);
@@ -0,0 +1,113 @@
+//! Helper functions for truncating text with an ellipsis.
+use std::borrow::Cow;
+
+/// The character inserted in place of the removed text.
+const ELLIPSIS: char = '…';
+
+/// The position at which a too long text is truncated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Ellipsis {
+    /// The end of the text is replaced by an ellipsis.
+    End,
+    /// The middle of the text is replaced by an ellipsis.
+    Middle,
+}
+
+impl Default for Ellipsis {
+    fn default() -> Self {
+        Self::End
+    }
+}
+
+/// Truncates the given text with an ellipsis, so that its width measured by
+/// `measure` does not exceed `max_width`.
+///
+/// The text is returned unchanged if it already fits. If not even the
+/// ellipsis alone fits, only the ellipsis is returned.
+pub fn truncate<F>(text: &str, ellipsis: Ellipsis, max_width: f32, measure: F) -> Cow<'_, str>
+where
+    F: Fn(&str) -> f32,
+{
+    if measure(text) <= max_width {
+        return Cow::Borrowed(text);
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+
+    // Binary search for the maximum amount of kept characters.
+    let mut low = 0;
+    let mut high = chars.len().saturating_sub(1);
+
+    while low < high {
+        let mid = (low + high + 1) / 2;
+
+        if measure(&shorten(&chars, ellipsis, mid)) <= max_width {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    Cow::Owned(shorten(&chars, ellipsis, low))
+}
+
+/// Keeps the given amount of characters of the text and inserts an ellipsis.
+fn shorten(chars: &[char], ellipsis: Ellipsis, keep: usize) -> String {
+    match ellipsis {
+        Ellipsis::End => chars[..keep]
+            .iter()
+            .chain(std::iter::once(&ELLIPSIS))
+            .collect(),
+        Ellipsis::Middle => {
+            let tail = keep / 2;
+            let head = keep - tail;
+
+            chars[..head]
+                .iter()
+                .chain(std::iter::once(&ELLIPSIS))
+                .chain(chars[chars.len() - tail..].iter())
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{truncate, Ellipsis};
+
+    /// Measures the text by counting its characters.
+    #[allow(clippy::cast_precision_loss)]
+    fn measure(text: &str) -> f32 {
+        text.chars().count() as f32
+    }
+
+    #[test]
+    fn fitting_text_is_unchanged() {
+        assert_eq!(
+            truncate("Hello World", Ellipsis::End, 11.0, measure),
+            "Hello World"
+        );
+    }
+
+    #[test]
+    fn truncate_end() {
+        assert_eq!(
+            truncate("Hello World", Ellipsis::End, 6.0, measure),
+            "Hello…"
+        );
+    }
+
+    #[test]
+    fn truncate_middle() {
+        assert_eq!(
+            truncate("Hello World", Ellipsis::Middle, 6.0, measure),
+            "Hel…ld"
+        );
+    }
+
+    #[test]
+    fn truncate_to_ellipsis() {
+        assert_eq!(truncate("Hello World", Ellipsis::End, 0.0, measure), "…");
+        assert_eq!(truncate("Hello World", Ellipsis::Middle, 1.0, measure), "…");
+    }
+}
@@ -0,0 +1,70 @@
+//! Helper functions for filtering options by a search query.
+use std::fmt::Display;
+
+/// Returns the indices of the options matching the given query, ordered by
+/// relevance.
+///
+/// Options starting with the query come first, followed by options containing
+/// all characters of the query in the same order. Both keep the order of the
+/// options. The case of the characters is ignored and an empty query matches
+/// all options.
+pub fn filter<T: Display>(options: &[T], query: &str) -> Vec<usize> {
+    let query = query.to_lowercase();
+
+    let mut prefix_matches = Vec::new();
+    let mut fuzzy_matches = Vec::new();
+
+    for (index, option) in options.iter().enumerate() {
+        let label = option.to_string().to_lowercase();
+
+        if label.starts_with(&query) {
+            prefix_matches.push(index);
+        } else if is_subsequence(&query, &label) {
+            fuzzy_matches.push(index);
+        }
+    }
+
+    prefix_matches.append(&mut fuzzy_matches);
+    prefix_matches
+}
+
+/// Checks if all characters of the query appear in the text in the same order.
+fn is_subsequence(query: &str, text: &str) -> bool {
+    let mut text = text.chars();
+
+    query.chars().all(|q| text.any(|c| c == q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::filter;
+
+    /// The options used for the tests.
+    const OPTIONS: [&str; 5] = ["Banana", "Apple", "Pineapple", "Grape", "Apricot"];
+
+    #[test]
+    fn empty_query_matches_all() {
+        assert_eq!(filter(&OPTIONS, ""), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn prefix_matches_come_first() {
+        assert_eq!(filter(&OPTIONS, "ap"), vec![1, 4, 2, 3]);
+    }
+
+    #[test]
+    fn fuzzy_match() {
+        assert_eq!(filter(&OPTIONS, "ppl"), vec![1, 2]);
+        assert_eq!(filter(&OPTIONS, "grp"), vec![3]);
+    }
+
+    #[test]
+    fn ignores_case() {
+        assert_eq!(filter(&OPTIONS, "BAN"), vec![0]);
+    }
+
+    #[test]
+    fn no_match() {
+        assert!(filter(&OPTIONS, "cherry").is_empty());
+    }
+}
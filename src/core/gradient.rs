@@ -0,0 +1,202 @@
+//! Helper functions and structs for editing gradients.
+//!
+//! *This API requires the following crate features to be activated: `gradient_picker`*
+
+use iced_graphics::Color;
+
+/// A color at an offset along a linear gradient.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorStop {
+    /// The offset of the [`ColorStop`](ColorStop) between 0.0 and 1.0.
+    pub offset: f32,
+    /// The color of the [`ColorStop`](ColorStop).
+    pub color: Color,
+}
+
+impl ColorStop {
+    /// Creates a new [`ColorStop`](ColorStop) with the given color at the
+    /// given offset, which is clamped between 0.0 and 1.0.
+    #[must_use]
+    pub fn new(offset: f32, color: Color) -> Self {
+        Self {
+            offset: offset.max(0.0).min(1.0),
+            color,
+        }
+    }
+}
+
+/// Sorts the stops by their offsets.
+///
+/// The order of stops at the same offset is preserved.
+pub fn sort(stops: &mut [ColorStop]) {
+    stops.sort_by(|a, b| {
+        a.offset
+            .partial_cmp(&b.offset)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
+
+/// Returns the color of the gradient defined by the sorted stops at the given
+/// offset.
+///
+/// The color is interpolated linearly between the neighbouring stops. Before
+/// the first and after the last stop, the color of that stop is continued.
+/// Without any stop, the gradient is transparent.
+#[must_use]
+pub fn color_at(stops: &[ColorStop], offset: f32) -> Color {
+    let first = match stops.first() {
+        Some(first) => first,
+        None => return Color::TRANSPARENT,
+    };
+
+    if offset <= first.offset {
+        return first.color;
+    }
+
+    stops
+        .windows(2)
+        .find(|pair| offset <= pair[1].offset)
+        .map_or_else(
+            || stops[stops.len() - 1].color,
+            |pair| {
+                let (start, end) = (pair[0], pair[1]);
+                let distance = end.offset - start.offset;
+                let t = if distance > 0.0 {
+                    (offset - start.offset) / distance
+                } else {
+                    1.0
+                };
+
+                Color {
+                    r: start.color.r + (end.color.r - start.color.r) * t,
+                    g: start.color.g + (end.color.g - start.color.g) * t,
+                    b: start.color.b + (end.color.b - start.color.b) * t,
+                    a: start.color.a + (end.color.a - start.color.a) * t,
+                }
+            },
+        )
+}
+
+/// Inserts a new stop into the sorted stops at the given offset.
+///
+/// The new stop gets the color the gradient currently has at the offset, so
+/// inserting it doesn't change the gradient. Returns the index of the new
+/// stop.
+pub fn insert(stops: &mut Vec<ColorStop>, offset: f32) -> usize {
+    let stop = ColorStop::new(offset, color_at(stops, offset));
+    let index = stops
+        .iter()
+        .position(|other| other.offset > stop.offset)
+        .unwrap_or_else(|| stops.len());
+
+    stops.insert(index, stop);
+
+    index
+}
+
+/// Moves the stop at the given index of the sorted stops to the given offset,
+/// keeping the stops sorted.
+///
+/// Returns the new index of the moved stop.
+pub fn move_stop(stops: &mut Vec<ColorStop>, index: usize, offset: f32) -> usize {
+    let mut stop = stops.remove(index);
+    stop.offset = offset.max(0.0).min(1.0);
+
+    let index = stops
+        .iter()
+        .position(|other| other.offset > stop.offset)
+        .unwrap_or_else(|| stops.len());
+
+    stops.insert(index, stop);
+
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{color_at, insert, move_stop, sort, ColorStop};
+    use iced_graphics::Color;
+
+    fn stops() -> Vec<ColorStop> {
+        vec![
+            ColorStop::new(0.0, Color::BLACK),
+            ColorStop::new(0.5, Color::from_rgb(1.0, 0.0, 0.0)),
+            ColorStop::new(1.0, Color::WHITE),
+        ]
+    }
+
+    #[test]
+    fn new_test() {
+        assert_eq!(
+            ColorStop::new(-0.5, Color::BLACK),
+            ColorStop {
+                offset: 0.0,
+                color: Color::BLACK
+            }
+        );
+        assert_eq!(
+            ColorStop::new(1.5, Color::BLACK),
+            ColorStop {
+                offset: 1.0,
+                color: Color::BLACK
+            }
+        );
+    }
+
+    #[test]
+    fn sort_test() {
+        let mut stops = vec![
+            ColorStop::new(1.0, Color::WHITE),
+            ColorStop::new(0.0, Color::BLACK),
+        ];
+        sort(&mut stops);
+
+        assert_eq!(stops[0].color, Color::BLACK);
+        assert_eq!(stops[1].color, Color::WHITE);
+    }
+
+    #[test]
+    fn color_at_test() {
+        let stops = stops();
+
+        assert_eq!(color_at(&stops, 0.0), Color::BLACK);
+        assert_eq!(color_at(&stops, 0.25), Color::from_rgb(0.5, 0.0, 0.0));
+        assert_eq!(color_at(&stops, 0.75), Color::from_rgb(1.0, 0.5, 0.5));
+        assert_eq!(color_at(&stops, 1.0), Color::WHITE);
+    }
+
+    #[test]
+    fn color_at_outside_test() {
+        let stops = vec![
+            ColorStop::new(0.25, Color::BLACK),
+            ColorStop::new(0.75, Color::WHITE),
+        ];
+
+        assert_eq!(color_at(&stops, 0.0), Color::BLACK);
+        assert_eq!(color_at(&stops, 1.0), Color::WHITE);
+        assert_eq!(color_at(&[], 0.5), Color::TRANSPARENT);
+    }
+
+    #[test]
+    fn insert_test() {
+        let mut stops = stops();
+        let index = insert(&mut stops, 0.25);
+
+        assert_eq!(index, 1);
+        assert_eq!(stops.len(), 4);
+        assert_eq!(stops[1].color, Color::from_rgb(0.5, 0.0, 0.0));
+    }
+
+    #[test]
+    fn move_stop_test() {
+        let mut stops = stops();
+
+        let index = move_stop(&mut stops, 0, 0.75);
+        assert_eq!(index, 1);
+        assert_eq!(stops[1], ColorStop::new(0.75, Color::BLACK));
+
+        let index = move_stop(&mut stops, 1, 2.0);
+        assert_eq!(index, 2);
+        assert_eq!(stops[2], ColorStop::new(1.0, Color::BLACK));
+    }
+}
@@ -0,0 +1,215 @@
+//! A bounded undo/redo history for editing widgets.
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// The default capacity of a [`History`](History).
+const DEFAULT_CAPACITY: usize = 100;
+
+/// The default time window in which consecutive edits are coalesced.
+const DEFAULT_COALESCE_WINDOW: Duration = Duration::from_millis(500);
+
+/// A bounded undo/redo history of snapshots of type `T`.
+///
+/// Edits recorded in quick succession (within the coalesce window) are merged
+/// into a single undo step, so that e.g. typing a word can be undone at once.
+#[derive(Clone, Debug)]
+pub struct History<T> {
+    /// The snapshots that can be restored by undoing.
+    undo: VecDeque<T>,
+    /// The snapshots that can be restored by redoing.
+    redo: Vec<T>,
+    /// The maximum amount of undo steps.
+    capacity: usize,
+    /// The time window in which consecutive edits are coalesced.
+    coalesce_window: Duration,
+    /// The time of the last recorded edit.
+    last_edit: Option<Instant>,
+}
+
+impl<T> History<T> {
+    /// Creates a new empty [`History`](History) with the given capacity.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            capacity,
+            coalesce_window: DEFAULT_COALESCE_WINDOW,
+            last_edit: None,
+        }
+    }
+
+    /// Sets the time window in which consecutive edits of the
+    /// [`History`](History) are coalesced.
+    #[must_use]
+    pub const fn coalesce_window(mut self, coalesce_window: Duration) -> Self {
+        self.coalesce_window = coalesce_window;
+        self
+    }
+
+    /// Records the snapshot taken right before an edit.
+    pub fn push(&mut self, previous: T) {
+        self.push_at(previous, Instant::now());
+    }
+
+    /// Records the snapshot taken right before an edit at the given time.
+    ///
+    /// If the last edit happened within the coalesce window, the snapshot is
+    /// discarded and the edit is merged into the previous undo step.
+    pub fn push_at(&mut self, previous: T, now: Instant) {
+        let coalesce = self.last_edit.map_or(false, |last| {
+            now.duration_since(last) < self.coalesce_window
+        });
+
+        self.last_edit = Some(now);
+        self.redo.clear();
+
+        if coalesce && !self.undo.is_empty() {
+            return;
+        }
+
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.undo.len() == self.capacity {
+            let _ = self.undo.pop_front();
+        }
+        self.undo.push_back(previous);
+    }
+
+    /// Ends the current coalescing group, so that the next edit always starts
+    /// a new undo step.
+    pub fn seal(&mut self) {
+        self.last_edit = None;
+    }
+
+    /// Undoes the last edit.
+    ///
+    /// Takes the current snapshot, which can be restored by redoing, and
+    /// returns the snapshot to restore, if any.
+    pub fn undo(&mut self, current: T) -> Option<T> {
+        let previous = self.undo.pop_back()?;
+        self.redo.push(current);
+        self.last_edit = None;
+        Some(previous)
+    }
+
+    /// Redoes the last undone edit.
+    ///
+    /// Takes the current snapshot, which can be restored by undoing, and
+    /// returns the snapshot to restore, if any.
+    pub fn redo(&mut self, current: T) -> Option<T> {
+        let next = self.redo.pop()?;
+        if self.undo.len() == self.capacity {
+            let _ = self.undo.pop_front();
+        }
+        self.undo.push_back(current);
+        self.last_edit = None;
+        Some(next)
+    }
+
+    /// Returns true if there is an edit that can be undone.
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns true if there is an edit that can be redone.
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Removes all recorded snapshots of the [`History`](History).
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.last_edit = None;
+    }
+}
+
+impl<T> Default for History<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::History;
+
+    #[test]
+    fn undo_redo() {
+        let mut history = History::new(10).coalesce_window(Duration::from_millis(0));
+        let start = Instant::now();
+
+        history.push_at(0, start);
+        history.push_at(1, start + Duration::from_millis(1));
+
+        assert_eq!(history.undo(2), Some(1));
+        assert_eq!(history.undo(1), Some(0));
+        assert_eq!(history.undo(0), None);
+
+        assert_eq!(history.redo(0), Some(1));
+        assert_eq!(history.redo(1), Some(2));
+        assert_eq!(history.redo(2), None);
+    }
+
+    #[test]
+    fn push_clears_redo() {
+        let mut history = History::new(10).coalesce_window(Duration::from_millis(0));
+        let start = Instant::now();
+
+        history.push_at(0, start);
+        assert_eq!(history.undo(1), Some(0));
+        assert!(history.can_redo());
+
+        history.push_at(0, start + Duration::from_millis(1));
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn coalesce() {
+        let mut history = History::new(10).coalesce_window(Duration::from_millis(500));
+        let start = Instant::now();
+
+        history.push_at("", start);
+        history.push_at("a", start + Duration::from_millis(100));
+        history.push_at("ab", start + Duration::from_millis(200));
+        history.push_at("abc", start + Duration::from_millis(1000));
+
+        assert_eq!(history.undo("abcd"), Some("abc"));
+        assert_eq!(history.undo("abc"), Some(""));
+        assert_eq!(history.undo(""), None);
+    }
+
+    #[test]
+    fn seal() {
+        let mut history = History::new(10).coalesce_window(Duration::from_millis(500));
+        let start = Instant::now();
+
+        history.push_at(0, start);
+        history.seal();
+        history.push_at(1, start + Duration::from_millis(100));
+
+        assert_eq!(history.undo(2), Some(1));
+        assert_eq!(history.undo(1), Some(0));
+    }
+
+    #[test]
+    fn capacity() {
+        let mut history = History::new(2).coalesce_window(Duration::from_millis(0));
+        let start = Instant::now();
+
+        for i in 0..5 {
+            history.push_at(i, start + Duration::from_millis(i));
+        }
+
+        assert_eq!(history.undo(5), Some(4));
+        assert_eq!(history.undo(4), Some(3));
+        assert_eq!(history.undo(3), None);
+    }
+}
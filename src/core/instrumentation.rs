@@ -0,0 +1,174 @@
+//! Record the interactions of the user with the widgets.
+//!
+//! Once a [`Sink`](Sink) is installed with [`set_sink`](set_sink), the
+//! interactive widgets of this crate report every [`Interaction`](Interaction)
+//! to it. This can be used for usage analytics or for assertions in automated
+//! UI tests.
+//!
+//! Every widget listed in [`WidgetKind`](WidgetKind) reports its
+//! interactions. Widgets that only position other elements, like the
+//! [`FloatingButton`](crate::native::FloatingButton), the
+//! [`FloatingElement`](crate::native::FloatingElement) or the
+//! [`Badge`](crate::native::Badge), report nothing themselves.
+//!
+//! The indices reported by a [`TreeView`](crate::native::TreeView) are the
+//! indices of its visible rows.
+//!
+//! *This API requires the following crate features to be activated: `instrumentation`*
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+use lazy_static::lazy_static;
+
+lazy_static! {
+    /// The globally installed sink receiving the interactions.
+    static ref SINK: RwLock<Option<Box<dyn Sink>>> = RwLock::new(None);
+}
+
+/// The kind of the widget an [`Interaction`](Interaction) originated from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WidgetKind {
+    /// A [`Breadcrumbs`](crate::native::Breadcrumbs).
+    Breadcrumbs,
+    /// A [`Card`](crate::native::Card).
+    Card,
+    /// A [`Collapse`](crate::native::Collapse).
+    Collapse,
+    /// A [`ColorPicker`](crate::native::ColorPicker).
+    ColorPicker,
+    /// A [`ComboBox`](crate::native::ComboBox).
+    ComboBox,
+    /// A [`ContextMenu`](crate::native::ContextMenu).
+    ContextMenu,
+    /// A [`DatePicker`](crate::native::DatePicker).
+    DatePicker,
+    /// A [`GradientPicker`](crate::native::GradientPicker).
+    GradientPicker,
+    /// A [`Knob`](crate::native::Knob).
+    Knob,
+    /// A [`Modal`](crate::native::Modal).
+    Modal,
+    /// A [`NumberInput`](crate::native::NumberInput).
+    NumberInput,
+    /// A [`Pagination`](crate::native::Pagination).
+    Pagination,
+    /// A [`Popover`](crate::native::Popover).
+    Popover,
+    /// A [`RangeSlider`](crate::native::RangeSlider).
+    RangeSlider,
+    /// A [`ReorderableList`](crate::native::ReorderableList).
+    ReorderableList,
+    /// A [`SegmentedButton`](crate::native::SegmentedButton).
+    SegmentedButton,
+    /// A [`SelectionList`](crate::native::SelectionList).
+    SelectionList,
+    /// A [`SpeedDial`](crate::native::floating_button::SpeedDial).
+    SpeedDial,
+    /// A [`Split`](crate::native::Split).
+    Split,
+    /// A [`Steps`](crate::native::Steps).
+    Steps,
+    /// A [`TabBar`](crate::native::TabBar).
+    TabBar,
+    /// A [`Table`](crate::native::Table).
+    Table,
+    /// A [`TimePicker`](crate::native::TimePicker).
+    TimePicker,
+    /// A [`Toast`](crate::native::Toast).
+    Toast,
+    /// A [`TreeView`](crate::native::TreeView).
+    TreeView,
+}
+
+/// The action the user performed on a widget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// The element at the given index was selected.
+    Select(usize),
+    /// The element at the given index was deselected.
+    Deselect(usize),
+    /// The element at the given index was expanded.
+    Expand(usize),
+    /// The element at the given index was collapsed.
+    Collapse(usize),
+    /// The element at the first index was moved to the second index.
+    Reorder(usize, usize),
+    /// The column at the given index was clicked to sort by it.
+    Sort(usize),
+    /// The element at the given index was closed.
+    CloseIndex(usize),
+    /// The notification with the given id was dismissed by the user.
+    Dismiss(usize),
+    /// The notification with the given id expired.
+    Timeout(usize),
+    /// The value of the widget was changed.
+    Change,
+    /// The widget was opened.
+    Open,
+    /// The widget was closed.
+    Close,
+    /// The widget was dismissed by pressing the escape key.
+    Escape,
+    /// The widget was dismissed by clicking on the backdrop.
+    Backdrop,
+    /// The widget was cancelled.
+    Cancel,
+    /// The widget was submitted.
+    Submit,
+}
+
+/// A structured interaction event of a widget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Interaction {
+    /// The kind of the widget the interaction originated from.
+    pub widget: WidgetKind,
+    /// The performed action.
+    pub action: Action,
+    /// The time the interaction happened.
+    pub timestamp: SystemTime,
+}
+
+/// A sink receiving the [`Interaction`](Interaction)s of the widgets.
+pub trait Sink: Send + Sync {
+    /// Records the given [`Interaction`](Interaction).
+    fn record(&self, interaction: Interaction);
+}
+
+impl<F> Sink for F
+where
+    F: Fn(Interaction) + Send + Sync,
+{
+    fn record(&self, interaction: Interaction) {
+        (self)(interaction);
+    }
+}
+
+/// Installs the given [`Sink`](Sink), replacing the previously installed one.
+pub fn set_sink<S>(sink: S)
+where
+    S: 'static + Sink,
+{
+    if let Ok(mut guard) = SINK.write() {
+        *guard = Some(Box::new(sink));
+    }
+}
+
+/// Removes the installed [`Sink`](Sink).
+pub fn clear_sink() {
+    if let Ok(mut guard) = SINK.write() {
+        *guard = None;
+    }
+}
+
+/// Reports an interaction to the installed [`Sink`](Sink), if any.
+pub(crate) fn record(widget: WidgetKind, action: Action) {
+    if let Ok(guard) = SINK.read() {
+        if let Some(sink) = guard.as_ref() {
+            sink.record(Interaction {
+                widget,
+                action,
+                timestamp: SystemTime::now(),
+            });
+        }
+    }
+}
@@ -1,21 +1,84 @@
 //! A module fitting `iced_core`.
+//!
+//! It contains the helpers the widgets of this crate are built upon. They are
+//! part of the public API, so other crates can build their own widgets in the
+//! same style:
+//!
+//! * [`renderer::DrawEnvironment`](renderer::DrawEnvironment) bundles
+//!     everything a renderer needs to draw a widget.
+//! * [`overlay::Position`](overlay::Position) places overlays so that they
+//!     stay fully visible, or anchors them next to a target on the side of an
+//!     [`overlay::Placement`](overlay::Placement).
+//! * [`accessibility`](accessibility) contains the role, label and
+//!     description the widgets pass to their renderers to be announced.
+//! * `animation` contains the progress and easing of the open and close
+//!     animations of overlays.
+//! * [`Icon`](Icon) and [`ICON_FONT`](ICON_FONT) are the icons used by the
+//!     widgets and the font to draw them with. [`icon_to_char`](icon_to_char)
+//!     gets the glyph of an icon in the font.
+//! * `date`, `time`, `clock` and `color` contain the values and calculations
+//!     of the pickers, if their feature is activated.
+//! * `breadcrumbs` collapses the middle segments of the breadcrumbs, if
+//!     their feature is activated.
+//! * `filter` ranks the options of the combo box by the typed text, if its
+//!     feature is activated.
+//! * `gradient` contains the color stops of the gradient picker and their
+//!     interpolation, if its feature is activated.
+//! * `pagination` collapses the pages of the pagination, if its feature is
+//!     activated.
+//! * `table` distributes the width of the table to its columns, if its
+//!     feature is activated.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod accessibility;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod animation;
 
 #[cfg(feature = "date_picker")]
 //#[cfg(all(feature = "date_picker", not(target_arch = "wasm32")))]
 pub mod date;
 
+#[cfg(all(feature = "breadcrumbs", not(target_arch = "wasm32")))]
+pub mod breadcrumbs;
+
 #[cfg(all(feature = "time_picker", not(target_arch = "wasm32")))]
 pub mod clock;
 
 #[cfg(all(feature = "color_picker", not(target_arch = "wasm32")))]
 pub mod color;
 
+#[cfg(all(feature = "badge", not(target_arch = "wasm32")))]
+pub mod ellipsis;
+
+#[cfg(all(feature = "combo_box", not(target_arch = "wasm32")))]
+pub mod filter;
+
+#[cfg(all(feature = "gradient_picker", not(target_arch = "wasm32")))]
+pub mod gradient;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod history;
+
+#[cfg(all(feature = "instrumentation", not(target_arch = "wasm32")))]
+pub mod instrumentation;
+
 #[cfg(not(target_arch = "wasm32"))]
 pub mod overlay;
 
+#[cfg(all(feature = "pagination", not(target_arch = "wasm32")))]
+pub mod pagination;
+
 #[cfg(not(target_arch = "wasm32"))]
 pub mod renderer;
 
+#[cfg(all(feature = "table", not(target_arch = "wasm32")))]
+pub mod table;
+
 #[cfg(feature = "time_picker")]
 //#[cfg(all(feature = "time_picker", not(target_arch = "wasm32")))]
 pub mod time;
+
+#[cfg(not(target_arch = "wasm32"))]
+#[doc(no_inline)]
+pub use crate::graphics::icons::{icon_to_char, Icon, ICON_FONT};
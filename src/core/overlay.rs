@@ -1,19 +1,83 @@
 //! Helper functions for overlays
-use iced_native::{Point, Size};
+use iced_native::{Point, Rectangle, Size};
+
+/// The side of a target on which an anchored node is placed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Placement {
+    /// Above the target.
+    Top,
+    /// Below the target.
+    Bottom,
+    /// Left of the target.
+    Left,
+    /// Right of the target.
+    Right,
+}
+
+impl Placement {
+    /// Gets the opposite side of this [`Placement`](Placement).
+    #[must_use]
+    pub const fn flipped(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    /// Gets the space between the target and the edge of the bounds on this
+    /// side.
+    fn room(self, target: Rectangle, bounds: Size) -> f32 {
+        match self {
+            Self::Top => target.y,
+            Self::Bottom => bounds.height - (target.y + target.height),
+            Self::Left => target.x,
+            Self::Right => bounds.width - (target.x + target.width),
+        }
+    }
+}
 
 /// Trait containing functions for positioning of nodes.
 pub trait Position {
     /// Centers this node around the given position. If the node is over the
     /// specified bounds it's bouncing back to be fully visible on screen.
     fn center_and_bounce(&mut self, position: Point, bounds: Size);
+
+    /// Places the top left corner of this node on the given position. If the
+    /// node is over the specified bounds it's bouncing back to be fully visible
+    /// on screen.
+    fn place_and_bounce(&mut self, position: Point, bounds: Size);
+
+    /// Places this node next to the target on the side of the given
+    /// [`Placement`](Placement), centered along the target and separated by
+    /// the spacing.
+    ///
+    /// If the node doesn't fit on that side, but there is more room on the
+    /// opposite side, it's flipped over. It's then bouncing back to be fully
+    /// visible on screen. Returns the side the node was placed on.
+    fn anchor_and_bounce(
+        &mut self,
+        target: Rectangle,
+        placement: Placement,
+        spacing: f32,
+        bounds: Size,
+    ) -> Placement;
 }
 
 impl Position for iced_native::layout::Node {
     fn center_and_bounce(&mut self, position: Point, bounds: Size) {
-        self.move_to(Point::new(
-            (position.x - self.size().width / 2.0).max(0.0),
-            (position.y - self.size().height / 2.0).max(0.0),
-        ));
+        self.place_and_bounce(
+            Point::new(
+                position.x - self.size().width / 2.0,
+                position.y - self.size().height / 2.0,
+            ),
+            bounds,
+        );
+    }
+
+    fn place_and_bounce(&mut self, position: Point, bounds: Size) {
+        self.move_to(Point::new(position.x.max(0.0), position.y.max(0.0)));
 
         self.move_to(Point::new(
             if self.bounds().x + self.bounds().width > bounds.width {
@@ -30,4 +94,124 @@ impl Position for iced_native::layout::Node {
             },
         ));
     }
+
+    fn anchor_and_bounce(
+        &mut self,
+        target: Rectangle,
+        placement: Placement,
+        spacing: f32,
+        bounds: Size,
+    ) -> Placement {
+        let size = self.size();
+        let needed = match placement {
+            Placement::Top | Placement::Bottom => size.height,
+            Placement::Left | Placement::Right => size.width,
+        } + spacing;
+
+        let placement = if placement.room(target, bounds) < needed
+            && placement.flipped().room(target, bounds) > placement.room(target, bounds)
+        {
+            placement.flipped()
+        } else {
+            placement
+        };
+
+        let center_x = target.x + (target.width - size.width) / 2.0;
+        let center_y = target.y + (target.height - size.height) / 2.0;
+
+        let position = match placement {
+            Placement::Top => Point::new(center_x, target.y - spacing - size.height),
+            Placement::Bottom => Point::new(center_x, target.y + target.height + spacing),
+            Placement::Left => Point::new(target.x - spacing - size.width, center_y),
+            Placement::Right => Point::new(target.x + target.width + spacing, center_y),
+        };
+
+        self.place_and_bounce(position, bounds);
+
+        placement
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{layout::Node, Point, Rectangle, Size};
+
+    use super::{Placement, Position};
+
+    /// The size of the screen the nodes are placed on.
+    const BOUNDS: Size = Size {
+        width: 400.0,
+        height: 300.0,
+    };
+
+    #[test]
+    fn anchor_on_the_given_side() {
+        let target = Rectangle::new(Point::new(150.0, 100.0), Size::new(100.0, 20.0));
+        let mut node = Node::new(Size::new(60.0, 30.0));
+
+        let placement = node.anchor_and_bounce(target, Placement::Top, 5.0, BOUNDS);
+
+        assert_eq!(placement, Placement::Top);
+        assert_eq!(
+            node.bounds(),
+            Rectangle::new(Point::new(170.0, 65.0), Size::new(60.0, 30.0))
+        );
+
+        let placement = node.anchor_and_bounce(target, Placement::Right, 5.0, BOUNDS);
+
+        assert_eq!(placement, Placement::Right);
+        assert_eq!(
+            node.bounds(),
+            Rectangle::new(Point::new(255.0, 95.0), Size::new(60.0, 30.0))
+        );
+    }
+
+    #[test]
+    fn anchor_flips_at_the_edges() {
+        let target = Rectangle::new(Point::new(10.0, 10.0), Size::new(100.0, 20.0));
+        let mut node = Node::new(Size::new(60.0, 30.0));
+
+        let placement = node.anchor_and_bounce(target, Placement::Top, 5.0, BOUNDS);
+
+        assert_eq!(placement, Placement::Bottom);
+        assert_eq!(
+            node.bounds(),
+            Rectangle::new(Point::new(30.0, 35.0), Size::new(60.0, 30.0))
+        );
+
+        let placement = node.anchor_and_bounce(target, Placement::Left, 5.0, BOUNDS);
+
+        assert_eq!(placement, Placement::Right);
+        assert_eq!(
+            node.bounds(),
+            Rectangle::new(Point::new(115.0, 5.0), Size::new(60.0, 30.0))
+        );
+    }
+
+    #[test]
+    fn anchor_keeps_side_without_more_room() {
+        let target = Rectangle::new(Point::new(0.0, 0.0), Size::new(400.0, 300.0));
+        let mut node = Node::new(Size::new(60.0, 30.0));
+
+        let placement = node.anchor_and_bounce(target, Placement::Bottom, 5.0, BOUNDS);
+
+        assert_eq!(placement, Placement::Bottom);
+        assert_eq!(
+            node.bounds(),
+            Rectangle::new(Point::new(170.0, 270.0), Size::new(60.0, 30.0))
+        );
+    }
+
+    #[test]
+    fn anchor_bounces_along_the_target() {
+        let target = Rectangle::new(Point::new(0.0, 100.0), Size::new(20.0, 20.0));
+        let mut node = Node::new(Size::new(60.0, 30.0));
+
+        node.anchor_and_bounce(target, Placement::Bottom, 5.0, BOUNDS);
+
+        assert_eq!(
+            node.bounds(),
+            Rectangle::new(Point::new(0.0, 125.0), Size::new(60.0, 30.0))
+        );
+    }
 }
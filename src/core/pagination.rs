@@ -0,0 +1,139 @@
+//! Helper functions for collapsing the pages of a pagination.
+
+/// The text shown in place of the collapsed pages.
+pub const ELLIPSIS: &str = "\u{2026}";
+
+/// An item shown by a pagination.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Page {
+    /// The page at the given index.
+    Number(usize),
+    /// An ellipsis replacing the collapsed pages.
+    Ellipsis,
+}
+
+/// Returns the items of a pagination with the given number of pages.
+///
+/// The first and the last `boundaries` pages and the `siblings` pages around
+/// the current page are always shown. The other pages are replaced by an
+/// ellipsis, unless the ellipsis would only replace a single page. Thus, the
+/// number of items stays the same while the current page changes.
+#[allow(
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss,
+    clippy::cast_possible_truncation
+)]
+pub fn pages(current: usize, count: usize, siblings: usize, boundaries: usize) -> Vec<Page> {
+    // The calculations are done with the signed numbers of the pages starting
+    // at one.
+    let page = current as isize + 1;
+    let count = count as isize;
+    let siblings = siblings as isize;
+    let boundaries = boundaries as isize;
+
+    let start_pages = 1..=boundaries.min(count);
+    let end_pages = (count - boundaries + 1).max(boundaries + 1)..=count;
+
+    let siblings_start = (page - siblings)
+        .min(count - boundaries - 2 * siblings - 1)
+        .max(boundaries + 2);
+    let siblings_end =
+        (page + siblings)
+            .max(boundaries + 2 * siblings + 2)
+            .min(if boundaries > 0 {
+                end_pages.start() - 2
+            } else {
+                count - 1
+            });
+
+    let leading = if siblings_start > boundaries + 2 {
+        Some(Page::Ellipsis)
+    } else if boundaries + 1 < count - boundaries {
+        Some(Page::Number(boundaries as usize))
+    } else {
+        None
+    };
+
+    let trailing = if siblings_end < count - boundaries - 1 {
+        Some(Page::Ellipsis)
+    } else if count - boundaries > boundaries {
+        Some(Page::Number((count - boundaries - 1) as usize))
+    } else {
+        None
+    };
+
+    let number = |page: isize| Page::Number((page - 1) as usize);
+
+    start_pages
+        .map(number)
+        .chain(leading)
+        .chain((siblings_start..=siblings_end).map(number))
+        .chain(trailing)
+        .chain(end_pages.map(number))
+        .collect()
+}
+
+/// Returns the label of a compact pagination showing the current page and
+/// the number of pages.
+pub fn compact_label(current: usize, count: usize) -> String {
+    format!("Page {} of {}", current + 1, count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compact_label, pages, Page};
+
+    /// Returns the items for the given page numbers, where zero stands for an
+    /// ellipsis.
+    fn items(numbers: &[usize]) -> Vec<Page> {
+        numbers
+            .iter()
+            .map(|&number| match number {
+                0 => Page::Ellipsis,
+                number => Page::Number(number - 1),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn few_pages_are_not_collapsed() {
+        assert_eq!(pages(0, 5, 1, 1), items(&[1, 2, 3, 4, 5]));
+        assert_eq!(pages(4, 7, 1, 1), items(&[1, 2, 3, 4, 5, 6, 7]));
+    }
+
+    #[test]
+    fn trailing_pages_are_collapsed() {
+        assert_eq!(pages(0, 10, 1, 1), items(&[1, 2, 3, 4, 5, 0, 10]));
+    }
+
+    #[test]
+    fn pages_around_the_current_page_are_kept() {
+        assert_eq!(pages(4, 10, 1, 1), items(&[1, 0, 4, 5, 6, 0, 10]));
+    }
+
+    #[test]
+    fn leading_pages_are_collapsed() {
+        assert_eq!(pages(9, 10, 1, 1), items(&[1, 0, 6, 7, 8, 9, 10]));
+    }
+
+    #[test]
+    fn siblings_and_boundaries_are_configurable() {
+        assert_eq!(
+            pages(9, 20, 2, 2),
+            items(&[1, 2, 0, 8, 9, 10, 11, 12, 0, 19, 20])
+        );
+        assert_eq!(pages(4, 10, 0, 0), items(&[0, 5, 0]));
+    }
+
+    #[test]
+    fn empty_and_single_pages() {
+        assert_eq!(pages(0, 0, 1, 1), items(&[]));
+        assert_eq!(pages(0, 1, 1, 1), items(&[1]));
+        assert_eq!(pages(0, 2, 1, 1), items(&[1, 2]));
+    }
+
+    #[test]
+    fn compact_label_counts_from_one() {
+        assert_eq!(compact_label(0, 3), "Page 1 of 3");
+    }
+}
@@ -0,0 +1,86 @@
+//! Helper functions for distributing the width of a table to its columns.
+use iced_native::Length;
+
+/// Returns the widths of columns with the given lengths sharing the available
+/// width.
+///
+/// Columns with a [`Length::Units`](Length::Units) keep their width. The other
+/// columns share the remaining width by their fill factor, a
+/// [`Length::Shrink`](Length::Shrink) counting like a
+/// [`Length::Fill`](Length::Fill). If the available width is unbounded, they
+/// don't get any width at all.
+pub fn column_widths(lengths: &[Length], available: f32) -> Vec<f32> {
+    let fixed: f32 = lengths
+        .iter()
+        .map(|length| match length {
+            Length::Units(units) => f32::from(*units),
+            _ => 0.0,
+        })
+        .sum();
+
+    let fill_factors: u16 = lengths
+        .iter()
+        .map(|length| match length {
+            Length::Units(_) => 0,
+            _ => length.fill_factor().max(1),
+        })
+        .sum();
+
+    let remaining = if available.is_finite() {
+        (available - fixed).max(0.0)
+    } else {
+        0.0
+    };
+
+    lengths
+        .iter()
+        .map(|length| match length {
+            Length::Units(units) => f32::from(*units),
+            _ => remaining * f32::from(length.fill_factor().max(1)) / f32::from(fill_factors),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::column_widths;
+    use iced_native::Length;
+
+    #[test]
+    fn fixed_columns_keep_their_width() {
+        let widths = column_widths(&[Length::Units(50), Length::Units(100)], 500.0);
+
+        assert_eq!(widths, vec![50.0, 100.0]);
+    }
+
+    #[test]
+    fn fill_columns_share_the_remaining_width() {
+        let widths = column_widths(
+            &[Length::Units(100), Length::Fill, Length::FillPortion(3)],
+            500.0,
+        );
+
+        assert_eq!(widths, vec![100.0, 100.0, 300.0]);
+    }
+
+    #[test]
+    fn shrink_columns_count_like_fill_columns() {
+        let widths = column_widths(&[Length::Shrink, Length::Fill], 200.0);
+
+        assert_eq!(widths, vec![100.0, 100.0]);
+    }
+
+    #[test]
+    fn fill_columns_are_empty_without_remaining_width() {
+        let widths = column_widths(&[Length::Units(300), Length::Fill], 200.0);
+
+        assert_eq!(widths, vec![300.0, 0.0]);
+    }
+
+    #[test]
+    fn fill_columns_are_empty_for_unbounded_width() {
+        let widths = column_widths(&[Length::Units(50), Length::Fill], f32::INFINITY);
+
+        assert_eq!(widths, vec![50.0, 0.0]);
+    }
+}
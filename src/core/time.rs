@@ -1,9 +1,17 @@
-//! Use a time picker as an input element for picking times.
+//! The time value of the time picker.
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
-use std::fmt::Display;
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
 
 /// The time value
+///
+/// Times are compared by the time of the day they represent, independent of
+/// their period or whether they contain seconds. For example, `8:30 PM` is
+/// equal to `20:30:00`. This makes them usable directly as keys of the model.
 #[derive(Clone, Copy, Debug)]
 pub enum Time {
     /// The time value containing hour, minute and period.
@@ -50,6 +58,94 @@ impl Time {
             period,
         }
     }
+
+    /// The hour value of the time as shown by its [`Period`](Period).
+    #[must_use]
+    pub const fn hour(&self) -> u32 {
+        match self {
+            Self::Hm { hour, .. } | Self::Hms { hour, .. } => *hour,
+        }
+    }
+
+    /// The minute value of the time.
+    #[must_use]
+    pub const fn minute(&self) -> u32 {
+        match self {
+            Self::Hm { minute, .. } | Self::Hms { minute, .. } => *minute,
+        }
+    }
+
+    /// The second value of the time. This is always `0` for
+    /// [`Time::Hm`](Time::Hm).
+    #[must_use]
+    pub const fn second(&self) -> u32 {
+        match self {
+            Self::Hm { .. } => 0,
+            Self::Hms { second, .. } => *second,
+        }
+    }
+
+    /// The [`Period`](Period) of the time.
+    #[must_use]
+    pub const fn period(&self) -> Period {
+        match self {
+            Self::Hm { period, .. } | Self::Hms { period, .. } => *period,
+        }
+    }
+
+    /// Formats the time with the given format string.
+    ///
+    /// The format string follows the syntax of
+    /// [`chrono::format::strftime`](chrono::format::strftime), e.g.
+    /// `"%I:%M %p"`.
+    ///
+    /// An invalid time, e.g. one with 61 minutes, can't be formatted and
+    /// falls back to the rendering of its fields.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn format(&self, format: &str) -> String {
+        let (h, m, s) = self.hms();
+
+        chrono::NaiveTime::from_hms_opt(h, m, s)
+            .map_or_else(|| self.to_string(), |time| time.format(format).to_string())
+    }
+
+    /// The hour (in 24 hour format), minute and second of the time.
+    const fn hms(&self) -> (u32, u32, u32) {
+        let hour = match (self.period(), self.hour()) {
+            (Period::H24, hour) => hour,
+            (Period::Am, hour) => hour % 12,
+            (Period::Pm, hour) => hour % 12 + 12,
+        };
+
+        (hour, self.minute(), self.second())
+    }
+}
+
+impl PartialEq for Time {
+    fn eq(&self, other: &Self) -> bool {
+        self.hms() == other.hms()
+    }
+}
+
+impl Eq for Time {}
+
+impl PartialOrd for Time {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Time {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.hms().cmp(&other.hms())
+    }
+}
+
+impl Hash for Time {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hms().hash(state);
+    }
 }
 
 impl Display for Time {
@@ -75,7 +171,7 @@ impl Display for Time {
 }
 
 /// The current period of the clock
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Period {
     /// No period - using 24 hour format.
     H24,
@@ -101,29 +197,11 @@ impl Display for Period {
 
 #[cfg(not(target_arch = "wasm32"))]
 impl From<Time> for chrono::NaiveTime {
+    /// Converts the time, clamping fields that are out of range.
     fn from(time: Time) -> Self {
-        let (h, m, s, p) = match time {
-            Time::Hm {
-                hour,
-                minute,
-                period,
-            } => (hour, minute, 0, period),
-            Time::Hms {
-                hour,
-                minute,
-                second,
-                period,
-            } => (hour, minute, second, period),
-        };
-
-        let h = if h == 12 && p != Period::H24 { 0 } else { h };
-
-        let h = match p {
-            Period::H24 | Period::Am => h,
-            Period::Pm => (h + 12) % 24,
-        };
+        let (h, m, s) = time.hms();
 
-        Self::from_hms(h, m, s)
+        Self::from_hms(h.min(23), m.min(59), s.min(59))
     }
 }
 
@@ -205,4 +283,70 @@ mod tests {
         let naive: NaiveTime = time.into();
         assert_eq!(naive, NaiveTime::from_hms(17, 52, 0));
     }
+
+    #[test]
+    fn time_accessors() {
+        let time = Time::Hm {
+            hour: 8,
+            minute: 52,
+            period: Period::Pm,
+        };
+
+        assert_eq!(time.hour(), 8);
+        assert_eq!(time.minute(), 52);
+        assert_eq!(time.second(), 0);
+        assert_eq!(time.period(), Period::Pm);
+    }
+
+    #[test]
+    fn time_format() {
+        let time = Time::Hms {
+            hour: 8,
+            minute: 52,
+            second: 17,
+            period: Period::Pm,
+        };
+
+        assert_eq!(time.to_string(), "08:52:17 PM");
+        assert_eq!(time.format("%H:%M"), "20:52");
+    }
+
+    #[test]
+    fn invalid_time_format() {
+        let time = Time::Hms {
+            hour: 25,
+            minute: 61,
+            second: 0,
+            period: Period::H24,
+        };
+
+        assert_eq!(time.format("%H:%M"), "25:61:00");
+
+        let naive: NaiveTime = time.into();
+        assert_eq!(naive, NaiveTime::from_hms(23, 59, 0));
+    }
+
+    #[test]
+    fn time_order() {
+        let am = Time::Hm {
+            hour: 12,
+            minute: 30,
+            period: Period::Am,
+        };
+        let pm = Time::Hm {
+            hour: 1,
+            minute: 0,
+            period: Period::Pm,
+        };
+        let h24 = Time::Hms {
+            hour: 13,
+            minute: 0,
+            second: 0,
+            period: Period::H24,
+        };
+
+        assert!(am < pm);
+        assert_eq!(pm, h24);
+        assert_ne!(am, pm);
+    }
 }
@@ -0,0 +1,9 @@
+//! Announce the accessibility information of the widgets.
+//!
+//! The renderer of `iced_graphics` has no accessibility backend yet, so it
+//! uses the default implementation ignoring the announcements.
+
+impl<B> crate::core::accessibility::Renderer for iced_graphics::Renderer<B> where
+    B: iced_graphics::Backend
+{
+}
@@ -0,0 +1,35 @@
+//! Draw the open and close animation of overlays, shared by the modal and
+//! the pickers.
+use iced_graphics::{Primitive, Vector};
+
+/// The distance an overlay slides down while it is hidden.
+const SLIDE_DISTANCE: f32 = 16.0;
+
+/// Slides the given primitive of an overlay up into place while its
+/// visibility progresses from hidden (`0.0`) to shown (`1.0`).
+pub(crate) fn slide(primitive: Primitive, progress: f32) -> Primitive {
+    if progress >= 1.0 {
+        return primitive;
+    }
+
+    Primitive::Translate {
+        translation: Vector::new(0.0, (1.0 - progress) * SLIDE_DISTANCE),
+        content: Box::new(primitive),
+    }
+}
+
+#[cfg(any(
+    feature = "color_picker",
+    feature = "date_picker",
+    feature = "time_picker"
+))]
+impl<B> crate::native::overlay::animated::Renderer for iced_graphics::Renderer<B>
+where
+    B: iced_graphics::Backend,
+{
+    fn animate(&mut self, output: Self::Output, progress: f32) -> Self::Output {
+        let (primitive, mouse_interaction) = output;
+
+        (slide(primitive, progress), mouse_interaction)
+    }
+}
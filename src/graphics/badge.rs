@@ -1,22 +1,38 @@
 //! Use a badge for color highlighting important information.
 //!
 //! *This API requires the following crate features to be activated: badge*
-use iced_graphics::{defaults, Backend, Color, Defaults, Primitive, Renderer};
+use iced_graphics::{
+    backend, defaults, Backend, Color, Defaults, Font, HorizontalAlignment, Primitive, Rectangle,
+    Renderer, Size, VerticalAlignment,
+};
+use iced_native::mouse;
 
+pub use crate::native::badge::{Anchor, Ellipsis, TooltipState};
 pub use crate::style::badge::{Style, StyleSheet};
 use crate::{core::renderer::DrawEnvironment, native::badge};
 
 /// The ratio of the border radius.
 const BORDER_RADIUS_RATIO: f32 = 34.0 / 15.0;
 
+/// The padding around the text of the tooltip.
+const TOOLTIP_PADDING: f32 = 5.0;
+
+/// The spacing between the badge and its tooltip.
+const TOOLTIP_SPACING: f32 = 2.0;
+
 /// A badge for color highlighting small information.
 ///
-/// This is an alias of an `iced_native` Badge with an `iced_wgpu::Renderer`.
+/// This is an alias of an `iced_native` Badge with an `iced_graphics::Renderer`.
 pub type Badge<'a, Message, Backend> = badge::Badge<'a, Message, Renderer<Backend>>;
 
+/// A badge attached to a corner of another element.
+///
+/// This is an alias of an `iced_native` `BadgeAnchor` with an `iced_graphics::Renderer`.
+pub type BadgeAnchor<'a, Message, Backend> = badge::BadgeAnchor<'a, Message, Renderer<Backend>>;
+
 impl<B> badge::Renderer for Renderer<B>
 where
-    B: Backend,
+    B: Backend + backend::Text,
 {
     type Style = Box<dyn StyleSheet>;
 
@@ -69,4 +85,68 @@ where
             mouse_interaction,
         )
     }
+
+    fn draw_tooltip(
+        &mut self,
+        env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
+        tooltip: Option<&str>,
+    ) -> Self::Output {
+        let tooltip = match tooltip {
+            Some(tooltip) => tooltip,
+            None => return (Primitive::None, mouse::Interaction::default()),
+        };
+
+        let style = env.style_sheet.active();
+        let size = self.backend().default_size();
+        let (width, height) = self.backend().measure(
+            tooltip,
+            f32::from(size),
+            Font::Default,
+            Size::new(f32::INFINITY, f32::INFINITY),
+        );
+
+        let layout_bounds = env.layout.bounds();
+        let bounds = Rectangle {
+            x: layout_bounds.x,
+            y: layout_bounds.y + TOOLTIP_SPACING,
+            width: width + 2.0 * TOOLTIP_PADDING,
+            height: height + 2.0 * TOOLTIP_PADDING,
+        };
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius.unwrap_or(0.0),
+            border_width: style.border_width,
+            border_color: style.border_color.unwrap_or(Color::BLACK),
+        };
+
+        let text = Primitive::Text {
+            content: tooltip.to_owned(),
+            bounds: Rectangle {
+                x: bounds.x + TOOLTIP_PADDING,
+                y: bounds.y + TOOLTIP_PADDING,
+                width,
+                height,
+            },
+            color: style.text_color,
+            size: f32::from(size),
+            font: Font::Default,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![background, text],
+            },
+            mouse::Interaction::default(),
+        )
+    }
 }
+
+/// A text that is truncated with an ellipsis if it does not fit into its
+/// bounds.
+///
+/// This is an alias of an `iced_native` `EllipsisText` with an `iced_graphics::Renderer`.
+pub type EllipsisText<Backend> = badge::EllipsisText<Renderer<Backend>>;
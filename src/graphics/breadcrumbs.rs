@@ -0,0 +1,112 @@
+//! Use breadcrumbs to show the path to the current location, e.g. in a file
+//! manager or settings hierarchy.
+//!
+//! *This API requires the following crate features to be activated: `breadcrumbs`*
+use iced_graphics::{
+    backend, Backend, Color, Font, HorizontalAlignment, Primitive, Rectangle, Renderer, Size,
+    VerticalAlignment,
+};
+use iced_native::mouse;
+
+pub use crate::native::breadcrumbs::Crumb;
+pub use crate::style::breadcrumbs::{Style, StyleSheet};
+use crate::{
+    core::{breadcrumbs::ELLIPSIS, renderer::DrawEnvironment},
+    native::breadcrumbs,
+};
+
+/// A path of clickable segments separated by a divider.
+///
+/// This is an alias of an `iced_native` `Breadcrumbs` with an `iced_graphics::Renderer`.
+pub type Breadcrumbs<'a, T, Message, Backend> =
+    breadcrumbs::Breadcrumbs<'a, T, Message, Renderer<Backend>>;
+
+impl<B> breadcrumbs::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SPACING: u16 = 5;
+
+    fn default_size(&self) -> u16 {
+        self.backend().default_size()
+    }
+
+    fn measure(&self, text: &str, size: u16) -> (f32, f32) {
+        self.backend().measure(
+            text,
+            f32::from(size),
+            Font::Default,
+            Size::new(f32::INFINITY, f32::INFINITY),
+        )
+    }
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        crumbs: &[Crumb],
+        labels: &[String],
+        divider: &str,
+        text_size: u16,
+    ) -> Self::Output {
+        let style = env.style_sheet.active();
+
+        let mut primitives = Vec::new();
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        let text = |content: &str, bounds: Rectangle, color: Color| Primitive::Text {
+            content: content.to_owned(),
+            bounds: Rectangle {
+                y: bounds.center_y(),
+                ..bounds
+            },
+            size: f32::from(text_size),
+            color,
+            font: Font::Default,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Center,
+        };
+
+        let mut children = env.layout.children();
+
+        for (i, crumb) in crumbs.iter().enumerate() {
+            if i > 0 {
+                let divider_bounds = children
+                    .next()
+                    .expect("Graphics: Layout should have a divider layout for Breadcrumbs")
+                    .bounds();
+
+                primitives.push(text(divider, divider_bounds, style.divider_color));
+            }
+
+            let crumb_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a segment layout for Breadcrumbs")
+                .bounds();
+
+            match crumb {
+                Crumb::Segment(index) => {
+                    let color = if *index + 1 == labels.len() {
+                        style.current_text_color
+                    } else if crumb_bounds.contains(env.cursor_position) {
+                        style.hovered_text_color
+                    } else {
+                        style.text_color
+                    };
+
+                    if crumb_bounds.contains(env.cursor_position) {
+                        mouse_interaction = mouse::Interaction::Pointer;
+                    }
+
+                    primitives.push(text(&labels[*index], crumb_bounds, color));
+                }
+                Crumb::Ellipsis => {
+                    primitives.push(text(ELLIPSIS, crumb_bounds, style.divider_color));
+                }
+            }
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
@@ -1,9 +1,11 @@
 //! Displays a [`Card`](Card).
 //!
 //! *This API requires the following crate features to be activated: card*
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use iced_graphics::{
     backend, defaults, Backend, Color, Defaults, HorizontalAlignment, Point, Primitive, Rectangle,
-    Renderer, VerticalAlignment,
+    Renderer, Vector, VerticalAlignment,
 };
 use iced_native::{mouse, Element, Layout};
 
@@ -12,9 +14,18 @@ use crate::{core::renderer::DrawEnvironment, native::card};
 
 /// A card consisting of a head, body and optional foot.
 ///
-/// This is an alias of an `iced_native` Card with an `iced_wgpu::Renderer`.
+/// This is an alias of an `iced_native` Card with an `iced_graphics::Renderer`.
 pub type Card<'a, Message, Backend> = card::Card<'a, Message, Renderer<Backend>>;
 
+/// The duration of a shimmer cycle of the skeleton in milliseconds.
+const SHIMMER_PERIOD: u128 = 1500;
+
+/// The width of the shimmer band of the skeleton.
+const SHIMMER_WIDTH: f32 = 60.0;
+
+/// The border radius of the skeleton.
+const SKELETON_BORDER_RADIUS: f32 = 4.0;
+
 impl<B> card::Renderer for Renderer<B>
 where
     B: Backend + backend::Text,
@@ -23,6 +34,8 @@ where
 
     const DEFAULT_PADDING: f32 = 10.0;
 
+    const SCROLLBAR_WIDTH: f32 = 6.0;
+
     fn default_size(&self) -> f32 {
         f32::from(self.backend().default_size())
     }
@@ -33,6 +46,8 @@ where
         head: &iced_native::Element<'_, Message, Self>,
         body: &iced_native::Element<'_, Message, Self>,
         foot: &Option<iced_native::Element<'_, Message, Self>>,
+        loading: bool,
+        body_offset: Option<f32>,
     ) -> Self::Output {
         let bounds = env.layout.bounds();
         let mut children = env.layout.children();
@@ -67,6 +82,7 @@ where
             env.cursor_position,
             env.viewport.expect("A viewport should exist for Card"),
             &style,
+            loading,
         );
 
         // ----------- Body ----------------------
@@ -80,6 +96,8 @@ where
             env.cursor_position,
             env.viewport.expect("A viewport should exist for Card"),
             &style,
+            loading,
+            body_offset,
         );
 
         // ----------- Foot ----------------------
@@ -93,6 +111,7 @@ where
             env.cursor_position,
             env.viewport.expect("A viewport should exist for Card"),
             &style,
+            loading,
         );
 
         (
@@ -115,6 +134,7 @@ fn draw_head<Message, B>(
     cursor_position: Point,
     viewport: &Rectangle,
     style: &Style,
+    loading: bool,
 ) -> (Primitive, mouse::Interaction)
 where
     B: Backend + backend::Text,
@@ -128,19 +148,32 @@ where
         border_color: Color::TRANSPARENT,
     };
 
-    let (head, head_mouse_interaction) = head.draw(
-        renderer,
-        &Defaults {
-            text: defaults::Text {
-                color: style.head_text_color,
+    let head_content_layout = head_children
+        .next()
+        .expect("Graphics: Layout should have a head content layout");
+
+    let (head, head_mouse_interaction) = if loading {
+        (
+            skeleton(
+                layout.bounds(),
+                head_content_layout.bounds(),
+                style.head_text_color,
+            ),
+            mouse::Interaction::default(),
+        )
+    } else {
+        head.draw(
+            renderer,
+            &Defaults {
+                text: defaults::Text {
+                    color: style.head_text_color,
+                },
             },
-        },
-        head_children
-            .next()
-            .expect("Graphics: Layout should have a head content layout"),
-        cursor_position,
-        viewport,
-    );
+            head_content_layout,
+            cursor_position,
+            viewport,
+        )
+    };
 
     let (close, close_mouse_interaction) = head_children.next().map_or(
         (Primitive::None, mouse::Interaction::default()),
@@ -181,6 +214,10 @@ where
 }
 
 /// Draws the body of the card.
+///
+/// A scrollable body is clipped to its bounds and gets a scrollbar if its
+/// content does not fit into it.
+#[allow(clippy::too_many_arguments)]
 fn draw_body<Message, B>(
     renderer: &mut Renderer<B>,
     body: &Element<'_, Message, Renderer<B>>,
@@ -188,6 +225,8 @@ fn draw_body<Message, B>(
     cursor_position: Point,
     viewport: &Rectangle,
     style: &Style,
+    loading: bool,
+    offset: Option<f32>,
 ) -> (Primitive, mouse::Interaction)
 where
     B: Backend + backend::Text,
@@ -201,23 +240,80 @@ where
         border_color: Color::TRANSPARENT,
     };
 
-    let (body, mouse_interaction) = body.draw(
-        renderer,
-        &Defaults {
-            text: defaults::Text {
-                color: style.body_text_color,
+    let body_content_layout = body_children
+        .next()
+        .expect("Graphics: Layout should have a body content layout");
+
+    let (body, mouse_interaction) = if loading {
+        (
+            skeleton(
+                layout.bounds(),
+                body_content_layout.bounds(),
+                style.body_text_color,
+            ),
+            mouse::Interaction::default(),
+        )
+    } else {
+        let (cursor_position, viewport) = offset.map_or((cursor_position, *viewport), |offset| {
+            (
+                card::scrolled_cursor_position(layout, offset, cursor_position),
+                Rectangle {
+                    y: layout.bounds().y + offset,
+                    ..layout.bounds()
+                },
+            )
+        });
+
+        body.draw(
+            renderer,
+            &Defaults {
+                text: defaults::Text {
+                    color: style.body_text_color,
+                },
             },
-        },
-        body_children
-            .next()
-            .expect("Graphics: Layout should have a body content layout"),
-        cursor_position,
-        viewport,
-    );
+            body_content_layout,
+            cursor_position,
+            &viewport,
+        )
+    };
+
+    let offset = match offset {
+        Some(offset) => offset,
+        None => {
+            return (
+                Primitive::Group {
+                    primitives: vec![body_background, body],
+                },
+                mouse_interaction,
+            )
+        }
+    };
+
+    let scroller = card::scrollbar(
+        layout,
+        offset,
+        <Renderer<B> as card::Renderer>::SCROLLBAR_WIDTH,
+    )
+    .filter(|_| !loading)
+    .map_or(Primitive::None, |scrollbar| Primitive::Quad {
+        bounds: scrollbar.scroller,
+        background: style.scroller_color.into(),
+        border_radius: scrollbar.scroller.width / 2.0,
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    });
 
     (
         Primitive::Group {
-            primitives: vec![body_background, body],
+            primitives: vec![
+                body_background,
+                Primitive::Clip {
+                    bounds: layout.bounds(),
+                    offset: Vector::new(0, offset as u32),
+                    content: Box::new(body),
+                },
+                scroller,
+            ],
         },
         mouse_interaction,
     )
@@ -231,6 +327,7 @@ fn draw_foot<Message, B>(
     cursor_position: Point,
     viewport: &Rectangle,
     style: &Style,
+    loading: bool,
 ) -> (Primitive, mouse::Interaction)
 where
     B: Backend + backend::Text,
@@ -247,19 +344,32 @@ where
     let (foot, foot_mouse_interaction) = foot.as_ref().map_or_else(
         || (Primitive::None, mouse::Interaction::default()),
         |foot| {
-            foot.draw(
-                renderer,
-                &Defaults {
-                    text: defaults::Text {
-                        color: style.foot_text_color,
+            let foot_content_layout = foot_children
+                .next()
+                .expect("Graphics: Layout should have a foot content layout");
+
+            if loading {
+                (
+                    skeleton(
+                        layout.bounds(),
+                        foot_content_layout.bounds(),
+                        style.foot_text_color,
+                    ),
+                    mouse::Interaction::default(),
+                )
+            } else {
+                foot.draw(
+                    renderer,
+                    &Defaults {
+                        text: defaults::Text {
+                            color: style.foot_text_color,
+                        },
                     },
-                },
-                foot_children
-                    .next()
-                    .expect("Graphics: Layout should have a foot content layout"),
-                cursor_position,
-                viewport,
-            )
+                    foot_content_layout,
+                    cursor_position,
+                    viewport,
+                )
+            }
         },
     );
 
@@ -270,3 +380,54 @@ where
         foot_mouse_interaction,
     )
 }
+
+/// Draws a shimmering skeleton in place of the content of a section of the
+/// card.
+///
+/// The skeleton spans the whole width of the section (without its padding)
+/// and the height of the content.
+#[allow(clippy::cast_precision_loss)]
+fn skeleton(section_bounds: Rectangle, content_bounds: Rectangle, color: Color) -> Primitive {
+    let padding = content_bounds.x - section_bounds.x;
+    let bounds = Rectangle {
+        width: (section_bounds.width - 2.0 * padding).max(0.0),
+        ..content_bounds
+    };
+
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis());
+    let phase = (elapsed % SHIMMER_PERIOD) as f32 / SHIMMER_PERIOD as f32;
+
+    let bone = Primitive::Quad {
+        bounds,
+        background: Color { a: 0.1, ..color }.into(),
+        border_radius: SKELETON_BORDER_RADIUS,
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    };
+
+    let shimmer = Primitive::Clip {
+        bounds,
+        offset: Vector::new(0, 0),
+        content: Box::new(Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x - SHIMMER_WIDTH + phase * (bounds.width + SHIMMER_WIDTH),
+                width: SHIMMER_WIDTH,
+                ..bounds
+            },
+            background: Color {
+                a: 0.3,
+                ..Color::WHITE
+            }
+            .into(),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }),
+    };
+
+    Primitive::Group {
+        primitives: vec![bone, shimmer],
+    }
+}
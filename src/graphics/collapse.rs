@@ -0,0 +1,142 @@
+//! Use a collapse to show or hide the bodies of sections by clicking on their
+//! headers.
+//!
+//! *This API requires the following crate features to be activated: `collapse`*
+use iced_graphics::{
+    backend, Backend, Color, HorizontalAlignment, Primitive, Rectangle, Renderer, VerticalAlignment,
+};
+use iced_native::{mouse, Element};
+
+pub use crate::native::collapse::State;
+pub use crate::style::collapse::{Style, StyleSheet};
+use crate::{
+    core::renderer::DrawEnvironment,
+    graphics::icons::{Icon, ICON_FONT},
+    native::collapse,
+};
+
+/// A column of sections with headers showing or hiding their bodies.
+///
+/// This is an alias of an `iced_native` `Collapse` with an `iced_graphics::Renderer`.
+pub type Collapse<'a, Message, Backend> = collapse::Collapse<'a, Message, Renderer<Backend>>;
+
+impl<B> collapse::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 10;
+
+    fn default_size(&self) -> u16 {
+        self.backend().default_size()
+    }
+
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        headers: &[Element<'_, Message, Self>],
+        bodies: &[Element<'_, Message, Self>],
+    ) -> Self::Output {
+        let viewport = env
+            .viewport
+            .expect("Graphics: A viewport should exist for a Collapse");
+
+        let mut primitives = Vec::new();
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        for ((header, body), section) in headers.iter().zip(bodies).zip(env.layout.children()) {
+            let section_bounds = section.bounds();
+            let mut children = section.children();
+
+            let header_layout = children
+                .next()
+                .expect("Graphics: Layout should have a header layout for a Collapse section");
+            let mut header_children = header_layout.children();
+            let indicator_bounds = header_children
+                .next()
+                .expect("Graphics: Layout should have an indicator layout for a Collapse")
+                .bounds();
+            let header_content_layout = header_children
+                .next()
+                .expect("Graphics: Layout should have a header content layout for a Collapse");
+            let body_layout = children.next();
+
+            let is_hovered = header_layout.bounds().contains(env.cursor_position);
+            let style = if is_hovered {
+                mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
+                env.style_sheet.hovered()
+            } else {
+                env.style_sheet.active()
+            };
+
+            primitives.push(Primitive::Quad {
+                bounds: section_bounds,
+                background: style.body_background,
+                border_radius: style.border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+
+            primitives.push(Primitive::Quad {
+                bounds: header_layout.bounds(),
+                background: style.header_background,
+                border_radius: style.border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+
+            let icon = if body_layout.is_some() {
+                Icon::CaretDownFill
+            } else {
+                Icon::CaretRightFill
+            };
+
+            primitives.push(Primitive::Text {
+                content: icon.into(),
+                font: ICON_FONT,
+                size: indicator_bounds.height,
+                bounds: Rectangle {
+                    x: indicator_bounds.center_x(),
+                    y: indicator_bounds.center_y(),
+                    ..indicator_bounds
+                },
+                color: style.indicator_color,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+
+            let (header, header_mouse_interaction) = header.draw(
+                self,
+                env.defaults,
+                header_content_layout,
+                env.cursor_position,
+                viewport,
+            );
+            primitives.push(header);
+            mouse_interaction = mouse_interaction.max(header_mouse_interaction);
+
+            if let Some(body_layout) = body_layout {
+                let (body, body_mouse_interaction) = body.draw(
+                    self,
+                    env.defaults,
+                    body_layout,
+                    env.cursor_position,
+                    viewport,
+                );
+                primitives.push(body);
+                mouse_interaction = mouse_interaction.max(body_mouse_interaction);
+            }
+
+            primitives.push(Primitive::Quad {
+                bounds: section_bounds,
+                background: Color::TRANSPARENT.into(),
+                border_radius: style.border_radius,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            });
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
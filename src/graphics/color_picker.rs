@@ -23,15 +23,156 @@ use crate::{
     },
 };
 
-use crate::native::color_picker;
 pub use crate::native::color_picker::State;
+use crate::native::color_picker::{
+    self,
+    channel_slider::{self, Channel},
+    color_swatch, hsv_selector,
+};
 
 /// An input element for picking colors.
 ///
-/// This is an alias of an `iced_native` `ColorPicker` with an `iced_wgpu::Renderer`.
+/// This is an alias of an `iced_native` `ColorPicker` with an `iced_graphics::Renderer`.
 pub type ColorPicker<'a, Message, Backend> =
     color_picker::ColorPicker<'a, Message, Renderer<Backend>>;
 
+/// A color picker embedded inline into the layout.
+///
+/// This is an alias of an `iced_native` `InlineColorPicker` with an `iced_graphics::Renderer`.
+pub type InlineColorPicker<'a, Message, Backend> =
+    color_picker::InlineColorPicker<'a, Message, Renderer<Backend>>;
+
+/// A small swatch showing a color.
+///
+/// This is an alias of an `iced_native` `ColorSwatch` with an `iced_graphics::Renderer`.
+pub type ColorSwatch<Message, Backend> = color_swatch::ColorSwatch<Message, Renderer<Backend>>;
+
+/// A selector for the hue, saturation and value of a color.
+///
+/// This is an alias of an `iced_native` `HsvSelector` with an `iced_graphics::Renderer`.
+pub type HsvSelector<'a, Message, Backend> =
+    hsv_selector::HsvSelector<'a, Message, Renderer<Backend>>;
+
+/// A slider editing a single channel of a color.
+///
+/// This is an alias of an `iced_native` `ChannelSlider` with an `iced_graphics::Renderer`.
+pub type ChannelSlider<'a, Message, Backend> =
+    channel_slider::ChannelSlider<'a, Message, Renderer<Backend>>;
+
+/// Collects the styles of the given style sheet for the parts of the color
+/// picker drawn by the standalone widgets.
+fn style_map(style_sheet: &dyn StyleSheet) -> HashMap<StyleState, Style> {
+    let mut style = HashMap::new();
+    let _ = style.insert(StyleState::Active, style_sheet.active());
+    let _ = style.insert(StyleState::Hovered, style_sheet.hovered());
+    let _ = style.insert(StyleState::Focused, style_sheet.focused());
+
+    style
+}
+
+impl<B> hsv_selector::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_HEIGHT: u16 = 200;
+
+    const HUE_BAR_HEIGHT: u16 = 20;
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        color: Color,
+        sat_value_canvas_cache: &canvas::Cache,
+        hue_canvas_cache: &canvas::Cache,
+    ) -> Self::Output {
+        hsv_color(
+            env.layout,
+            &color,
+            sat_value_canvas_cache,
+            hue_canvas_cache,
+            env.cursor_position,
+            &style_map(env.style_sheet.as_ref()),
+            Focus::None,
+        )
+    }
+}
+
+impl<B> channel_slider::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_HEIGHT: u16 = 20;
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, bool>,
+        channel: Channel,
+        color: Color,
+    ) -> Self::Output {
+        let value = channel.value(color);
+        let bar_color = match channel {
+            Channel::Red => Color::from_rgb(value, 0.0, 0.0),
+            Channel::Green => Color::from_rgb(0.0, value, 0.0),
+            Channel::Blue => Color::from_rgb(0.0, 0.0, value),
+            Channel::Alpha => Color::from_rgba(0.0, 0.0, 0.0, value),
+        };
+
+        rgba_bar(
+            env.layout,
+            channel.label(),
+            bar_color,
+            value,
+            env.cursor_position,
+            env.defaults,
+            &style_map(env.style_sheet.as_ref()),
+            env.focus,
+        )
+    }
+}
+
+impl<B> color_swatch::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SIZE: u16 = 20;
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        color: Color,
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+        let is_mouse_over = bounds.contains(env.cursor_position);
+
+        let style = if is_mouse_over {
+            env.style_sheet.hovered()
+        } else {
+            env.style_sheet.active()
+        };
+
+        (
+            Primitive::Quad {
+                bounds,
+                background: color.into(),
+                border_radius: style.bar_border_radius,
+                border_width: style.bar_border_width,
+                border_color: style.bar_border_color,
+            },
+            if is_mouse_over {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
+
 impl<B> color_picker::Renderer for Renderer<B>
 where
     B: Backend + backend::Text,
@@ -42,6 +183,8 @@ where
         &mut self,
         env: DrawEnvironment<'_, Self::Defaults, Self::Style, Focus>,
         color: &iced_graphics::Color,
+        hex_input: &str,
+        swatches: &[Color],
         sat_value_canvas_cache: &canvas::Cache,
         hue_canvas_cache: &canvas::Cache,
         //text_input: &iced_native::Element<'_, Message, Self>,
@@ -96,6 +239,8 @@ where
         let (block2, block2_mouse_interaction) = block2(
             self,
             color,
+            hex_input,
+            swatches,
             cancel_button,
             submit_button,
             &DrawEnvironment {
@@ -150,10 +295,14 @@ fn block1(
     (hsv_color, hsv_color_mouse_interaction)
 }
 
-/// Draws the 2. block of the color picker containing the RGBA part, Hex and buttons.
+/// Draws the 2. block of the color picker containing the RGBA part, Hex,
+/// swatches and buttons.
+#[allow(clippy::too_many_arguments)]
 fn block2<Message, B>(
     renderer: &mut Renderer<B>,
     color: &Color,
+    hex_input: &str,
+    swatches: &[Color],
     cancel_button: &iced_native::Element<'_, Message, Renderer<B>>,
     submit_button: &iced_native::Element<'_, Message, Renderer<B>>,
     env: &DrawEnvironment<'_, Defaults, (), Focus>,
@@ -182,15 +331,23 @@ where
     let hex_text_layout = block2_children
         .next()
         .expect("Graphics: Layout should have a hex text layout");
-    let hex_text = hex_text(
+    let (hex_text, hex_text_mouse_interaction) = hex_text(
         hex_text_layout,
         color,
+        hex_input,
         env.cursor_position,
         env.defaults,
         style,
         env.focus,
     );
 
+    // ----------- Swatches ----------------------
+    let swatches_layout = block2_children
+        .next()
+        .expect("Graphics: Layout should have a swatches layout");
+    let (swatches, swatches_mouse_interaction) =
+        self::swatches(swatches_layout, swatches, env.cursor_position, style);
+
     // ----------- Buttons -------------------------
     let cancel_button_layout = block2_children
         .next()
@@ -249,6 +406,7 @@ where
             primitives: vec![
                 rgba_color,
                 hex_text,
+                swatches,
                 cancel_button,
                 submit_button,
                 cancel_button_focus,
@@ -256,6 +414,8 @@ where
             ],
         },
         rgba_color_mouse_interaction
+            .max(hex_text_mouse_interaction)
+            .max(swatches_mouse_interaction)
             .max(cancel_mouse_interaction)
             .max(submit_mouse_interaction),
     )
@@ -453,105 +613,21 @@ fn rgba_color(
 
     let mouse_interaction = mouse::Interaction::default();
 
-    let f = |layout: Layout,
+    let f = |layout: Layout<'_>,
              label: &str,
              color: Color,
              value: f32,
              cursor_position: Point,
-             target: Focus|
-     -> (Primitive, mouse::Interaction) {
-        let mut children = layout.children();
-
-        let mouse_interaction = mouse::Interaction::default();
-
-        let label_layout = children
-            .next()
-            .expect("Graphics: Layout should have a label layout");
-        let bar_layout = children
-            .next()
-            .expect("Graphics: Layout should have a bar layout");
-        let value_layout = children
-            .next()
-            .expect("Graphics: Layout should have a value layout");
-
-        let label = Primitive::Text {
-            content: label.to_owned(),
-            bounds: Rectangle {
-                x: label_layout.bounds().center_x(),
-                y: label_layout.bounds().center_y(),
-                ..label_layout.bounds()
-            },
-            color: defaults.text.color,
-            size: label_layout.bounds().height,
-            font: iced_graphics::Font::default(),
-            horizontal_alignment: iced_graphics::HorizontalAlignment::Center,
-            vertical_alignment: iced_graphics::VerticalAlignment::Center,
-        };
-
-        let bounds = bar_layout.bounds();
-
-        let (bar_style_state, bar_mouse_interaction) =
-            if bar_layout.bounds().contains(cursor_position) {
-                (
-                    StyleState::Hovered,
-                    mouse::Interaction::ResizingHorizontally,
-                )
-            } else {
-                (StyleState::Active, mouse::Interaction::default())
-            };
-
-        let bar_background = Primitive::Quad {
-            bounds: Rectangle {
-                x: bounds.x,
-                y: bounds.y,
-                width: bounds.width * value,
-                height: bounds.height,
-            },
-            background: color.into(),
-            border_radius: style.get(&bar_style_state).unwrap().bar_border_radius,
-            border_width: style.get(&bar_style_state).unwrap().bar_border_width,
-            border_color: Color::TRANSPARENT,
-        };
-
-        let bar = Primitive::Quad {
-            bounds,
-            background: Color::TRANSPARENT.into(),
-            border_radius: style.get(&bar_style_state).unwrap().bar_border_radius,
-            border_width: style.get(&bar_style_state).unwrap().bar_border_width,
-            border_color: style.get(&bar_style_state).unwrap().bar_border_color,
-        };
-
-        let value = Primitive::Text {
-            content: format!("{}", (255.0 * value) as u8),
-            bounds: Rectangle {
-                x: value_layout.bounds().center_x(),
-                y: value_layout.bounds().center_y(),
-                ..value_layout.bounds()
-            },
-            color: defaults.text.color,
-            size: value_layout.bounds().height,
-            font: iced_graphics::Font::default(),
-            horizontal_alignment: iced_graphics::HorizontalAlignment::Center,
-            vertical_alignment: iced_graphics::VerticalAlignment::Center,
-        };
-
-        let focus = if focus == target {
-            Primitive::Quad {
-                bounds: layout.bounds(),
-                background: Color::TRANSPARENT.into(),
-                border_radius: style.get(&StyleState::Focused).unwrap().border_radius,
-                border_width: style.get(&StyleState::Focused).unwrap().border_width,
-                border_color: style.get(&StyleState::Focused).unwrap().border_color,
-            }
-        } else {
-            Primitive::None
-        };
-
-        (
-            Primitive::Group {
-                primitives: vec![label, bar_background, bar, focus, value],
-            },
-            mouse_interaction.max(bar_mouse_interaction),
+             target: Focus| {
+        rgba_bar(
+            layout,
+            label,
+            color,
+            value,
+            cursor_position,
+            defaults,
+            style,
+            focus == target,
         )
     };
 
@@ -623,24 +699,186 @@ fn rgba_color(
     )
 }
 
+/// Draws a bar of a single RGBA channel with its label and value.
+#[allow(clippy::too_many_arguments)]
+fn rgba_bar(
+    layout: Layout<'_>,
+    label: &str,
+    color: Color,
+    value: f32,
+    cursor_position: Point,
+    defaults: &Defaults,
+    style: &HashMap<StyleState, Style>,
+    focused: bool,
+) -> (Primitive, mouse::Interaction) {
+    let mut children = layout.children();
+
+    let mouse_interaction = mouse::Interaction::default();
+
+    let label_layout = children
+        .next()
+        .expect("Graphics: Layout should have a label layout");
+    let bar_layout = children
+        .next()
+        .expect("Graphics: Layout should have a bar layout");
+    let value_layout = children
+        .next()
+        .expect("Graphics: Layout should have a value layout");
+
+    let label = Primitive::Text {
+        content: label.to_owned(),
+        bounds: Rectangle {
+            x: label_layout.bounds().center_x(),
+            y: label_layout.bounds().center_y(),
+            ..label_layout.bounds()
+        },
+        color: defaults.text.color,
+        size: label_layout.bounds().height,
+        font: iced_graphics::Font::default(),
+        horizontal_alignment: iced_graphics::HorizontalAlignment::Center,
+        vertical_alignment: iced_graphics::VerticalAlignment::Center,
+    };
+
+    let bounds = bar_layout.bounds();
+
+    let (bar_style_state, bar_mouse_interaction) = if bar_layout.bounds().contains(cursor_position)
+    {
+        (
+            StyleState::Hovered,
+            mouse::Interaction::ResizingHorizontally,
+        )
+    } else {
+        (StyleState::Active, mouse::Interaction::default())
+    };
+
+    let bar_background = Primitive::Quad {
+        bounds: Rectangle {
+            x: bounds.x,
+            y: bounds.y,
+            width: bounds.width * value,
+            height: bounds.height,
+        },
+        background: color.into(),
+        border_radius: style.get(&bar_style_state).unwrap().bar_border_radius,
+        border_width: style.get(&bar_style_state).unwrap().bar_border_width,
+        border_color: Color::TRANSPARENT,
+    };
+
+    let bar = Primitive::Quad {
+        bounds,
+        background: Color::TRANSPARENT.into(),
+        border_radius: style.get(&bar_style_state).unwrap().bar_border_radius,
+        border_width: style.get(&bar_style_state).unwrap().bar_border_width,
+        border_color: style.get(&bar_style_state).unwrap().bar_border_color,
+    };
+
+    let value = Primitive::Text {
+        content: format!("{}", (255.0 * value) as u8),
+        bounds: Rectangle {
+            x: value_layout.bounds().center_x(),
+            y: value_layout.bounds().center_y(),
+            ..value_layout.bounds()
+        },
+        color: defaults.text.color,
+        size: value_layout.bounds().height,
+        font: iced_graphics::Font::default(),
+        horizontal_alignment: iced_graphics::HorizontalAlignment::Center,
+        vertical_alignment: iced_graphics::VerticalAlignment::Center,
+    };
+
+    let focus = if focused {
+        Primitive::Quad {
+            bounds: layout.bounds(),
+            background: Color::TRANSPARENT.into(),
+            border_radius: style.get(&StyleState::Focused).unwrap().border_radius,
+            border_width: style.get(&StyleState::Focused).unwrap().border_width,
+            border_color: style.get(&StyleState::Focused).unwrap().border_color,
+        }
+    } else {
+        Primitive::None
+    };
+
+    (
+        Primitive::Group {
+            primitives: vec![label, bar_background, bar, focus, value],
+        },
+        mouse_interaction.max(bar_mouse_interaction),
+    )
+}
+
+/// Draws the swatches of the palette and the recently submitted colors.
+fn swatches(
+    layout: Layout<'_>,
+    colors: &[Color],
+    cursor_position: Point,
+    style: &HashMap<StyleState, Style>,
+) -> (Primitive, mouse::Interaction) {
+    let mut mouse_interaction = mouse::Interaction::default();
+
+    let primitives = layout
+        .children()
+        .zip(colors)
+        .map(|(swatch_layout, color)| {
+            let bounds = swatch_layout.bounds();
+            let swatch_style_state = if bounds.contains(cursor_position) {
+                mouse_interaction = mouse::Interaction::Pointer;
+                StyleState::Hovered
+            } else {
+                StyleState::Active
+            };
+
+            Primitive::Quad {
+                bounds,
+                background: (*color).into(),
+                border_radius: style[&swatch_style_state].bar_border_radius,
+                border_width: style[&swatch_style_state].bar_border_width,
+                border_color: style[&swatch_style_state].bar_border_color,
+            }
+        })
+        .collect();
+
+    (Primitive::Group { primitives }, mouse_interaction)
+}
+
 /// Draws the hex text representation of the color.
+///
+/// While the text field is focused, the typed characters are shown instead.
 fn hex_text(
     layout: Layout<'_>,
     color: &Color,
+    hex_input: &str,
     cursor_position: Point,
     _defaults: &Defaults,
     style: &HashMap<StyleState, Style>,
-    _focus: Focus,
-) -> Primitive {
+    focus: Focus,
+) -> (Primitive, mouse::Interaction) {
     let hsv: Hsv = color.clone().into();
 
-    let hex_text_style_state = if layout.bounds().contains(cursor_position) {
-        StyleState::Hovered
+    let (hex_text_style_state, mouse_interaction) = if layout.bounds().contains(cursor_position) {
+        (StyleState::Hovered, mouse::Interaction::Text)
     } else {
-        StyleState::Active
+        (StyleState::Active, mouse::Interaction::default())
     };
 
-    Primitive::Group {
+    let content = if focus == Focus::Hex && !hex_input.is_empty() {
+        format!("#{}", hex_input.to_uppercase())
+    } else {
+        color.to_owned().as_hex_string()
+    };
+
+    let focus = if focus == Focus::Hex {
+        Primitive::Quad {
+            bounds: layout.bounds(),
+            background: Color::TRANSPARENT.into(),
+            border_radius: style[&StyleState::Focused].border_radius,
+            border_width: style[&StyleState::Focused].border_width,
+            border_color: style[&StyleState::Focused].border_color,
+        }
+    } else {
+        Primitive::None
+    };
+
+    let primitive = Primitive::Group {
         primitives: vec![
             Primitive::Quad {
                 bounds: layout.bounds(),
@@ -650,7 +888,7 @@ fn hex_text(
                 border_color: style[&hex_text_style_state].bar_border_color,
             },
             Primitive::Text {
-                content: color.to_owned().as_hex_string(),
+                content,
                 bounds: Rectangle {
                     x: layout.bounds().center_x(),
                     y: layout.bounds().center_y(),
@@ -670,6 +908,9 @@ fn hex_text(
                 horizontal_alignment: iced_graphics::HorizontalAlignment::Center,
                 vertical_alignment: iced_graphics::VerticalAlignment::Center,
             },
+            focus,
         ],
-    }
+    };
+
+    (primitive, mouse_interaction)
 }
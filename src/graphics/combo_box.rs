@@ -0,0 +1,94 @@
+//! Use a combo box to select an option from a list filtered by the text typed
+//! into a text field.
+//!
+//! *This API requires the following crate features to be activated: `combo_box`*
+use iced_graphics::{
+    backend, Backend, Color, HorizontalAlignment, Primitive, Rectangle, Renderer, VerticalAlignment,
+};
+use iced_native::mouse;
+
+pub use crate::native::combo_box::State;
+pub use crate::style::combo_box::{Style, StyleSheet};
+use crate::{
+    core::renderer::DrawEnvironment,
+    native::{combo_box, overlay::combo_box as combo_box_overlay},
+};
+
+/// A text field with a list of suggestions below it, filtered by the typed
+/// text.
+///
+/// This is an alias of an `iced_native` `ComboBox` with an `iced_graphics::Renderer`.
+pub type ComboBox<'a, T, Message, Backend> = combo_box::ComboBox<'a, T, Message, Renderer<Backend>>;
+
+impl<B> combo_box_overlay::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn default_size(&self) -> u16 {
+        self.backend().default_size()
+    }
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, Option<usize>>,
+        labels: &[String],
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+        let style = env.style_sheet.active();
+        let size = self.backend().default_size();
+
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        }];
+
+        for (i, (label, suggestion_layout)) in labels.iter().zip(env.layout.children()).enumerate()
+        {
+            let suggestion_bounds = suggestion_layout.bounds();
+            let is_hovered = env.focus == Some(i);
+
+            if suggestion_bounds.contains(env.cursor_position) {
+                mouse_interaction = mouse::Interaction::Pointer;
+            }
+
+            if is_hovered {
+                primitives.push(Primitive::Quad {
+                    bounds: suggestion_bounds,
+                    background: style.hovered_background,
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            primitives.push(Primitive::Text {
+                content: label.clone(),
+                bounds: Rectangle {
+                    x: suggestion_bounds.x + f32::from(Self::DEFAULT_PADDING),
+                    y: suggestion_bounds.center_y(),
+                    ..suggestion_bounds
+                },
+                size: f32::from(size),
+                color: if is_hovered {
+                    style.hovered_text_color
+                } else {
+                    style.text_color
+                },
+                font: iced_graphics::Font::Default,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
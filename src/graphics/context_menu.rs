@@ -0,0 +1,52 @@
+//! Use a context menu for showing actions on right click.
+//!
+//! *This API requires the following crate features to be activated: context_menu*
+use iced_graphics::{Backend, Primitive, Renderer};
+
+pub use crate::native::context_menu::State;
+pub use crate::style::context_menu::{Style, StyleSheet};
+use crate::{
+    core::renderer::DrawEnvironment,
+    native::{context_menu, overlay::context_menu as context_menu_overlay},
+};
+
+/// A context menu opened on right click.
+///
+/// This is an alias of an `iced_native` ContextMenu with an `iced_graphics::Renderer`.
+pub type ContextMenu<'a, S, Content, Message, Backend> =
+    context_menu::ContextMenu<'a, S, Content, Message, Renderer<Backend>>;
+
+impl<B> context_menu_overlay::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
+        content: &iced_native::Element<'_, Message, Self>,
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+
+        let style = env.style_sheet.active();
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        };
+
+        let (content, mouse_interaction) =
+            content.draw(self, env.defaults, env.layout, env.cursor_position, &bounds);
+
+        (
+            Primitive::Group {
+                primitives: vec![background, content],
+            },
+            mouse_interaction,
+        )
+    }
+}
@@ -4,7 +4,7 @@
 use std::collections::HashMap;
 
 use crate::{
-    core::{date::IsInMonth, renderer::DrawEnvironment},
+    core::renderer::DrawEnvironment,
     style::{date_picker::Style, style_state::StyleState},
 };
 use crate::{native::overlay::date_picker::Focus, style::date_picker::StyleSheet};
@@ -15,27 +15,41 @@ use iced_graphics::{
 };
 use iced_native::{mouse, Element};
 
-use crate::native::date_picker;
-pub use crate::native::date_picker::{Date, State};
+use crate::native::date_picker::{self, calendar, date_picker_input};
+pub use crate::native::date_picker::{Date, Locale, State, Weekday};
 
 use super::icons::{Icon, ICON_FONT};
 
 /// An input element for picking dates.
 ///
-/// This is an alias of an `iced_native` `DatePicker` with an `iced_wgpu::Renderer`.
+/// This is an alias of an `iced_native` `DatePicker` with an `iced_graphics::Renderer`.
 pub type DatePicker<'a, Message, Backend> = date_picker::DatePicker<'a, Message, Renderer<Backend>>;
 
+/// A text field for entering dates, with a button opening a calendar below it.
+///
+/// This is an alias of an `iced_native` `DatePickerInput` with an `iced_graphics::Renderer`.
+pub type DatePickerInput<'a, Message, Backend> =
+    date_picker_input::DatePickerInput<'a, Message, Renderer<Backend>>;
+
+/// A calendar for picking dates, shown inline instead of as an overlay.
+///
+/// This is an alias of an `iced_native` `Calendar` with an `iced_graphics::Renderer`.
+pub type Calendar<'a, Message, Backend> = calendar::Calendar<'a, Message, Renderer<Backend>>;
+
 impl<B> date_picker::Renderer for Renderer<B>
 where
     B: Backend + backend::Text,
 {
     type Style = Box<dyn StyleSheet>;
 
-    #[allow(clippy::too_many_lines)]
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
     fn draw<Message>(
         &mut self,
         env: DrawEnvironment<'_, Self::Defaults, Self::Style, Focus>,
         date: chrono::NaiveDate,
+        range: Option<(chrono::NaiveDate, chrono::NaiveDate)>,
+        is_enabled: &dyn Fn(chrono::NaiveDate) -> bool,
+        locale: &Locale,
         year_str: &str,
         month_str: &str,
         cancel_button: &Element<'_, Message, Self>,
@@ -43,10 +57,6 @@ where
     ) -> Self::Output {
         let bounds = env.layout.bounds();
         let mut children = env.layout.children();
-        let mut date_children = children
-            .next()
-            .expect("Graphics: Layout should have a date layout")
-            .children();
 
         let mut style: HashMap<StyleState, Style> = HashMap::new();
         let _ = style.insert(StyleState::Active, env.style_sheet.active());
@@ -72,31 +82,24 @@ where
             border_color: style[&style_state].border_color,
         };
 
-        // ----------- Year/Month----------------------
-        let month_year_layout = date_children
+        // ----------- Calendar ----------------------
+        let calendar_layout = children
             .next()
-            .expect("Graphics: Layout should have a month/year layout");
-
-        let (month_year, month_year_mouse_interaction) = month_year(
-            month_year_layout,
-            month_str,
+            .expect("Graphics: Layout should have a calendar layout");
+
+        let (calendar, calendar_mouse_interaction) = calendar(
+            calendar_layout,
+            date,
+            range,
+            is_enabled,
+            locale,
             year_str,
+            month_str,
             env.cursor_position,
             &style,
             env.focus,
         );
 
-        // ----------- Days ---------------------------
-        let days_layout = date_children
-            .next()
-            .expect("Graphics: Layout should have a days layout parent")
-            .children()
-            .next()
-            .expect("Graphics: Layout should have a days layout");
-
-        let (days, days_mouse_interaction) =
-            days(days_layout, date, env.cursor_position, &style, env.focus);
-
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
             .next()
@@ -151,8 +154,7 @@ where
             Primitive::Group {
                 primitives: vec![
                     background,
-                    month_year,
-                    days,
+                    calendar,
                     cancel_button,
                     submit_button,
                     cancel_button_focus,
@@ -160,14 +162,179 @@ where
                 ],
             },
             mouse_interaction
-                .max(month_year_mouse_interaction)
-                .max(days_mouse_interaction)
+                .max(calendar_mouse_interaction)
                 .max(cancel_mouse_interaction)
                 .max(submit_mouse_interaction),
         )
     }
 }
 
+impl<B> calendar::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, Focus>,
+        date: chrono::NaiveDate,
+        is_enabled: &dyn Fn(chrono::NaiveDate) -> bool,
+        locale: &Locale,
+        year_str: &str,
+        month_str: &str,
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+
+        let mut style: HashMap<StyleState, Style> = HashMap::new();
+        let _ = style.insert(StyleState::Active, env.style_sheet.active());
+        let _ = style.insert(StyleState::Selected, env.style_sheet.selected());
+        let _ = style.insert(StyleState::Hovered, env.style_sheet.hovered());
+        let _ = style.insert(StyleState::Focused, env.style_sheet.focused());
+
+        let style_state = if bounds.contains(env.cursor_position) {
+            StyleState::Hovered
+        } else {
+            StyleState::Active
+        };
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style[&style_state].background,
+            border_radius: style[&style_state].border_radius,
+            border_width: style[&style_state].border_width,
+            border_color: style[&style_state].border_color,
+        };
+
+        let calendar_layout = env
+            .layout
+            .children()
+            .next()
+            .expect("Graphics: Layout should have a calendar layout");
+
+        let (calendar, calendar_mouse_interaction) = calendar(
+            calendar_layout,
+            date,
+            None,
+            is_enabled,
+            locale,
+            year_str,
+            month_str,
+            env.cursor_position,
+            &style,
+            env.focus,
+        );
+
+        (
+            Primitive::Group {
+                primitives: vec![background, calendar],
+            },
+            calendar_mouse_interaction,
+        )
+    }
+}
+
+impl<B> date_picker_input::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, (), ()>,
+        text_input: &Element<'_, Message, Self>,
+        button: &Element<'_, Message, Self>,
+    ) -> Self::Output {
+        let mut children = env.layout.children();
+        let viewport = env
+            .viewport
+            .expect("Graphics: A viewport should exist for a DatePickerInput");
+
+        let (text_input, text_input_mouse_interaction) = text_input.draw(
+            self,
+            env.defaults,
+            children
+                .next()
+                .expect("Graphics: Layout should have a text input layout for a DatePickerInput"),
+            env.cursor_position,
+            viewport,
+        );
+
+        let (button, button_mouse_interaction) = button.draw(
+            self,
+            env.defaults,
+            children
+                .next()
+                .expect("Graphics: Layout should have a button layout for a DatePickerInput"),
+            env.cursor_position,
+            viewport,
+        );
+
+        (
+            Primitive::Group {
+                primitives: vec![text_input, button],
+            },
+            text_input_mouse_interaction.max(button_mouse_interaction),
+        )
+    }
+}
+
+/// Draws the calendar, i.e. the month/year row and the days table.
+#[allow(clippy::too_many_arguments)]
+fn calendar(
+    layout: iced_native::Layout<'_>,
+    date: chrono::NaiveDate,
+    range: Option<(chrono::NaiveDate, chrono::NaiveDate)>,
+    is_enabled: &dyn Fn(chrono::NaiveDate) -> bool,
+    locale: &Locale,
+    year_str: &str,
+    month_str: &str,
+    cursor_position: iced_graphics::Point,
+    style: &HashMap<StyleState, Style>,
+    focus: Focus,
+) -> (Primitive, mouse::Interaction) {
+    let mut children = layout.children();
+
+    // ----------- Year/Month----------------------
+    let month_year_layout = children
+        .next()
+        .expect("Graphics: Layout should have a month/year layout");
+
+    let (month_year, month_year_mouse_interaction) = month_year(
+        month_year_layout,
+        month_str,
+        year_str,
+        cursor_position,
+        style,
+        focus,
+    );
+
+    // ----------- Days ---------------------------
+    let days_layout = children
+        .next()
+        .expect("Graphics: Layout should have a days layout parent")
+        .children()
+        .next()
+        .expect("Graphics: Layout should have a days layout");
+
+    let (days, days_mouse_interaction) = days(
+        days_layout,
+        date,
+        range,
+        is_enabled,
+        locale,
+        cursor_position,
+        style,
+        focus,
+    );
+
+    (
+        Primitive::Group {
+            primitives: vec![month_year, days],
+        },
+        month_year_mouse_interaction.max(days_mouse_interaction),
+    )
+}
+
 /// Draws the month/year row
 fn month_year(
     layout: iced_native::Layout<'_>,
@@ -293,9 +460,13 @@ fn month_year(
 }
 
 /// Draws the days
+#[allow(clippy::too_many_arguments)]
 fn days(
     layout: iced_native::Layout<'_>,
     date: chrono::NaiveDate,
+    range: Option<(chrono::NaiveDate, chrono::NaiveDate)>,
+    is_enabled: &dyn Fn(chrono::NaiveDate) -> bool,
+    locale: &Locale,
     cursor_position: iced_graphics::Point,
     //style: &Style,
     style: &HashMap<StyleState, Style>,
@@ -306,10 +477,18 @@ fn days(
     let day_labels_layout = children
         .next()
         .expect("Graphics: Layout should have a day labels layout");
-    let labels = day_labels(day_labels_layout, style, focus);
-
-    let (table, table_mouse_interaction) =
-        day_table(&mut children, date, cursor_position, style, focus);
+    let labels = day_labels(day_labels_layout, locale, style, focus);
+
+    let (table, table_mouse_interaction) = day_table(
+        &mut children,
+        date,
+        range,
+        is_enabled,
+        locale.first_weekday,
+        cursor_position,
+        style,
+        focus,
+    );
 
     (
         Primitive::Group {
@@ -322,16 +501,17 @@ fn days(
 /// Draws the day labels
 fn day_labels(
     layout: iced_native::Layout<'_>,
+    locale: &Locale,
     style: &HashMap<StyleState, Style>,
     _focus: Focus,
 ) -> Primitive {
     let mut labels: Vec<Primitive> = Vec::new();
 
-    for (i, label) in layout.children().enumerate() {
+    for (label, content) in layout.children().zip(locale.weekday_labels()) {
         let bounds = label.bounds();
 
         labels.push(Primitive::Text {
-            content: crate::core::date::WEEKDAY_LABELS[i].clone(),
+            content: content.to_owned(),
             bounds: Rectangle {
                 x: bounds.center_x(),
                 y: bounds.center_y(),
@@ -349,9 +529,13 @@ fn day_labels(
 }
 
 /// Draws the day table
+#[allow(clippy::too_many_arguments)]
 fn day_table(
     children: &mut dyn Iterator<Item = iced_native::Layout<'_>>,
     date: chrono::NaiveDate,
+    range: Option<(chrono::NaiveDate, chrono::NaiveDate)>,
+    is_enabled: &dyn Fn(chrono::NaiveDate) -> bool,
+    first_weekday: Weekday,
     cursor_position: iced_graphics::Point,
     style: &HashMap<StyleState, Style>,
     focus: Focus,
@@ -363,18 +547,25 @@ fn day_table(
     for (y, row) in children.enumerate() {
         for (x, label) in row.children().enumerate() {
             let bounds = label.bounds();
-            let (number, is_in_month) =
-                crate::core::date::position_to_day(x, y, date.year(), date.month());
+            let day_date = crate::core::date::position_to_date(x, y, date, first_weekday);
+            let is_in_month = day_date.month() == date.month();
+            let enabled = is_enabled(day_date);
 
-            let mouse_over = bounds.contains(cursor_position);
+            let mouse_over = enabled && bounds.contains(cursor_position);
             if mouse_over {
                 mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
             }
 
-            let selected = date.day() == number as u32 && is_in_month == IsInMonth::Same;
+            let selected = day_date == date;
+            let (range_bound, in_range) = range.map_or((false, false), |(start, end)| {
+                (
+                    day_date == start || day_date == end,
+                    start < day_date && day_date < end,
+                )
+            });
 
             let mut style_state = StyleState::Active;
-            if selected {
+            if selected || range_bound {
                 style_state = style_state.max(StyleState::Selected);
             }
             if mouse_over {
@@ -383,7 +574,11 @@ fn day_table(
 
             primitives.push(Primitive::Quad {
                 bounds,
-                background: style.get(&style_state).unwrap().day_background,
+                background: if in_range && style_state == StyleState::Active {
+                    style.get(&style_state).unwrap().day_range_background
+                } else {
+                    style.get(&style_state).unwrap().day_background
+                },
                 border_radius: bounds.height / 2.0,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
@@ -400,13 +595,13 @@ fn day_table(
             }
 
             primitives.push(Primitive::Text {
-                content: format!("{:02}", number),
+                content: format!("{:02}", day_date.day()),
                 bounds: Rectangle {
                     x: bounds.center_x(),
                     y: bounds.center_y(),
                     ..bounds
                 },
-                color: if is_in_month == IsInMonth::Same {
+                color: if is_in_month && enabled {
                     style.get(&style_state).unwrap().text_color
                 } else {
                     style.get(&style_state).unwrap().text_attenuated_color
@@ -4,10 +4,12 @@
 use iced_graphics::Renderer;
 
 use crate::native::floating_button;
-pub use floating_button::{Anchor, Offset};
+pub use floating_button::{
+    anchor, offset, padding, speed_dial, Anchor, Offset, Padding, SpeedDial,
+};
 
 /// A floating button floating over some content.
 ///
-/// This is an alias of an `iced_native` `FloatingButton` with an `iced_wgpu::Renderer`.
-pub type FloatingButton<'a, B, Message, Backend> =
-    floating_button::FloatingButton<'a, B, Message, Renderer<Backend>>;
+/// This is an alias of an `iced_native` `FloatingButton` with an `iced_graphics::Renderer`.
+pub type FloatingButton<'a, Message, Backend> =
+    floating_button::FloatingButton<'a, Message, Renderer<Backend>>;
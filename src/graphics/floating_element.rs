@@ -0,0 +1,13 @@
+//! Use a floating element to overlay an element over some content
+//!
+//! *This API requires the following crate features to be activated: `floating_element`*
+use iced_graphics::Renderer;
+
+use crate::native::floating_element;
+pub use floating_element::{anchor, offset, padding, Anchor, Offset, Padding};
+
+/// A floating element floating over some content.
+///
+/// This is an alias of an `iced_native` `FloatingElement` with an `iced_graphics::Renderer`.
+pub type FloatingElement<'a, Message, Backend> =
+    floating_element::FloatingElement<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,56 @@
+//! Compile time checks of the renderers for the `iced_glow` backend.
+//!
+//! The renderers of this crate are implemented for the
+//! `iced_graphics::Renderer` of any backend. These checks make sure that
+//! the renderer of `iced_glow` stays supported by every enabled widget.
+//!
+//! *This API requires the following crate features to be activated: `glow`*
+
+/// Fails to compile if the `iced_glow::Renderer` doesn't implement the
+/// `Renderer` trait of the given widget modules.
+macro_rules! assert_glow_renderer {
+    ($($feature:literal => $module:ident),+ $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            const _: fn() = || {
+                /// Requires the given renderer to implement the trait.
+                fn implements<R: crate::native::$module::Renderer>() {}
+                implements::<iced_glow::Renderer>();
+            };
+        )+
+    };
+}
+
+assert_glow_renderer!(
+    "badge" => badge,
+    "breadcrumbs" => breadcrumbs,
+    "card" => card,
+    "collapse" => collapse,
+    "color_picker" => color_picker,
+    "combo_box" => combo_box,
+    "context_menu" => context_menu,
+    "date_picker" => date_picker,
+    "gradient_picker" => gradient_picker,
+    "icon_text" => icon_text,
+    "knob" => knob,
+    "modal" => modal,
+    "number_input" => number_input,
+    "overlay_corner" => overlay_corner,
+    "pagination" => pagination,
+    "popover" => popover,
+    "progress_ring" => progress_ring,
+    "range_slider" => range_slider,
+    "reorderable_list" => reorderable_list,
+    "segmented_button" => segmented_button,
+    "selection_list" => selection_list,
+    "spinner" => spinner,
+    "split" => split,
+    "steps" => steps,
+    "table" => table,
+    "tab_bar" => tab_bar,
+    "tabs" => tabs,
+    "time_picker" => time_picker,
+    "toast" => toast,
+    "tree_view" => tree_view,
+    "wrap" => wrap,
+);
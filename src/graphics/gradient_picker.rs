@@ -0,0 +1,112 @@
+//! Use a gradient picker to edit the color stops of a linear gradient.
+//!
+//! *This API requires the following crate features to be activated: `gradient_picker`*
+use iced_graphics::{Backend, Color, Primitive, Rectangle, Renderer};
+use iced_native::mouse;
+
+pub use crate::native::gradient_picker::{ColorStop, State};
+pub use crate::style::gradient_picker::{Style, StyleSheet};
+use crate::{
+    core::{gradient::color_at, renderer::DrawEnvironment},
+    native::gradient_picker::{self, bar_and_handles, handle_at, handle_bounds},
+};
+
+/// The width of the slices the bar previewing the gradient is drawn with.
+const SLICE_WIDTH: f32 = 2.0;
+
+/// An editor for the color stops of a linear gradient.
+///
+/// This is an alias of an `iced_native` `GradientPicker` with an `iced_graphics::Renderer`.
+pub type GradientPicker<'a, Message, Backend> =
+    gradient_picker::GradientPicker<'a, Message, Renderer<Backend>>;
+
+impl<B> gradient_picker::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_HEIGHT: u16 = 24;
+
+    const HANDLE_SIZE: u16 = 12;
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, Option<usize>>,
+        stops: &[ColorStop],
+    ) -> Self::Output {
+        let (bar, handles) = bar_and_handles(env.layout);
+        let mut mouse_interaction = if bar.contains(env.cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        };
+
+        let active = env.style_sheet.active();
+        let mut primitives = Vec::new();
+
+        // There is no gradient primitive, so the gradient is drawn as thin
+        // slices of solid colors.
+        let mut x = 0.0;
+        while x < bar.width {
+            let width = SLICE_WIDTH.min(bar.width - x);
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bar.x + x,
+                    width,
+                    ..bar
+                },
+                background: color_at(stops, (x + width / 2.0) / bar.width).into(),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+
+            x += SLICE_WIDTH;
+        }
+
+        primitives.push(Primitive::Quad {
+            bounds: bar,
+            background: Color::TRANSPARENT.into(),
+            border_radius: active.bar_border_radius,
+            border_width: active.bar_border_width,
+            border_color: active.bar_border_color,
+        });
+
+        let hovered = handle_at(env.layout, stops, env.focus, env.cursor_position);
+
+        // The selected handle is drawn last to stay on top.
+        let order = (0..stops.len())
+            .filter(|index| Some(*index) != env.focus)
+            .chain(env.focus);
+
+        for index in order {
+            let stop = match stops.get(index) {
+                Some(stop) => stop,
+                None => continue,
+            };
+
+            let style = if hovered == Some(index) {
+                mouse_interaction = mouse::Interaction::Grab;
+                env.style_sheet.hovered()
+            } else {
+                active
+            };
+
+            primitives.push(Primitive::Quad {
+                bounds: handle_bounds(bar, handles, stop.offset),
+                background: stop.color.into(),
+                border_radius: style.handle_border_radius,
+                border_width: style.handle_border_width,
+                border_color: if env.focus == Some(index) {
+                    style.selected_handle_border_color
+                } else {
+                    style.handle_border_color
+                },
+            });
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
@@ -13,7 +13,7 @@ use super::icons::ICON_FONT;
 
 /// Text widget with icon font.
 ///
-/// This is an alias of an `iced_native` `IconText` with an `iced_wgpu::Renderer`.
+/// This is an alias of an `iced_native` `IconText` with an `iced_graphics::Renderer`.
 pub type IconText<Backend> = crate::native::icon_text::IconText<Renderer<Backend>>;
 
 impl<B> icon_text::Renderer for Renderer<B>
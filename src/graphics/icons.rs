@@ -1,5 +1,6 @@
 //! The default icon font of the widgets of this library.
 use iced_graphics::Font;
+use iced_native::{text, Text};
 
 #[cfg(feature = "icons")]
 mod bootstrap;
@@ -25,6 +26,20 @@ pub const ICON_FONT: Font = iced_native::Font::External {
     bytes: include_bytes!("./fonts/required-icons.ttf"),
 };
 
+impl Icon {
+    /// Creates a [`Text`](iced_native::Text) widget showing the icon in the
+    /// [`ICON_FONT`](ICON_FONT), e.g. to be used as the content of a button or
+    /// the label of a tab.
+    #[must_use]
+    pub fn into_text<Renderer>(self) -> Text<Renderer>
+    where
+        Renderer: text::Renderer,
+        Renderer::Font: From<Font>,
+    {
+        Text::new(self).font(ICON_FONT)
+    }
+}
+
 impl From<Icon> for char {
     fn from(icon: Icon) -> Self {
         icon_to_char(icon)
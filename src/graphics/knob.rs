@@ -0,0 +1,130 @@
+//! Use a knob to adjust a value within a range by dragging or scrolling.
+//!
+//! *This API requires the following crate features to be activated: `knob`*
+use std::f32::consts::PI;
+
+use iced_graphics::{
+    canvas::{path::Arc, Frame, LineCap, Path, Stroke},
+    Backend, Point, Primitive, Renderer, Vector,
+};
+use iced_native::mouse;
+
+pub use crate::native::knob::State;
+pub use crate::style::knob::{Style, StyleSheet};
+use crate::{core::renderer::DrawEnvironment, native::knob};
+
+/// The angle of the start of the range, at the bottom left.
+const START_ANGLE: f32 = 0.75 * PI;
+
+/// The angle covered by the arc of the whole range, leaving a gap at the
+/// bottom.
+const SWEEP_ANGLE: f32 = 1.5 * PI;
+
+/// A circular control adjusting a value within a range.
+///
+/// This is an alias of an `iced_native` `Knob` with an `iced_graphics::Renderer`.
+pub type Knob<'a, T, Message, Backend> = knob::Knob<'a, T, Message, Renderer<Backend>>;
+
+impl<B> knob::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SIZE: u16 = 48;
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, bool>,
+        value: f32,
+        detent: Option<f32>,
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+        let is_mouse_over = bounds.contains(env.cursor_position);
+
+        let (style, mouse_interaction) = if env.focus {
+            (env.style_sheet.dragging(), mouse::Interaction::Grabbing)
+        } else if is_mouse_over {
+            (env.style_sheet.hovered(), mouse::Interaction::Grab)
+        } else {
+            (env.style_sheet.active(), mouse::Interaction::default())
+        };
+
+        let mut frame = Frame::new(bounds.size());
+        let center = frame.center();
+        let radius = ((bounds.width.min(bounds.height) - style.arc_width) / 2.0).max(0.0);
+        let knob_radius = (radius - 1.5 * style.arc_width).max(0.0);
+
+        let angle = |percent: f32| START_ANGLE + percent * SWEEP_ANGLE;
+        let arc = |from: f32, to: f32| {
+            Path::new(|builder| {
+                builder.arc(Arc {
+                    center,
+                    radius,
+                    start_angle: angle(from.min(to)),
+                    end_angle: angle(from.max(to)),
+                });
+            })
+        };
+
+        let stroke = Stroke {
+            width: style.arc_width,
+            line_cap: LineCap::Round,
+            ..Stroke::default()
+        };
+
+        frame.stroke(
+            &arc(0.0, 1.0),
+            Stroke {
+                color: style.track_color,
+                ..stroke
+            },
+        );
+
+        frame.stroke(
+            &arc(detent.unwrap_or(0.0), value),
+            Stroke {
+                color: style.value_color,
+                ..stroke
+            },
+        );
+
+        let body = Path::circle(center, knob_radius);
+        frame.fill(&body, style.knob_color);
+        frame.stroke(
+            &body,
+            Stroke {
+                color: style.knob_border_color,
+                width: style.knob_border_width,
+                ..Stroke::default()
+            },
+        );
+
+        let direction = Vector::new(angle(value).cos(), angle(value).sin());
+        let pointer_at = |distance: f32| {
+            Point::new(
+                center.x + direction.x * distance,
+                center.y + direction.y * distance,
+            )
+        };
+
+        frame.stroke(
+            &Path::line(
+                pointer_at(0.4 * knob_radius),
+                pointer_at(0.85 * knob_radius),
+            ),
+            Stroke {
+                color: style.pointer_color,
+                ..stroke
+            },
+        );
+
+        (
+            Primitive::Translate {
+                translation: Vector::new(bounds.x, bounds.y),
+                content: Box::new(frame.into_geometry().into_primitive()),
+            },
+            mouse_interaction,
+        )
+    }
+}
@@ -1,22 +1,71 @@
 //! A module fitting `iced_graphics`.
+//!
+//! The renderers are implemented for the `iced_graphics::Renderer` of any
+//! [`Backend`](iced_graphics::Backend), like the ones of `iced_wgpu` and
+//! `iced_glow`. With the `glow` feature enabled, the support of the
+//! `iced_glow` renderer is checked at compile time.
 
 pub mod icons;
 
+#[cfg(feature = "glow")]
+mod glow;
+
+#[cfg(any(
+    feature = "card",
+    feature = "color_picker",
+    feature = "date_picker",
+    feature = "modal",
+    feature = "tab_bar",
+    feature = "time_picker"
+))]
+mod accessibility;
+
+#[cfg(any(
+    feature = "color_picker",
+    feature = "date_picker",
+    feature = "modal",
+    feature = "time_picker"
+))]
+mod animated;
+
+#[cfg(any(feature = "progress_ring", feature = "spinner"))]
+mod ring;
+
 #[cfg(feature = "badge")]
 pub mod badge;
 #[cfg(feature = "badge")]
 pub use badge::Badge;
 
+#[cfg(feature = "breadcrumbs")]
+pub mod breadcrumbs;
+#[cfg(feature = "breadcrumbs")]
+pub use breadcrumbs::Breadcrumbs;
+
 #[cfg(feature = "card")]
 pub mod card;
 #[cfg(feature = "card")]
 pub use card::Card;
 
+#[cfg(feature = "collapse")]
+pub mod collapse;
+#[cfg(feature = "collapse")]
+pub use collapse::Collapse;
+
 #[cfg(feature = "color_picker")]
 pub mod color_picker;
 #[cfg(feature = "color_picker")]
 pub use color_picker::ColorPicker;
 
+#[cfg(feature = "combo_box")]
+pub mod combo_box;
+#[cfg(feature = "combo_box")]
+pub use combo_box::ComboBox;
+
+#[cfg(feature = "context_menu")]
+pub mod context_menu;
+#[cfg(feature = "context_menu")]
+pub use context_menu::ContextMenu;
+
 #[cfg(feature = "date_picker")]
 pub mod date_picker;
 #[cfg(feature = "date_picker")]
@@ -27,15 +76,95 @@ pub mod floating_button;
 #[cfg(feature = "floating_button")]
 pub use floating_button::FloatingButton;
 
+#[cfg(feature = "floating_element")]
+pub mod floating_element;
+#[cfg(feature = "floating_element")]
+pub use floating_element::FloatingElement;
+
+#[cfg(feature = "gradient_picker")]
+pub mod gradient_picker;
+#[cfg(feature = "gradient_picker")]
+pub use gradient_picker::GradientPicker;
+
 #[cfg(feature = "icon_text")]
 pub mod icon_text;
 #[cfg(feature = "icon_text")]
 pub use icon_text::IconText;
 
+#[cfg(feature = "knob")]
+pub mod knob;
+#[cfg(feature = "knob")]
+pub use knob::Knob;
+
 #[cfg(feature = "modal")]
 pub mod modal;
 #[cfg(feature = "modal")]
-pub use modal::Modal;
+pub use modal::{Modal, ModalStack};
+
+#[cfg(feature = "number_input")]
+pub mod number_input;
+#[cfg(feature = "number_input")]
+pub use number_input::NumberInput;
+
+#[cfg(feature = "overlay_corner")]
+pub mod overlay_corner;
+#[cfg(feature = "overlay_corner")]
+pub use overlay_corner::OverlayCorner;
+
+#[cfg(feature = "pagination")]
+pub mod pagination;
+#[cfg(feature = "pagination")]
+pub use pagination::Pagination;
+
+#[cfg(feature = "popover")]
+pub mod popover;
+#[cfg(feature = "popover")]
+pub use popover::Popover;
+
+#[cfg(feature = "progress_ring")]
+pub mod progress_ring;
+#[cfg(feature = "progress_ring")]
+pub use progress_ring::ProgressRing;
+
+#[cfg(feature = "range_slider")]
+pub mod range_slider;
+#[cfg(feature = "range_slider")]
+pub use range_slider::RangeSlider;
+
+#[cfg(feature = "reorderable_list")]
+pub mod reorderable_list;
+#[cfg(feature = "reorderable_list")]
+pub use reorderable_list::ReorderableList;
+
+#[cfg(feature = "segmented_button")]
+pub mod segmented_button;
+#[cfg(feature = "segmented_button")]
+pub use segmented_button::SegmentedButton;
+
+#[cfg(feature = "selection_list")]
+pub mod selection_list;
+#[cfg(feature = "selection_list")]
+pub use selection_list::SelectionList;
+
+#[cfg(feature = "spinner")]
+pub mod spinner;
+#[cfg(feature = "spinner")]
+pub use spinner::Spinner;
+
+#[cfg(feature = "split")]
+pub mod split;
+#[cfg(feature = "split")]
+pub use split::Split;
+
+#[cfg(feature = "steps")]
+pub mod steps;
+#[cfg(feature = "steps")]
+pub use steps::Steps;
+
+#[cfg(feature = "table")]
+pub mod table;
+#[cfg(feature = "table")]
+pub use table::Table;
 
 #[cfg(feature = "tab_bar")]
 pub mod tab_bar;
@@ -53,3 +182,18 @@ pub mod time_picker;
 #[doc(no_inline)]
 #[cfg(feature = "time_picker")]
 pub use time_picker::TimePicker;
+
+#[cfg(feature = "toast")]
+pub mod toast;
+#[cfg(feature = "toast")]
+pub use toast::Toast;
+
+#[cfg(feature = "tree_view")]
+pub mod tree_view;
+#[cfg(feature = "tree_view")]
+pub use tree_view::TreeView;
+
+#[cfg(feature = "wrap")]
+pub mod wrap;
+#[cfg(feature = "wrap")]
+pub use wrap::Wrap;
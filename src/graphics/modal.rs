@@ -2,18 +2,30 @@
 //!
 //! *This API requires the following crate features to be activated: badge*
 
-use iced_graphics::{Backend, Color, Primitive, Renderer};
+use iced_graphics::{Backend, Background, Color, Point, Primitive, Renderer, Vector};
+use iced_native::mouse;
 
+use super::animated;
 pub use crate::native::modal::State;
 pub use crate::style::modal::{Style, StyleSheet};
 use crate::{core::renderer::DrawEnvironment, native::modal};
 
 /// A modal content as an overlay.
 ///
-/// This is an alias of an `iced_native` Modal with an `iced_wgpu::Renderer`.
+/// This is an alias of an `iced_native` Modal with an `iced_graphics::Renderer`.
 pub type Modal<'a, State, Content, Message, Backend> =
     modal::Modal<'a, State, Content, Message, Renderer<Backend>>;
 
+/// A stack of modal contents as overlays.
+///
+/// This is an alias of an `iced_native` `ModalStack` with an `iced_graphics::Renderer`.
+pub type ModalStack<'a, Message, Backend> = modal::ModalStack<'a, Message, Renderer<Backend>>;
+
+/// A layer of a [`ModalStack`](ModalStack).
+///
+/// This is an alias of an `iced_native` `ModalLayer` with an `iced_graphics::Renderer`.
+pub type ModalLayer<'a, Message, Backend> = modal::ModalLayer<'a, Message, Renderer<Backend>>;
+
 impl<B> modal::Renderer for Renderer<B>
 where
     B: Backend,
@@ -24,14 +36,21 @@ where
         &mut self,
         env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
         modal: &iced_native::Element<'_, Message, Self>,
+        visibility: f32,
     ) -> Self::Output {
         let bounds = env.layout.bounds();
 
         let style = env.style_sheet.active();
 
+        // The backdrop fades in while the content slides into place.
+        let Background::Color(color) = style.background;
         let background = Primitive::Quad {
             bounds,
-            background: style.background,
+            background: Color {
+                a: color.a * visibility,
+                ..color
+            }
+            .into(),
             border_radius: 0.0,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
@@ -42,9 +61,52 @@ where
 
         (
             Primitive::Group {
-                primitives: vec![background, modal],
+                primitives: vec![background, animated::slide(modal, visibility)],
             },
             mouse_interaction,
         )
     }
+
+    fn draw_stack<Message>(
+        &mut self,
+        env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
+        layers: &[&iced_native::Element<'_, Message, Self>],
+    ) -> Self::Output {
+        let mut primitives = Vec::with_capacity(layers.len());
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        for (index, (layer, layout)) in layers.iter().zip(env.layout.children()).enumerate() {
+            // The layers below the topmost one are covered by its backdrop.
+            let cursor_position = if index + 1 == layers.len() {
+                env.cursor_position
+            } else {
+                Point::new(-1.0, -1.0)
+            };
+
+            let (primitive, new_mouse_interaction) = modal::Renderer::draw(
+                self,
+                DrawEnvironment {
+                    defaults: env.defaults,
+                    layout,
+                    cursor_position,
+                    style_sheet: env.style_sheet,
+                    viewport: None,
+                    focus: (),
+                },
+                layer,
+                1.0,
+            );
+
+            // Clipping puts every layer into its own render layer, so the text
+            // of a lower layer is not drawn on top of the backdrop of a higher one.
+            primitives.push(Primitive::Clip {
+                bounds: layout.bounds(),
+                offset: Vector::new(0, 0),
+                content: Box::new(primitive),
+            });
+            mouse_interaction = new_mouse_interaction;
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
 }
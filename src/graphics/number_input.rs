@@ -0,0 +1,73 @@
+//! A text field for entering numbers, with buttons to increase and decrease
+//! the value.
+//!
+//! *This API requires the following crate features to be activated: `number_input`*
+use iced_graphics::{backend, Backend, Primitive, Renderer};
+use iced_native::Element;
+
+use crate::{core::renderer::DrawEnvironment, native::number_input};
+pub use number_input::State;
+
+/// A text field for entering numbers, with buttons to increase and decrease
+/// the value.
+///
+/// This is an alias of an `iced_native` `NumberInput` with an `iced_graphics::Renderer`.
+pub type NumberInput<'a, T, Message, Backend> =
+    number_input::NumberInput<'a, T, Message, Renderer<Backend>>;
+
+impl<B> number_input::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, (), ()>,
+        text_input: &Element<'_, Message, Self>,
+        decrease_button: &Element<'_, Message, Self>,
+        increase_button: &Element<'_, Message, Self>,
+    ) -> Self::Output {
+        let mut children = env.layout.children();
+        let viewport = env
+            .viewport
+            .expect("Graphics: A viewport should exist for a NumberInput");
+
+        let (text_input, text_input_mouse_interaction) = text_input.draw(
+            self,
+            env.defaults,
+            children
+                .next()
+                .expect("Graphics: Layout should have a text input layout for a NumberInput"),
+            env.cursor_position,
+            viewport,
+        );
+
+        let (decrease_button, decrease_button_mouse_interaction) = decrease_button.draw(
+            self,
+            env.defaults,
+            children
+                .next()
+                .expect("Graphics: Layout should have a decrease button layout for a NumberInput"),
+            env.cursor_position,
+            viewport,
+        );
+
+        let (increase_button, increase_button_mouse_interaction) = increase_button.draw(
+            self,
+            env.defaults,
+            children
+                .next()
+                .expect("Graphics: Layout should have an increase button layout for a NumberInput"),
+            env.cursor_position,
+            viewport,
+        );
+
+        (
+            Primitive::Group {
+                primitives: vec![text_input, decrease_button, increase_button],
+            },
+            text_input_mouse_interaction
+                .max(decrease_button_mouse_interaction)
+                .max(increase_button_mouse_interaction),
+        )
+    }
+}
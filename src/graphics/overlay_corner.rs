@@ -0,0 +1,52 @@
+//! Use an overlay corner to pin a small element to a corner of the window.
+//!
+//! *This API requires the following crate features to be activated: `overlay_corner`*
+use iced_graphics::{Backend, Primitive, Rectangle, Renderer, Vector};
+use iced_native::mouse;
+
+use crate::native::overlay_corner;
+pub use overlay_corner::{Anchor, Offset};
+
+/// An element pinned to a corner of the window.
+///
+/// This is an alias of an `iced_native` `OverlayCorner` with an `iced_graphics::Renderer`.
+pub type OverlayCorner<'a, Message, Backend> =
+    overlay_corner::OverlayCorner<'a, Message, Renderer<Backend>>;
+
+impl<B> overlay_corner::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn stack(
+        &mut self,
+        underlay: Self::Output,
+        element: Self::Output,
+        bounds: Rectangle,
+    ) -> Self::Output {
+        let (underlay, underlay_mouse_interaction) = underlay;
+        let (element, element_mouse_interaction) = element;
+
+        let mouse_interaction = if element_mouse_interaction == mouse::Interaction::Idle {
+            underlay_mouse_interaction
+        } else {
+            element_mouse_interaction
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    underlay,
+                    // Clipping puts the pinned element into its own render
+                    // layer, so the text of the underlay is not drawn on top
+                    // of it.
+                    Primitive::Clip {
+                        bounds,
+                        offset: Vector::new(0, 0),
+                        content: Box::new(element),
+                    },
+                ],
+            },
+            mouse_interaction,
+        )
+    }
+}
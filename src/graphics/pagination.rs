@@ -0,0 +1,179 @@
+//! Use a pagination to navigate between the pages of some content.
+//!
+//! *This API requires the following crate features to be activated: `pagination`*
+use iced_graphics::{
+    backend, Backend, Color, Font, HorizontalAlignment, Primitive, Rectangle, Renderer, Size,
+    VerticalAlignment,
+};
+use iced_native::mouse;
+
+pub use crate::native::pagination::Page;
+pub use crate::style::pagination::{Style, StyleSheet};
+use crate::{
+    core::{
+        pagination::{compact_label, ELLIPSIS},
+        renderer::DrawEnvironment,
+    },
+    graphics::icons::{Icon, ICON_FONT},
+    native::pagination,
+};
+
+/// A row of buttons to navigate between pages, framed by buttons to go to the
+/// previous and the next page.
+///
+/// This is an alias of an `iced_native` `Pagination` with an `iced_graphics::Renderer`.
+pub type Pagination<Message, Backend> = pagination::Pagination<Message, Renderer<Backend>>;
+
+impl<B> pagination::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SPACING: u16 = 5;
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn default_size(&self) -> u16 {
+        self.backend().default_size()
+    }
+
+    fn measure(&self, text: &str, size: u16) -> (f32, f32) {
+        self.backend().measure(
+            text,
+            f32::from(size),
+            Font::Default,
+            Size::new(f32::INFINITY, f32::INFINITY),
+        )
+    }
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        items: &[Page],
+        current: usize,
+        count: usize,
+        compact: bool,
+        text_size: u16,
+    ) -> Self::Output {
+        let mut children = env.layout.children();
+        let previous_bounds = children
+            .next()
+            .expect("Graphics: Layout should have a previous button layout for a Pagination")
+            .bounds();
+        let next_bounds = env
+            .layout
+            .children()
+            .last()
+            .expect("Graphics: Layout should have a next button layout for a Pagination")
+            .bounds();
+
+        let label =
+            |bounds: Rectangle, content: String, font: Font, color: Color| Primitive::Text {
+                content,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: f32::from(text_size),
+                color,
+                font,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            };
+
+        let button = |bounds: Rectangle,
+                      content: String,
+                      font: Font,
+                      is_enabled: bool,
+                      is_selected: bool| {
+            let is_hovered = is_enabled && bounds.contains(env.cursor_position);
+
+            let style = if !is_enabled {
+                env.style_sheet.disabled()
+            } else if is_hovered {
+                env.style_sheet.hovered(is_selected)
+            } else {
+                env.style_sheet.active(is_selected)
+            };
+
+            let primitive = Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds,
+                        background: style.background,
+                        border_radius: style.border_radius,
+                        border_width: style.border_width,
+                        border_color: style.border_color,
+                    },
+                    label(bounds, content, font, style.text_color),
+                ],
+            };
+
+            (primitive, is_hovered)
+        };
+
+        let text_color = env.style_sheet.active(false).text_color;
+        let mut buttons = vec![button(
+            previous_bounds,
+            Icon::ChevronLeft.into(),
+            ICON_FONT,
+            current > 0,
+            false,
+        )];
+
+        if compact {
+            if let Some(layout) = children.next() {
+                buttons.push((
+                    label(
+                        layout.bounds(),
+                        compact_label(current, count),
+                        Font::Default,
+                        text_color,
+                    ),
+                    false,
+                ));
+            }
+        }
+
+        buttons.extend(items.iter().zip(children).map(|(item, layout)| match item {
+            Page::Number(page) => button(
+                layout.bounds(),
+                (page + 1).to_string(),
+                Font::Default,
+                true,
+                *page == current,
+            ),
+            Page::Ellipsis => (
+                label(
+                    layout.bounds(),
+                    ELLIPSIS.to_owned(),
+                    Font::Default,
+                    text_color,
+                ),
+                false,
+            ),
+        }));
+
+        buttons.push(button(
+            next_bounds,
+            Icon::ChevronRight.into(),
+            ICON_FONT,
+            current + 1 < count,
+            false,
+        ));
+
+        let mouse_interaction = if buttons.iter().any(|(_, is_hovered)| *is_hovered) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        };
+        let primitives = buttons
+            .into_iter()
+            .map(|(primitive, _)| primitive)
+            .collect();
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
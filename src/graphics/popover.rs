@@ -0,0 +1,52 @@
+//! Use a popover for showing a floating panel next to a target element.
+//!
+//! *This API requires the following crate features to be activated: popover*
+use iced_graphics::{Backend, Primitive, Renderer};
+
+pub use crate::native::popover::{Placement, State, Trigger};
+pub use crate::style::popover::{Style, StyleSheet};
+use crate::{
+    core::renderer::DrawEnvironment,
+    native::{overlay::popover as popover_overlay, popover},
+};
+
+/// A popover showing a floating panel anchored to a target element.
+///
+/// This is an alias of an `iced_native` Popover with an `iced_graphics::Renderer`.
+pub type Popover<'a, S, Content, Message, Backend> =
+    popover::Popover<'a, S, Content, Message, Renderer<Backend>>;
+
+impl<B> popover_overlay::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
+        content: &iced_native::Element<'_, Message, Self>,
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+
+        let style = env.style_sheet.active();
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        };
+
+        let (content, mouse_interaction) =
+            content.draw(self, env.defaults, env.layout, env.cursor_position, &bounds);
+
+        (
+            Primitive::Group {
+                primitives: vec![background, content],
+            },
+            mouse_interaction,
+        )
+    }
+}
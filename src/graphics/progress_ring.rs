@@ -0,0 +1,72 @@
+//! Use a progress ring to show the progress of some work as an arc around a
+//! circle.
+//!
+//! *This API requires the following crate features to be activated: `progress_ring`*
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use iced_graphics::{defaults, Backend, Defaults, Primitive, Renderer};
+use iced_native::{mouse, Element};
+
+pub use crate::style::progress_ring::{Style, StyleSheet};
+use crate::{core::renderer::DrawEnvironment, graphics::ring::ring, native::progress_ring};
+
+/// A determinate progress indicator drawing an arc around a circle.
+///
+/// This is an alias of an `iced_native` `ProgressRing` with an `iced_graphics::Renderer`.
+pub type ProgressRing<'a, Message, Backend> =
+    progress_ring::ProgressRing<'a, Message, Renderer<Backend>>;
+
+impl<B> progress_ring::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SIZE: u16 = 40;
+
+    const DEFAULT_STROKE_WIDTH: f32 = 4.0;
+
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        progress: f32,
+        stroke_width: f32,
+        label: Option<&Element<'_, Message, Self>>,
+    ) -> Self::Output {
+        let style = env.style_sheet.active();
+
+        // The arc starts at the top, i.e. a quarter turn before the angle 0.
+        let start_angle = -FRAC_PI_2;
+        let ring = ring(
+            env.layout.bounds(),
+            stroke_width,
+            style.track_color,
+            style.color,
+            start_angle,
+            start_angle + progress * 2.0 * PI,
+        );
+
+        let (label, mouse_interaction) = match (label, env.layout.children().next()) {
+            (Some(label), Some(layout)) => label.draw(
+                self,
+                &Defaults {
+                    text: defaults::Text {
+                        color: style.text_color,
+                    },
+                },
+                layout,
+                env.cursor_position,
+                env.viewport
+                    .expect("Graphics: A viewport should exist for ProgressRing"),
+            ),
+            _ => (Primitive::None, mouse::Interaction::default()),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![ring, label],
+            },
+            mouse_interaction,
+        )
+    }
+}
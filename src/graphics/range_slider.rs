@@ -0,0 +1,117 @@
+//! Use a range slider to select an interval of values with two handles.
+//!
+//! *This API requires the following crate features to be activated: `range_slider`*
+use std::ops::RangeInclusive;
+
+use iced_graphics::{Backend, Color, Primitive, Rectangle, Renderer};
+use iced_native::mouse;
+
+pub use crate::native::range_slider::{Handle, State};
+pub use crate::style::range_slider::{Style, StyleSheet};
+use crate::{core::renderer::DrawEnvironment, native::range_slider};
+
+/// The height of the tick marks.
+const TICK_HEIGHT: f32 = 6.0;
+
+/// A slider with two handles on a single rail to select an interval of
+/// values.
+///
+/// This is an alias of an `iced_native` `RangeSlider` with an `iced_graphics::Renderer`.
+pub type RangeSlider<'a, T, Message, Backend> =
+    range_slider::RangeSlider<'a, T, Message, Renderer<Backend>>;
+
+impl<B> range_slider::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_HEIGHT: u16 = 22;
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, Option<Handle>>,
+        value: RangeInclusive<f32>,
+        ticks: &[f32],
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+        let is_hovered = bounds.contains(env.cursor_position);
+
+        let style = if env.focus.is_some() {
+            env.style_sheet.dragging()
+        } else if is_hovered {
+            env.style_sheet.hovered()
+        } else {
+            env.style_sheet.active()
+        };
+
+        let rail_y = bounds.center_y() - style.rail_width / 2.0;
+        let start_x = bounds.x + value.start() * bounds.width;
+        let end_x = bounds.x + value.end() * bounds.width;
+
+        let mut primitives: Vec<Primitive> = ticks
+            .iter()
+            .map(|tick| Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + tick * bounds.width - 0.5,
+                    y: bounds.center_y() + style.rail_width / 2.0,
+                    width: 1.0,
+                    height: TICK_HEIGHT,
+                },
+                background: style.tick_color.into(),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            })
+            .collect();
+
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x,
+                y: rail_y,
+                width: bounds.width,
+                height: style.rail_width,
+            },
+            background: style.rail_color.into(),
+            border_radius: style.rail_width / 2.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        });
+
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                x: start_x,
+                y: rail_y,
+                width: end_x - start_x,
+                height: style.rail_width,
+            },
+            background: style.range_color.into(),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        });
+
+        primitives.extend([start_x, end_x].iter().map(|x| Primitive::Quad {
+            bounds: Rectangle {
+                x: x - style.handle_radius,
+                y: bounds.center_y() - style.handle_radius,
+                width: 2.0 * style.handle_radius,
+                height: 2.0 * style.handle_radius,
+            },
+            background: style.handle_color.into(),
+            border_radius: style.handle_radius,
+            border_width: style.handle_border_width,
+            border_color: style.handle_border_color,
+        }));
+
+        let mouse_interaction = if env.focus.is_some() {
+            mouse::Interaction::Grabbing
+        } else if is_hovered {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        };
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
@@ -0,0 +1,109 @@
+//! Use a reorderable list to let the user rearrange elements by dragging them.
+//!
+//! *This API requires the following crate features to be activated: `reorderable_list`*
+use iced_graphics::{Backend, Color, Primitive, Rectangle, Renderer};
+use iced_native::{mouse, Element};
+
+pub use crate::native::reorderable_list::State;
+pub use crate::style::reorderable_list::{Style, StyleSheet};
+use crate::{core::renderer::DrawEnvironment, native::reorderable_list};
+
+/// A column of elements that can be rearranged by dragging them with the
+/// mouse.
+///
+/// This is an alias of an `iced_native` `ReorderableList` with an `iced_graphics::Renderer`.
+pub type ReorderableList<'a, Message, Backend> =
+    reorderable_list::ReorderableList<'a, Message, Renderer<Backend>>;
+
+impl<B> reorderable_list::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, Option<(usize, f32)>>,
+        elements: &[Element<'_, Message, Self>],
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+        let viewport = env
+            .viewport
+            .expect("Graphics: A viewport should exist for a ReorderableList");
+        let style = env.style_sheet.active();
+
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        let mut primitives: Vec<Primitive> = elements
+            .iter()
+            .zip(env.layout.children())
+            .enumerate()
+            .map(|(index, (element, layout))| {
+                // The dragged element is drawn by the overlay, leaving a
+                // placeholder in the list.
+                if env.focus.map_or(false, |(dragged, _)| dragged == index) {
+                    return Primitive::Quad {
+                        bounds: layout.bounds(),
+                        background: style.placeholder_background,
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    };
+                }
+
+                let (primitive, new_mouse_interaction) =
+                    element.draw(self, env.defaults, layout, env.cursor_position, viewport);
+
+                mouse_interaction = mouse_interaction.max(new_mouse_interaction);
+
+                primitive
+            })
+            .collect();
+
+        if let Some((_, indicator)) = env.focus {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: indicator - style.indicator_width / 2.0,
+                    width: bounds.width,
+                    height: style.indicator_width,
+                },
+                background: style.indicator_color.into(),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+
+            mouse_interaction = mouse::Interaction::Grabbing;
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+
+    fn draw_dragged<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        element: &Element<'_, Message, Self>,
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+        let style = env.style_sheet.active();
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.dragged_background,
+            border_radius: style.dragged_border_radius,
+            border_width: style.dragged_border_width,
+            border_color: style.dragged_border_color,
+        };
+
+        let (content, _) =
+            element.draw(self, env.defaults, env.layout, env.cursor_position, &bounds);
+
+        (
+            Primitive::Group {
+                primitives: vec![background, content],
+            },
+            mouse::Interaction::Grabbing,
+        )
+    }
+}
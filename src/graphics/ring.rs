@@ -0,0 +1,59 @@
+//! Draw a ring with an arc on top of it, shared by the spinner and the
+//! progress ring.
+use iced_graphics::{
+    canvas::{path::Arc, Frame, LineCap, Path, Stroke},
+    Color, Primitive, Rectangle, Vector,
+};
+
+/// Draws a circle filling the given bounds in the track color and the arc
+/// between the given angles on top of it in the arc color.
+///
+/// The arc is left out if it is empty.
+pub(crate) fn ring(
+    bounds: Rectangle,
+    stroke_width: f32,
+    track_color: Color,
+    arc_color: Color,
+    start_angle: f32,
+    end_angle: f32,
+) -> Primitive {
+    let mut frame = Frame::new(bounds.size());
+    let center = frame.center();
+    let radius = ((bounds.width.min(bounds.height) - stroke_width) / 2.0).max(0.0);
+
+    let stroke = Stroke {
+        width: stroke_width,
+        line_cap: LineCap::Round,
+        ..Stroke::default()
+    };
+
+    frame.stroke(
+        &Path::circle(center, radius),
+        Stroke {
+            color: track_color,
+            ..stroke
+        },
+    );
+
+    if end_angle > start_angle {
+        frame.stroke(
+            &Path::new(|builder| {
+                builder.arc(Arc {
+                    center,
+                    radius,
+                    start_angle,
+                    end_angle,
+                });
+            }),
+            Stroke {
+                color: arc_color,
+                ..stroke
+            },
+        );
+    }
+
+    Primitive::Translate {
+        translation: Vector::new(bounds.x, bounds.y),
+        content: Box::new(frame.into_geometry().into_primitive()),
+    }
+}
@@ -0,0 +1,119 @@
+//! Use a segmented button to select one of a few mutually exclusive options.
+//!
+//! *This API requires the following crate features to be activated: `segmented_button`*
+use iced_graphics::{
+    backend, Backend, Color, Font, HorizontalAlignment, Primitive, Rectangle, Renderer, Size,
+    VerticalAlignment,
+};
+use iced_native::mouse;
+
+pub use crate::style::segmented_button::{Style, StyleSheet};
+use crate::{core::renderer::DrawEnvironment, native::segmented_button};
+
+/// The width of the dividers between the segments.
+const DIVIDER_WIDTH: f32 = 1.0;
+
+/// A horizontal group of joined buttons of which exactly one can be selected.
+///
+/// This is an alias of an `iced_native` `SegmentedButton` with an `iced_graphics::Renderer`.
+pub type SegmentedButton<'a, T, Message, Backend> =
+    segmented_button::SegmentedButton<'a, T, Message, Renderer<Backend>>;
+
+impl<B> segmented_button::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn default_size(&self) -> u16 {
+        self.backend().default_size()
+    }
+
+    fn measure(&self, label: &str, size: u16, bounds: Size) -> (f32, f32) {
+        self.backend()
+            .measure(label, f32::from(size), Font::Default, bounds)
+    }
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        labels: &[String],
+        selected: Option<usize>,
+        text_size: u16,
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+        let base_style = env.style_sheet.active(false);
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: base_style.background,
+            border_radius: base_style.border_radius,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }];
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        for (index, (label, segment)) in labels.iter().zip(env.layout.children()).enumerate() {
+            let segment_bounds = segment.bounds();
+            let is_selected = selected == Some(index);
+            let is_hovered = segment_bounds.contains(env.cursor_position);
+
+            let style = if is_hovered {
+                mouse_interaction = mouse::Interaction::Pointer;
+                env.style_sheet.hovered(is_selected)
+            } else {
+                env.style_sheet.active(is_selected)
+            };
+
+            if is_selected || is_hovered {
+                primitives.push(Primitive::Quad {
+                    bounds: segment_bounds,
+                    background: style.background,
+                    border_radius: style.border_radius,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            if index > 0 {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: segment_bounds.x - DIVIDER_WIDTH / 2.0,
+                        width: DIVIDER_WIDTH,
+                        ..segment_bounds
+                    },
+                    background: base_style.divider_color.into(),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            primitives.push(Primitive::Text {
+                content: label.clone(),
+                bounds: Rectangle {
+                    x: segment_bounds.center_x(),
+                    y: segment_bounds.center_y(),
+                    ..segment_bounds
+                },
+                size: f32::from(text_size),
+                color: style.text_color,
+                font: Font::Default,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        primitives.push(Primitive::Quad {
+            bounds,
+            background: Color::TRANSPARENT.into(),
+            border_radius: base_style.border_radius,
+            border_width: base_style.border_width,
+            border_color: base_style.border_color,
+        });
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
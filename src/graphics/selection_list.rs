@@ -0,0 +1,154 @@
+//! Use a selection list to select one or more entries of a scrollable list.
+//!
+//! *This API requires the following crate features to be activated: `selection_list`*
+use iced_graphics::{
+    backend, Backend, Color, HorizontalAlignment, Primitive, Rectangle, Renderer, Vector,
+    VerticalAlignment,
+};
+use iced_native::mouse;
+
+pub use crate::native::selection_list::State;
+pub use crate::style::selection_list::{Style, StyleSheet};
+use crate::{core::renderer::DrawEnvironment, native::selection_list};
+
+/// The width of the scrollbar.
+const SCROLLBAR_WIDTH: f32 = 4.0;
+
+/// A scrollable list of entries highlighting the selected ones.
+///
+/// This is an alias of an `iced_native` `SelectionList` with an `iced_graphics::Renderer`.
+pub type SelectionList<'a, T, Message, Backend> =
+    selection_list::SelectionList<'a, T, Message, Renderer<Backend>>;
+
+impl<B> selection_list::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn default_size(&self) -> u16 {
+        self.backend().default_size()
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        labels: &[String],
+        state: &State,
+        offset: f32,
+        text_size: u16,
+        padding: u16,
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+        let style = env.style_sheet.active();
+        let entry_height = f32::from(text_size + 2 * padding);
+        let content_height = labels.len() as f32 * entry_height;
+
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        // Only the entries within the bounds are drawn.
+        let first = (offset / entry_height) as usize;
+        let last = ((offset + bounds.height) / entry_height).ceil() as usize;
+
+        let mut entries = Vec::new();
+        for (index, label) in labels
+            .iter()
+            .enumerate()
+            .take(last.min(labels.len()))
+            .skip(first)
+        {
+            let entry_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + index as f32 * entry_height,
+                width: bounds.width,
+                height: entry_height,
+            };
+
+            let is_selected = state.selected.contains(&index);
+            let is_hovered = state.hovered == Some(index);
+
+            if is_hovered {
+                mouse_interaction = mouse::Interaction::Pointer;
+            }
+
+            let (background, text_color) = if is_selected {
+                (Some(style.selected_background), style.selected_text_color)
+            } else if is_hovered {
+                (Some(style.hovered_background), style.hovered_text_color)
+            } else {
+                (None, style.text_color)
+            };
+
+            if let Some(background) = background {
+                entries.push(Primitive::Quad {
+                    bounds: entry_bounds,
+                    background,
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            entries.push(Primitive::Text {
+                content: label.clone(),
+                bounds: Rectangle {
+                    x: entry_bounds.x + f32::from(padding),
+                    y: entry_bounds.center_y(),
+                    ..entry_bounds
+                },
+                size: f32::from(text_size),
+                color: text_color,
+                font: iced_graphics::Font::Default,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        let mut primitives = vec![
+            Primitive::Quad {
+                bounds,
+                background: style.background,
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, offset as u32),
+                content: Box::new(Primitive::Group {
+                    primitives: entries,
+                }),
+            },
+        ];
+
+        if content_height > bounds.height {
+            let scrollbar_height = bounds.height * bounds.height / content_height;
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + bounds.width - SCROLLBAR_WIDTH,
+                    y: bounds.y + offset * bounds.height / content_height,
+                    width: SCROLLBAR_WIDTH,
+                    height: scrollbar_height,
+                },
+                background: style.scrollbar_color.into(),
+                border_radius: SCROLLBAR_WIDTH / 2.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        primitives.push(Primitive::Quad {
+            bounds,
+            background: Color::TRANSPARENT.into(),
+            border_radius: 0.0,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        });
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
@@ -0,0 +1,52 @@
+//! Use a spinner to indicate that some work is running in the background.
+//!
+//! *This API requires the following crate features to be activated: `spinner`*
+use std::f32::consts::PI;
+
+use iced_graphics::{Backend, Renderer};
+use iced_native::mouse;
+
+pub use crate::native::spinner::State;
+pub use crate::style::spinner::{Style, StyleSheet};
+use crate::{core::renderer::DrawEnvironment, graphics::ring::ring, native::spinner};
+
+/// The part of the circle covered by the rotating arc.
+const ARC_LENGTH: f32 = 0.75;
+
+/// An indeterminate loading indicator drawing a rotating arc.
+///
+/// This is an alias of an `iced_native` `Spinner` with an `iced_graphics::Renderer`.
+pub type Spinner<'a, Backend> = spinner::Spinner<'a, Renderer<Backend>>;
+
+impl<B> spinner::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SIZE: u16 = 40;
+
+    const DEFAULT_STROKE_WIDTH: f32 = 4.0;
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        progress: f32,
+        stroke_width: f32,
+    ) -> Self::Output {
+        let style = env.style_sheet.active();
+        let start_angle = progress * 2.0 * PI;
+
+        (
+            ring(
+                env.layout.bounds(),
+                stroke_width,
+                style.track_color,
+                style.color,
+                start_angle,
+                start_angle + ARC_LENGTH * 2.0 * PI,
+            ),
+            mouse::Interaction::default(),
+        )
+    }
+}
@@ -0,0 +1,166 @@
+//! Use a split to split the available space in two parts to display two
+//! different elements separated by a draggable divider.
+//!
+//! *This API requires the following crate features to be activated: split*
+use iced_graphics::{Backend, Background, Color, Primitive, Renderer, Vector};
+use iced_native::{mouse, Element, Layout, Point, Rectangle};
+
+pub use crate::native::split::{Axis, State};
+pub use crate::style::split::{Style, StyleSheet};
+use crate::{core::renderer::DrawEnvironment, native::split};
+
+/// A split can divide the available space by a horizontal or vertical divider
+/// into two different panes, each displaying its own element.
+///
+/// This is an alias of an `iced_native` Split with an `iced_graphics::Renderer`.
+pub type Split<'a, Message, Backend> = split::Split<'a, Message, Renderer<Backend>>;
+
+impl<B> split::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_DIVIDER_WIDTH: f32 = 5.0;
+
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        first: &Element<'_, Message, Self>,
+        second: &Element<'_, Message, Self>,
+        axis: Axis,
+        dragging: bool,
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+        let mut children = env.layout.children();
+
+        let first_layout = children
+            .next()
+            .expect("Graphics: Layout should have a first pane layout");
+        let divider_layout = children
+            .next()
+            .expect("Graphics: Layout should have a divider layout");
+        let second_layout = children
+            .next()
+            .expect("Graphics: Layout should have a second pane layout");
+
+        let is_divider_hovered = divider_layout.bounds().contains(env.cursor_position);
+
+        let style = if dragging {
+            env.style_sheet.dragged()
+        } else if is_divider_hovered {
+            env.style_sheet.hovered()
+        } else {
+            env.style_sheet.active()
+        };
+
+        let mut primitives = Vec::new();
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        if let Some(background) = style.background {
+            primitives.push(Primitive::Quad {
+                bounds,
+                background,
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        for (pane, pane_layout, background) in [
+            (first, first_layout, style.first_background),
+            (second, second_layout, style.second_background),
+        ]
+        .iter()
+        {
+            let (primitive, new_mouse_interaction) = draw_pane(
+                self,
+                env.defaults,
+                pane,
+                *pane_layout,
+                *background,
+                env.cursor_position,
+                env.viewport.expect("A viewport should exist for Split"),
+            );
+
+            mouse_interaction = mouse_interaction.max(new_mouse_interaction);
+            primitives.push(primitive);
+        }
+
+        primitives.push(Primitive::Quad {
+            bounds: divider_layout.bounds(),
+            background: style.divider_background,
+            border_radius: 0.0,
+            border_width: style.divider_border_width,
+            border_color: style.divider_border_color,
+        });
+
+        if dragging || is_divider_hovered {
+            mouse_interaction = match axis {
+                Axis::Horizontal => mouse::Interaction::ResizingVertically,
+                Axis::Vertical => mouse::Interaction::ResizingHorizontally,
+            };
+        }
+
+        if style.border_width > 0.0 {
+            primitives.push(Primitive::Quad {
+                bounds,
+                background: Color::TRANSPARENT.into(),
+                border_radius: 0.0,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            });
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
+
+/// Draws a pane of the [`Split`](Split) clipped to its bounds.
+fn draw_pane<Message, B>(
+    renderer: &mut Renderer<B>,
+    defaults: &<Renderer<B> as iced_native::Renderer>::Defaults,
+    pane: &Element<'_, Message, Renderer<B>>,
+    layout: Layout<'_>,
+    background: Option<Background>,
+    cursor_position: Point,
+    viewport: &Rectangle,
+) -> (Primitive, mouse::Interaction)
+where
+    B: Backend,
+{
+    let bounds = layout.bounds();
+
+    let background = background.map_or(Primitive::None, |background| Primitive::Quad {
+        bounds,
+        background,
+        border_radius: 0.0,
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    });
+
+    let (content, mouse_interaction) = pane.draw(
+        renderer,
+        defaults,
+        layout
+            .children()
+            .next()
+            .expect("Graphics: Layout should have a content layout for the pane"),
+        cursor_position,
+        viewport,
+    );
+
+    (
+        Primitive::Group {
+            primitives: vec![
+                background,
+                Primitive::Clip {
+                    bounds,
+                    offset: Vector::new(0, 0),
+                    content: Box::new(content),
+                },
+            ],
+        },
+        mouse_interaction,
+    )
+}
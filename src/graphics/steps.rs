@@ -0,0 +1,166 @@
+//! Use steps to show the progress through a sequence of numbered steps, like
+//! the pages of a wizard.
+//!
+//! *This API requires the following crate features to be activated: `steps`*
+use iced_graphics::{
+    backend, Backend, Color, Font, HorizontalAlignment, Primitive, Rectangle, Renderer, Size,
+    VerticalAlignment,
+};
+use iced_native::mouse;
+
+pub use crate::native::steps::{Orientation, StepState};
+pub use crate::style::steps::{Style, StyleSheet};
+use crate::{
+    core::renderer::DrawEnvironment,
+    graphics::icons::{Icon, ICON_FONT},
+    native::steps,
+};
+
+/// The width of the lines connecting the indicators.
+const CONNECTOR_WIDTH: f32 = 2.0;
+
+/// A sequence of numbered steps marking the completed steps, the current step
+/// and the pending steps.
+///
+/// This is an alias of an `iced_native` `Steps` with an `iced_graphics::Renderer`.
+pub type Steps<Message, Backend> = steps::Steps<Message, Renderer<Backend>>;
+
+impl<B> steps::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SPACING: u16 = 10;
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn default_size(&self) -> u16 {
+        self.backend().default_size()
+    }
+
+    fn measure(&self, text: &str, size: u16, bounds: Size) -> (f32, f32) {
+        self.backend()
+            .measure(text, f32::from(size), Font::Default, bounds)
+    }
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        labels: &[String],
+        current: usize,
+        orientation: Orientation,
+        is_clickable: bool,
+        text_size: u16,
+    ) -> Self::Output {
+        let mut connectors = Vec::new();
+        let mut step_primitives = Vec::new();
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        // The bounds and the connector color of the previous indicator.
+        let mut previous: Option<(Rectangle, Color)> = None;
+
+        for (index, (label, step)) in labels.iter().zip(env.layout.children()).enumerate() {
+            let mut children = step.children();
+            let indicator_bounds = children
+                .next()
+                .expect("Graphics: Layout should have an indicator layout for a step of Steps")
+                .bounds();
+            let label_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a label layout for a step of Steps")
+                .bounds();
+
+            let state = StepState::of(index, current);
+            let style = if is_clickable
+                && index != current
+                && step.bounds().contains(env.cursor_position)
+            {
+                mouse_interaction = mouse::Interaction::Pointer;
+                env.style_sheet.hovered(state)
+            } else {
+                env.style_sheet.active(state)
+            };
+
+            if let Some((previous_bounds, color)) = previous {
+                let bounds = match orientation {
+                    Orientation::Horizontal => Rectangle {
+                        x: previous_bounds.x + previous_bounds.width,
+                        y: previous_bounds.center_y() - CONNECTOR_WIDTH / 2.0,
+                        width: (indicator_bounds.x - previous_bounds.x - previous_bounds.width)
+                            .max(0.0),
+                        height: CONNECTOR_WIDTH,
+                    },
+                    Orientation::Vertical => Rectangle {
+                        x: previous_bounds.center_x() - CONNECTOR_WIDTH / 2.0,
+                        y: previous_bounds.y + previous_bounds.height,
+                        width: CONNECTOR_WIDTH,
+                        height: (indicator_bounds.y - previous_bounds.y - previous_bounds.height)
+                            .max(0.0),
+                    },
+                };
+
+                connectors.push(Primitive::Quad {
+                    bounds,
+                    background: color.into(),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+            previous = Some((indicator_bounds, style.connector_color));
+
+            step_primitives.push(Primitive::Quad {
+                bounds: indicator_bounds,
+                background: style.indicator_background,
+                border_radius: indicator_bounds.width / 2.0,
+                border_width: style.indicator_border_width,
+                border_color: style.indicator_border_color,
+            });
+
+            let (content, font) = if state == StepState::Completed {
+                (Icon::Check.into(), ICON_FONT)
+            } else {
+                ((index + 1).to_string(), Font::Default)
+            };
+
+            step_primitives.push(Primitive::Text {
+                content,
+                bounds: Rectangle {
+                    x: indicator_bounds.center_x(),
+                    y: indicator_bounds.center_y(),
+                    ..indicator_bounds
+                },
+                size: f32::from(text_size),
+                color: style.indicator_text_color,
+                font,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+
+            let (x, horizontal_alignment) = match orientation {
+                Orientation::Horizontal => (label_bounds.center_x(), HorizontalAlignment::Center),
+                Orientation::Vertical => (label_bounds.x, HorizontalAlignment::Left),
+            };
+
+            step_primitives.push(Primitive::Text {
+                content: label.clone(),
+                bounds: Rectangle { x, ..label_bounds },
+                size: f32::from(text_size),
+                color: style.label_color,
+                font: Font::Default,
+                horizontal_alignment,
+                vertical_alignment: VerticalAlignment::Top,
+            });
+        }
+
+        connectors.append(&mut step_primitives);
+
+        (
+            Primitive::Group {
+                primitives: connectors,
+            },
+            mouse_interaction,
+        )
+    }
+}
@@ -4,17 +4,23 @@
 //! to use the [`Tabs`](super::tabs) widget instead.
 //!
 //! *This API requires the following crate features to be activated: `tab_bar`*
-use iced_graphics::{backend, Backend, Color, Primitive, Rectangle, Renderer};
-use iced_native::{mouse, Font, HorizontalAlignment, Layout, VerticalAlignment};
-pub use tab_bar::tab_label::TabLabel;
+use iced_graphics::{backend, Backend, Color, Primitive, Rectangle, Renderer, Vector};
+use iced_native::{mouse, Font, HorizontalAlignment, Layout, Point, VerticalAlignment};
+pub use tab_bar::{
+    tab_label::{TabLabel, TabLabelLayout},
+    DraggedTab, RenderedLabel, ScrollInfo, State,
+};
 
-pub use crate::style::tab_bar::{Style, StyleSheet};
+pub use crate::style::tab_bar::{CloseStyle, Style, StyleSheet};
 use crate::{core::renderer::DrawEnvironment, native::tab_bar};
 
+/// The width of the indicator showing where a dragged tab will be inserted.
+const INSERTION_INDICATOR_WIDTH: f32 = 2.0;
+
 /// A tab bar to show tabs.
 ///
-/// This is an alias of an `iced_native` `TabBar` with an `iced_wgpu::Renderer`.
-pub type TabBar<Message, Backend> = tab_bar::TabBar<Message, Renderer<Backend>>;
+/// This is an alias of an `iced_native` `TabBar` with an `iced_graphics::Renderer`.
+pub type TabBar<'a, Message, Backend> = tab_bar::TabBar<'a, Message, Renderer<Backend>>;
 
 impl<B> tab_bar::Renderer for Renderer<B>
 where
@@ -32,19 +38,25 @@ where
 
     const DEFAULT_SPACING: u16 = 0;
 
+    const SCROLL_BUTTON_WIDTH: u16 = 24;
+
     fn draw(
         &mut self,
-        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, bool>,
         active_tab: usize,
-        tab_labels: &[TabLabel],
+        tab_labels: Vec<RenderedLabel<'_, Self::Output>>,
         icon_font: Option<Font>,
         text_font: Option<Font>,
+        scroll: Option<ScrollInfo>,
+        dragged: Option<DraggedTab>,
     ) -> Self::Output {
         // TODO tab bar background
         let bounds = env.layout.bounds();
         let children = env.layout.children();
         let is_mouse_over = bounds.contains(env.cursor_position);
-        let style = if is_mouse_over {
+        let style = if env.focus {
+            env.style_sheet.focused(false)
+        } else if is_mouse_over {
             env.style_sheet.hovered(false)
         } else {
             env.style_sheet.active(false)
@@ -62,15 +74,26 @@ where
             border_color: style.border_color.unwrap_or(Color::TRANSPARENT),
         }];
 
-        primitives = tab_labels.iter().enumerate().zip(children).fold(
-            primitives,
+        // Tabs scrolled out of the visible area can't be hovered.
+        let cursor_position = match scroll {
+            Some(scroll) if !scroll.viewport.contains(env.cursor_position) => {
+                Point::new(-1.0, -1.0)
+            }
+            _ => env.cursor_position,
+        };
+
+        let mut dragged_primitive = Primitive::None;
+
+        let mut tabs = tab_labels.into_iter().enumerate().zip(children).fold(
+            Vec::new(),
             |mut primitives, ((i, tab), layout)| {
                 let (primitive, new_mouse_interaction) = draw_tab(
                     tab,
                     layout,
                     env.style_sheet,
                     i == active_tab,
-                    env.cursor_position,
+                    env.focus && i == active_tab,
+                    cursor_position,
                     icon_font.unwrap_or(B::ICON_FONT),
                     text_font.unwrap_or_default(),
                 );
@@ -79,28 +102,155 @@ where
                     mouse_interaction = new_mouse_interaction;
                 }
 
-                primitives.push(primitive);
+                match dragged {
+                    Some(dragged) if dragged.index == i => dragged_primitive = primitive,
+                    _ => primitives.push(primitive),
+                }
                 primitives
             },
         );
 
+        // The dragged tab slides along the bar on top of the other tabs.
+        if let Some(dragged) = dragged {
+            mouse_interaction = mouse::Interaction::Grabbing;
+
+            tabs.push(Primitive::Quad {
+                bounds: if dragged.indicator.width > 0.0 {
+                    Rectangle {
+                        y: dragged.indicator.y - INSERTION_INDICATOR_WIDTH / 2.0,
+                        height: INSERTION_INDICATOR_WIDTH,
+                        ..dragged.indicator
+                    }
+                } else {
+                    Rectangle {
+                        x: dragged.indicator.x - INSERTION_INDICATOR_WIDTH / 2.0,
+                        width: INSERTION_INDICATOR_WIDTH,
+                        ..dragged.indicator
+                    }
+                },
+                background: env.style_sheet.insertion_indicator_color().into(),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+            tabs.push(Primitive::Translate {
+                translation: dragged.offset,
+                content: Box::new(dragged_primitive),
+            });
+        }
+
+        match scroll {
+            Some(scroll) => {
+                primitives.push(Primitive::Clip {
+                    bounds: scroll.viewport,
+                    offset: Vector::new(0, 0),
+                    content: Box::new(Primitive::Group { primitives: tabs }),
+                });
+
+                if scroll.buttons {
+                    let (buttons, new_mouse_interaction) =
+                        draw_scroll_buttons(bounds, scroll, &style, env.cursor_position);
+
+                    if new_mouse_interaction > mouse_interaction {
+                        mouse_interaction = new_mouse_interaction;
+                    }
+
+                    primitives.push(buttons);
+                }
+            }
+            None => primitives.extend(tabs),
+        }
+
         (Primitive::Group { primitives }, mouse_interaction)
     }
 }
 
+/// Draws the buttons to scroll the tabs to the left and to the right next to
+/// the visible area.
+fn draw_scroll_buttons(
+    bounds: Rectangle,
+    scroll: ScrollInfo,
+    style: &Style,
+    cursor_position: Point,
+) -> (Primitive, mouse::Interaction) {
+    let width = bounds.width - scroll.viewport.width;
+    let left = Rectangle {
+        width: width / 2.0,
+        ..bounds
+    };
+    let right = Rectangle {
+        x: scroll.viewport.x + scroll.viewport.width,
+        ..left
+    };
+
+    let mut mouse_interaction = mouse::Interaction::default();
+
+    let primitives = [
+        (left, super::icons::Icon::CaretLeftFill, scroll.offset > 0.0),
+        (
+            right,
+            super::icons::Icon::CaretRightFill,
+            scroll.offset < scroll.max_offset,
+        ),
+    ]
+    .iter()
+    .map(|(button_bounds, icon, can_scroll)| {
+        if *can_scroll && button_bounds.contains(cursor_position) {
+            mouse_interaction = mouse::Interaction::Pointer;
+        }
+
+        // A button that can't scroll any further is dimmed.
+        let color = if *can_scroll {
+            style.icon_color
+        } else {
+            Color {
+                a: style.icon_color.a * 0.3,
+                ..style.icon_color
+            }
+        };
+
+        Primitive::Text {
+            content: (*icon).into(),
+            font: super::icons::ICON_FONT,
+            size: button_bounds.width.min(button_bounds.height),
+            bounds: Rectangle {
+                x: button_bounds.center_x(),
+                y: button_bounds.center_y(),
+                ..*button_bounds
+            },
+            color,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+        }
+    })
+    .collect();
+
+    (Primitive::Group { primitives }, mouse_interaction)
+}
+
 /// Draws a tab.
-#[allow(clippy::borrowed_box, clippy::too_many_lines)]
+///
+/// `is_focused` is true if the tab is selected and the tab bar has the
+/// keyboard focus.
+#[allow(
+    clippy::borrowed_box,
+    clippy::too_many_lines,
+    clippy::too_many_arguments
+)]
 fn draw_tab(
-    tab: &TabLabel,
+    tab: RenderedLabel<'_, (Primitive, mouse::Interaction)>,
     layout: Layout<'_>,
     style_sheet: &Box<dyn StyleSheet>,
     is_selected: bool,
+    is_focused: bool,
     cursor_position: iced_native::Point,
     icon_font: Font,
     text_font: Font,
 ) -> (Primitive, mouse::Interaction) {
     let is_mouse_over = layout.bounds().contains(cursor_position);
-    let style = if is_mouse_over {
+    let style = if is_focused {
+        style_sheet.focused(is_selected)
+    } else if is_mouse_over {
         style_sheet.hovered(is_selected)
     } else {
         style_sheet.active(is_selected)
@@ -124,8 +274,13 @@ fn draw_tab(
     let cross = children.next().map_or(Primitive::None, |cross_layout| {
         let cross_bounds = cross_layout.bounds();
         let is_mouse_over_cross = cross_bounds.contains(cursor_position);
+        let close_style = if is_mouse_over_cross {
+            Some(style_sheet.close_hovered(is_selected))
+        } else {
+            None
+        };
 
-        Primitive::Text {
+        let icon = Primitive::Text {
             content: super::icons::Icon::X.into(),
             font: super::icons::ICON_FONT,
             size: cross_bounds.height + if is_mouse_over_cross { 5.0 } else { 0.0 },
@@ -134,12 +289,54 @@ fn draw_tab(
                 y: cross_bounds.center_y(),
                 ..cross_bounds
             },
-            color: style.icon_color,
+            color: close_style.map_or(style.icon_color, |close_style| close_style.icon_color),
             horizontal_alignment: HorizontalAlignment::Center,
             vertical_alignment: VerticalAlignment::Center,
+        };
+
+        match close_style.and_then(|close_style| {
+            close_style
+                .background
+                .map(|background| (background, close_style.border_radius))
+        }) {
+            Some((background, border_radius)) => Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds: cross_bounds,
+                        background,
+                        border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    icon,
+                ],
+            },
+            None => icon,
         }
     });
 
+    let mut mouse_interaction = if is_mouse_over {
+        mouse::Interaction::Pointer
+    } else {
+        mouse::Interaction::default()
+    };
+
+    let tab = match tab {
+        RenderedLabel::Label(tab) => tab,
+        RenderedLabel::Element((primitive, new_mouse_interaction)) => {
+            if new_mouse_interaction > mouse_interaction {
+                mouse_interaction = new_mouse_interaction;
+            }
+
+            return (
+                Primitive::Group {
+                    primitives: vec![background, primitive, cross],
+                },
+                mouse_interaction,
+            );
+        }
+    };
+
     let primitive = match tab {
         TabLabel::Icon(icon) => {
             let icon_bounds = label_layout_children
@@ -198,52 +395,48 @@ fn draw_tab(
                 .next()
                 .expect("Graphics: Layout should have an icons layout for an IconText")
                 .bounds();
-            let text_bounds = label_layout_children
-                .next()
-                .expect("Graphics: Layout should have a text layout for an IconText")
-                .bounds();
 
-            Primitive::Group {
-                primitives: vec![
-                    background,
-                    Primitive::Text {
-                        content: icon.to_string(),
-                        font: icon_font,
-                        size: icon_bounds.height,
-                        bounds: Rectangle {
-                            x: icon_bounds.center_x(),
-                            y: icon_bounds.center_y(),
-                            ..icon_bounds
-                        },
-                        color: style.icon_color,
-                        horizontal_alignment: HorizontalAlignment::Center,
-                        vertical_alignment: VerticalAlignment::Center,
+            let mut primitives = vec![
+                background,
+                Primitive::Text {
+                    content: icon.to_string(),
+                    font: icon_font,
+                    size: icon_bounds.height,
+                    bounds: Rectangle {
+                        x: icon_bounds.center_x(),
+                        y: icon_bounds.center_y(),
+                        ..icon_bounds
                     },
-                    Primitive::Text {
-                        content: text.to_string(),
-                        font: text_font,
-                        size: text_bounds.height,
-                        bounds: Rectangle {
-                            x: text_bounds.center_x(),
-                            y: text_bounds.center_y(),
-                            ..text_bounds
-                        },
-                        color: style.text_color,
-                        horizontal_alignment: HorizontalAlignment::Center,
-                        vertical_alignment: VerticalAlignment::Center,
+                    color: style.icon_color,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                },
+            ];
+
+            // The text layout is missing if the tab only shows its icon.
+            if let Some(text_layout) = label_layout_children.next() {
+                let text_bounds = text_layout.bounds();
+
+                primitives.push(Primitive::Text {
+                    content: text.to_string(),
+                    font: text_font,
+                    size: text_bounds.height,
+                    bounds: Rectangle {
+                        x: text_bounds.center_x(),
+                        y: text_bounds.center_y(),
+                        ..text_bounds
                     },
-                    cross,
-                ],
+                    color: style.text_color,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
             }
+
+            primitives.push(cross);
+
+            Primitive::Group { primitives }
         }
     };
 
-    (
-        primitive,
-        if is_mouse_over {
-            mouse::Interaction::Pointer
-        } else {
-            mouse::Interaction::default()
-        },
-    )
+    (primitive, mouse_interaction)
 }
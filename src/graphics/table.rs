@@ -0,0 +1,264 @@
+//! Use a table to display rows of elements in columns below a header row.
+//!
+//! *This API requires the following crate features to be activated: `table`*
+use iced_graphics::{
+    backend, defaults, Backend, Color, Defaults, HorizontalAlignment, Primitive, Rectangle,
+    Renderer, Vector, VerticalAlignment,
+};
+use iced_native::{mouse, Element, Point};
+
+pub use crate::native::table::{SortOrder, State};
+pub use crate::style::table::{Style, StyleSheet};
+use crate::{
+    core::renderer::DrawEnvironment,
+    graphics::icons::{Icon, ICON_FONT},
+    native::table,
+};
+
+/// The width of the scrollbar.
+const SCROLLBAR_WIDTH: f32 = 4.0;
+
+/// A table displaying rows of elements in columns below a fixed header row.
+///
+/// This is an alias of an `iced_native` `Table` with an `iced_graphics::Renderer`.
+pub type Table<'a, Message, Backend> = table::Table<'a, Message, Renderer<Backend>>;
+
+impl<B> table::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn default_size(&self) -> u16 {
+        self.backend().default_size()
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        headers: &[String],
+        rows: &[Vec<Element<'_, Message, Self>>],
+        sort: Option<(usize, SortOrder)>,
+        is_sortable: bool,
+        state: &State,
+        text_size: u16,
+        padding: u16,
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+        let body_bounds = table::body_bounds(env.layout);
+        let style = env.style_sheet.active();
+        let padding = f32::from(padding);
+
+        let mut children = env.layout.children();
+        let header_layout = children
+            .next()
+            .expect("Graphics: Layout should have a header layout for a Table");
+        let header_bounds = header_layout.bounds();
+
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        // The rows are scrolled, so the cursor is moved by the offset.
+        let cursor_position = if body_bounds.contains(env.cursor_position) {
+            Point::new(env.cursor_position.x, env.cursor_position.y + state.offset)
+        } else {
+            Point::new(-1.0, -1.0)
+        };
+        let body_viewport = Rectangle {
+            y: body_bounds.y + state.offset,
+            ..body_bounds
+        };
+        let defaults = Defaults {
+            text: defaults::Text {
+                color: style.text_color,
+            },
+        };
+
+        let mut content_height = 0.0;
+        let mut body = Vec::new();
+        for (index, (row, row_layout)) in rows.iter().zip(children).enumerate() {
+            let row_bounds = row_layout.bounds();
+            content_height = row_bounds.y + row_bounds.height - body_bounds.y;
+
+            // Only the rows within the body are drawn.
+            if row_bounds.y + row_bounds.height < body_viewport.y
+                || row_bounds.y > body_viewport.y + body_viewport.height
+            {
+                continue;
+            }
+
+            let background = if row_bounds.contains(cursor_position) {
+                style.hovered_row_background
+            } else if index % 2 == 1 {
+                style.striped_row_background
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                body.push(Primitive::Quad {
+                    bounds: row_bounds,
+                    background,
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            for (cell, cell_layout) in row.iter().zip(row_layout.children()) {
+                let (primitive, new_mouse_interaction) = cell.draw(
+                    self,
+                    &defaults,
+                    cell_layout,
+                    cursor_position,
+                    &body_viewport,
+                );
+
+                mouse_interaction = mouse_interaction.max(new_mouse_interaction);
+                body.push(primitive);
+            }
+        }
+
+        let mut primitives = vec![
+            Primitive::Quad {
+                bounds,
+                background: style.background,
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            Primitive::Clip {
+                bounds: body_bounds,
+                offset: Vector::new(0, state.offset as u32),
+                content: Box::new(Primitive::Group { primitives: body }),
+            },
+        ];
+
+        if content_height > body_bounds.height {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: body_bounds.x + body_bounds.width - SCROLLBAR_WIDTH,
+                    y: body_bounds.y + state.offset * body_bounds.height / content_height,
+                    width: SCROLLBAR_WIDTH,
+                    height: body_bounds.height * body_bounds.height / content_height,
+                },
+                background: style.scrollbar_color.into(),
+                border_radius: SCROLLBAR_WIDTH / 2.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                width: bounds.width,
+                ..header_bounds
+            },
+            background: style.header_background,
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        });
+
+        for (index, (header, cell)) in headers.iter().zip(header_layout.children()).enumerate() {
+            let cell_bounds = cell.bounds();
+
+            if is_sortable && cell_bounds.contains(env.cursor_position) {
+                mouse_interaction = mouse::Interaction::Pointer;
+
+                primitives.push(Primitive::Quad {
+                    bounds: cell_bounds,
+                    background: style.hovered_header_background,
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            if index > 0 {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: cell_bounds.x,
+                        width: 1.0,
+                        ..cell_bounds
+                    },
+                    background: style.divider_color.into(),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            let indicator =
+                sort.filter(|(column, _)| *column == index)
+                    .map(|(_, order)| match order {
+                        SortOrder::Ascending => Icon::CaretUpFill,
+                        SortOrder::Descending => Icon::CaretDownFill,
+                    });
+            let indicator_width = indicator.map_or(0.0, |_| f32::from(text_size) + padding);
+
+            primitives.push(Primitive::Clip {
+                bounds: Rectangle {
+                    x: cell_bounds.x + padding,
+                    width: (cell_bounds.width - 2.0 * padding - indicator_width).max(0.0),
+                    ..cell_bounds
+                },
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Text {
+                    content: header.clone(),
+                    bounds: Rectangle {
+                        x: cell_bounds.x + padding,
+                        y: cell_bounds.center_y(),
+                        ..cell_bounds
+                    },
+                    size: f32::from(text_size),
+                    color: style.header_text_color,
+                    font: iced_graphics::Font::Default,
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Center,
+                }),
+            });
+
+            if let Some(icon) = indicator {
+                primitives.push(Primitive::Text {
+                    content: icon.into(),
+                    bounds: Rectangle {
+                        x: cell_bounds.x + cell_bounds.width - padding,
+                        y: cell_bounds.center_y(),
+                        ..cell_bounds
+                    },
+                    size: f32::from(text_size),
+                    color: style.header_text_color,
+                    font: ICON_FONT,
+                    horizontal_alignment: HorizontalAlignment::Right,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+        }
+
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x,
+                y: header_bounds.y + header_bounds.height - 1.0,
+                width: bounds.width,
+                height: 1.0,
+            },
+            background: style.divider_color.into(),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        });
+
+        primitives.push(Primitive::Quad {
+            bounds,
+            background: Color::TRANSPARENT.into(),
+            border_radius: 0.0,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        });
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
@@ -5,9 +5,10 @@
 //! the content of the tabs.
 //!
 //! *This API requires the following crate features to be activated: tabs*
-use iced_graphics::{backend, Backend, Primitive, Renderer};
+use iced_graphics::{backend, Backend, Color, Primitive, Renderer};
 
-pub use crate::style::tab_bar::{Style, StyleSheet};
+pub use crate::native::tab_bar::TabLabelLayout;
+pub use crate::style::tab_bar::{CloseStyle, ContentStyle, Style, StyleSheet};
 use crate::{core::renderer::DrawEnvironment, native::tabs};
 use iced_native::mouse;
 pub use tabs::tab_bar_position::TabBarPosition;
@@ -16,7 +17,7 @@ pub use tabs::tab_bar_position::TabBarPosition;
 /// along with the tab's content.
 ///
 /// This is an alias of an `iced_native` Tabs widget with an
-/// `iced_wgpu::Renderer`.
+/// `iced_graphics::Renderer`.
 pub type Tabs<'a, Message, Backend> = tabs::Tabs<'a, Message, Renderer<Backend>>;
 
 impl<B> tabs::Renderer for Renderer<B>
@@ -25,7 +26,7 @@ where
 {
     fn draw<Message>(
         &mut self,
-        env: DrawEnvironment<'_, Self::Defaults, (), ()>,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
         active_tab: usize,
         tab_bar: Self::Output,
         tabs: &[iced_native::Element<'_, Message, Self>],
@@ -34,10 +35,10 @@ where
         let mut children = env.layout.children();
 
         let tab_content_layout = match tab_bar_position {
-            TabBarPosition::Top => children
+            TabBarPosition::Top | TabBarPosition::Left => children
                 .last()
                 .expect("Graphics: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
+            TabBarPosition::Bottom | TabBarPosition::Right => children
                 .next()
                 .expect("Graphics: There should be a TabBar at the bottom position"),
         };
@@ -53,11 +54,28 @@ where
 
         primitives.push(tab_bar_primitive);
 
+        let content_style = env.style_sheet.content(active_tab);
+
+        if content_style.background.is_some() || content_style.border_width > 0.0 {
+            primitives.push(Primitive::Quad {
+                bounds: tab_content_layout.bounds(),
+                background: content_style
+                    .background
+                    .unwrap_or_else(|| Color::TRANSPARENT.into()),
+                border_radius: content_style.border_radius,
+                border_width: content_style.border_width,
+                border_color: content_style.border_color.unwrap_or(Color::TRANSPARENT),
+            });
+        }
+
         if let Some(element) = tabs.get(active_tab) {
             let (tab_content_primitive, new_mouse_interaction) = element.draw(
                 self,
                 env.defaults,
-                tab_content_layout,
+                tab_content_layout
+                    .children()
+                    .next()
+                    .expect("Graphics: There should be a layout for the content of the tab"),
                 env.cursor_position,
                 env.viewport.expect("A viewport should exist for Tabs"),
             );
@@ -23,7 +23,7 @@ use iced_graphics::{
 };
 use iced_native::mouse;
 
-use crate::native::time_picker;
+use crate::native::time_picker::{self, clock as clock_widget};
 pub use crate::native::time_picker::{Period, State, Time};
 
 use super::icons::{Icon, ICON_FONT};
@@ -35,9 +35,14 @@ const PERIOD_SIZE_PERCENTAGE: f32 = 0.2;
 
 /// An input element for picking times.
 ///
-/// This is an alias of an `iced_native` `TimePicker` with an `iced_wgpu::Renderer`.
+/// This is an alias of an `iced_native` `TimePicker` with an `iced_graphics::Renderer`.
 pub type TimePicker<'a, Message, Backend> = time_picker::TimePicker<'a, Message, Renderer<Backend>>;
 
+/// A clock face for picking times, shown inline instead of as an overlay.
+///
+/// This is an alias of an `iced_native` `Clock` with an `iced_graphics::Renderer`.
+pub type Clock<'a, Message, Backend> = clock_widget::Clock<'a, Message, Renderer<Backend>>;
+
 impl<B> time_picker::Renderer for Renderer<B>
 where
     B: Backend + backend::Text,
@@ -83,15 +88,31 @@ where
         let clock_layout = children
             .next()
             .expect("Graphics: Layout should have a clock canvas layout");
-        let (clock, clock_mouse_interaction) = clock(
-            clock_layout,
-            state.time,
-            &state.clock_cache,
-            env.cursor_position,
-            state.use_24h,
-            state.show_seconds,
-            &style,
-        );
+        let (clock, clock_mouse_interaction) = if state.digital_only {
+            (Primitive::None, mouse::Interaction::default())
+        } else {
+            clock(
+                clock_layout,
+                state.time,
+                &state.clock_cache,
+                env.cursor_position,
+                state.use_24h,
+                state.show_seconds,
+                &style,
+            )
+        };
+
+        let clock_focus = if env.focus == Focus::Clock {
+            Primitive::Quad {
+                bounds: clock_layout.bounds(),
+                background: Color::TRANSPARENT.into(),
+                border_radius: style[&StyleState::Focused].border_radius,
+                border_width: style[&StyleState::Focused].border_width,
+                border_color: style[&StyleState::Focused].border_color,
+            }
+        } else {
+            Primitive::None
+        };
 
         // ----------- Digital clock ------------------
         let digital_clock_layout = children
@@ -100,6 +121,7 @@ where
         let (digital_clock, digital_clock_mouse_interaction) = digital_clock(
             digital_clock_layout,
             state.time,
+            state.digital_hour(),
             env.cursor_position,
             state.use_24h,
             state.show_seconds,
@@ -162,6 +184,7 @@ where
                 primitives: vec![
                     background,
                     clock,
+                    clock_focus,
                     digital_clock,
                     cancel_button,
                     submit_button,
@@ -178,6 +201,37 @@ where
     }
 }
 
+impl<B> clock_widget::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SIZE: u16 = 200;
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        state: &crate::native::overlay::time_picker::State,
+    ) -> Self::Output {
+        let mut style: HashMap<StyleState, Style> = HashMap::new();
+        let _ = style.insert(StyleState::Active, env.style_sheet.active());
+        let _ = style.insert(StyleState::Selected, env.style_sheet.selected());
+        let _ = style.insert(StyleState::Hovered, env.style_sheet.hovered());
+        let _ = style.insert(StyleState::Focused, env.style_sheet.focused());
+
+        clock(
+            env.layout,
+            state.time,
+            &state.clock_cache,
+            env.cursor_position,
+            state.use_24h,
+            state.show_seconds,
+            &style,
+        )
+    }
+}
+
 /// Draws the analog clock.
 #[allow(clippy::too_many_lines)]
 fn clock(
@@ -454,10 +508,11 @@ fn clock(
 }
 
 /// Draws the digital clock.
-#[allow(clippy::too_many_lines)]
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
 fn digital_clock(
     layout: iced_native::Layout<'_>,
     time: NaiveTime,
+    hour: u32,
     cursor_position: Point,
     use_24h: bool,
     show_seconds: bool,
@@ -570,18 +625,7 @@ fn digital_clock(
     let hour_layout = children
         .next()
         .expect("Graphics: Layout should have a hour layout");
-    let (hour, hour_mouse_interaction) = f(
-        hour_layout,
-        format!(
-            "{:02}",
-            if use_24h {
-                time.hour()
-            } else {
-                time.hour12().1
-            }
-        ),
-        Focus::DigitalHour,
-    );
+    let (hour, hour_mouse_interaction) = f(hour_layout, format!("{:02}", hour), Focus::DigitalHour);
 
     let hour_minute_separator = children
         .next()
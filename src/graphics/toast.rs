@@ -0,0 +1,125 @@
+//! Use a toast to display short notifications stacked in a corner of the
+//! window.
+//!
+//! *This API requires the following crate features to be activated: `toast`*
+use iced_graphics::{
+    backend, Backend, Font, HorizontalAlignment, Primitive, Rectangle, Renderer, Size,
+    VerticalAlignment,
+};
+use iced_native::mouse;
+
+pub use crate::native::toast::{Anchor, Notification, Offset, Severity, State};
+pub use crate::style::toast::{Style, StyleSheet};
+use crate::{
+    core::renderer::DrawEnvironment,
+    graphics::icons::{Icon, ICON_FONT},
+    native::{overlay::toast as toast_overlay, toast},
+};
+
+/// A wrapper around some content displaying stacked notification cards in a
+/// corner of the window.
+///
+/// This is an alias of an `iced_native` `Toast` with an `iced_graphics::Renderer`.
+pub type Toast<'a, Message, Backend> = toast::Toast<'a, Message, Renderer<Backend>>;
+
+impl<B> toast_overlay::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_WIDTH: f32 = 300.0;
+
+    const DEFAULT_SPACING: u16 = 10;
+
+    const DEFAULT_PADDING: u16 = 10;
+
+    fn default_size(&self) -> u16 {
+        self.backend().default_size()
+    }
+
+    fn measure(&self, content: &str, size: u16, bounds: Size) -> (f32, f32) {
+        self.backend()
+            .measure(content, f32::from(size), Font::Default, bounds)
+    }
+
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        notifications: &[&Notification],
+        text_size: u16,
+    ) -> Self::Output {
+        let mut primitives = Vec::new();
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        for (notification, card_layout) in notifications.iter().zip(env.layout.children()) {
+            let style = match notification.severity {
+                Severity::Info => env.style_sheet.info(),
+                Severity::Warning => env.style_sheet.warning(),
+                Severity::Error => env.style_sheet.error(),
+            };
+
+            let mut children = card_layout.children();
+            let title_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a title layout for a Toast notification")
+                .bounds();
+            let body_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a body layout for a Toast notification")
+                .bounds();
+            let close_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a close layout for a Toast notification")
+                .bounds();
+
+            primitives.push(Primitive::Quad {
+                bounds: card_layout.bounds(),
+                background: style.background,
+                border_radius: style.border_radius,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            });
+
+            primitives.push(Primitive::Text {
+                content: notification.title.clone(),
+                bounds: title_bounds,
+                size: f32::from(text_size),
+                color: style.title_color,
+                font: Font::Default,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+            });
+
+            primitives.push(Primitive::Text {
+                content: notification.body.clone(),
+                bounds: body_bounds,
+                size: f32::from(text_size),
+                color: style.body_color,
+                font: Font::Default,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+            });
+
+            if close_bounds.contains(env.cursor_position) {
+                mouse_interaction = mouse::Interaction::Pointer;
+            }
+
+            primitives.push(Primitive::Text {
+                content: Icon::X.into(),
+                bounds: Rectangle {
+                    x: close_bounds.center_x(),
+                    y: close_bounds.center_y(),
+                    ..close_bounds
+                },
+                size: f32::from(text_size),
+                color: style.close_color,
+                font: ICON_FONT,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
@@ -0,0 +1,150 @@
+//! Use a tree view to display hierarchical data with expandable nodes.
+//!
+//! *This API requires the following crate features to be activated: `tree_view`*
+use iced_graphics::{
+    backend, Backend, Color, HorizontalAlignment, Primitive, Rectangle, Renderer, VerticalAlignment,
+};
+use iced_native::mouse;
+
+pub use crate::native::tree_view::{Node, Path, Row, State};
+pub use crate::style::tree_view::{Style, StyleSheet};
+use crate::{
+    core::renderer::DrawEnvironment,
+    graphics::icons::{Icon, ICON_FONT},
+    native::tree_view,
+};
+
+/// The width of the indentation guides.
+const GUIDE_WIDTH: f32 = 1.0;
+
+/// A tree view displaying hierarchical data.
+///
+/// This is an alias of an `iced_native` `TreeView` with an `iced_graphics::Renderer`.
+pub type TreeView<'a, T, Message, Backend> = tree_view::TreeView<'a, T, Message, Renderer<Backend>>;
+
+impl<B> tree_view::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_INDENT: f32 = 20.0;
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn default_size(&self) -> u16 {
+        self.backend().default_size()
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        rows: &[Row],
+        indent: f32,
+        text_size: u16,
+    ) -> Self::Output {
+        let bounds = env.layout.bounds();
+        let style = env.style_sheet.active();
+
+        let mut primitives = Vec::new();
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        if let Some(background) = style.background {
+            primitives.push(Primitive::Quad {
+                bounds,
+                background,
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        for (row, row_layout) in rows.iter().zip(env.layout.children()) {
+            let row_bounds = row_layout.bounds();
+            let mut children = row_layout.children();
+            let toggle_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a toggle layout for a TreeView row")
+                .bounds();
+            let label_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a label layout for a TreeView row")
+                .bounds();
+
+            let is_hovered = row_bounds.contains(env.cursor_position);
+            if is_hovered {
+                mouse_interaction = mouse::Interaction::Pointer;
+            }
+
+            if row.is_selected || is_hovered {
+                primitives.push(Primitive::Quad {
+                    bounds: row_bounds,
+                    background: if row.is_selected {
+                        style.selected_background
+                    } else {
+                        style.hovered_background
+                    },
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            // Indentation guides in the middle of the toggles of the ancestors.
+            primitives.extend((0..row.depth).map(|depth| Primitive::Quad {
+                bounds: Rectangle {
+                    x: row_bounds.x + depth as f32 * indent + (indent - GUIDE_WIDTH) / 2.0,
+                    y: row_bounds.y,
+                    width: GUIDE_WIDTH,
+                    height: row_bounds.height,
+                },
+                background: style.guide_color.into(),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            }));
+
+            if row.has_children {
+                let icon = if row.is_expanded {
+                    Icon::CaretDownFill
+                } else {
+                    Icon::CaretRightFill
+                };
+
+                primitives.push(Primitive::Text {
+                    content: icon.into(),
+                    font: ICON_FONT,
+                    size: f32::from(text_size),
+                    bounds: Rectangle {
+                        x: toggle_bounds.center_x(),
+                        y: toggle_bounds.center_y(),
+                        ..toggle_bounds
+                    },
+                    color: style.toggle_color,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+
+            primitives.push(Primitive::Text {
+                content: row.label.clone(),
+                font: iced_graphics::Font::Default,
+                size: f32::from(text_size),
+                bounds: Rectangle {
+                    y: label_bounds.center_y(),
+                    ..label_bounds
+                },
+                color: if row.is_selected {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                },
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
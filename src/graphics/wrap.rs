@@ -0,0 +1,46 @@
+//! Use a wrap to lay out elements in lines, wrapping them to the next line
+//! when the available width is exceeded.
+//!
+//! *This API requires the following crate features to be activated: `wrap`*
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Element};
+
+use crate::{core::renderer::DrawEnvironment, native::wrap};
+
+/// A container laying out its children from left to right, wrapping them to
+/// the next line when a line exceeds the available width.
+///
+/// This is an alias of an `iced_native` `Wrap` with an `iced_graphics::Renderer`.
+pub type Wrap<'a, Message, Backend> = wrap::Wrap<'a, Message, Renderer<Backend>>;
+
+impl<B> wrap::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, (), ()>,
+        elements: &[Element<'_, Message, Self>],
+    ) -> Self::Output {
+        let viewport = env
+            .viewport
+            .expect("Graphics: A viewport should exist for a Wrap");
+
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        let primitives = elements
+            .iter()
+            .zip(env.layout.children())
+            .map(|(element, layout)| {
+                let (primitive, new_mouse_interaction) =
+                    element.draw(self, env.defaults, layout, env.cursor_position, viewport);
+
+                mouse_interaction = mouse_interaction.max(new_mouse_interaction);
+
+                primitive
+            })
+            .collect();
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
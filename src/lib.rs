@@ -55,20 +55,39 @@ pub mod style;
 mod platform {
     #[doc(no_inline)]
     #[cfg(feature = "icons")]
-    pub use {crate::graphics::icons::Icon, crate::graphics::icons::ICON_FONT};
+    pub use crate::graphics::icons::{icon_to_char, Icon, ICON_FONT};
 
     #[doc(no_inline)]
     #[cfg(feature = "badge")]
-    pub use {crate::graphics::badge, badge::Badge};
+    pub use {
+        crate::graphics::badge,
+        badge::{Badge, BadgeAnchor},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "breadcrumbs")]
+    pub use {crate::graphics::breadcrumbs, breadcrumbs::Breadcrumbs};
 
     #[doc(no_inline)]
     #[cfg(feature = "card")]
     pub use {crate::graphics::card, card::Card};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "collapse")]
+    pub use {crate::graphics::collapse, collapse::Collapse};
+
     #[doc(no_inline)]
     #[cfg(feature = "color_picker")]
     pub use {crate::graphics::color_picker, color_picker::ColorPicker};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "combo_box")]
+    pub use {crate::graphics::combo_box, combo_box::ComboBox};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "context_menu")]
+    pub use {crate::graphics::context_menu, context_menu::ContextMenu};
+
     #[doc(no_inline)]
     #[cfg(feature = "date_picker")]
     pub use {crate::graphics::date_picker, date_picker::DatePicker};
@@ -77,9 +96,76 @@ mod platform {
     #[cfg(feature = "floating_button")]
     pub use {crate::graphics::floating_button, floating_button::FloatingButton};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "floating_element")]
+    pub use {crate::graphics::floating_element, floating_element::FloatingElement};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "gradient_picker")]
+    pub use {crate::graphics::gradient_picker, gradient_picker::GradientPicker};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "knob")]
+    pub use {crate::graphics::knob, knob::Knob};
+
     #[doc(no_inline)]
     #[cfg(feature = "modal")]
-    pub use {crate::graphics::modal, modal::Modal};
+    pub use {
+        crate::graphics::modal,
+        modal::{Modal, ModalStack},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "number_input")]
+    pub use {crate::graphics::number_input, number_input::NumberInput};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "overlay_corner")]
+    pub use {crate::graphics::overlay_corner, overlay_corner::OverlayCorner};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "pagination")]
+    pub use {crate::graphics::pagination, pagination::Pagination};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "popover")]
+    pub use {crate::graphics::popover, popover::Popover};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "progress_ring")]
+    pub use {crate::graphics::progress_ring, progress_ring::ProgressRing};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "range_slider")]
+    pub use {crate::graphics::range_slider, range_slider::RangeSlider};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "reorderable_list")]
+    pub use {crate::graphics::reorderable_list, reorderable_list::ReorderableList};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "segmented_button")]
+    pub use {crate::graphics::segmented_button, segmented_button::SegmentedButton};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "selection_list")]
+    pub use {crate::graphics::selection_list, selection_list::SelectionList};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "spinner")]
+    pub use {crate::graphics::spinner, spinner::Spinner};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "split")]
+    pub use {crate::graphics::split, split::Split};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "steps")]
+    pub use {crate::graphics::steps, steps::Steps};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "table")]
+    pub use {crate::graphics::table, table::Table};
 
     #[doc(no_inline)]
     #[cfg(feature = "tab_bar")]
@@ -98,6 +184,18 @@ mod platform {
     #[doc(no_inline)]
     #[cfg(feature = "time_picker")]
     pub use {crate::graphics::time_picker, time_picker::TimePicker};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "toast")]
+    pub use {crate::graphics::toast, toast::Toast};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tree_view")]
+    pub use {crate::graphics::tree_view, tree_view::TreeView};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "wrap")]
+    pub use {crate::graphics::wrap, wrap::Wrap};
 }
 #[cfg(target_arch = "wasm32")]
 pub mod web;
@@ -129,6 +227,14 @@ mod platform {
     #[cfg(feature = "modal")]
     pub use crate::web::{modal, modal::Modal};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "number_input")]
+    pub use crate::web::{number_input, number_input::NumberInput};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "spinner")]
+    pub use crate::web::{spinner, spinner::Spinner};
+
     #[doc(no_inline)]
     #[cfg(feature = "time_picker")]
     pub use crate::web::{time_picker, time_picker::TimePicker};
@@ -4,11 +4,22 @@
 use std::hash::Hash;
 
 use iced_native::{
-    event, layout, Align, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Widget,
+    event, layout, mouse, overlay, text, Align, Clipboard, Element, Event, Layout, Length, Point,
+    Rectangle, Widget,
 };
 
+use super::overlay::badge::BadgeTooltipOverlay;
 use crate::core::renderer::DrawEnvironment;
 
+pub mod anchor;
+pub use anchor::Anchor;
+
+pub mod badge_anchor;
+pub use badge_anchor::BadgeAnchor;
+
+pub mod ellipsis_text;
+pub use ellipsis_text::{Ellipsis, EllipsisText};
+
 /// A badge for color highlighting small information.
 ///
 /// # Example
@@ -31,6 +42,11 @@ pub struct Badge<'a, Message, Renderer: self::Renderer> {
     width: Length,
     /// The height of the [`Badge`].
     height: Length,
+    /// The maximum width of the [`Badge`].
+    max_width: u32,
+    /// The optional tooltip shown while the [`Badge`] is hovered, together
+    /// with the state tracking the hover.
+    tooltip: Option<(&'a mut TooltipState, String)>,
     /// The horizontal alignment of the [`Badge`](Badge).
     horizontal_alignment: Align,
     /// The vertical alignment of the [`Badge`](Badge).
@@ -57,6 +73,8 @@ where
             padding: 7,
             width: Length::Shrink,
             height: Length::Shrink,
+            max_width: u32::MAX,
+            tooltip: None,
             horizontal_alignment: Align::Center,
             vertical_alignment: Align::Center,
             style: Renderer::Style::default(),
@@ -64,6 +82,22 @@ where
         }
     }
 
+    /// Creates a new [`Badge`](Badge) showing the given text, truncated with
+    /// the given [`Ellipsis`](Ellipsis) if it exceeds the maximum width of
+    /// the [`Badge`](Badge).
+    ///
+    /// The full text is shown as a tooltip while the [`Badge`](Badge) is
+    /// hovered, which is tracked in the given [`TooltipState`](TooltipState).
+    pub fn truncated<T>(state: &'a mut TooltipState, text: T, ellipsis: Ellipsis) -> Self
+    where
+        T: Into<String>,
+        Renderer: 'a + text::Renderer,
+    {
+        let text = text.into();
+
+        Self::new(EllipsisText::new(text.clone()).ellipsis(ellipsis)).tooltip(state, text)
+    }
+
     /// Sets the padding of the [`Badge`](Badge).
     pub fn padding(mut self, units: u16) -> Self {
         self.padding = units;
@@ -82,6 +116,22 @@ where
         self
     }
 
+    /// Sets the maximum width of the [`Badge`](Badge).
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the tooltip that is shown below the [`Badge`](Badge) while it is
+    /// hovered, which is tracked in the given [`TooltipState`](TooltipState).
+    pub fn tooltip<T>(mut self, state: &'a mut TooltipState, tooltip: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.tooltip = Some((state, tooltip.into()));
+        self
+    }
+
     /// Sets the horizontal alignment of the content of the [`Badge`](Badge).
     pub fn align_x(mut self, alignment: Align) -> Self {
         self.horizontal_alignment = alignment;
@@ -99,6 +149,36 @@ where
         self.style = style.into();
         self
     }
+
+    /// Attaches the [`Badge`](Badge) to the top right corner of the given
+    /// child.
+    ///
+    /// The corner can be changed with [`BadgeAnchor::anchor`](BadgeAnchor::anchor).
+    pub fn attach<E>(self, child: E) -> BadgeAnchor<'a, Message, Renderer>
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        BadgeAnchor::new(self, child)
+    }
+}
+
+/// The state of the tooltip of a [`Badge`](Badge), tracking whether the
+/// [`Badge`](Badge) is hovered.
+///
+/// The tooltip is only added as an overlay while the [`Badge`](Badge) is
+/// hovered, so it doesn't hide the overlays of other widgets.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TooltipState {
+    /// Whether the [`Badge`](Badge) is hovered.
+    hovered: bool,
+}
+
+impl TooltipState {
+    /// Creates a new [`TooltipState`](TooltipState).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Badge<'a, Message, Renderer>
@@ -124,6 +204,7 @@ where
             .loose()
             .width(self.width)
             .height(self.height)
+            .max_width(self.max_width)
             .pad(padding);
 
         let mut content = self.content.layout(renderer, &limits.loose());
@@ -144,6 +225,12 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        if let (Some((state, _)), Event::Mouse(mouse::Event::CursorMoved { .. })) =
+            (&mut self.tooltip, &event)
+        {
+            state.hovered = layout.bounds().contains(cursor_position);
+        }
+
         self.content.on_event(
             event,
             layout
@@ -186,9 +273,24 @@ where
         self.padding.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.max_width.hash(state);
 
         self.content.hash_layout(state);
     }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        match &self.tooltip {
+            Some((state, tooltip)) if state.hovered => {
+                Some(BadgeTooltipOverlay::new(tooltip, layout.bounds(), &self.style).overlay())
+            }
+            _ => self.content.overlay(
+                layout
+                    .children()
+                    .next()
+                    .expect("Native: Layout should have a children layout for a badge."),
+            ),
+        }
+    }
 }
 
 /// The renderer of a [`Badge`](Badge).
@@ -205,6 +307,14 @@ pub trait Renderer: iced_native::Renderer {
         env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
         content: &Element<'_, Message, Self>,
     ) -> Self::Output;
+
+    /// Draws the tooltip of a [`Badge`](Badge) at the position of the given
+    /// layout, if the tooltip is shown.
+    fn draw_tooltip(
+        &mut self,
+        env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
+        tooltip: Option<&str>,
+    ) -> Self::Output;
 }
 
 #[cfg(debug_assertions)]
@@ -217,6 +327,13 @@ impl Renderer for iced_native::renderer::Null {
         _content: &Element<'_, Message, Self>,
     ) -> Self::Output {
     }
+
+    fn draw_tooltip(
+        &mut self,
+        _env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
+        _tooltip: Option<&str>,
+    ) -> Self::Output {
+    }
 }
 
 impl<'a, Message, Renderer> From<Badge<'a, Message, Renderer>> for Element<'a, Message, Renderer>
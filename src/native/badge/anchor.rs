@@ -0,0 +1,25 @@
+//! Use a badge for color highlighting important information.
+//!
+//! *This API requires the following crate features to be activated: badge*
+
+/// Positional [`Anchor`](Anchor) for the corner of the child of a
+/// [`BadgeAnchor`](super::BadgeAnchor) the [`Badge`](super::Badge) is placed
+/// on.
+#[derive(Copy, Clone, Debug, Hash)]
+pub enum Anchor {
+    /// NorthWest [`Anchor`](Anchor) for placing the [`Badge`](super::Badge)
+    /// on the top left corner of the child.
+    NorthWest,
+
+    /// NorthEast [`Anchor`](Anchor) for placing the [`Badge`](super::Badge)
+    /// on the top right corner of the child.
+    NorthEast,
+
+    /// SouthWest [`Anchor`](Anchor) for placing the [`Badge`](super::Badge)
+    /// on the bottom left corner of the child.
+    SouthWest,
+
+    /// SouthEast [`Anchor`](Anchor) for placing the [`Badge`](super::Badge)
+    /// on the bottom right corner of the child.
+    SouthEast,
+}
@@ -0,0 +1,161 @@
+//! Use a badge for color highlighting important information.
+//!
+//! *This API requires the following crate features to be activated: badge*
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout, overlay, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Widget,
+};
+
+use super::{Anchor, Badge};
+use crate::native::overlay::badge::BadgeAnchorOverlay;
+
+/// A [`Badge`](Badge) attached to a corner of another element, e.g. a
+/// notification count on a button.
+///
+/// The [`Badge`](Badge) is drawn as an overlay centered on the corner and
+/// covers that part of the child, which does not receive the cursor there.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::badge::Anchor;
+/// # use iced_native::{button, Button, Text, renderer::Null};
+/// #
+/// # pub type Badge<'a, Message> = iced_aw::native::Badge<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Notifications,
+/// }
+///
+/// let mut button_state = button::State::new();
+///
+/// let bell = Badge::new(Text::new("3"))
+///     .attach(
+///         Button::new(&mut button_state, Text::new("Notifications"))
+///             .on_press(Message::Notifications),
+///     )
+///     .anchor(Anchor::NorthEast);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct BadgeAnchor<'a, Message, Renderer: super::Renderer> {
+    /// The element the [`Badge`](Badge) is attached to.
+    child: Element<'a, Message, Renderer>,
+    /// The attached [`Badge`](Badge).
+    badge: Badge<'a, Message, Renderer>,
+    /// The corner of the child the [`Badge`](Badge) is placed on.
+    anchor: Anchor,
+}
+
+impl<'a, Message, Renderer> BadgeAnchor<'a, Message, Renderer>
+where
+    Renderer: super::Renderer,
+{
+    /// Creates a new [`BadgeAnchor`](BadgeAnchor) attaching the given
+    /// [`Badge`](Badge) to the top right corner of the given child.
+    pub fn new<E>(badge: Badge<'a, Message, Renderer>, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        BadgeAnchor {
+            child: child.into(),
+            badge,
+            anchor: Anchor::NorthEast,
+        }
+    }
+
+    /// Sets the [`Anchor`](Anchor) of the corner of the child the
+    /// [`Badge`](Badge) is placed on.
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for BadgeAnchor<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + super::Renderer,
+{
+    fn width(&self) -> Length {
+        self.child.width()
+    }
+
+    fn height(&self) -> Length {
+        self.child.height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.child.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        self.child.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.child
+            .draw(renderer, defaults, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.anchor.hash(state);
+        self.child.hash_layout(state);
+        self.badge.hash_layout(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let bounds = layout.bounds();
+        let position = match self.anchor {
+            Anchor::NorthWest => Point::new(bounds.x, bounds.y),
+            Anchor::NorthEast => Point::new(bounds.x + bounds.width, bounds.y),
+            Anchor::SouthWest => Point::new(bounds.x, bounds.y + bounds.height),
+            Anchor::SouthEast => Point::new(bounds.x + bounds.width, bounds.y + bounds.height),
+        };
+
+        // The overlay of the child takes precedence over the badge, e.g. the
+        // menu of a pick list.
+        let layout_child = self.child.overlay(layout);
+        if layout_child.is_some() {
+            return layout_child;
+        }
+
+        Some(BadgeAnchorOverlay::new(&self.badge).overlay(position))
+    }
+}
+
+impl<'a, Message, Renderer> From<BadgeAnchor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + super::Renderer,
+{
+    fn from(badge_anchor: BadgeAnchor<'a, Message, Renderer>) -> Self {
+        Element::new(badge_anchor)
+    }
+}
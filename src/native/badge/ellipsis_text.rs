@@ -0,0 +1,180 @@
+//! A text that is truncated with an ellipsis if it does not fit into its
+//! bounds.
+//!
+//! *This API requires the following crate features to be activated: badge*
+use std::{borrow::Cow, hash::Hash};
+
+use iced_native::{
+    layout, text, Color, Element, Hasher, HorizontalAlignment, Layout, Length, Point, Rectangle,
+    Size, VerticalAlignment, Widget,
+};
+
+pub use crate::core::ellipsis::Ellipsis;
+
+/// A text that is truncated with an [`Ellipsis`](Ellipsis) if it does not
+/// fit into its bounds.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::badge::{Ellipsis, EllipsisText};
+/// # use iced_native::renderer::Null;
+/// #
+/// let text = EllipsisText::<Null>::new("A very long status message")
+///     .ellipsis(Ellipsis::Middle);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct EllipsisText<Renderer: text::Renderer> {
+    /// The full content of the [`EllipsisText`](EllipsisText).
+    content: String,
+    /// The position of the ellipsis of the [`EllipsisText`](EllipsisText).
+    ellipsis: Ellipsis,
+    /// The optional size of the [`EllipsisText`](EllipsisText).
+    size: Option<u16>,
+    /// The optional color of the [`EllipsisText`](EllipsisText).
+    color: Option<Color>,
+    /// The font of the [`EllipsisText`](EllipsisText).
+    font: Renderer::Font,
+    /// The width of the [`EllipsisText`](EllipsisText).
+    width: Length,
+}
+
+impl<Renderer: text::Renderer> EllipsisText<Renderer> {
+    /// Creates a new [`EllipsisText`](EllipsisText) with the given content.
+    pub fn new<T: Into<String>>(content: T) -> Self {
+        Self {
+            content: content.into(),
+            ellipsis: Ellipsis::default(),
+            size: None,
+            color: None,
+            font: Renderer::Font::default(),
+            width: Length::Shrink,
+        }
+    }
+
+    /// Sets the position of the [`Ellipsis`](Ellipsis) of the
+    /// [`EllipsisText`](EllipsisText).
+    pub fn ellipsis(mut self, ellipsis: Ellipsis) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Sets the size of the [`EllipsisText`](EllipsisText).
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`](iced_native::Color) of the
+    /// [`EllipsisText`](EllipsisText).
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the font of the [`EllipsisText`](EllipsisText).
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`EllipsisText`](EllipsisText) boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Truncates the content to the given maximum width.
+    fn truncate(&self, renderer: &Renderer, size: u16, max_width: f32) -> Cow<'_, str> {
+        crate::core::ellipsis::truncate(&self.content, self.ellipsis, max_width, |content| {
+            text::Renderer::measure(
+                renderer,
+                content,
+                size,
+                self.font,
+                Size::new(f32::INFINITY, f32::INFINITY),
+            )
+            .0
+        })
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for EllipsisText<Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let size = self
+            .size
+            .unwrap_or_else(|| text::Renderer::default_size(renderer));
+
+        let bounds = limits.max();
+        let content = self.truncate(renderer, size, bounds.width);
+
+        let (width, height) = text::Renderer::measure(
+            renderer,
+            &content,
+            size,
+            self.font,
+            Size::new(f32::INFINITY, bounds.height),
+        );
+
+        layout::Node::new(limits.resolve(Size::new(width, height)))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let size = self
+            .size
+            .unwrap_or_else(|| text::Renderer::default_size(renderer));
+        let content = self.truncate(renderer, size, bounds.width).into_owned();
+
+        text::Renderer::draw(
+            renderer,
+            defaults,
+            bounds,
+            &content,
+            size,
+            self.font,
+            self.color,
+            HorizontalAlignment::Left,
+            VerticalAlignment::Top,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash(state);
+        self.ellipsis.hash(state);
+        self.size.hash(state);
+        self.width.hash(state);
+    }
+}
+
+impl<'a, Message, Renderer> From<EllipsisText<Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + text::Renderer,
+{
+    fn from(text: EllipsisText<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(text)
+    }
+}
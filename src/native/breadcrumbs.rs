@@ -0,0 +1,348 @@
+//! Use breadcrumbs to show the path to the current location, e.g. in a file
+//! manager or settings hierarchy.
+//!
+//! *This API requires the following crate features to be activated: `breadcrumbs`*
+use std::{fmt::Display, hash::Hash};
+
+use iced_native::{
+    event, layout, mouse, touch, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+pub use crate::core::breadcrumbs::Crumb;
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::{
+    breadcrumbs::{collapse, ELLIPSIS},
+    renderer::DrawEnvironment,
+};
+
+/// A path of clickable segments separated by a divider.
+///
+/// If the segments don't fit into the available width, the middle segments
+/// are collapsed into an ellipsis.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Breadcrumbs<'a, T, Message> = iced_aw::native::Breadcrumbs<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Navigate(usize),
+/// }
+///
+/// let breadcrumbs = Breadcrumbs::new(
+///     &["Home", "Documents", "Invoices"],
+///     Message::Navigate,
+/// )
+/// .divider(">");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Breadcrumbs<'a, T, Message, Renderer: self::Renderer> {
+    /// The segments of the [`Breadcrumbs`](Breadcrumbs).
+    segments: &'a [T],
+    /// The function that produces a message when a segment is clicked.
+    on_click: Box<dyn Fn(usize) -> Message>,
+    /// The divider between the segments.
+    divider: String,
+    /// The spacing around the dividers.
+    spacing: u16,
+    /// The optional text size of the [`Breadcrumbs`](Breadcrumbs).
+    text_size: Option<u16>,
+    /// The width of the [`Breadcrumbs`](Breadcrumbs).
+    width: Length,
+    /// The style of the [`Breadcrumbs`](Breadcrumbs).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> Breadcrumbs<'a, T, Message, Renderer>
+where
+    T: Display,
+    Renderer: self::Renderer,
+{
+    /// Creates new [`Breadcrumbs`](Breadcrumbs).
+    ///
+    /// It expects:
+    ///     * the segments of the path, starting at the root.
+    ///     * a function that will be called with the index of a segment when
+    ///         it is clicked.
+    pub fn new<F>(segments: &'a [T], on_click: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        Breadcrumbs {
+            segments,
+            on_click: Box::new(on_click),
+            divider: String::from("/"),
+            spacing: Renderer::DEFAULT_SPACING,
+            text_size: None,
+            width: Length::Shrink,
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the divider between the segments.
+    pub fn divider(mut self, divider: impl Into<String>) -> Self {
+        self.divider = divider.into();
+        self
+    }
+
+    /// Sets the spacing around the dividers.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the [`Breadcrumbs`](Breadcrumbs).
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the width of the [`Breadcrumbs`](Breadcrumbs).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the style of the [`Breadcrumbs`](Breadcrumbs).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the visible items fitting into the given width.
+    fn crumbs(&self, renderer: &Renderer, max_width: f32) -> Vec<Crumb> {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let measure = |text: &str| renderer.measure(text, text_size).0;
+
+        let widths: Vec<f32> = self
+            .segments
+            .iter()
+            .map(|segment| measure(&segment.to_string()))
+            .collect();
+
+        collapse(
+            &widths,
+            measure(&self.divider) + 2.0 * f32::from(self.spacing),
+            measure(ELLIPSIS),
+            max_width,
+        )
+    }
+
+    /// Returns the visible items of the given layout.
+    ///
+    /// Every item but the first one is preceded by a divider.
+    fn laid_out_crumbs(&self, layout: Layout<'_>) -> Vec<Crumb> {
+        let count = (layout.children().count() + 1) / 2;
+
+        if count == self.segments.len() {
+            (0..count).map(Crumb::Segment).collect()
+        } else {
+            // The first segment, the ellipsis and the trailing segments.
+            std::iter::once(Crumb::Segment(0))
+                .chain(std::iter::once(Crumb::Ellipsis))
+                .chain((self.segments.len() + 2 - count..self.segments.len()).map(Crumb::Segment))
+                .collect()
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for Breadcrumbs<'a, T, Message, Renderer>
+where
+    T: Display,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let spacing = f32::from(self.spacing);
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let crumbs = self.crumbs(renderer, limits.max().width);
+
+        let mut x = 0.0;
+        let mut height = 0.0_f32;
+        let mut children = Vec::with_capacity(2 * crumbs.len());
+
+        let mut push = |text: &str, x: &mut f32| {
+            let (width, text_height) = renderer.measure(text, text_size);
+            let mut node = layout::Node::new(Size::new(width, text_height));
+            node.move_to(Point::new(*x, 0.0));
+
+            *x += width;
+            height = height.max(text_height);
+            children.push(node);
+        };
+
+        for (i, crumb) in crumbs.iter().enumerate() {
+            if i > 0 {
+                x += spacing;
+                push(&self.divider, &mut x);
+                x += spacing;
+            }
+
+            match crumb {
+                Crumb::Segment(index) => push(&self.segments[*index].to_string(), &mut x),
+                Crumb::Ellipsis => push(ELLIPSIS, &mut x),
+            }
+        }
+
+        let size = limits.resolve(Size::new(x, height));
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let crumbs = self.laid_out_crumbs(layout);
+
+                let clicked = crumbs
+                    .iter()
+                    .zip(layout.children().step_by(2))
+                    .find(|(_, crumb_layout)| crumb_layout.bounds().contains(cursor_position));
+
+                match clicked {
+                    Some((Crumb::Segment(index), _)) => {
+                        #[cfg(feature = "instrumentation")]
+                        instrumentation::record(WidgetKind::Breadcrumbs, Action::Select(*index));
+                        messages.push((self.on_click)(*index));
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let crumbs = self.laid_out_crumbs(layout);
+        let labels: Vec<String> = self.segments.iter().map(ToString::to_string).collect();
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: None,
+                focus: (),
+            },
+            &crumbs,
+            &labels,
+            &self.divider,
+            text_size,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.divider.hash(state);
+        self.spacing.hash(state);
+        self.text_size.hash(state);
+        self.width.hash(state);
+
+        for segment in self.segments {
+            segment.to_string().hash(state);
+        }
+    }
+}
+
+/// The renderer of [`Breadcrumbs`](Breadcrumbs).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use [`Breadcrumbs`](Breadcrumbs) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default spacing around the dividers of
+    /// [`Breadcrumbs`](Breadcrumbs).
+    const DEFAULT_SPACING: u16;
+
+    /// The default text size of [`Breadcrumbs`](Breadcrumbs).
+    fn default_size(&self) -> u16;
+
+    /// Measures the given text and returns its width and height.
+    fn measure(&self, text: &str, size: u16) -> (f32, f32);
+
+    /// Draws [`Breadcrumbs`](Breadcrumbs).
+    ///
+    /// The children of the layout are the visible items, each but the first
+    /// one preceded by a divider.
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        crumbs: &[Crumb],
+        labels: &[String],
+        divider: &str,
+        text_size: u16,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_SPACING: u16 = 0;
+
+    fn default_size(&self) -> u16 {
+        0
+    }
+
+    fn measure(&self, _text: &str, _size: u16) -> (f32, f32) {
+        (0.0, 0.0)
+    }
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _crumbs: &[Crumb],
+        _labels: &[String],
+        _divider: &str,
+        _text_size: u16,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, T, Message, Renderer> From<Breadcrumbs<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Display,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(breadcrumbs: Breadcrumbs<'a, T, Message, Renderer>) -> Self {
+        Element::new(breadcrumbs)
+    }
+}
@@ -3,10 +3,21 @@
 //! *This API requires the following crate features to be activated: card*
 use std::hash::Hash;
 
-use iced_native::{event, touch, Clipboard, Element, Event, Layout, Length, Point, Size, Widget};
+use iced_native::{
+    event, layout, touch, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Size, Widget,
+};
 use iced_native::{mouse, Align};
 
-use crate::core::renderer::DrawEnvironment;
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::{
+    accessibility::{self, Accessibility, Role},
+    renderer::DrawEnvironment,
+};
+
+/// The distance the body of a [`Card`](Card) is scrolled per line of a
+/// mouse wheel.
+const SCROLL_LINE: f32 = 30.0;
 
 /// A card consisting of a head, body and optional foot.
 ///
@@ -48,12 +59,20 @@ pub struct Card<'a, Message, Renderer: self::Renderer> {
     close_size: Option<f32>,
     /// The optional message that is send if the close icon of the [`Card`](Card) is pressed.
     on_close: Option<Message>,
+    /// Whether the content of the [`Card`](Card) is still loading.
+    loading: bool,
     /// The head [`Element`](iced_native::Element) of the [`Card`](Card).
     head: Element<'a, Message, Renderer>,
     /// The body [`Element`](iced_native::Element) of the [`Card`](Card).
     body: Element<'a, Message, Renderer>,
     /// The optional foot [`Element`](iced_native::Element) of the [`Card`](Card).
     foot: Option<Element<'a, Message, Renderer>>,
+    /// The optional maximum height of the body of the [`Card`](Card).
+    max_body_height: Option<u16>,
+    /// The state of the scrollable body of the [`Card`](Card).
+    body_state: Option<&'a mut State>,
+    /// The accessibility information of the [`Card`](Card).
+    accessibility: Accessibility,
     /// The style of the [`Card`](Card).
     style: <Renderer as self::Renderer>::Style,
 }
@@ -84,9 +103,13 @@ where
             padding_foot: <Renderer as self::Renderer>::DEFAULT_PADDING,
             close_size: None,
             on_close: None,
+            loading: false,
             head: head.into(),
             body: body.into(),
             foot: None,
+            max_body_height: None,
+            body_state: None,
+            accessibility: Accessibility::default(),
             style: <Renderer as self::Renderer>::Style::default(),
         }
     }
@@ -125,6 +148,18 @@ where
         self
     }
 
+    /// Sets the maximum height of the body of the [`Card`](Card).
+    ///
+    /// A body whose content is taller than this is cut off at the maximum
+    /// height and can be scrolled by using the mouse wheel or by dragging its
+    /// scrollbar. The scroll position is kept in the given [`State`](State).
+    /// The scrollbar is drawn over the right padding of the body.
+    pub fn max_body_height(mut self, height: u16, state: &'a mut State) -> Self {
+        self.max_body_height = Some(height);
+        self.body_state = Some(state);
+        self
+    }
+
     /// Sets the padding of the [`Card`](Card).
     ///
     /// This will set the padding of the head, body and foot to the
@@ -169,11 +204,125 @@ where
         self
     }
 
+    /// Sets whether the content of the [`Card`](Card) is still loading.
+    ///
+    /// While loading, a skeleton is drawn over the head, body and foot instead
+    /// of their content and the content does not receive any events. The
+    /// layout of the [`Card`](Card) is not affected, so it keeps its size once
+    /// the data arrives.
+    ///
+    /// The skeleton shimmers as long as the application keeps redrawing, e.g.
+    /// by subscribing to a timer.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Sets the label announced for the [`Card`](Card), e.g. the text of its
+    /// head.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.accessibility.label = Some(label.into());
+        self
+    }
+
+    /// Sets the description announced for the [`Card`](Card).
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.accessibility.description = Some(description.into());
+        self
+    }
+
     /// Sets the style of the [`Card`](Card).
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
         self.style = style.into();
         self
     }
+
+    /// Returns the current offset of the body of the [`Card`](Card) with the
+    /// given layout, clamped to its scrollable range.
+    ///
+    /// Returns `None` if the body is not scrollable.
+    fn body_offset(&self, body_layout: Layout<'_>) -> Option<f32> {
+        self.body_state
+            .as_ref()
+            .map(|state| state.offset.min(max_body_offset(body_layout)).max(0.0))
+    }
+
+    /// Scrolls the body of the [`Card`](Card) on a mouse wheel or on a drag
+    /// of its scrollbar.
+    fn scroll_body(
+        &mut self,
+        event: Event,
+        body_layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> event::Status {
+        let max_offset = max_body_offset(body_layout);
+        let state = match self.body_state.as_mut() {
+            Some(state) if max_offset > 0.0 => state,
+            _ => return event::Status::Ignored,
+        };
+        state.offset = state.offset.min(max_offset).max(0.0);
+
+        let scrollbar = match scrollbar(
+            body_layout,
+            state.offset,
+            <Renderer as self::Renderer>::SCROLLBAR_WIDTH,
+        ) {
+            Some(scrollbar) => scrollbar,
+            None => return event::Status::Ignored,
+        };
+
+        // Moves the scroller so that the grabbed point is below the cursor.
+        let drag_to = |grab: f32| {
+            let range = (scrollbar.track.height - scrollbar.scroller.height).max(1.0);
+            let ratio = (cursor_position.y - grab - scrollbar.track.y) / range;
+
+            (ratio * max_offset).min(max_offset).max(0.0)
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if body_layout.bounds().contains(cursor_position) =>
+            {
+                let delta_y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * SCROLL_LINE,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                state.offset = (state.offset - delta_y).min(max_offset).max(0.0);
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if scrollbar.track.contains(cursor_position) =>
+            {
+                let grab = if scrollbar.scroller.contains(cursor_position) {
+                    cursor_position.y - scrollbar.scroller.y
+                } else {
+                    // Clicking the track centers the scroller on the cursor.
+                    let grab = scrollbar.scroller.height / 2.0;
+                    state.offset = drag_to(grab);
+                    grab
+                };
+
+                state.grab = Some(grab);
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                state.grab.map_or(event::Status::Ignored, |grab| {
+                    state.offset = drag_to(grab);
+                    event::Status::Captured
+                })
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => state
+                .grab
+                .take()
+                .map_or(event::Status::Ignored, |_| event::Status::Captured),
+            _ => event::Status::Ignored,
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Card<'a, Message, Renderer>
@@ -209,7 +358,14 @@ where
             self.close_size,
         );
 
-        let mut body_node = body_node(renderer, &limits, &self.body, self.padding_body, self.width);
+        let mut body_node = body_node(
+            renderer,
+            &limits,
+            &self.body,
+            self.padding_body,
+            self.width,
+            self.max_body_height,
+        );
 
         body_node.move_to(Point::new(
             body_node.bounds().x,
@@ -252,16 +408,21 @@ where
             .next()
             .expect("Native: Layout should have a head layout");
         let mut head_children = head_layout.children();
-        let head_status = self.head.on_event(
-            event.clone(),
-            head_children
-                .next()
-                .expect("Native: Layout should have a head content layout"),
-            cursor_position,
-            renderer,
-            clipboard,
-            messages,
-        );
+        let head_content_layout = head_children
+            .next()
+            .expect("Native: Layout should have a head content layout");
+        let head_status = if self.loading {
+            event::Status::Ignored
+        } else {
+            self.head.on_event(
+                event.clone(),
+                head_content_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                messages,
+            )
+        };
 
         let close_status = head_children
             .next()
@@ -275,6 +436,8 @@ where
                         // see issue #53667 <https://github.com/rust-lang/rust/issues/53667> for more information
                         .filter(|_| close_layout.bounds().contains(cursor_position))
                         .map_or(event::Status::Ignored, |on_close| {
+                            #[cfg(feature = "instrumentation")]
+                            instrumentation::record(WidgetKind::Card, Action::Close);
                             messages.push(on_close);
                             event::Status::Captured
                         }),
@@ -286,33 +449,55 @@ where
             .next()
             .expect("Native: Layout should have a body layout");
         let mut body_children = body_layout.children();
-        let body_status = self.body.on_event(
-            event.clone(),
-            body_children
-                .next()
-                .expect("Native: Layout should have a body content layout"),
-            cursor_position,
-            renderer,
-            clipboard,
-            messages,
-        );
+        let body_content_layout = body_children
+            .next()
+            .expect("Native: Layout should have a body content layout");
+        let body_status = if self.loading {
+            event::Status::Ignored
+        } else {
+            let body_cursor_position = self
+                .body_offset(body_layout)
+                .map_or(cursor_position, |offset| {
+                    scrolled_cursor_position(body_layout, offset, cursor_position)
+                });
+            let status = self.body.on_event(
+                event.clone(),
+                body_content_layout,
+                body_cursor_position,
+                renderer,
+                clipboard,
+                messages,
+            );
+
+            match status {
+                event::Status::Ignored => {
+                    self.scroll_body(event.clone(), body_layout, cursor_position)
+                }
+                event::Status::Captured => status,
+            }
+        };
 
         let foot_layout = children
             .next()
             .expect("Native: Layout should have a foot layout");
         let mut foot_children = foot_layout.children();
-        let foot_status = self.foot.as_mut().map_or(event::Status::Ignored, |foot| {
-            foot.on_event(
-                event,
-                foot_children
-                    .next()
-                    .expect("Native: Layout should have a foot content layout"),
-                cursor_position,
-                renderer,
-                clipboard,
-                messages,
-            )
-        });
+        let loading = self.loading;
+        let foot_status =
+            self.foot
+                .as_mut()
+                .filter(|_| !loading)
+                .map_or(event::Status::Ignored, |foot| {
+                    foot.on_event(
+                        event,
+                        foot_children
+                            .next()
+                            .expect("Native: Layout should have a foot content layout"),
+                        cursor_position,
+                        renderer,
+                        clipboard,
+                        messages,
+                    )
+                });
 
         head_status
             .merge(close_status)
@@ -328,6 +513,19 @@ where
         cursor_position: iced_graphics::Point,
         viewport: &iced_graphics::Rectangle,
     ) -> Renderer::Output {
+        let body_offset = self.body_offset(
+            layout
+                .children()
+                .nth(1)
+                .expect("Native: Layout should have a body layout"),
+        );
+
+        accessibility::Renderer::announce(
+            renderer,
+            self.accessibility.node(Role::Group),
+            layout.bounds(),
+        );
+
         renderer.draw(
             DrawEnvironment {
                 defaults,
@@ -340,6 +538,8 @@ where
             &self.head,
             &self.body,
             &self.foot,
+            self.loading,
+            body_offset,
         )
     }
 
@@ -352,6 +552,7 @@ where
         self.height.hash(state);
         self.max_width.hash(state);
         self.max_height.hash(state);
+        self.max_body_height.hash(state);
         self.head.hash_layout(state);
         self.body.hash_layout(state);
         if let Some(foot) = self.foot.as_ref() {
@@ -360,6 +561,104 @@ where
     }
 }
 
+/// The state of a [`Card`](Card) with a scrollable body.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct State {
+    /// The distance the body is scrolled down.
+    offset: f32,
+    /// The distance between the top of the scroller and the cursor while the
+    /// scroller is dragged.
+    grab: Option<f32>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) with the body scrolled to the top.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the distance the body is scrolled down.
+    #[must_use]
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Scrolls the body back to the top.
+    pub fn scroll_to_top(&mut self) {
+        self.offset = 0.0;
+    }
+}
+
+/// The scrollbar of the body of a [`Card`](Card) whose content is taller
+/// than its maximum height.
+#[derive(Clone, Copy, Debug)]
+pub struct Scrollbar {
+    /// The bounds of the track of the scrollbar.
+    pub track: Rectangle,
+    /// The bounds of the scroller inside the track.
+    pub scroller: Rectangle,
+}
+
+/// Returns the distance the body with the given layout can be scrolled down.
+fn max_body_offset(body_layout: Layout<'_>) -> f32 {
+    let bounds = body_layout.bounds();
+    let content_bounds = body_layout
+        .children()
+        .next()
+        .expect("Native: Layout should have a body content layout")
+        .bounds();
+    let padding = content_bounds.y - bounds.y;
+
+    (content_bounds.height + 2.0 * padding - bounds.height).max(0.0)
+}
+
+/// Calculates the [`Scrollbar`](Scrollbar) of the body with the given layout
+/// scrolled down by the given offset.
+///
+/// Returns `None` if the content of the body fits into it.
+pub fn scrollbar(body_layout: Layout<'_>, offset: f32, width: f32) -> Option<Scrollbar> {
+    let max_offset = max_body_offset(body_layout);
+    if max_offset <= 0.0 {
+        return None;
+    }
+
+    let bounds = body_layout.bounds();
+    let track = Rectangle {
+        x: bounds.x + bounds.width - width,
+        width,
+        ..bounds
+    };
+
+    let height = (track.height * bounds.height / (bounds.height + max_offset))
+        .max(2.0 * width)
+        .min(track.height);
+    let scroller = Rectangle {
+        y: track.y + (track.height - height) * offset / max_offset,
+        height,
+        ..track
+    };
+
+    Some(Scrollbar { track, scroller })
+}
+
+/// Translates the cursor position into the scrolled content of the body with
+/// the given layout.
+///
+/// The cursor is moved out of the content if it is outside of the body, so
+/// the parts of the content scrolled out of view are not hovered.
+pub fn scrolled_cursor_position(
+    body_layout: Layout<'_>,
+    offset: f32,
+    cursor_position: Point,
+) -> Point {
+    if body_layout.bounds().contains(cursor_position) {
+        Point::new(cursor_position.x, cursor_position.y + offset)
+    } else {
+        Point::new(-1.0, -1.0)
+    }
+}
+
 /// Calculates the layout of the head.
 fn head_node<'a, Message, Renderer>(
     renderer: &Renderer,
@@ -412,24 +711,36 @@ where
 }
 
 /// Calculates the layout of the body.
+///
+/// With a maximum height, the content is laid out with its full height and
+/// the body is cut off at the maximum height.
 fn body_node<'a, Message, Renderer>(
     renderer: &Renderer,
     limits: &iced_native::layout::Limits,
     body: &Element<'a, Message, Renderer>,
     padding: f32,
     width: Length,
+    max_height: Option<u16>,
 ) -> iced_native::layout::Node
 where
     Renderer: self::Renderer,
 {
-    let limits = limits
-        .clone()
-        .loose()
-        .width(width)
-        .height(body.height())
-        .pad(padding);
+    let mut limits = limits.clone().loose().width(width).height(body.height());
+    if let Some(max_height) = max_height {
+        limits = limits.max_height(u32::from(max_height));
+    }
+    let limits = limits.pad(padding);
 
-    let mut body = body.layout(renderer, &limits);
+    let mut body = if max_height.is_some() {
+        let content_limits = layout::Limits::new(
+            Size::new(limits.min().width, 0.0),
+            Size::new(limits.max().width, f32::INFINITY),
+        );
+
+        body.layout(renderer, &content_limits)
+    } else {
+        body.layout(renderer, &limits)
+    };
     let size = limits.resolve(body.size());
 
     body.move_to(Point::new(padding, padding));
@@ -469,23 +780,30 @@ where
 ///
 /// Your renderer will need to implement this trait before being
 /// able to use a [`Cary`](Card) in your user interface.
-pub trait Renderer: iced_native::Renderer {
+pub trait Renderer: iced_native::Renderer + accessibility::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
     /// The default padding of a [`Card`](Card).
     const DEFAULT_PADDING: f32;
 
+    /// The width of the scrollbar of a scrollable body of a [`Card`](Card).
+    const SCROLLBAR_WIDTH: f32;
+
     /// The default text size of a [`Card`](Card).
     fn default_size(&self) -> f32;
 
     /// Draws a [`Card`](Card).
+    ///
+    /// A scrollable body is scrolled down by the given offset.
     fn draw<Message>(
         &mut self,
         env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
         head: &Element<'_, Message, Self>,
         body: &Element<'_, Message, Self>,
         foot: &Option<Element<'_, Message, Self>>,
+        loading: bool,
+        body_offset: Option<f32>,
     ) -> Self::Output;
 }
 
@@ -495,6 +813,8 @@ impl Renderer for iced_native::renderer::Null {
 
     const DEFAULT_PADDING: f32 = 0.0;
 
+    const SCROLLBAR_WIDTH: f32 = 0.0;
+
     fn default_size(&self) -> f32 {
         0.0
     }
@@ -505,6 +825,8 @@ impl Renderer for iced_native::renderer::Null {
         _head: &Element<'_, Message, Self>,
         _body: &Element<'_, Message, Self>,
         _foot: &Option<Element<'_, Message, Self>>,
+        _loading: bool,
+        _body_offset: Option<f32>,
     ) -> Self::Output {
     }
 }
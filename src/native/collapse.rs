@@ -0,0 +1,459 @@
+//! Use a collapse to show or hide the bodies of sections by clicking on their
+//! headers.
+//!
+//! *This API requires the following crate features to be activated: `collapse`*
+use std::{collections::HashSet, hash::Hash};
+
+use iced_native::{
+    event, layout, mouse, overlay, touch, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::renderer::DrawEnvironment;
+
+/// A column of sections, each consisting of a header with an expand indicator
+/// and a body that is only shown while the section is expanded.
+///
+/// In exclusive mode, at most one section is expanded at a time, forming an
+/// accordion.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::collapse;
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type Collapse<'a, Message> = iced_aw::native::Collapse<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Toggled(usize, bool),
+/// }
+///
+/// let mut state = collapse::State::new();
+///
+/// let collapse = Collapse::new(&mut state)
+///     .push(Text::new("General"), Text::new("General settings"))
+///     .push(Text::new("Advanced"), Text::new("Advanced settings"))
+///     .exclusive(true)
+///     .on_toggle(Message::Toggled);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Collapse<'a, Message, Renderer: self::Renderer> {
+    /// The state of the [`Collapse`](Collapse).
+    state: &'a mut State,
+    /// The headers of the sections.
+    headers: Vec<Element<'a, Message, Renderer>>,
+    /// The bodies of the sections.
+    bodies: Vec<Element<'a, Message, Renderer>>,
+    /// Whether at most one section can be expanded at a time.
+    exclusive: bool,
+    /// The optional function that produces a message when a section is
+    /// expanded or collapsed.
+    on_toggle: Option<Box<dyn Fn(usize, bool) -> Message>>,
+    /// The width of the [`Collapse`](Collapse).
+    width: Length,
+    /// The spacing between the sections.
+    spacing: u16,
+    /// The padding of the headers and bodies.
+    padding: u16,
+    /// The optional size of the expand indicators.
+    indicator_size: Option<u16>,
+    /// The style of the [`Collapse`](Collapse).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Collapse<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Collapse`](Collapse) without any section.
+    ///
+    /// It expects a mutable reference to the [`Collapse`](Collapse)'s
+    /// [`State`](State).
+    pub fn new(state: &'a mut State) -> Self {
+        Collapse {
+            state,
+            headers: Vec::new(),
+            bodies: Vec::new(),
+            exclusive: false,
+            on_toggle: None,
+            width: Length::Fill,
+            spacing: 0,
+            padding: Renderer::DEFAULT_PADDING,
+            indicator_size: None,
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Adds a section with the given header and body to the
+    /// [`Collapse`](Collapse).
+    pub fn push<H, B>(mut self, header: H, body: B) -> Self
+    where
+        H: Into<Element<'a, Message, Renderer>>,
+        B: Into<Element<'a, Message, Renderer>>,
+    {
+        self.headers.push(header.into());
+        self.bodies.push(body.into());
+        self
+    }
+
+    /// Sets whether at most one section can be expanded at a time.
+    ///
+    /// Expanding a section then collapses all other sections.
+    pub fn exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
+    /// Sets the function that will be called with the index of a section and
+    /// whether it is expanded when its header is clicked.
+    pub fn on_toggle<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(usize, bool) -> Message,
+    {
+        self.on_toggle = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`Collapse`](Collapse).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between the sections.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding of the headers and bodies.
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the size of the expand indicators.
+    pub fn indicator_size(mut self, size: u16) -> Self {
+        self.indicator_size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`Collapse`](Collapse).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Collapse<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let indicator_size = f32::from(
+            self.indicator_size
+                .unwrap_or_else(|| renderer.default_size()),
+        );
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let width = limits.max().width;
+
+        let mut y = 0.0;
+        let mut sections = Vec::with_capacity(self.headers.len());
+
+        for (index, (header, body)) in self.headers.iter().zip(&self.bodies).enumerate() {
+            let header_limits = layout::Limits::new(
+                Size::ZERO,
+                Size::new(
+                    (width - 3.0 * padding - indicator_size).max(0.0),
+                    f32::INFINITY,
+                ),
+            )
+            .width(header.width())
+            .height(header.height());
+            let mut header = header.layout(renderer, &header_limits);
+            let header_height = header.size().height.max(indicator_size) + 2.0 * padding;
+            header.move_to(Point::new(
+                2.0 * padding + indicator_size,
+                (header_height - header.size().height) / 2.0,
+            ));
+
+            let mut indicator = layout::Node::new(Size::new(indicator_size, indicator_size));
+            indicator.move_to(Point::new(padding, (header_height - indicator_size) / 2.0));
+
+            let mut children = vec![layout::Node::with_children(
+                Size::new(width, header_height),
+                vec![indicator, header],
+            )];
+            let mut height = header_height;
+
+            if self.state.is_expanded(index) {
+                let body_limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new((width - 2.0 * padding).max(0.0), f32::INFINITY),
+                )
+                .width(body.width())
+                .height(body.height());
+                let mut body = body.layout(renderer, &body_limits);
+                body.move_to(Point::new(padding, header_height + padding));
+
+                height += body.size().height + 2.0 * padding;
+                children.push(body);
+            }
+
+            let mut section = layout::Node::with_children(Size::new(width, height), children);
+            section.move_to(Point::new(0.0, y));
+
+            y += height + f32::from(self.spacing);
+            sections.push(section);
+        }
+
+        let height = (y - f32::from(self.spacing)).max(0.0);
+        let size = limits.resolve(Size::new(width, height));
+
+        layout::Node::with_children(size, sections)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let mut status = event::Status::Ignored;
+        let mut toggled = None;
+
+        for (index, ((header, body), section)) in self
+            .headers
+            .iter_mut()
+            .zip(self.bodies.iter_mut())
+            .zip(layout.children())
+            .enumerate()
+        {
+            let mut children = section.children();
+            let header_layout = children
+                .next()
+                .expect("Native: Layout should have a header layout for a Collapse section");
+            let header_content_layout = header_layout
+                .children()
+                .nth(1)
+                .expect("Native: Layout should have a header content layout for a Collapse");
+
+            let header_status = header.on_event(
+                event.clone(),
+                header_content_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                messages,
+            );
+
+            if let Some(body_layout) = children.next() {
+                status = status.merge(body.on_event(
+                    event.clone(),
+                    body_layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    messages,
+                ));
+            }
+
+            // Clicks handled by the content of the header don't toggle.
+            if header_status == event::Status::Ignored
+                && header_layout.bounds().contains(cursor_position)
+            {
+                match event {
+                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                    | Event::Touch(touch::Event::FingerPressed { .. }) => toggled = Some(index),
+                    _ => {}
+                }
+            }
+
+            status = status.merge(header_status);
+        }
+
+        if let Some(index) = toggled {
+            let expanded = !self.state.is_expanded(index);
+
+            if expanded {
+                self.state.expand(index, self.exclusive);
+                #[cfg(feature = "instrumentation")]
+                instrumentation::record(WidgetKind::Collapse, Action::Expand(index));
+            } else {
+                self.state.collapse(index);
+                #[cfg(feature = "instrumentation")]
+                instrumentation::record(WidgetKind::Collapse, Action::Collapse(index));
+            }
+
+            if let Some(on_toggle) = &self.on_toggle {
+                messages.push(on_toggle(index, expanded));
+            }
+
+            return event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: Some(viewport),
+                focus: (),
+            },
+            &self.headers,
+            &self.bodies,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.spacing.hash(state);
+        self.padding.hash(state);
+        self.indicator_size.hash(state);
+
+        for (index, (header, body)) in self.headers.iter().zip(&self.bodies).enumerate() {
+            header.hash_layout(state);
+
+            if self.state.is_expanded(index) {
+                index.hash(state);
+                body.hash_layout(state);
+            }
+        }
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        // Only the sections of expanded bodies have a body layout.
+        self.bodies
+            .iter_mut()
+            .zip(layout.children())
+            .find_map(|(body, section)| {
+                section
+                    .children()
+                    .nth(1)
+                    .and_then(|body_layout| body.overlay(body_layout))
+            })
+    }
+}
+
+/// The state of a [`Collapse`](Collapse) keeping track of the expanded
+/// sections.
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    /// The indices of the expanded sections.
+    expanded: HashSet<usize>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) with all sections collapsed.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the section at the given index is expanded.
+    pub fn is_expanded(&self, index: usize) -> bool {
+        self.expanded.contains(&index)
+    }
+
+    /// Expands the section at the given index.
+    ///
+    /// If `exclusive` is true, all other sections are collapsed.
+    pub fn expand(&mut self, index: usize, exclusive: bool) {
+        if exclusive {
+            self.expanded.clear();
+        }
+
+        let _ = self.expanded.insert(index);
+    }
+
+    /// Collapses the section at the given index.
+    pub fn collapse(&mut self, index: usize) {
+        let _ = self.expanded.remove(&index);
+    }
+}
+
+/// The renderer of a [`Collapse`](Collapse).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Collapse`](Collapse) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default padding of the headers and bodies of a
+    /// [`Collapse`](Collapse).
+    const DEFAULT_PADDING: u16;
+
+    /// The default size of the expand indicators of a
+    /// [`Collapse`](Collapse).
+    fn default_size(&self) -> u16;
+
+    /// Draws a [`Collapse`](Collapse).
+    ///
+    /// A section is expanded if its layout contains a body layout.
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        headers: &[Element<'_, Message, Self>],
+        bodies: &[Element<'_, Message, Self>],
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 0;
+
+    fn default_size(&self) -> u16 {
+        0
+    }
+
+    fn draw<Message>(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _headers: &[Element<'_, Message, Self>],
+        _bodies: &[Element<'_, Message, Self>],
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<Collapse<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(collapse: Collapse<'a, Message, Renderer>) -> Self {
+        Element::new(collapse)
+    }
+}
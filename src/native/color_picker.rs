@@ -1,20 +1,41 @@
 //! Use a color picker as an input element for picking colors.
 //!
 //! *This API requires the following crate features to be activated: `color_picker`*
-use std::hash::Hash;
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
 
 use color_picker::ColorBarDragged;
 use iced_native::{
-    button, column, event, overlay, row, text_input, Clipboard, Color, Element, Event, Layout,
-    Point, Widget,
+    button, column, event, overlay, row, text, text_input, Button, Clipboard, Color, Element,
+    Event, Layout, Length, Point, Widget,
 };
 
 pub use super::overlay::color_picker::Renderer;
 use super::{
     icon_text,
-    overlay::color_picker::{self, ColorPickerOverlay, Focus},
+    overlay::{
+        animated::{self, AnimatedOverlay},
+        color_picker::{self, picker_layout, picker_on_event, swatches, ColorPickerOverlay, Focus},
+    },
+    stateful_overlay::{OverlayState, StatefulOverlayWidget},
+    IconText,
+};
+use crate::{
+    core::{accessibility::Accessibility, renderer::DrawEnvironment},
+    graphics::icons::Icon,
 };
 
+pub mod channel_slider;
+pub use channel_slider::ChannelSlider;
+
+pub mod color_swatch;
+pub use color_swatch::ColorSwatch;
+
+pub mod hsv_selector;
+pub use hsv_selector::HsvSelector;
+
 /// An input element for picking colors.
 ///
 /// # Example
@@ -56,6 +77,15 @@ where
     on_cancel: Message,
     /// The function thet produces a message when the submit button of the [`ColorPickerOverlay`](ColorPickerOverlay) is pressed.
     on_submit: Box<dyn Fn(Color) -> Message>,
+    /// The preset colors shown as swatches in the
+    /// [`ColorPickerOverlay`](ColorPickerOverlay).
+    palette: Vec<Color>,
+    /// The optional duration of the open and close animation of the
+    /// [`ColorPickerOverlay`](ColorPickerOverlay).
+    animation: Option<Duration>,
+    /// The accessibility information of the
+    /// [`ColorPickerOverlay`](ColorPickerOverlay).
+    accessibility: Accessibility,
     /// The style of the [`ColorPickerOverlay`](ColorPickerOverlay).
     style: <Renderer as color_picker::Renderer>::Style,
 }
@@ -85,10 +115,49 @@ where
             underlay: underlay.into(),
             on_cancel,
             on_submit: Box::new(on_submit),
+            palette: Vec::new(),
+            animation: None,
+            accessibility: Accessibility::default(),
             style: <Renderer as color_picker::Renderer>::Style::default(),
         }
     }
 
+    /// Sets the preset colors shown as swatches in the
+    /// [`ColorPickerOverlay`](ColorPickerOverlay).
+    ///
+    /// The swatches of the palette are followed by the swatches of the
+    /// recently submitted colors. Pressing on a swatch selects its color.
+    pub fn palette(mut self, palette: Vec<Color>) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Animates the opening and closing of the [`ColorPickerOverlay`](ColorPickerOverlay)
+    /// over the given duration.
+    ///
+    /// The overlay slides into place when it is shown and out of place when
+    /// it is hidden. The animation only advances while the application
+    /// redraws, e.g. by subscribing to a timer. Without an animation the
+    /// overlay is shown and hidden instantly.
+    pub fn animation(mut self, duration: Duration) -> Self {
+        self.animation = Some(duration);
+        self
+    }
+
+    /// Sets the label announced for the open
+    /// [`ColorPickerOverlay`](ColorPickerOverlay).
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.accessibility.label = Some(label.into());
+        self
+    }
+
+    /// Sets the description announced for the open
+    /// [`ColorPickerOverlay`](ColorPickerOverlay).
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.accessibility.description = Some(description.into());
+        self
+    }
+
     /// Sets the style of the [`ColorPicker`](ColorPicker).
     pub fn style<S>(mut self, style: S) -> Self
     where
@@ -104,6 +173,8 @@ where
 pub struct State {
     /// The visibility of the overlay.
     pub(crate) show: bool,
+    /// The point in time the overlay was last shown or hidden.
+    pub(crate) changed_at: Option<Instant>,
     /// The state of the overlay.
     pub(crate) overlay_state: color_picker::State,
     /// The state of the cancel button.
@@ -118,6 +189,7 @@ impl State {
     pub fn new() -> Self {
         Self {
             show: false,
+            changed_at: None,
             overlay_state: color_picker::State::default(),
             cancel_button: button::State::new(),
             submit_button: button::State::new(),
@@ -127,13 +199,38 @@ impl State {
     /// Sets the visibility of the [`ColorPickerOverlay`](ColorPickerOverlay).
     pub fn show(&mut self, b: bool) {
         self.overlay_state.focus = if b { Focus::Overlay } else { Focus::None };
+        if b != self.show {
+            self.changed_at = Some(Instant::now());
+        }
         self.show = b;
     }
 
+    /// Returns the recently submitted colors, starting with the most recent
+    /// one.
+    #[must_use]
+    pub fn recent_colors(&self) -> &[Color] {
+        &self.overlay_state.recent
+    }
+
     /// Resets the color of the state.
     pub fn reset(&mut self) {
         self.overlay_state.color = Color::from_rgb(0.5, 0.25, 0.25);
         self.overlay_state.color_bar_dragged = ColorBarDragged::None;
+        self.overlay_state.hex_input.clear();
+    }
+}
+
+impl OverlayState for State {
+    fn is_shown(&self) -> bool {
+        self.show
+    }
+
+    fn show(&mut self, b: bool) {
+        Self::show(self, b);
+    }
+
+    fn changed_at(&self) -> Option<Instant> {
+        self.changed_at
     }
 }
 
@@ -141,6 +238,7 @@ impl<'a, Message, Renderer> Widget<Message, Renderer> for ColorPicker<'a, Messag
 where
     Message: 'static + Clone,
     Renderer: color_picker::Renderer
+        + animated::Renderer
         + button::Renderer
         + column::Renderer
         + icon_text::Renderer
@@ -203,14 +301,43 @@ where
     }
 
     fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
-        if !self.state.show {
-            return self.underlay.overlay(layout);
-        }
+        self.stateful_overlay(layout)
+    }
+}
+
+impl<'a, Message, Renderer> StatefulOverlayWidget<'a, Message, Renderer>
+    for ColorPicker<'a, Message, Renderer>
+where
+    Message: 'static + Clone,
+    Renderer: color_picker::Renderer
+        + animated::Renderer
+        + button::Renderer
+        + column::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text_input::Renderer,
+{
+    type State = State;
+
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    fn underlay_mut(&mut self) -> &mut Element<'a, Message, Renderer> {
+        &mut self.underlay
+    }
 
+    fn animation(&self) -> Option<Duration> {
+        self.animation
+    }
+
+    fn build_overlay(&mut self, layout: Layout<'_>) -> overlay::Element<'_, Message, Renderer> {
         let bounds = layout.bounds();
         let position = Point::new(bounds.center_x(), bounds.center_y());
 
-        Some(
+        let visibility = self.visibility();
+
+        AnimatedOverlay::new(
             ColorPickerOverlay::new(
                 &mut self.state,
                 self.on_cancel.clone(),
@@ -218,8 +345,12 @@ where
                 position,
                 &self.style,
             )
+            .palette(&self.palette)
+            .accessibility(&self.accessibility)
             .overlay(),
+            visibility,
         )
+        .overlay()
     }
 }
 
@@ -229,6 +360,7 @@ where
     Message: 'static + Clone,
     Renderer: 'a
         + color_picker::Renderer
+        + animated::Renderer
         + button::Renderer
         + column::Renderer
         + icon_text::Renderer
@@ -239,3 +371,234 @@ where
         Element::new(color_picker)
     }
 }
+
+/// A color picker embedded inline into the layout instead of being shown as
+/// an overlay.
+///
+/// It shares the same selection logic as the overlay of the
+/// [`ColorPicker`](ColorPicker), so it can live inside property grids and
+/// toolbars.
+///
+/// # Example
+/// ```
+/// # use iced_aw::color_picker;
+/// # use iced_native::{Color, renderer::Null};
+/// #
+/// # pub type InlineColorPicker<'a, Message> = iced_aw::native::color_picker::InlineColorPicker<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Cancel,
+///     Submit(Color),
+/// }
+///
+/// let mut state = color_picker::State::new();
+///
+/// let color_picker = InlineColorPicker::new(
+///     &mut state,
+///     Message::Cancel,
+///     Message::Submit,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct InlineColorPicker<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: color_picker::Renderer,
+{
+    /// The state of the [`InlineColorPicker`](InlineColorPicker).
+    state: &'a mut color_picker::State,
+    /// The cancel button of the [`InlineColorPicker`](InlineColorPicker).
+    cancel_button: Element<'a, Message, Renderer>,
+    /// The submit button of the [`InlineColorPicker`](InlineColorPicker).
+    submit_button: Element<'a, Message, Renderer>,
+    /// The message that is send if the [`InlineColorPicker`](InlineColorPicker)
+    /// is cancelled.
+    on_cancel: Message,
+    /// The function that produces a message when the submit button of the [`InlineColorPicker`](InlineColorPicker) is pressed.
+    on_submit: Box<dyn Fn(Color) -> Message>,
+    /// The preset colors shown as swatches.
+    palette: Vec<Color>,
+    /// The style of the [`InlineColorPicker`](InlineColorPicker).
+    style: <Renderer as color_picker::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> InlineColorPicker<'a, Message, Renderer>
+where
+    Message: 'static + Clone,
+    Renderer: 'a + color_picker::Renderer + button::Renderer + icon_text::Renderer,
+{
+    /// Creates a new [`InlineColorPicker`](InlineColorPicker).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`ColorPicker`](ColorPicker)'s [`State`](State).
+    ///     * a message that will be send when the cancel button of the
+    ///         [`InlineColorPicker`](InlineColorPicker) is pressed.
+    ///     * a function that will be called when the submit button of the
+    ///         [`InlineColorPicker`](InlineColorPicker) is pressed, which takes
+    ///         the picked [`Color`](iced_native::Color) value.
+    pub fn new<F>(state: &'a mut State, on_cancel: Message, on_submit: F) -> Self
+    where
+        F: 'static + Fn(Color) -> Message,
+    {
+        let State {
+            overlay_state,
+            cancel_button,
+            submit_button,
+            ..
+        } = state;
+
+        Self {
+            state: overlay_state,
+            cancel_button: Button::new(cancel_button, IconText::new(Icon::X).width(Length::Fill))
+                .width(Length::Fill)
+                .on_press(on_cancel.clone())
+                .into(),
+            submit_button: Button::new(
+                submit_button,
+                IconText::new(Icon::Check).width(Length::Fill),
+            )
+            .width(Length::Fill)
+            .on_press(on_cancel.clone()) // Sending a fake message
+            .into(),
+            on_cancel,
+            on_submit: Box::new(on_submit),
+            palette: Vec::new(),
+            style: <Renderer as color_picker::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the preset colors shown as swatches in the
+    /// [`InlineColorPicker`](InlineColorPicker).
+    ///
+    /// The swatches of the palette are followed by the swatches of the
+    /// recently submitted colors. Pressing on a swatch selects its color.
+    pub fn palette(mut self, palette: Vec<Color>) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Sets the style of the [`InlineColorPicker`](InlineColorPicker).
+    pub fn style<S>(mut self, style: S) -> Self
+    where
+        S: Into<<Renderer as color_picker::Renderer>::Style>,
+    {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for InlineColorPicker<'a, Message, Renderer>
+where
+    Message: 'static + Clone,
+    Renderer: 'a
+        + color_picker::Renderer
+        + button::Renderer
+        + column::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer
+        + text_input::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        picker_layout(
+            renderer,
+            limits.max(),
+            swatches(&self.palette, &self.state.recent).len(),
+            &self.cancel_button,
+            &self.submit_button,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        picker_on_event(
+            self.state,
+            &mut self.cancel_button,
+            &mut self.submit_button,
+            &self.on_cancel,
+            &self.on_submit,
+            &self.palette,
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: iced_native::Layout<'_>,
+        cursor_position: iced_graphics::Point,
+        viewport: &iced_graphics::Rectangle,
+    ) -> Renderer::Output {
+        <Renderer as color_picker::Renderer>::draw(
+            renderer,
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: Some(viewport),
+                focus: self.state.focus,
+            },
+            &self.state.color,
+            &self.state.hex_input,
+            &swatches(&self.palette, &self.state.recent),
+            &self.state.sat_value_canvas_cache,
+            &self.state.hue_canvas_cache,
+            &self.cancel_button,
+            &self.submit_button,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        swatches(&self.palette, &self.state.recent)
+            .len()
+            .hash(state);
+    }
+}
+
+impl<'a, Message, Renderer> From<InlineColorPicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'static + Clone,
+    Renderer: 'a
+        + color_picker::Renderer
+        + button::Renderer
+        + column::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer
+        + text_input::Renderer,
+{
+    fn from(color_picker: InlineColorPicker<'a, Message, Renderer>) -> Self {
+        Element::new(color_picker)
+    }
+}
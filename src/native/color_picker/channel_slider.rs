@@ -0,0 +1,339 @@
+//! A slider for a single RGBA channel of a color, as used by the
+//! [`ColorPicker`](super::ColorPicker).
+//!
+//! *This API requires the following crate features to be activated: `color_picker`*
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout, mouse, touch, Clipboard, Color, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+use crate::core::renderer::DrawEnvironment;
+
+/// The spacing between the label, the bar and the value of a
+/// [`ChannelSlider`](ChannelSlider).
+const SPACING: u16 = 10;
+
+/// A channel of a color edited by a [`ChannelSlider`](ChannelSlider).
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Channel {
+    /// The red channel.
+    Red,
+
+    /// The green channel.
+    Green,
+
+    /// The blue channel.
+    Blue,
+
+    /// The alpha channel.
+    Alpha,
+}
+
+impl Channel {
+    /// Gets the value of this channel of the given color.
+    #[must_use]
+    pub const fn value(self, color: Color) -> f32 {
+        match self {
+            Self::Red => color.r,
+            Self::Green => color.g,
+            Self::Blue => color.b,
+            Self::Alpha => color.a,
+        }
+    }
+
+    /// Sets the value of this channel of the given color.
+    #[must_use]
+    pub fn with_value(self, color: Color, value: f32) -> Color {
+        let value = value.clamp(0.0, 1.0);
+
+        match self {
+            Self::Red => Color { r: value, ..color },
+            Self::Green => Color { g: value, ..color },
+            Self::Blue => Color { b: value, ..color },
+            Self::Alpha => Color { a: value, ..color },
+        }
+    }
+
+    /// Gets the label of this channel.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Red => "R:",
+            Self::Green => "G:",
+            Self::Blue => "B:",
+            Self::Alpha => "A:",
+        }
+    }
+}
+
+/// The state of a [`ChannelSlider`](ChannelSlider).
+#[derive(Debug, Default)]
+pub struct State {
+    /// Whether the bar of the [`ChannelSlider`](ChannelSlider) is dragged.
+    dragging: bool,
+}
+
+impl State {
+    /// Creates a new [`State`](State).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// A slider editing a single channel of a color, showing a label, a bar and
+/// the value of the channel.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::color_picker::channel_slider::{self, Channel};
+/// # use iced_native::{Color, renderer::Null};
+/// #
+/// # pub type ChannelSlider<'a, Message> = iced_aw::native::color_picker::ChannelSlider<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     ColorChanged(Color),
+/// }
+///
+/// let mut state = channel_slider::State::new();
+///
+/// let channel_slider = ChannelSlider::new(
+///     &mut state,
+///     Channel::Alpha,
+///     Color::BLACK,
+///     Message::ColorChanged,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ChannelSlider<'a, Message, Renderer: self::Renderer> {
+    /// The state of the [`ChannelSlider`](ChannelSlider).
+    state: &'a mut State,
+    /// The edited channel.
+    channel: Channel,
+    /// The edited color.
+    color: Color,
+    /// The function that produces a message when the color changed.
+    on_change: Box<dyn Fn(Color) -> Message>,
+    /// The width of the [`ChannelSlider`](ChannelSlider).
+    width: Length,
+    /// The style of the [`ChannelSlider`](ChannelSlider).
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer> ChannelSlider<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`ChannelSlider`](ChannelSlider) editing the given
+    /// channel of the given color.
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`ChannelSlider`](ChannelSlider)'s [`State`](State).
+    ///     * the [`Channel`](Channel) to edit.
+    ///     * the current [`Color`](iced_native::Color).
+    ///     * a function that will be called with the changed
+    ///         [`Color`](iced_native::Color) when the channel is changed.
+    pub fn new<F>(state: &'a mut State, channel: Channel, color: Color, on_change: F) -> Self
+    where
+        F: 'static + Fn(Color) -> Message,
+    {
+        Self {
+            state,
+            channel,
+            color,
+            on_change: Box::new(on_change),
+            width: Length::Fill,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`ChannelSlider`](ChannelSlider).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the style of the [`ChannelSlider`](ChannelSlider).
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for ChannelSlider<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(Renderer::DEFAULT_HEIGHT)
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let height = f32::from(Renderer::DEFAULT_HEIGHT);
+        let spacing = f32::from(SPACING);
+
+        let size = limits
+            .width(self.width)
+            .height(Length::Units(Renderer::DEFAULT_HEIGHT))
+            .resolve(Size::ZERO);
+
+        let label = layout::Node::new(Size::new(height, height));
+
+        let mut value = layout::Node::new(Size::new(2.0 * height, height));
+        value.move_to(Point::new(size.width - 2.0 * height, 0.0));
+
+        let mut bar = layout::Node::new(Size::new(
+            (size.width - 3.0 * height - 2.0 * spacing).max(0.0),
+            height,
+        ));
+        bar.move_to(Point::new(height + spacing, 0.0));
+
+        layout::Node::with_children(size, vec![label, bar, value])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bar_bounds = layout
+            .children()
+            .nth(1)
+            .expect("Native: Layout should have a bar layout")
+            .bounds();
+
+        let value = self.channel.value(self.color);
+        let new_value = match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
+                mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
+                    if bar_bounds.contains(cursor_position) {
+                        Some(value.mul_add(255.0, y).clamp(0.0, 255.0) / 255.0)
+                    } else {
+                        None
+                    }
+                }
+            },
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                self.state.dragging = bar_bounds.contains(cursor_position);
+                None
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.dragging = false;
+                None
+            }
+            _ => None,
+        };
+
+        let new_value = new_value.or_else(|| {
+            if self.state.dragging {
+                Some(((cursor_position.x - bar_bounds.x).max(0.0) / bar_bounds.width).min(1.0))
+            } else {
+                None
+            }
+        });
+
+        match new_value {
+            Some(new_value) => {
+                if (new_value - value).abs() > f32::EPSILON {
+                    messages.push((self.on_change)(
+                        self.channel.with_value(self.color, new_value),
+                    ));
+                }
+                event::Status::Captured
+            }
+            None => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: Some(viewport),
+                focus: self.state.dragging,
+            },
+            self.channel,
+            self.color,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+    }
+}
+
+/// The renderer of a [`ChannelSlider`](ChannelSlider).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`ChannelSlider`](ChannelSlider) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default height of a [`ChannelSlider`](ChannelSlider).
+    const DEFAULT_HEIGHT: u16;
+
+    /// Draws a [`ChannelSlider`](ChannelSlider).
+    ///
+    /// The focus of the environment is true while the bar is dragged.
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, bool>,
+        channel: Channel,
+        color: Color,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_HEIGHT: u16 = 0;
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, bool>,
+        _channel: Channel,
+        _color: Color,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<ChannelSlider<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(channel_slider: ChannelSlider<'a, Message, Renderer>) -> Self {
+        Element::new(channel_slider)
+    }
+}
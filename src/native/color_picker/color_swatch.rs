@@ -0,0 +1,202 @@
+//! A small swatch showing a color, usable as a compact trigger for a
+//! [`ColorPicker`](super::ColorPicker).
+//!
+//! *This API requires the following crate features to be activated: `color_picker`*
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout, mouse, touch, Clipboard, Color, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+use crate::core::renderer::DrawEnvironment;
+
+/// A small swatch showing a color.
+///
+/// # Example
+/// ```
+/// # use iced_aw::color_picker;
+/// # use iced_native::{Color, renderer::Null};
+/// #
+/// # pub type ColorPicker<'a, Message> = iced_aw::native::ColorPicker<'a, Message, Null>;
+/// # pub type ColorSwatch<Message> = iced_aw::native::color_picker::ColorSwatch<Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Open,
+///     Cancel,
+///     Submit(Color),
+/// }
+///
+/// let mut state = color_picker::State::new();
+///
+/// let color_picker = ColorPicker::new(
+///     &mut state,
+///     ColorSwatch::new(Color::BLACK).on_press(Message::Open),
+///     Message::Cancel,
+///     Message::Submit,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ColorSwatch<Message, Renderer: self::Renderer> {
+    /// The color shown by the [`ColorSwatch`](ColorSwatch).
+    color: Color,
+    /// The size of the [`ColorSwatch`](ColorSwatch).
+    size: u16,
+    /// The message that is send if the [`ColorSwatch`](ColorSwatch) is pressed.
+    on_press: Option<Message>,
+    /// The style of the [`ColorSwatch`](ColorSwatch).
+    style: Renderer::Style,
+}
+
+impl<Message, Renderer> ColorSwatch<Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`ColorSwatch`](ColorSwatch) showing the given
+    /// [`Color`](iced_native::Color).
+    pub fn new(color: Color) -> Self {
+        Self {
+            color,
+            size: Renderer::DEFAULT_SIZE,
+            on_press: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the size of the [`ColorSwatch`](ColorSwatch).
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the message that will be produced when the
+    /// [`ColorSwatch`](ColorSwatch) is pressed.
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`ColorSwatch`](ColorSwatch).
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for ColorSwatch<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits
+            .width(Length::Units(self.size))
+            .height(Length::Units(self.size));
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => self
+                .on_press
+                .clone()
+                .filter(|_| layout.bounds().contains(cursor_position))
+                .map_or(event::Status::Ignored, |on_press| {
+                    messages.push(on_press);
+                    event::Status::Captured
+                }),
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: Some(viewport),
+                focus: (),
+            },
+            self.color,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.size.hash(state);
+    }
+}
+
+/// The renderer of a [`ColorSwatch`](ColorSwatch).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`ColorSwatch`](ColorSwatch) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default size of a [`ColorSwatch`](ColorSwatch).
+    const DEFAULT_SIZE: u16;
+
+    /// Draws a [`ColorSwatch`](ColorSwatch).
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        color: Color,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 0;
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _color: Color,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<ColorSwatch<Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(color_swatch: ColorSwatch<Message, Renderer>) -> Self {
+        Element::new(color_swatch)
+    }
+}
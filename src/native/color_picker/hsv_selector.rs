@@ -0,0 +1,368 @@
+//! A selector for the hue, saturation and value of a color, as used by the
+//! [`ColorPicker`](super::ColorPicker).
+//!
+//! *This API requires the following crate features to be activated: `color_picker`*
+use std::hash::Hash;
+
+use iced_graphics::canvas;
+use iced_native::{
+    event, layout, mouse, touch, Clipboard, Color, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+use crate::core::{color::Hsv, renderer::DrawEnvironment};
+
+/// The spacing between the saturation/value area and the hue bar.
+const SPACING: u16 = 10;
+
+/// The dragged part of a [`HsvSelector`](HsvSelector).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Dragged {
+    /// Nothing is dragged.
+    None,
+
+    /// The saturation/value area is dragged.
+    SatValue,
+
+    /// The hue bar is dragged.
+    Hue,
+}
+
+/// The state of a [`HsvSelector`](HsvSelector).
+#[derive(Debug)]
+pub struct State {
+    /// The color the canvases are drawn for.
+    color: Option<Color>,
+    /// The dragged part of the [`HsvSelector`](HsvSelector).
+    dragged: Dragged,
+    /// The cache of the saturation/value canvas.
+    sat_value_canvas_cache: canvas::Cache,
+    /// The cache of the hue canvas.
+    hue_canvas_cache: canvas::Cache,
+}
+
+impl State {
+    /// Creates a new [`State`](State).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            color: None,
+            dragged: Dragged::None,
+            sat_value_canvas_cache: canvas::Cache::default(),
+            hue_canvas_cache: canvas::Cache::default(),
+        }
+    }
+}
+
+/// A selector for the hue, saturation and value of a color, showing a
+/// saturation/value area above a hue bar.
+///
+/// The alpha of the color is kept unchanged.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::color_picker::hsv_selector;
+/// # use iced_native::{Color, renderer::Null};
+/// #
+/// # pub type HsvSelector<'a, Message> = iced_aw::native::color_picker::HsvSelector<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     ColorChanged(Color),
+/// }
+///
+/// let mut state = hsv_selector::State::new();
+///
+/// let hsv_selector = HsvSelector::new(
+///     &mut state,
+///     Color::BLACK,
+///     Message::ColorChanged,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct HsvSelector<'a, Message, Renderer: self::Renderer> {
+    /// The state of the [`HsvSelector`](HsvSelector).
+    state: &'a mut State,
+    /// The edited color.
+    color: Color,
+    /// The function that produces a message when the color changed.
+    on_change: Box<dyn Fn(Color) -> Message>,
+    /// The width of the [`HsvSelector`](HsvSelector).
+    width: Length,
+    /// The height of the [`HsvSelector`](HsvSelector).
+    height: Length,
+    /// The style of the [`HsvSelector`](HsvSelector).
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer> HsvSelector<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`HsvSelector`](HsvSelector) editing the given color.
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`HsvSelector`](HsvSelector)'s [`State`](State).
+    ///     * the current [`Color`](iced_native::Color).
+    ///     * a function that will be called with the changed
+    ///         [`Color`](iced_native::Color) when the color is changed.
+    pub fn new<F>(state: &'a mut State, color: Color, on_change: F) -> Self
+    where
+        F: 'static + Fn(Color) -> Message,
+    {
+        if state.color != Some(color) {
+            state.color = Some(color);
+            state.sat_value_canvas_cache.clear();
+            state.hue_canvas_cache.clear();
+        }
+
+        Self {
+            state,
+            color,
+            on_change: Box::new(on_change),
+            width: Length::Fill,
+            height: Length::Units(Renderer::DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`HsvSelector`](HsvSelector).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`HsvSelector`](HsvSelector).
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`HsvSelector`](HsvSelector).
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for HsvSelector<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let size = limits
+            .width(self.width)
+            .height(self.height)
+            .resolve(Size::ZERO);
+
+        let hue_height = f32::from(Renderer::HUE_BAR_HEIGHT);
+
+        let sat_value = layout::Node::new(Size::new(
+            size.width,
+            (size.height - hue_height - f32::from(SPACING)).max(0.0),
+        ));
+
+        let mut hue = layout::Node::new(Size::new(size.width, hue_height));
+        hue.move_to(Point::new(0.0, (size.height - hue_height).max(0.0)));
+
+        layout::Node::with_children(size, vec![sat_value, hue])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let sat_value_bounds = children
+            .next()
+            .expect("Native: Layout should have a sat/value layout")
+            .bounds();
+        let hue_bounds = children
+            .next()
+            .expect("Native: Layout should have a hue layout")
+            .bounds();
+
+        let hsv_color: Hsv = self.color.into();
+
+        let mut hsv = match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
+                mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
+                    if hue_bounds.contains(cursor_position) {
+                        Some(Hsv {
+                            hue: ((i32::from(hsv_color.hue) + y as i32).rem_euclid(360)) as u16,
+                            ..hsv_color
+                        })
+                    } else {
+                        None
+                    }
+                }
+            },
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                self.state.dragged = if sat_value_bounds.contains(cursor_position) {
+                    Dragged::SatValue
+                } else if hue_bounds.contains(cursor_position) {
+                    Dragged::Hue
+                } else {
+                    Dragged::None
+                };
+                None
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.dragged = Dragged::None;
+                None
+            }
+            _ => None,
+        };
+
+        let percentage = |position: f32, start: f32, length: f32| {
+            ((position - start).max(0.0) / length).min(1.0)
+        };
+
+        match self.state.dragged {
+            Dragged::SatValue => {
+                hsv = Some(Hsv {
+                    saturation: percentage(
+                        cursor_position.x,
+                        sat_value_bounds.x,
+                        sat_value_bounds.width,
+                    ),
+                    value: percentage(
+                        cursor_position.y,
+                        sat_value_bounds.y,
+                        sat_value_bounds.height,
+                    ),
+                    ..hsv_color
+                });
+            }
+            Dragged::Hue => {
+                hsv = Some(Hsv {
+                    hue: (percentage(cursor_position.x, hue_bounds.x, hue_bounds.width) * 360.0)
+                        as u16,
+                    ..hsv_color
+                });
+            }
+            Dragged::None => {}
+        }
+
+        match hsv {
+            Some(hsv) => {
+                let color = Color {
+                    a: self.color.a,
+                    ..hsv.into()
+                };
+                if color != self.color {
+                    messages.push((self.on_change)(color));
+                }
+                event::Status::Captured
+            }
+            None => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: Some(viewport),
+                focus: (),
+            },
+            self.color,
+            &self.state.sat_value_canvas_cache,
+            &self.state.hue_canvas_cache,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`HsvSelector`](HsvSelector).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`HsvSelector`](HsvSelector) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default height of a [`HsvSelector`](HsvSelector).
+    const DEFAULT_HEIGHT: u16;
+
+    /// The height of the hue bar of a [`HsvSelector`](HsvSelector).
+    const HUE_BAR_HEIGHT: u16;
+
+    /// Draws a [`HsvSelector`](HsvSelector).
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        color: Color,
+        sat_value_canvas_cache: &canvas::Cache,
+        hue_canvas_cache: &canvas::Cache,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_HEIGHT: u16 = 0;
+
+    const HUE_BAR_HEIGHT: u16 = 0;
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _color: Color,
+        _sat_value_canvas_cache: &canvas::Cache,
+        _hue_canvas_cache: &canvas::Cache,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<HsvSelector<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(hsv_selector: HsvSelector<'a, Message, Renderer>) -> Self {
+        Element::new(hsv_selector)
+    }
+}
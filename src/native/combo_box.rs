@@ -0,0 +1,300 @@
+//! Use a combo box to select an option from a list filtered by the text typed
+//! into a text field.
+//!
+//! *This API requires the following crate features to be activated: `combo_box`*
+use std::{fmt::Display, hash::Hash};
+
+use iced_native::{
+    event, keyboard, layout, mouse, overlay, text_input, touch, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Rectangle, TextInput, Widget,
+};
+
+pub use super::overlay::combo_box::Renderer;
+use super::overlay::combo_box::{self, ComboBoxOverlay};
+
+/// The padding around the text of the text field.
+const PADDING: u16 = 5;
+
+/// A text field with a list of suggestions below it, filtered by the typed
+/// text.
+///
+/// Options starting with the typed text are suggested first, followed by
+/// options containing its characters in the same order. While the text field
+/// is focused, the arrow keys up and down move through the suggestions and
+/// Enter selects the hovered one.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::combo_box;
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type ComboBox<'a, T, Message> = iced_aw::native::ComboBox<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     FruitSelected(&'static str),
+/// }
+///
+/// let mut state = combo_box::State::new();
+///
+/// let combo_box = ComboBox::new(
+///     &mut state,
+///     "Search a fruit...",
+///     &["Apple", "Banana", "Cherry"],
+///     Message::FruitSelected,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ComboBox<'a, T, Message, Renderer: self::Renderer> {
+    /// The state of the suggestions.
+    state: &'a mut combo_box::State,
+    /// The options of the [`ComboBox`](ComboBox).
+    options: &'a [T],
+    /// Whether the text field is focused.
+    focused: bool,
+    /// The width of the [`ComboBox`](ComboBox).
+    width: Length,
+    /// The maximum number of suggestions shown at a time.
+    max_suggestions: usize,
+    /// The text field of the [`ComboBox`](ComboBox).
+    text_input: Element<'a, String, Renderer>,
+    /// The function that produces a message when an option is selected.
+    on_selected: Box<dyn Fn(T) -> Message>,
+    /// The style of the suggestions of the [`ComboBox`](ComboBox).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Renderer: 'a + self::Renderer + text_input::Renderer,
+{
+    /// Creates a new [`ComboBox`](ComboBox).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`ComboBox`](ComboBox)'s
+    ///         [`State`](State).
+    ///     * the placeholder of the text field.
+    ///     * the options to choose from.
+    ///     * a function that will be called when an option is selected.
+    pub fn new<F>(state: &'a mut State, placeholder: &str, options: &'a [T], on_selected: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        let State {
+            text_input,
+            overlay_state,
+        } = state;
+
+        overlay_state.filter(options);
+
+        Self {
+            focused: text_input.is_focused(),
+            width: Length::Fill,
+            max_suggestions: 8,
+            text_input: TextInput::new(text_input, placeholder, &overlay_state.text, |text| text)
+                .width(Length::Fill)
+                .padding(PADDING)
+                .into(),
+            state: overlay_state,
+            options,
+            on_selected: Box::new(on_selected),
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`ComboBox`](ComboBox).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the maximum number of suggestions shown at a time. The other
+    /// suggestions are reached by scrolling.
+    ///
+    /// Fewer suggestions are shown if they don't fit into the window. By
+    /// default, up to 8 suggestions are shown.
+    pub fn max_suggestions(mut self, max_suggestions: usize) -> Self {
+        self.max_suggestions = max_suggestions.max(1);
+        self
+    }
+
+    /// Sets the style of the suggestions of the [`ComboBox`](ComboBox).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The state of the [`ComboBox`](ComboBox).
+#[derive(Debug, Default)]
+pub struct State {
+    /// The state of the text field.
+    pub(crate) text_input: text_input::State,
+    /// The state of the suggestions.
+    pub(crate) overlay_state: combo_box::State,
+}
+
+impl State {
+    /// Creates a new [`State`](State).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the text of the text field.
+    pub fn text(&self) -> &str {
+        &self.overlay_state.text
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Renderer: 'a + self::Renderer + text_input::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let text_input = self.text_input.layout(renderer, &limits);
+
+        layout::Node::with_children(text_input.size(), vec![text_input])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let text_input_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a text input layout for a ComboBox");
+
+        let mut key_status = event::Status::Ignored;
+
+        match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) if self.focused => {
+                key_status = event::Status::Captured;
+
+                match key_code {
+                    keyboard::KeyCode::Down => self.state.hover_next(),
+                    keyboard::KeyCode::Up => self.state.hover_previous(),
+                    keyboard::KeyCode::Enter => {
+                        if let Some(index) = self.state.hovered.filter(|_| self.state.is_open) {
+                            if let Some(message) =
+                                self.state
+                                    .select(index, self.options, self.on_selected.as_ref())
+                            {
+                                messages.push(message);
+                            }
+                        }
+                    }
+                    keyboard::KeyCode::Escape => self.state.close(),
+                    _ => key_status = event::Status::Ignored,
+                }
+            }
+            // Clicks on the suggestions are captured by the overlay.
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !layout.bounds().contains(cursor_position) =>
+            {
+                self.state.close();
+            }
+            _ => {}
+        }
+
+        let mut edits = Vec::new();
+
+        let text_input_status = self.text_input.on_event(
+            event,
+            text_input_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut edits,
+        );
+
+        if let Some(text) = edits.pop() {
+            self.state.text = text;
+            self.state.is_open = true;
+            self.state.hovered = None;
+            self.state.filter(self.options);
+        }
+
+        key_status.merge(text_input_status)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.text_input.draw(
+            renderer,
+            defaults,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a text input layout for a ComboBox"),
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.text_input.hash_layout(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.state.is_open || self.state.filtered.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(
+            ComboBoxOverlay::new(
+                self.state,
+                self.options,
+                self.on_selected.as_ref(),
+                Point::new(bounds.x, bounds.y + bounds.height),
+                bounds.width,
+                self.max_suggestions,
+                &self.style,
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, T, Message, Renderer> From<ComboBox<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Clone + Display,
+    Message: 'a,
+    Renderer: 'a + self::Renderer + text_input::Renderer,
+{
+    fn from(combo_box: ComboBox<'a, T, Message, Renderer>) -> Self {
+        Element::new(combo_box)
+    }
+}
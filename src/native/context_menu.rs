@@ -0,0 +1,281 @@
+//! A context menu for showing actions on right click.
+//!
+//! *This API requires the following crate features to be activated: `context_menu`*
+use std::hash::Hash;
+
+use iced_native::{event, mouse, overlay, Clipboard, Element, Event, Layout, Point, Widget};
+
+pub use super::overlay::context_menu::Renderer;
+use super::{
+    overlay::context_menu::{self, ContextMenuOverlay},
+    stateful_overlay::{OverlayState, StatefulOverlayWidget},
+};
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+
+/// A context menu opened on right click at the position of the cursor.
+///
+/// The menu is closed again when one of its entries produces a message, when
+/// the user clicks outside of it or when the Escape key is pressed.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::context_menu;
+/// # use iced_native::{Button, Column, Text, button, renderer::Null};
+/// #
+/// # pub type ContextMenu<'a, S, Content, Message>
+/// #  = iced_aw::native::ContextMenu<'a, S, Content, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Copy,
+/// }
+///
+/// let mut state = context_menu::State::new(button::State::new());
+///
+/// let context_menu = ContextMenu::new(
+///     &mut state,
+///     Text::new("Right click me"),
+///     |button_state| {
+///         Column::new()
+///             .push(Button::new(button_state, Text::new("Copy")).on_press(Message::Copy))
+///             .into()
+///     },
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<'a, S, Content, Message, Renderer>
+where
+    S: 'a,
+    Content: Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: Clone,
+    Renderer: context_menu::Renderer,
+{
+    /// The state of the [`ContextMenu`](ContextMenu).
+    state: &'a mut State<S>,
+    /// The underlying element.
+    underlay: Element<'a, Message, Renderer>,
+    /// The content of the [`ContextMenuOverlay`](ContextMenuOverlay).
+    content: Content,
+    /// The style of the [`ContextMenuOverlay`](ContextMenuOverlay).
+    style: Renderer::Style,
+}
+
+impl<'a, S, Content, Message, Renderer> ContextMenu<'a, S, Content, Message, Renderer>
+where
+    S: 'a,
+    Content: Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: Clone,
+    Renderer: context_menu::Renderer,
+{
+    /// Creates a new [`ContextMenu`](ContextMenu) wrapped around the given
+    /// underlay.
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`ContextMenu`](ContextMenu)'s
+    ///         [`State`](State).
+    ///     * the underlay [`Element`](iced_native::Element) that opens the
+    ///         menu on right click.
+    ///     * a function that creates the content of the menu from the inner
+    ///         state.
+    pub fn new<U>(state: &'a mut State<S>, underlay: U, content: Content) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+    {
+        ContextMenu {
+            state,
+            underlay: underlay.into(),
+            content,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the style of the [`ContextMenu`](ContextMenu).
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The state of the context menu.
+#[derive(Debug)]
+pub struct State<S> {
+    /// The visibility of the [`ContextMenu`](ContextMenu) overlay.
+    pub(crate) show: bool,
+    /// The position at which the [`ContextMenu`](ContextMenu) overlay was
+    /// opened.
+    pub(crate) position: Point,
+    /// The state of the content of the [`ContextMenu`](ContextMenu) overlay.
+    pub(crate) state: S,
+}
+
+impl<S> State<S> {
+    /// Creates a new [`State`](State) containing the given state data.
+    pub const fn new(s: S) -> Self {
+        Self {
+            show: false,
+            position: Point::ORIGIN,
+            state: s,
+        }
+    }
+
+    /// Setting this to true shows the menu at the last position it was opened
+    /// at, false means the menu is hidden (closed).
+    pub fn show(&mut self, b: bool) {
+        self.show = b;
+    }
+
+    /// See if this menu will be shown or not.
+    pub const fn is_shown(&self) -> bool {
+        self.show
+    }
+
+    /// Get a mutable reference to the inner state data.
+    pub fn inner_mut(&mut self) -> &mut S {
+        &mut self.state
+    }
+
+    /// Get a reference to the inner state data.
+    pub const fn inner(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<S: Default> Default for State<S> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S> OverlayState for State<S> {
+    fn is_shown(&self) -> bool {
+        self.show
+    }
+
+    fn show(&mut self, b: bool) {
+        Self::show(self, b);
+    }
+}
+
+impl<'a, S, Content, Message, Renderer> Widget<Message, Renderer>
+    for ContextMenu<'a, S, Content, Message, Renderer>
+where
+    S: 'a,
+    Content: 'a + Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: 'a + Clone,
+    Renderer: 'a + context_menu::Renderer,
+{
+    fn width(&self) -> iced_native::Length {
+        self.underlay.width()
+    }
+
+    fn height(&self) -> iced_native::Length {
+        self.underlay.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.underlay.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let status = self.underlay.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                if layout.bounds().contains(cursor_position) =>
+            {
+                self.state.show = true;
+                self.state.position = cursor_position;
+                #[cfg(feature = "instrumentation")]
+                instrumentation::record(WidgetKind::ContextMenu, Action::Open);
+                event::Status::Captured
+            }
+            _ => status,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: iced_native::Layout<'_>,
+        cursor_position: iced_graphics::Point,
+        viewport: &iced_graphics::Rectangle,
+    ) -> Renderer::Output {
+        self.underlay
+            .draw(renderer, defaults, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.state.show.hash(state);
+        self.underlay.hash_layout(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.stateful_overlay(layout)
+    }
+}
+
+impl<'a, S, Content, Message, Renderer> StatefulOverlayWidget<'a, Message, Renderer>
+    for ContextMenu<'a, S, Content, Message, Renderer>
+where
+    S: 'a,
+    Content: 'a + Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: 'a + Clone,
+    Renderer: 'a + context_menu::Renderer,
+{
+    type State = State<S>;
+
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    fn underlay_mut(&mut self) -> &mut Element<'a, Message, Renderer> {
+        &mut self.underlay
+    }
+
+    fn build_overlay(&mut self, _layout: Layout<'_>) -> overlay::Element<'_, Message, Renderer> {
+        let position = self.state.position;
+
+        ContextMenuOverlay::new(&mut self.state, &self.content, &self.style).overlay(position)
+    }
+}
+
+impl<'a, S, Content, Message, Renderer> From<ContextMenu<'a, S, Content, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    S: 'a,
+    Content: 'a + Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: 'a + Clone,
+    Renderer: 'a + context_menu::Renderer,
+{
+    fn from(context_menu: ContextMenu<'a, S, Content, Message, Renderer>) -> Self {
+        Element::new(context_menu)
+    }
+}
@@ -1,9 +1,12 @@
 //! Use a date picker as an input element for picking dates.
 //!
 //! *This API requires the following crate features to be activated: `date_picker`*
-use std::hash::Hash;
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
 
-use chrono::Local;
+use chrono::{Local, NaiveDate};
 use iced_native::{
     button, column, container, event, overlay, row, text, Clipboard, Element, Event, Layout, Point,
     Widget,
@@ -12,10 +15,23 @@ use iced_native::{
 pub use super::overlay::date_picker::Renderer;
 use super::{
     icon_text,
-    overlay::date_picker::{self, DatePickerOverlay, Focus},
+    overlay::{
+        animated::{self, AnimatedOverlay},
+        date_picker::{self, DateBounds, DatePickerOverlay, Focus, OnSubmit},
+    },
+    stateful_overlay::{OverlayState, StatefulOverlayWidget},
 };
 
-pub use crate::core::date::Date;
+use crate::core::accessibility::Accessibility;
+pub use crate::core::date::{Date, Locale};
+pub use chrono::Weekday;
+
+pub mod calendar;
+pub use calendar::Calendar;
+
+pub mod date_picker_input;
+pub use date_picker_input::DatePickerInput;
+
 /// An input element for picking dates.
 ///
 /// # Example
@@ -52,7 +68,19 @@ pub struct DatePicker<'a, Message: Clone, Renderer: date_picker::Renderer + butt
     /// The message that is send if the cancel button of the [`DatePickerOverlay`](DatePickerOverlay) is pressed.
     on_cancel: Message,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`](DatePickerOverlay) is pressed.
-    on_submit: Box<dyn Fn(Date) -> Message>,
+    on_submit: Submit<Message>,
+    /// The earliest date that can be picked.
+    min_date: Option<NaiveDate>,
+    /// The latest date that can be picked.
+    max_date: Option<NaiveDate>,
+    /// The optional predicate returning true for the dates that can't be picked.
+    disabled: Option<Box<dyn Fn(Date) -> bool>>,
+    /// The optional duration of the open and close animation of the
+    /// [`DatePickerOverlay`](DatePickerOverlay).
+    animation: Option<Duration>,
+    /// The accessibility information of the
+    /// [`DatePickerOverlay`](DatePickerOverlay).
+    accessibility: Accessibility,
     /// The style of the [`DatePickerOverlay`](DatePickerOverlay).
     style: <Renderer as date_picker::Renderer>::Style,
     //button_style: <Renderer as button::Renderer>::Style, // clone not satisfied
@@ -80,12 +108,122 @@ impl<'a, Message: Clone, Renderer: date_picker::Renderer + button::Renderer>
             state,
             underlay: underlay.into(),
             on_cancel,
-            on_submit: Box::new(on_submit),
+            on_submit: Submit::Date(Box::new(on_submit)),
+            min_date: None,
+            max_date: None,
+            disabled: None,
+            animation: None,
+            accessibility: Accessibility::default(),
             style: <Renderer as date_picker::Renderer>::Style::default(),
             //button_style: <Renderer as button::Renderer>::Style::default(),
         }
     }
 
+    /// Creates a new [`DatePicker`](DatePicker) picking a range of dates,
+    /// wrapping around the given underlay.
+    ///
+    /// The user picks the start and the end date of the range by selecting
+    /// two days in the calendar, highlighting the days in between.
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`DatePicker`](DatePicker)'s [`State`](State).
+    ///     * the underlay [`Element`](iced_native::Element) on which this [`DatePicker`](DatePicker)
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`DatePicker`](DatePicker)
+    ///         is pressed.
+    ///     * a function that will be called when the submit button of the [`DatePicker`](DatePicker)
+    ///         is pressed, which takes the start and the end [`Date`](crate::date_picker::Date)
+    ///         of the picked range.
+    pub fn range<U, F>(state: &'a mut State, underlay: U, on_cancel: Message, on_submit: F) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+        F: 'static + Fn(Date, Date) -> Message,
+    {
+        Self {
+            state,
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: Submit::Range(Box::new(on_submit)),
+            min_date: None,
+            max_date: None,
+            disabled: None,
+            animation: None,
+            accessibility: Accessibility::default(),
+            style: <Renderer as date_picker::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the earliest date that can be picked in the
+    /// [`DatePickerOverlay`](DatePickerOverlay).
+    ///
+    /// The days before it are greyed out and the month and year navigation
+    /// doesn't go beyond it.
+    pub fn min_date(mut self, date: Date) -> Self {
+        self.min_date = Some(date.into());
+        self
+    }
+
+    /// Sets the latest date that can be picked in the
+    /// [`DatePickerOverlay`](DatePickerOverlay).
+    ///
+    /// The days after it are greyed out and the month and year navigation
+    /// doesn't go beyond it.
+    pub fn max_date(mut self, date: Date) -> Self {
+        self.max_date = Some(date.into());
+        self
+    }
+
+    /// Sets the predicate returning true for the dates that can't be picked in
+    /// the [`DatePickerOverlay`](DatePickerOverlay), e.g. weekends or fully
+    /// booked days.
+    ///
+    /// The disabled days are greyed out and skipped by the keyboard
+    /// navigation.
+    pub fn disabled<F>(mut self, disabled: F) -> Self
+    where
+        F: 'static + Fn(Date) -> bool,
+    {
+        self.disabled = Some(Box::new(disabled));
+        self
+    }
+
+    /// Sets the [`Locale`](Locale) of the calendar of the
+    /// [`DatePickerOverlay`](DatePickerOverlay), i.e. the first day of the
+    /// week and the names of the months and weekdays.
+    ///
+    /// The picked [`Date`](Date) can be shown in the date format of the
+    /// locale with [`Locale::format`](Locale::format).
+    pub fn locale(self, locale: Locale) -> Self {
+        self.state.overlay_state.locale = locale;
+        self
+    }
+
+    /// Animates the opening and closing of the [`DatePickerOverlay`](DatePickerOverlay)
+    /// over the given duration.
+    ///
+    /// The overlay slides into place when it is shown and out of place when
+    /// it is hidden. The animation only advances while the application
+    /// redraws, e.g. by subscribing to a timer. Without an animation the
+    /// overlay is shown and hidden instantly.
+    pub fn animation(mut self, duration: Duration) -> Self {
+        self.animation = Some(duration);
+        self
+    }
+
+    /// Sets the label announced for the open
+    /// [`DatePickerOverlay`](DatePickerOverlay).
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.accessibility.label = Some(label.into());
+        self
+    }
+
+    /// Sets the description announced for the open
+    /// [`DatePickerOverlay`](DatePickerOverlay).
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.accessibility.description = Some(description.into());
+        self
+    }
+
     /// Sets the style of the [`DatePicker`](DatePicker).
     pub fn style<S>(mut self, style: S) -> Self
     where
@@ -97,11 +235,34 @@ impl<'a, Message: Clone, Renderer: date_picker::Renderer + button::Renderer>
     }
 }
 
+/// The function that produces a message when the submit button of the
+/// [`DatePickerOverlay`](DatePickerOverlay) is pressed.
+enum Submit<Message> {
+    /// Submits the picked date.
+    Date(Box<dyn Fn(Date) -> Message>),
+
+    /// Submits the start and end date of the picked range.
+    Range(Box<dyn Fn(Date, Date) -> Message>),
+}
+
+impl<Message> Submit<Message> {
+    /// Borrows the function as the [`OnSubmit`](OnSubmit) of a
+    /// [`DatePickerOverlay`](DatePickerOverlay).
+    fn as_on_submit(&self) -> OnSubmit<'_, Message> {
+        match self {
+            Self::Date(on_submit) => OnSubmit::Date(on_submit.as_ref()),
+            Self::Range(on_submit) => OnSubmit::Range(on_submit.as_ref()),
+        }
+    }
+}
+
 /// The state of the [`DatePicker`](DatePicker) / [`DatePickerOverlay`](DatePickerOverlay).
 #[derive(Debug)]
 pub struct State {
     /// The visibility of the overlay.
     pub(crate) show: bool,
+    /// The point in time the overlay was last shown or hidden.
+    pub(crate) changed_at: Option<Instant>,
     /// The state of the overlay.
     pub(crate) overlay_state: date_picker::State,
     /// The state of the cancel button.
@@ -116,6 +277,7 @@ impl State {
     pub fn now() -> Self {
         Self {
             show: false,
+            changed_at: None,
             overlay_state: date_picker::State::default(),
             cancel_button: button::State::new(),
             submit_button: button::State::new(),
@@ -125,12 +287,32 @@ impl State {
     /// Sets the visibility of the [`DatePickerOverlay`](DatePickerOverlay).
     pub fn show(&mut self, b: bool) {
         self.overlay_state.focus = if b { Focus::Overlay } else { Focus::None };
+        if b != self.show {
+            self.changed_at = Some(Instant::now());
+        }
         self.show = b;
     }
 
-    /// Resets the date of the state to the current date.
+    /// Resets the date of the state to the current date and clears the
+    /// picked range.
     pub fn reset(&mut self) {
         self.overlay_state.date = Local::today().naive_local();
+        self.overlay_state.range_start = None;
+        self.overlay_state.range_end = None;
+    }
+}
+
+impl OverlayState for State {
+    fn is_shown(&self) -> bool {
+        self.show
+    }
+
+    fn show(&mut self, b: bool) {
+        Self::show(self, b);
+    }
+
+    fn changed_at(&self) -> Option<Instant> {
+        self.changed_at
     }
 }
 
@@ -138,6 +320,7 @@ impl<'a, Message, Renderer> Widget<Message, Renderer> for DatePicker<'a, Message
 where
     Message: Clone,
     Renderer: date_picker::Renderer
+        + animated::Renderer
         + button::Renderer
         + column::Renderer
         + container::Renderer
@@ -202,24 +385,62 @@ where
     }
 
     fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
-        if !self.state.show {
-            return self.underlay.overlay(layout);
-        }
+        self.stateful_overlay(layout)
+    }
+}
+
+impl<'a, Message, Renderer> StatefulOverlayWidget<'a, Message, Renderer>
+    for DatePicker<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: date_picker::Renderer
+        + animated::Renderer
+        + button::Renderer
+        + column::Renderer
+        + container::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer,
+{
+    type State = State;
+
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
 
+    fn underlay_mut(&mut self) -> &mut Element<'a, Message, Renderer> {
+        &mut self.underlay
+    }
+
+    fn animation(&self) -> Option<Duration> {
+        self.animation
+    }
+
+    fn build_overlay(&mut self, layout: Layout<'_>) -> overlay::Element<'_, Message, Renderer> {
         let bounds = layout.bounds();
         let position = Point::new(bounds.center_x(), bounds.center_y());
 
-        Some(
+        let visibility = self.visibility();
+
+        AnimatedOverlay::new(
             DatePickerOverlay::new(
                 &mut self.state,
                 self.on_cancel.clone(),
-                &self.on_submit,
+                self.on_submit.as_on_submit(),
                 position,
                 &self.style,
                 //self.button_style, // Clone not satisfied
             )
+            .bounds(DateBounds {
+                min: self.min_date,
+                max: self.max_date,
+                disabled: self.disabled.as_deref(),
+            })
+            .accessibility(&self.accessibility)
             .overlay(),
+            visibility,
         )
+        .overlay()
     }
 }
 
@@ -229,6 +450,7 @@ where
     Message: 'a + Clone,
     Renderer: 'a
         + date_picker::Renderer
+        + animated::Renderer
         + button::Renderer
         + column::Renderer
         + container::Renderer
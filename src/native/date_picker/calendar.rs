@@ -0,0 +1,348 @@
+//! A calendar for picking dates, as used by the
+//! [`DatePicker`](super::DatePicker), shown inline instead of as an overlay.
+//!
+//! *This API requires the following crate features to be activated: `date_picker`*
+use std::hash::Hash;
+
+use chrono::{Local, NaiveDate};
+use iced_native::{
+    column, container, event, keyboard, layout, mouse, row, text, touch, Clipboard, Element, Event,
+    Hasher, Layout, Length, Point, Rectangle, Widget,
+};
+
+use crate::core::{
+    date::{Date, Locale},
+    renderer::DrawEnvironment,
+};
+use crate::native::overlay::date_picker::{self, DateBounds, Focus};
+
+/// The padding around the calendar.
+const PADDING: u16 = 10;
+
+/// The state of a [`Calendar`](Calendar).
+#[derive(Debug)]
+pub struct State {
+    /// The state of the calendar.
+    pub(crate) overlay_state: date_picker::State,
+}
+
+impl State {
+    /// Creates a new [`State`](State) with the current date.
+    #[must_use]
+    pub fn now() -> Self {
+        Self {
+            overlay_state: date_picker::State::default(),
+        }
+    }
+
+    /// Gets the selected date of the [`Calendar`](Calendar).
+    #[must_use]
+    pub fn date(&self) -> Date {
+        self.overlay_state.date.into()
+    }
+
+    /// Sets the selected date of the [`Calendar`](Calendar).
+    pub fn set_date(&mut self, date: Date) {
+        self.overlay_state.date = date.into();
+    }
+
+    /// Resets the selected date of the [`Calendar`](Calendar) to the current
+    /// date.
+    pub fn reset(&mut self) {
+        self.overlay_state.date = Local::today().naive_local();
+    }
+}
+
+/// A calendar for picking dates, shown inline in the layout instead of as
+/// an overlay of a [`DatePicker`](super::DatePicker).
+///
+/// A message is produced directly whenever the selected date changes.
+///
+/// # Example
+/// ```
+/// # use iced_aw::{date_picker, native::date_picker::calendar};
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Calendar<'a, Message> = iced_aw::native::date_picker::Calendar<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Select(date_picker::Date),
+/// }
+///
+/// let mut state = calendar::State::now();
+///
+/// let calendar = Calendar::new(
+///     &mut state,
+///     Message::Select,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Calendar<'a, Message, Renderer: self::Renderer> {
+    /// The state of the [`Calendar`](Calendar).
+    state: &'a mut State,
+    /// The function that produces a message when a date is selected.
+    on_select: Box<dyn Fn(Date) -> Message>,
+    /// The earliest date that can be selected.
+    min_date: Option<NaiveDate>,
+    /// The latest date that can be selected.
+    max_date: Option<NaiveDate>,
+    /// The optional predicate returning true for the dates that can't be selected.
+    disabled: Option<Box<dyn Fn(Date) -> bool>>,
+    /// The width of the [`Calendar`](Calendar).
+    width: Length,
+    /// The height of the [`Calendar`](Calendar).
+    height: Length,
+    /// The style of the [`Calendar`](Calendar).
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer> Calendar<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Calendar`](Calendar).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`Calendar`](Calendar)'s [`State`](State).
+    ///     * a function that will be called with the selected
+    ///         [`Date`](crate::date_picker::Date) when the selected date changes.
+    pub fn new<F>(state: &'a mut State, on_select: F) -> Self
+    where
+        F: 'static + Fn(Date) -> Message,
+    {
+        Self {
+            state,
+            on_select: Box::new(on_select),
+            min_date: None,
+            max_date: None,
+            disabled: None,
+            width: Length::Units(300),
+            height: Length::Units(300),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the earliest date that can be selected in the
+    /// [`Calendar`](Calendar).
+    pub fn min_date(mut self, date: Date) -> Self {
+        let date: NaiveDate = date.into();
+        self.state.overlay_state.date = self.state.overlay_state.date.max(date);
+        self.min_date = Some(date);
+        self
+    }
+
+    /// Sets the latest date that can be selected in the
+    /// [`Calendar`](Calendar).
+    pub fn max_date(mut self, date: Date) -> Self {
+        let date: NaiveDate = date.into();
+        self.state.overlay_state.date = self.state.overlay_state.date.min(date);
+        self.max_date = Some(date);
+        self
+    }
+
+    /// Sets the predicate returning true for the dates that can't be
+    /// selected in the [`Calendar`](Calendar).
+    pub fn disabled<F>(mut self, disabled: F) -> Self
+    where
+        F: 'static + Fn(Date) -> bool,
+    {
+        self.disabled = Some(Box::new(disabled));
+        self
+    }
+
+    /// Sets the [`Locale`](Locale) of the [`Calendar`](Calendar).
+    pub fn locale(self, locale: Locale) -> Self {
+        self.state.overlay_state.locale = locale;
+        self
+    }
+
+    /// Sets the width of the [`Calendar`](Calendar).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Calendar`](Calendar).
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`Calendar`](Calendar).
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Calendar<'a, Message, Renderer>
+where
+    Renderer:
+        self::Renderer + column::Renderer + container::Renderer + row::Renderer + text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let padding = f32::from(PADDING);
+
+        let limits = limits.width(self.width).height(self.height).pad(padding);
+
+        let mut calendar = date_picker::calendar_layout(renderer, &limits);
+        let size = limits.resolve(calendar.size());
+
+        calendar.move_to(Point::new(padding, padding));
+
+        layout::Node::with_children(size.pad(padding), vec![calendar])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = DateBounds {
+            min: self.min_date,
+            max: self.max_date,
+            disabled: self.disabled.as_deref(),
+        };
+        let state = &mut self.state.overlay_state;
+        let date = state.date;
+
+        let status = match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !layout.bounds().contains(cursor_position) {
+                    state.focus = Focus::None;
+                }
+
+                date_picker::calendar_on_event(
+                    state,
+                    &bounds,
+                    false,
+                    &event,
+                    layout
+                        .children()
+                        .next()
+                        .expect("Native: Layout should have a calendar layout"),
+                    cursor_position,
+                )
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+                date_picker::calendar_on_key(state, &bounds, false, key_code)
+            }
+            _ => event::Status::Ignored,
+        };
+
+        if state.date != date {
+            messages.push((self.on_select)(state.date.into()));
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = DateBounds {
+            min: self.min_date,
+            max: self.max_date,
+            disabled: self.disabled.as_deref(),
+        };
+        let state = &self.state.overlay_state;
+
+        self::Renderer::draw(
+            renderer,
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: Some(viewport),
+                focus: state.focus,
+            },
+            state.date,
+            &|date| bounds.is_enabled(date),
+            &state.locale,
+            &crate::core::date::year_as_string(state.date),
+            state.locale.month_name(state.date),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`Calendar`](Calendar).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Calendar`](Calendar) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Calendar`](Calendar).
+    ///
+    /// The days for which `is_enabled` returns false can't be selected. The
+    /// calendar follows the conventions of the given [`Locale`](Locale).
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, Focus>,
+        date: NaiveDate,
+        is_enabled: &dyn Fn(NaiveDate) -> bool,
+        locale: &Locale,
+        year_str: &str,
+        month_str: &str,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, Focus>,
+        _date: NaiveDate,
+        _is_enabled: &dyn Fn(NaiveDate) -> bool,
+        _locale: &Locale,
+        _year_str: &str,
+        _month_str: &str,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<Calendar<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a
+        + self::Renderer
+        + column::Renderer
+        + container::Renderer
+        + row::Renderer
+        + text::Renderer,
+{
+    fn from(calendar: Calendar<'a, Message, Renderer>) -> Self {
+        Element::new(calendar)
+    }
+}
@@ -0,0 +1,533 @@
+//! A text field for entering dates, with a button opening a
+//! [`DatePicker`](super::DatePicker) overlay below it.
+//!
+//! *This API requires the following crate features to be activated: `date_picker`*
+use std::{cell::Cell, fmt::Write, hash::Hash, rc::Rc};
+
+use chrono::{Local, NaiveDate};
+use iced_native::{
+    button, column, container, event, layout, mouse, overlay, row, text, text_input, touch, Button,
+    Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size, TextInput, Widget,
+};
+
+use crate::{
+    core::renderer::DrawEnvironment,
+    graphics::icons::Icon,
+    native::{
+        icon_text,
+        overlay::date_picker::{self, DatePickerOverlay, OnSubmit},
+        IconText,
+    },
+};
+
+use super::{Date, Locale};
+
+/// The default format of the date in the text field.
+const DEFAULT_FORMAT: &str = "%Y-%m-%d";
+/// The padding around the text field and the button.
+const PADDING: u16 = 5;
+/// The spacing between the text field and the button.
+const SPACING: u16 = 5;
+
+/// The icon of the button opening the calendar.
+#[cfg(feature = "icons")]
+const CALENDAR_ICON: Icon = Icon::Calendar;
+/// The icon of the button opening the calendar.
+#[cfg(not(feature = "icons"))]
+const CALENDAR_ICON: Icon = Icon::CaretDownFill;
+
+/// The messages of the inner elements of the [`DatePickerInput`](DatePickerInput).
+#[derive(Clone, Debug)]
+enum InputMessage {
+    /// The text of the text field was edited.
+    Edit(String),
+    /// The text of the text field was submitted.
+    Submit,
+    /// The button opening the calendar was pressed.
+    Open,
+}
+
+/// A formatted, editable date text field with a button opening a calendar
+/// below it.
+///
+/// The text of the field and the date of the calendar are kept in sync:
+/// typing a valid date moves the calendar to it and picking a date in the
+/// calendar rewrites the text of the field. A typed date is submitted when
+/// `Enter` is pressed or when the text field loses focus.
+///
+/// # Example
+/// ```
+/// # use iced_aw::date_picker::{self, date_picker_input};
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type DatePickerInput<'a, Message> = iced_aw::native::date_picker::DatePickerInput<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Cancel,
+///     Submit(date_picker::Date),
+/// }
+///
+/// let mut state = date_picker_input::State::now();
+///
+/// let date_picker_input = DatePickerInput::new(
+///     &mut state,
+///     "Date of birth",
+///     Message::Cancel,
+///     Message::Submit,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DatePickerInput<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: date_picker::Renderer + button::Renderer,
+{
+    /// The state of the calendar of the [`DatePickerInput`](DatePickerInput).
+    picker: &'a mut super::State,
+    /// The text of the text field.
+    value: &'a mut String,
+    /// The format of the date in the text field.
+    format: &'a str,
+    /// The width of the [`DatePickerInput`](DatePickerInput).
+    width: Length,
+    /// Whether the text field is focused.
+    focused: bool,
+    /// The text field of the [`DatePickerInput`](DatePickerInput).
+    text_input: Element<'a, InputMessage, Renderer>,
+    /// The button opening the calendar of the [`DatePickerInput`](DatePickerInput).
+    button: Element<'a, InputMessage, Renderer>,
+    /// The message that is send if the cancel button of the calendar is pressed.
+    on_cancel: Message,
+    /// The function that produces a message when a date is submitted.
+    on_submit: Rc<dyn Fn(Date) -> Message>,
+    /// The function that produces a message when the submit button of the
+    /// calendar is pressed.
+    on_pick: Box<dyn Fn(Date) -> Message + 'a>,
+    /// The style of the calendar.
+    style: <Renderer as date_picker::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> DatePickerInput<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer:
+        'a + date_picker::Renderer + button::Renderer + icon_text::Renderer + text_input::Renderer,
+{
+    /// Creates a new [`DatePickerInput`](DatePickerInput).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`DatePickerInput`](DatePickerInput)'s
+    ///         [`State`](State).
+    ///     * the placeholder of the text field.
+    ///     * a message that will be send when the cancel button of the calendar
+    ///         is pressed.
+    ///     * a function that will be called when a valid date is submitted in
+    ///         the text field, with `Enter` or when it loses focus, or when the
+    ///         submit button of the calendar is pressed, which takes the
+    ///         [`Date`](crate::date_picker::Date) value.
+    pub fn new<F>(state: &'a mut State, placeholder: &str, on_cancel: Message, on_submit: F) -> Self
+    where
+        F: 'static + Fn(Date) -> Message,
+    {
+        let State {
+            picker,
+            text_input,
+            button,
+            value,
+            format,
+            picked,
+        } = state;
+
+        if let Some(date) = picked.take() {
+            *value = date.format(format).to_string();
+        }
+
+        let picked: &'a Cell<Option<NaiveDate>> = picked;
+        let on_submit: Rc<dyn Fn(Date) -> Message> = Rc::new(on_submit);
+        let on_pick = {
+            let on_submit = Rc::clone(&on_submit);
+            Box::new(move |date: Date| {
+                picked.set(Some(date.into()));
+                on_submit(date)
+            })
+        };
+
+        Self {
+            picker,
+            focused: text_input.is_focused(),
+            text_input: TextInput::new(text_input, placeholder, value, InputMessage::Edit)
+                .on_submit(InputMessage::Submit)
+                .width(Length::Fill)
+                .padding(PADDING)
+                .into(),
+            button: Button::new(button, IconText::new(CALENDAR_ICON))
+                .padding(PADDING)
+                .on_press(InputMessage::Open)
+                .into(),
+            value,
+            format,
+            width: Length::Fill,
+            on_cancel,
+            on_submit,
+            on_pick,
+            style: <Renderer as date_picker::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`DatePickerInput`](DatePickerInput).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the style of the calendar of the [`DatePickerInput`](DatePickerInput).
+    pub fn style<S>(mut self, style: S) -> Self
+    where
+        S: Into<<Renderer as date_picker::Renderer>::Style>,
+    {
+        self.style = style.into();
+        self
+    }
+
+    /// Submits the date of the text field if it is valid.
+    fn submit(&mut self, messages: &mut Vec<Message>) {
+        if let Ok(date) = NaiveDate::parse_from_str(self.value, self.format) {
+            self.picker.overlay_state.date = date;
+            messages.push((self.on_submit)(date.into()));
+        }
+    }
+}
+
+/// The state of the [`DatePickerInput`](DatePickerInput).
+#[derive(Debug)]
+pub struct State {
+    /// The state of the calendar.
+    pub(crate) picker: super::State,
+    /// The state of the text field.
+    pub(crate) text_input: text_input::State,
+    /// The state of the button opening the calendar.
+    pub(crate) button: button::State,
+    /// The text of the text field.
+    pub(crate) value: String,
+    /// The format of the date in the text field.
+    pub(crate) format: String,
+    /// The date picked in the calendar that is not yet written into the text
+    /// field.
+    pub(crate) picked: Cell<Option<NaiveDate>>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) with the current date.
+    #[must_use]
+    pub fn now() -> Self {
+        let picker = super::State::now();
+        let value = picker.overlay_state.date.format(DEFAULT_FORMAT).to_string();
+
+        Self {
+            picker,
+            text_input: text_input::State::new(),
+            button: button::State::new(),
+            value,
+            format: DEFAULT_FORMAT.to_owned(),
+            picked: Cell::new(None),
+        }
+    }
+
+    /// Sets the [`strftime`](chrono::format::strftime) format of the date in
+    /// the text field.
+    ///
+    /// A format that cannot be used for a date, like one with an unknown or a
+    /// time specifier, falls back to `%Y-%m-%d`.
+    #[must_use]
+    pub fn format(mut self, format: &str) -> Self {
+        self.format = checked_format(format).to_owned();
+        self.value = self
+            .picker
+            .overlay_state
+            .date
+            .format(&self.format)
+            .to_string();
+        self
+    }
+
+    /// Sets the [`Locale`](Locale) of the calendar, using its date format
+    /// for the date in the text field.
+    #[must_use]
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.format = checked_format(&locale.date_format).to_owned();
+        self.value = self
+            .picker
+            .overlay_state
+            .date
+            .format(&self.format)
+            .to_string();
+        self.picker.overlay_state.locale = locale;
+        self
+    }
+
+    /// Sets the visibility of the calendar.
+    pub fn show(&mut self, b: bool) {
+        self.picker.show(b);
+    }
+
+    /// Sets the date of the text field and the calendar.
+    pub fn set_date(&mut self, date: Date) {
+        let date: NaiveDate = date.into();
+        self.picker.overlay_state.date = date;
+        self.value = date.format(&self.format).to_string();
+    }
+
+    /// Resets the date of the text field and the calendar to the current date.
+    pub fn reset(&mut self) {
+        self.set_date(Local::today().naive_local().into());
+    }
+}
+
+/// Returns the given format if a date can be formatted with it, or the
+/// default format otherwise.
+fn checked_format(format: &str) -> &str {
+    let mut formatted = String::new();
+
+    match write!(
+        formatted,
+        "{}",
+        NaiveDate::from_ymd(2000, 1, 1).format(format)
+    ) {
+        Ok(()) => format,
+        Err(_) => DEFAULT_FORMAT,
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for DatePickerInput<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a
+        + self::Renderer
+        + date_picker::Renderer
+        + button::Renderer
+        + column::Renderer
+        + container::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer
+        + text_input::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let mut button = self.button.layout(renderer, &limits);
+
+        let text_input_limits =
+            limits.shrink(Size::new(button.bounds().width + f32::from(SPACING), 0.0));
+        let mut text_input = self.text_input.layout(renderer, &text_input_limits);
+
+        let height = text_input.bounds().height.max(button.bounds().height);
+
+        text_input.move_to(Point::new(0.0, (height - text_input.bounds().height) / 2.0));
+        button.move_to(Point::new(
+            text_input.bounds().width + f32::from(SPACING),
+            (height - button.bounds().height) / 2.0,
+        ));
+
+        let size = limits.resolve(Size::new(
+            text_input.bounds().width + f32::from(SPACING) + button.bounds().width,
+            height,
+        ));
+
+        layout::Node::with_children(size, vec![text_input, button])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let text_input_layout = children
+            .next()
+            .expect("Native: Layout should have a text input layout for a DatePickerInput");
+        let button_layout = children
+            .next()
+            .expect("Native: Layout should have a button layout for a DatePickerInput");
+
+        let mut input_messages = Vec::new();
+
+        let text_input_status = self.text_input.on_event(
+            event.clone(),
+            text_input_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut input_messages,
+        );
+
+        let lost_focus = self.focused
+            && match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                    !text_input_layout.bounds().contains(cursor_position)
+                }
+                Event::Touch(touch::Event::FingerPressed { position, .. }) => {
+                    !text_input_layout.bounds().contains(position)
+                }
+                _ => false,
+            };
+
+        let button_status = self.button.on_event(
+            event,
+            button_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut input_messages,
+        );
+
+        for message in input_messages {
+            match message {
+                InputMessage::Edit(value) => {
+                    if let Ok(date) = NaiveDate::parse_from_str(&value, self.format) {
+                        self.picker.overlay_state.date = date;
+                    }
+                    *self.value = value;
+                }
+                InputMessage::Submit => self.submit(messages),
+                InputMessage::Open => self.picker.show(true),
+            }
+        }
+
+        if lost_focus {
+            self.focused = false;
+            self.submit(messages);
+        }
+
+        text_input_status.merge(button_status)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &(),
+                viewport: Some(viewport),
+                focus: (),
+            },
+            &self.text_input,
+            &self.button,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.picker.show.hash(state);
+        self.text_input.hash_layout(state);
+        self.button.hash_layout(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.picker.show {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+        let position = Point::new(bounds.x, bounds.y + bounds.height);
+
+        Some(
+            DatePickerOverlay::new(
+                &mut self.picker,
+                self.on_cancel.clone(),
+                OnSubmit::Date(&self.on_pick),
+                position,
+                &self.style,
+            )
+            .anchored()
+            .overlay(),
+        )
+    }
+}
+
+/// The renderer of a [`DatePickerInput`](DatePickerInput).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`DatePickerInput`](DatePickerInput) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// Draws a [`DatePickerInput`](DatePickerInput).
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, (), ()>,
+        text_input: &Element<'_, Message, Self>,
+        button: &Element<'_, Message, Self>,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    fn draw<Message>(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, (), ()>,
+        _text_input: &Element<'_, Message, Self>,
+        _button: &Element<'_, Message, Self>,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<DatePickerInput<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a
+        + self::Renderer
+        + date_picker::Renderer
+        + button::Renderer
+        + column::Renderer
+        + container::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer
+        + text_input::Renderer,
+{
+    fn from(date_picker_input: DatePickerInput<'a, Message, Renderer>) -> Self {
+        Element::new(date_picker_input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checked_format, DEFAULT_FORMAT};
+
+    #[test]
+    fn valid_format_is_kept() {
+        assert_eq!(checked_format("%d.%m.%Y"), "%d.%m.%Y");
+        assert_eq!(checked_format("%A, %B %e"), "%A, %B %e");
+    }
+
+    #[test]
+    fn invalid_format_falls_back_to_default() {
+        assert_eq!(checked_format("%Q"), DEFAULT_FORMAT);
+        assert_eq!(checked_format("%Y-%m-%d %H:%M"), DEFAULT_FORMAT);
+    }
+}
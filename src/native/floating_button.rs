@@ -1,28 +1,28 @@
 //! Use a floating button to overlay a button over some content
 //!
 //! *This API requires the following crate features to be activated: `floating_button`*
-use std::hash::Hash;
-
 use iced_native::{
-    button, event, overlay, Button, Clipboard, Element, Event, Layout, Length, Point, Rectangle,
-    Widget,
+    button, column, event, overlay, Button, Clipboard, Element, Event, Layout, Length, Point,
+    Rectangle, Widget,
 };
 
-pub mod anchor;
-pub use anchor::Anchor;
-
-pub mod offset;
-pub use offset::Offset;
+pub use crate::native::floating_element::{anchor, offset, padding, Anchor, Offset, Padding};
+use crate::native::FloatingElement;
 
-use super::overlay::floating_button::FloatingButtonOverlay;
+pub mod speed_dial;
+use speed_dial::Dial;
+pub use speed_dial::SpeedDial;
 
 /// A floating button floating over some content.
 ///
+/// This is a thin wrapper around a [`FloatingElement`](FloatingElement)
+/// floating a [`Button`](iced_native::button::Button).
+///
 /// # Example
 /// ```
 /// # use iced_native::{button, Button, Column, renderer::Null, Text};
 /// #
-/// # pub type FloatingButton<'a, B, Message> = iced_aw::native::FloatingButton<'a, B, Message, Null>;
+/// # pub type FloatingButton<'a, Message> = iced_aw::native::FloatingButton<'a, Message, Null>;
 /// #[derive(Debug, Clone)]
 /// enum Message {
 ///     ButtonPressed,
@@ -39,31 +39,19 @@ use super::overlay::floating_button::FloatingButtonOverlay;
 /// );
 /// ```
 #[allow(missing_debug_implementations)]
-pub struct FloatingButton<'a, B, Message, Renderer>
+pub struct FloatingButton<'a, Message, Renderer>
 where
-    B: Fn(&mut button::State) -> Button<'_, Message, Renderer>,
     Message: Clone,
     Renderer: iced_native::button::Renderer,
 {
-    /// The state of the button.
-    state: &'a mut button::State,
-    /// The anchor of the button.
-    anchor: Anchor,
-    /// The offset of the button.
-    offset: Offset,
-    /// The visibility of the button.
-    hidden: bool,
-    /// The underlying element.
-    underlay: Element<'a, Message, Renderer>,
-    /// The floating button of the [`FloatingButtonOverlay`](FloatingButtonOverlay).
-    button: B,
+    /// The [`FloatingElement`](FloatingElement) floating the button.
+    floating_element: FloatingElement<'a, Message, Renderer>,
 }
 
-impl<'a, B, Message, Renderer> FloatingButton<'a, B, Message, Renderer>
+impl<'a, Message, Renderer> FloatingButton<'a, Message, Renderer>
 where
-    B: Fn(&mut button::State) -> Button<'_, Message, Renderer>,
-    Message: Clone,
-    Renderer: iced_native::button::Renderer,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::button::Renderer,
 {
     /// Creates a new [`FloatingButton`](FloatingButton) over some content,
     /// showing the given [`Button`](iced_native::button::Button).
@@ -73,24 +61,20 @@ where
     ///         [`State`](iced_native::button::State).
     ///     * the underlay [`Element`](iced_native::Element) on which this [`FloatingButton`](FloatingButton)
     ///         will be wrapped around.
-    ///     * a function that will lazy create the [`Button`](iced_native::Button) for the overlay.
-    pub fn new<U>(state: &'a mut button::State, underlay: U, button: B) -> Self
+    ///     * a function that will create the [`Button`](iced_native::Button) for the overlay.
+    pub fn new<U, B>(state: &'a mut button::State, underlay: U, button: B) -> Self
     where
         U: Into<Element<'a, Message, Renderer>>,
+        B: FnOnce(&'a mut button::State) -> Button<'a, Message, Renderer>,
     {
         FloatingButton {
-            state,
-            anchor: Anchor::SouthEast,
-            offset: 5.0.into(),
-            hidden: false,
-            underlay: underlay.into(),
-            button,
+            floating_element: FloatingElement::new(underlay, button(state)),
         }
     }
 
     /// Sets the [`Anchor`](Anchor) of the [`FloatingButton`](FloatingButton).
     pub fn anchor(mut self, anchor: Anchor) -> Self {
-        self.anchor = anchor;
+        self.floating_element = self.floating_element.anchor(anchor);
         self
     }
 
@@ -99,31 +83,72 @@ where
     where
         O: Into<Offset>,
     {
-        self.offset = offset.into();
+        self.floating_element = self.floating_element.offset(offset);
+        self
+    }
+
+    /// Sets the [`Padding`](Padding) of the underlay the
+    /// [`Button`](iced_native::button::Button) of the
+    /// [`FloatingButton`](FloatingButton) is kept out of.
+    ///
+    /// See [`FloatingElement::underlay_padding`](FloatingElement::underlay_padding).
+    pub fn underlay_padding<P>(mut self, padding: P) -> Self
+    where
+        P: Into<Padding>,
+    {
+        self.floating_element = self.floating_element.underlay_padding(padding);
         self
     }
 
     /// Hide or unhide the [`Button`](iced_native::button::Button) on the
     /// [`FloatingButton`](FloatingButton).
     pub fn hide(mut self, hide: bool) -> Self {
-        self.hidden = hide;
+        self.floating_element = self.floating_element.hide(hide);
+        self
+    }
+
+    /// Attaches a badge (e.g. a [`Badge`](crate::native::Badge) showing an
+    /// unread count) to the [`Button`](iced_native::button::Button) of the
+    /// [`FloatingButton`](FloatingButton).
+    ///
+    /// The badge is centered on the top trailing corner of the button. It is
+    /// treated as a part of the button, so hovering or pressing the badge
+    /// hovers or presses the button.
+    pub fn badge<E>(mut self, badge: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.floating_element = self.floating_element.badge(badge);
+        self
+    }
+
+    /// Turns the [`FloatingButton`](FloatingButton) into a speed dial
+    /// expanding the given [`SpeedDial`](SpeedDial) of secondary actions when
+    /// its [`Button`](iced_native::button::Button) is pressed.
+    ///
+    /// The button still produces its own message, if any, when it is pressed.
+    pub fn speed_dial(mut self, speed_dial: SpeedDial<'a, Message, Renderer>) -> Self
+    where
+        Renderer: column::Renderer,
+    {
+        self.floating_element = self
+            .floating_element
+            .map_element(|button| Dial::new(speed_dial, button).into());
         self
     }
 }
 
-impl<'a, B, Message, Renderer> Widget<Message, Renderer>
-    for FloatingButton<'a, B, Message, Renderer>
+impl<'a, Message, Renderer> Widget<Message, Renderer> for FloatingButton<'a, Message, Renderer>
 where
-    B: 'a + Fn(&mut button::State) -> Button<'_, Message, Renderer>,
     Message: 'a + Clone,
     Renderer: 'a + iced_native::button::Renderer,
 {
     fn width(&self) -> Length {
-        self.underlay.width()
+        self.floating_element.width()
     }
 
     fn height(&self) -> Length {
-        self.underlay.height()
+        self.floating_element.height()
     }
 
     fn layout(
@@ -131,7 +156,7 @@ where
         renderer: &Renderer,
         limits: &iced_native::layout::Limits,
     ) -> iced_native::layout::Node {
-        self.underlay.layout(renderer, limits)
+        self.floating_element.layout(renderer, limits)
     }
 
     fn on_event(
@@ -143,7 +168,7 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
-        self.underlay.on_event(
+        self.floating_element.on_event(
             event,
             layout,
             cursor_position,
@@ -161,52 +186,26 @@ where
         cursor_position: Point,
         viewport: &Rectangle,
     ) -> Renderer::Output {
-        self.underlay
+        self.floating_element
             .draw(renderer, defaults, layout, cursor_position, viewport)
     }
 
     fn hash_layout(&self, state: &mut iced_native::Hasher) {
-        #[allow(clippy::missing_docs_in_private_items)]
-        struct Marker;
-        std::any::TypeId::of::<Marker>().hash(state);
-
-        self.anchor.hash(state);
-        (self.offset.x as u32).hash(state);
-        (self.offset.y as u32).hash(state);
-        self.hidden.hash(state);
-        self.underlay.hash_layout(state);
+        self.floating_element.hash_layout(state);
     }
 
     fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
-        if self.hidden {
-            return None;
-        }
-
-        let bounds = layout.bounds();
-        let position = match self.anchor {
-            Anchor::NorthWest => Point::new(0.0, 0.0),
-            Anchor::NorthEast => Point::new(bounds.width, 0.0),
-            Anchor::SouthWest => Point::new(0.0, bounds.height),
-            Anchor::SouthEast => Point::new(bounds.width, bounds.height),
-        };
-
-        let position = Point::new(bounds.x + position.x, bounds.y + position.y);
-
-        Some(
-            FloatingButtonOverlay::new(&mut self.state, &self.button, &self.anchor, &self.offset)
-                .overlay(position),
-        )
+        self.floating_element.overlay(layout)
     }
 }
 
-impl<'a, B, Message, Renderer> From<FloatingButton<'a, B, Message, Renderer>>
+impl<'a, Message, Renderer> From<FloatingButton<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
-    B: 'a + Fn(&mut button::State) -> Button<'_, Message, Renderer>,
     Message: 'a + Clone,
     Renderer: 'a + iced_native::button::Renderer,
 {
-    fn from(floating_button: FloatingButton<'a, B, Message, Renderer>) -> Self {
+    fn from(floating_button: FloatingButton<'a, Message, Renderer>) -> Self {
         Element::new(floating_button)
     }
 }
@@ -0,0 +1,466 @@
+//! Use a speed dial to expand a floating button into secondary actions.
+//!
+//! *This API requires the following crate features to be activated: `floating_button`*
+use std::hash::Hash;
+
+use iced_native::{
+    column, event, keyboard, layout, mouse, touch, Align, Clipboard, Element, Event, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+
+/// The space between the actions of a [`SpeedDial`](SpeedDial) and between an
+/// action and its label.
+const SPACING: f32 = 10.0;
+
+/// The direction in which a [`SpeedDial`](SpeedDial) expands from the main
+/// button.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Direction {
+    /// The actions are stacked above the main button.
+    Up,
+
+    /// The actions are stacked below the main button.
+    Down,
+}
+
+/// The state of a [`SpeedDial`](SpeedDial).
+#[derive(Debug, Default)]
+pub struct State {
+    /// Whether the actions of the [`SpeedDial`](SpeedDial) are shown.
+    open: bool,
+}
+
+impl State {
+    /// Creates a new [`State`](State) of a collapsed [`SpeedDial`](SpeedDial).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the actions of the [`SpeedDial`](SpeedDial) are
+    /// shown.
+    #[must_use]
+    pub const fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Collapses the [`SpeedDial`](SpeedDial).
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+}
+
+/// A secondary action of a [`SpeedDial`](SpeedDial) shown with a label next
+/// to it.
+#[allow(missing_debug_implementations)]
+pub struct Action<'a, Message, Renderer> {
+    /// The label of the action.
+    label: Element<'a, Message, Renderer>,
+    /// The button of the action.
+    button: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Action<'a, Message, Renderer> {
+    /// Creates a new [`Action`](Action) showing the given label next to the
+    /// given button.
+    ///
+    /// The button is expected to produce the message of the action when it is
+    /// pressed.
+    pub fn new<L, B>(label: L, button: B) -> Self
+    where
+        L: Into<Element<'a, Message, Renderer>>,
+        B: Into<Element<'a, Message, Renderer>>,
+    {
+        Action {
+            label: label.into(),
+            button: button.into(),
+        }
+    }
+}
+
+/// The secondary actions a [`FloatingButton`](super::FloatingButton) expands
+/// into when its button is pressed.
+///
+/// The [`SpeedDial`](SpeedDial) collapses when one of its actions produced a
+/// message, when escape is pressed or when the user clicks anywhere outside
+/// of it.
+///
+/// # Example
+/// ```
+/// # use iced_native::{button, Button, Column, renderer::Null, Text};
+/// # use iced_aw::native::floating_button::speed_dial::{self, Action, SpeedDial};
+/// #
+/// # pub type FloatingButton<'a, Message> = iced_aw::native::FloatingButton<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Share,
+/// }
+///
+/// let mut button_state = button::State::default();
+/// let mut share_state = button::State::default();
+/// let mut speed_dial_state = speed_dial::State::default();
+///
+/// let content = Column::new();
+/// let floating_button = FloatingButton::new(
+///     &mut button_state,
+///     content,
+///     |state| Button::new(state, Text::new("+"))
+/// )
+/// .speed_dial(
+///     SpeedDial::new(&mut speed_dial_state).push(Action::new(
+///         Text::new("Share"),
+///         Button::new(&mut share_state, Text::new("S")).on_press(Message::Share),
+///     ))
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SpeedDial<'a, Message, Renderer> {
+    /// The state of the [`SpeedDial`](SpeedDial).
+    state: &'a mut State,
+    /// The secondary actions.
+    actions: Vec<Action<'a, Message, Renderer>>,
+    /// The direction the actions are stacked in.
+    direction: Direction,
+    /// The horizontal alignment of the actions and the main button.
+    align: Align,
+}
+
+impl<'a, Message, Renderer> SpeedDial<'a, Message, Renderer> {
+    /// Creates a new [`SpeedDial`](SpeedDial) without any actions.
+    ///
+    /// The actions are stacked above the main button and aligned to its
+    /// right edge by default, with their labels on the left.
+    pub fn new(state: &'a mut State) -> Self {
+        SpeedDial {
+            state,
+            actions: Vec::new(),
+            direction: Direction::Up,
+            align: Align::End,
+        }
+    }
+
+    /// Adds an [`Action`](Action) to the [`SpeedDial`](SpeedDial).
+    ///
+    /// The first action is the one closest to the main button.
+    pub fn push(mut self, action: Action<'a, Message, Renderer>) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Sets the [`Direction`](Direction) the actions of the
+    /// [`SpeedDial`](SpeedDial) are stacked in.
+    ///
+    /// Use [`Direction::Down`](Direction::Down) if the
+    /// [`FloatingButton`](super::FloatingButton) is anchored to the top of
+    /// the underlay.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the horizontal alignment of the actions of the
+    /// [`SpeedDial`](SpeedDial) with the main button.
+    ///
+    /// The labels are placed on the left of the actions, except for
+    /// [`Align::Start`](iced_native::Align::Start) where they are placed on
+    /// the right.
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+}
+
+/// The main button of a [`FloatingButton`](super::FloatingButton) together
+/// with the actions of its [`SpeedDial`](SpeedDial).
+///
+/// The children are the main button followed by the label and the button of
+/// every action, laid out in this order.
+pub(crate) struct Dial<'a, Message, Renderer> {
+    /// The state of the [`SpeedDial`](SpeedDial).
+    state: &'a mut State,
+    /// The main button followed by the labels and buttons of the actions.
+    children: Vec<Element<'a, Message, Renderer>>,
+    /// The direction the actions are stacked in.
+    direction: Direction,
+    /// The horizontal alignment of the actions and the main button.
+    align: Align,
+}
+
+impl<'a, Message, Renderer> Dial<'a, Message, Renderer> {
+    /// Creates a new [`Dial`](Dial) expanding the given main button into the
+    /// actions of the [`SpeedDial`](SpeedDial).
+    pub(crate) fn new(
+        speed_dial: SpeedDial<'a, Message, Renderer>,
+        button: Element<'a, Message, Renderer>,
+    ) -> Self {
+        let SpeedDial {
+            state,
+            actions,
+            direction,
+            align,
+        } = speed_dial;
+
+        let children = std::iter::once(button)
+            .chain(
+                actions
+                    .into_iter()
+                    .flat_map(|action| vec![action.label, action.button]),
+            )
+            .collect();
+
+        Dial {
+            state,
+            children,
+            direction,
+            align,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Dial<'a, Message, Renderer>
+where
+    Renderer: column::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.loose();
+        let mut children = self.children.iter();
+
+        let mut button = children
+            .next()
+            .expect("Native: There should be a main button")
+            .layout(renderer, &limits);
+
+        if !self.state.open {
+            return layout::Node::with_children(button.size(), vec![button]);
+        }
+
+        // Every action is laid out as a row of its label and its button.
+        let mut rows = Vec::new();
+        while let Some((label, action)) = children.next().zip(children.next()) {
+            let label = label.layout(renderer, &limits);
+            let action = action.layout(renderer, &limits);
+            let size = Size::new(
+                label.size().width + SPACING + action.size().width,
+                label.size().height.max(action.size().height),
+            );
+            rows.push((label, action, size));
+        }
+
+        let width = rows
+            .iter()
+            .map(|(_, _, size)| size.width)
+            .fold(button.size().width, f32::max);
+        let height = rows
+            .iter()
+            .map(|(_, _, size)| size.height + SPACING)
+            .sum::<f32>()
+            + button.size().height;
+
+        let align_x = |row_width: f32| match self.align {
+            Align::Start => 0.0,
+            Align::Center => (width - row_width) / 2.0,
+            Align::End => width - row_width,
+        };
+
+        let mut nodes = Vec::with_capacity(self.children.len());
+
+        let mut y = match self.direction {
+            Direction::Up => height - button.size().height,
+            Direction::Down => 0.0,
+        };
+        button.move_to(Point::new(align_x(button.size().width), y));
+        let button_height = button.size().height;
+        nodes.push(button);
+
+        if self.direction == Direction::Down {
+            y += button_height + SPACING;
+        }
+
+        for (mut label, mut action, size) in rows {
+            if self.direction == Direction::Up {
+                y -= size.height + SPACING;
+            }
+
+            let x = align_x(size.width);
+            let label_y = y + (size.height - label.size().height) / 2.0;
+            let action_y = y + (size.height - action.size().height) / 2.0;
+
+            match self.align {
+                Align::Start => {
+                    action.move_to(Point::new(x, action_y));
+                    label.move_to(Point::new(x + action.size().width + SPACING, label_y));
+                }
+                Align::Center | Align::End => {
+                    label.move_to(Point::new(x, label_y));
+                    action.move_to(Point::new(x + label.size().width + SPACING, action_y));
+                }
+            }
+
+            nodes.push(label);
+            nodes.push(action);
+
+            if self.direction == Direction::Down {
+                y += size.height + SPACING;
+            }
+        }
+
+        layout::Node::with_children(Size::new(width, height), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let button_layout = children
+            .next()
+            .expect("Native: Layout should have a main button layout");
+        let button_bounds = button_layout.bounds();
+
+        let toggled = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                button_bounds.contains(cursor_position)
+            }
+            _ => false,
+        };
+
+        let mut status = self.children[0].on_event(
+            event.clone(),
+            button_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        );
+
+        if toggled {
+            self.state.open = !self.state.open;
+
+            #[cfg(feature = "instrumentation")]
+            instrumentation::record(
+                WidgetKind::SpeedDial,
+                if self.state.open {
+                    Action::Open
+                } else {
+                    Action::Close
+                },
+            );
+
+            return event::Status::Captured;
+        }
+
+        if !self.state.open {
+            return status;
+        }
+
+        let mut hovered = false;
+        let mut selected = None;
+
+        for (index, (child, layout)) in self.children.iter_mut().skip(1).zip(children).enumerate() {
+            hovered |= layout.bounds().contains(cursor_position);
+
+            let before = messages.len();
+
+            status = status.merge(child.on_event(
+                event.clone(),
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                messages,
+            ));
+
+            if messages.len() > before {
+                selected = selected.or(Some(index));
+            }
+        }
+
+        let close = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => !hovered,
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+                key_code == keyboard::KeyCode::Escape
+            }
+            _ => false,
+        };
+
+        #[cfg(feature = "instrumentation")]
+        if let Some(index) = selected {
+            instrumentation::record(WidgetKind::SpeedDial, Action::Select(index));
+        } else if close {
+            let action = match event {
+                Event::Keyboard(_) => Action::Escape,
+                _ => Action::Backdrop,
+            };
+            instrumentation::record(WidgetKind::SpeedDial, action);
+        }
+
+        // The dial is closed once one of its actions was pressed.
+        if close || selected.is_some() {
+            self.state.open = false;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        // The layout only has children for the shown elements, so the
+        // actions of a collapsed dial are skipped.
+        <Renderer as column::Renderer>::draw(
+            renderer,
+            defaults,
+            &self.children,
+            layout,
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.state.open.hash(state);
+        self.direction.hash(state);
+        (self.align as u8).hash(state);
+
+        for child in &self.children {
+            child.hash_layout(state);
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Dial<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + column::Renderer,
+{
+    fn from(dial: Dial<'a, Message, Renderer>) -> Self {
+        Element::new(dial)
+    }
+}
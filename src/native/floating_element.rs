@@ -0,0 +1,262 @@
+//! Use a floating element to overlay an element over some content
+//!
+//! *This API requires the following crate features to be activated: `floating_element`*
+use std::hash::Hash;
+
+use iced_native::{
+    event, overlay, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Widget,
+};
+
+pub mod anchor;
+pub use anchor::Anchor;
+
+pub mod offset;
+pub use offset::Offset;
+
+pub mod padding;
+pub use padding::Padding;
+
+use super::overlay::floating_element::FloatingElementOverlay;
+
+/// A floating element floating over some content.
+///
+/// # Example
+/// ```
+/// # use iced_native::{Column, renderer::Null, Text};
+/// #
+/// # pub type FloatingElement<'a, Message> = iced_aw::native::FloatingElement<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+/// }
+///
+/// let content = Column::<Message, Null>::new();
+/// let floating_element = FloatingElement::new(
+///     content,
+///     Text::new("Zoom: 100%"),
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct FloatingElement<'a, Message, Renderer: iced_native::Renderer> {
+    /// The anchor of the element.
+    anchor: Anchor,
+    /// The offset of the element.
+    offset: Offset,
+    /// The padding of the underlay the element is kept out of.
+    underlay_padding: Padding,
+    /// The visibility of the element.
+    hidden: bool,
+    /// The underlying element.
+    underlay: Element<'a, Message, Renderer>,
+    /// The floating element of the [`FloatingElementOverlay`](FloatingElementOverlay).
+    element: Element<'a, Message, Renderer>,
+    /// The optional badge attached to the element.
+    badge: Option<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> FloatingElement<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// Creates a new [`FloatingElement`](FloatingElement) over some content,
+    /// showing the given [`Element`](iced_native::Element).
+    ///
+    /// It expects:
+    ///     * the underlay [`Element`](iced_native::Element) on which this
+    ///         [`FloatingElement`](FloatingElement) will be wrapped around.
+    ///     * the [`Element`](iced_native::Element) floating over the underlay,
+    ///         e.g. a toolbar or a cluster of zoom controls.
+    pub fn new<U, E>(underlay: U, element: E) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        FloatingElement {
+            anchor: Anchor::SouthEast,
+            offset: 5.0.into(),
+            underlay_padding: Padding::default(),
+            hidden: false,
+            underlay: underlay.into(),
+            element: element.into(),
+            badge: None,
+        }
+    }
+
+    /// Sets the [`Anchor`](Anchor) of the [`FloatingElement`](FloatingElement).
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the [`Offset`](Offset) of the [`FloatingElement`](FloatingElement).
+    pub fn offset<O>(mut self, offset: O) -> Self
+    where
+        O: Into<Offset>,
+    {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Sets the [`Padding`](Padding) of the underlay the floating element of
+    /// the [`FloatingElement`](FloatingElement) is kept out of.
+    ///
+    /// The [`Anchor`](Anchor) is placed on the corner of the underlay inside
+    /// of this padding, so the element does not cover e.g. a scrollbar along
+    /// the right edge or a status bar along the bottom edge of the underlay,
+    /// whichever corner it is anchored to. The [`Offset`](Offset) is applied
+    /// on top of it.
+    pub fn underlay_padding<P>(mut self, padding: P) -> Self
+    where
+        P: Into<Padding>,
+    {
+        self.underlay_padding = padding.into();
+        self
+    }
+
+    /// Hide or unhide the floating element of the
+    /// [`FloatingElement`](FloatingElement).
+    pub fn hide(mut self, hide: bool) -> Self {
+        self.hidden = hide;
+        self
+    }
+
+    /// Attaches a badge (e.g. a [`Badge`](crate::native::Badge) showing an
+    /// unread count) to the floating element of the
+    /// [`FloatingElement`](FloatingElement).
+    ///
+    /// The badge is centered on the top trailing corner of the element. It is
+    /// treated as a part of the element, so hovering or pressing the badge
+    /// hovers or presses the element.
+    pub fn badge<E>(mut self, badge: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.badge = Some(badge.into());
+        self
+    }
+
+    /// Replaces the floating element by the one produced from it by the
+    /// given function.
+    pub(crate) fn map_element<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Element<'a, Message, Renderer>) -> Element<'a, Message, Renderer>,
+    {
+        FloatingElement {
+            element: f(self.element),
+            ..self
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for FloatingElement<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn width(&self) -> Length {
+        self.underlay.width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.underlay.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        self.underlay.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: iced_native::Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.underlay
+            .draw(renderer, defaults, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.anchor.hash(state);
+        (self.offset.x as u32).hash(state);
+        (self.offset.y as u32).hash(state);
+        (self.underlay_padding.top as u32).hash(state);
+        (self.underlay_padding.right as u32).hash(state);
+        (self.underlay_padding.bottom as u32).hash(state);
+        (self.underlay_padding.left as u32).hash(state);
+        self.hidden.hash(state);
+        self.underlay.hash_layout(state);
+        self.element.hash_layout(state);
+
+        if let Some(badge) = &self.badge {
+            badge.hash_layout(state);
+        }
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if self.hidden {
+            return None;
+        }
+
+        let padding = self.underlay_padding;
+        let bounds = layout.bounds();
+        let bounds = Rectangle {
+            x: bounds.x + padding.left,
+            y: bounds.y + padding.top,
+            width: (bounds.width - padding.left - padding.right).max(0.0),
+            height: (bounds.height - padding.top - padding.bottom).max(0.0),
+        };
+        let position = match self.anchor {
+            Anchor::NorthWest => Point::new(0.0, 0.0),
+            Anchor::NorthEast => Point::new(bounds.width, 0.0),
+            Anchor::SouthWest => Point::new(0.0, bounds.height),
+            Anchor::SouthEast => Point::new(bounds.width, bounds.height),
+        };
+
+        let position = Point::new(bounds.x + position.x, bounds.y + position.y);
+
+        Some(
+            FloatingElementOverlay::new(&mut self.element, &self.anchor, &self.offset)
+                .badge(self.badge.as_ref())
+                .overlay(position),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<FloatingElement<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn from(floating_element: FloatingElement<'a, Message, Renderer>) -> Self {
+        Element::new(floating_element)
+    }
+}
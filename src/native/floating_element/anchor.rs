@@ -1,27 +1,27 @@
-//! Use a floating button to overlay a button over some content
+//! Use a floating element to overlay an element over some content
 //!
-//! *This API requires the following crate features to be activated: `floating_button`*
+//! *This API requires the following crate features to be activated: `floating_element`*
 
-/// Positional [`Anchor`](Anchor) for the [`FloatingButton`](super::FloatingButton).
+/// Positional [`Anchor`](Anchor) for the [`FloatingElement`](super::FloatingElement).
 #[derive(Copy, Clone, Debug, Hash)]
 pub enum Anchor {
     /// NortWest [`Anchor`](Anchor) for positioning the
-    /// [`Button`](iced_native::button::Button) on the top left of the
+    /// floating element on the top left of the
     /// underlying element.
     NorthWest,
 
     /// NorthEast [`Anchor`](Anchor) for positioning the
-    /// [`Button`](iced_native::button::Button) on the top right of the
+    /// floating element on the top right of the
     /// underlying element.
     NorthEast,
 
     /// SouthWest [`Anchor`](Anchor) for positioning the
-    /// [`Button`](iced_native::button::Button) on the bottom left of the
+    /// floating element on the bottom left of the
     /// underlying element.
     SouthWest,
 
     /// SouthEast [`Anchor`](Anchor) for positioning the
-    /// [`Button`](iced_native::button::Button) on the bottom right of the
+    /// floating element on the bottom right of the
     /// underlying element.
     SouthEast,
 }
@@ -0,0 +1,55 @@
+//! Use a floating element to overlay an element over some content
+//!
+//! *This API requires the following crate features to be activated: `floating_element`*
+
+/// The [`Padding`](Padding) of the underlay of a
+/// [`FloatingElement`](super::FloatingElement) the floating element is kept
+/// out of.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Padding {
+    /// Padding along the top edge of the underlay.
+    pub top: f32,
+    /// Padding along the right edge of the underlay.
+    pub right: f32,
+    /// Padding along the bottom edge of the underlay.
+    pub bottom: f32,
+    /// Padding along the left edge of the underlay.
+    pub left: f32,
+}
+
+impl From<f32> for Padding {
+    fn from(float: f32) -> Self {
+        Self {
+            top: float,
+            right: float,
+            bottom: float,
+            left: float,
+        }
+    }
+}
+
+impl From<[f32; 2]> for Padding {
+    /// Creates a [`Padding`](Padding) from the vertical and the horizontal
+    /// padding.
+    fn from(array: [f32; 2]) -> Self {
+        Self {
+            top: array[0],
+            right: array[1],
+            bottom: array[0],
+            left: array[1],
+        }
+    }
+}
+
+impl From<[f32; 4]> for Padding {
+    /// Creates a [`Padding`](Padding) from the top, right, bottom and left
+    /// padding.
+    fn from(array: [f32; 4]) -> Self {
+        Self {
+            top: array[0],
+            right: array[1],
+            bottom: array[2],
+            left: array[3],
+        }
+    }
+}
@@ -0,0 +1,547 @@
+//! Use a gradient picker to edit the color stops of a linear gradient.
+//!
+//! *This API requires the following crate features to be activated: `gradient_picker`*
+use std::{hash::Hash, rc::Rc};
+
+use iced_native::{
+    button, column, event, keyboard, layout, mouse, overlay, row, text, text_input, touch,
+    Clipboard, Color, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+pub use crate::core::gradient::ColorStop;
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::{
+    core::{gradient, renderer::DrawEnvironment},
+    native::{
+        icon_text,
+        overlay::{
+            color_picker::{self, Focus},
+            GradientPickerOverlay,
+        },
+    },
+};
+
+/// The number of stops that can't be removed from a gradient.
+const MIN_STOPS: usize = 2;
+
+/// An editor for the color stops of a linear gradient.
+///
+/// The stops are shown as handles below a bar previewing the gradient.
+/// Pressing on the bar adds a new stop, and a handle can be dragged to move
+/// its stop. Pressing on the selected handle again opens a color picker to
+/// edit the color of its stop. The selected stop is removed with the delete
+/// or the backspace key, or by right clicking its handle, as long as there
+/// are more than two stops.
+///
+/// Every change produces a message with the full list of stops sorted by
+/// their offsets. Canceling the color picker produces the unchanged stops.
+///
+/// # Example
+/// ```
+/// # use iced_aw::gradient_picker::{self, ColorStop};
+/// # use iced_native::{Color, renderer::Null};
+/// #
+/// # pub type GradientPicker<'a, Message> = iced_aw::native::GradientPicker<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     GradientChanged(Vec<ColorStop>),
+/// }
+///
+/// let mut state = gradient_picker::State::new();
+/// let stops = vec![
+///     ColorStop::new(0.0, Color::BLACK),
+///     ColorStop::new(1.0, Color::WHITE),
+/// ];
+///
+/// let gradient_picker = GradientPicker::new(&mut state, stops, Message::GradientChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct GradientPicker<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer + color_picker::Renderer,
+{
+    /// The state of the [`GradientPicker`](GradientPicker).
+    state: &'a mut State,
+    /// The stops of the gradient sorted by their offsets.
+    stops: Vec<ColorStop>,
+    /// The function that produces a message when the stops change.
+    on_change: Rc<dyn Fn(Vec<ColorStop>) -> Message>,
+    /// The function that produces a message when the color of the selected
+    /// stop is submitted.
+    on_color_submit: Box<dyn Fn(Color) -> Message>,
+    /// The width of the [`GradientPicker`](GradientPicker).
+    width: Length,
+    /// The height of the bar of the [`GradientPicker`](GradientPicker).
+    height: u16,
+    /// The style of the [`GradientPicker`](GradientPicker).
+    style: <Renderer as self::Renderer>::Style,
+    /// The style of the color picker of the [`GradientPicker`](GradientPicker).
+    color_picker_style: <Renderer as color_picker::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> GradientPicker<'a, Message, Renderer>
+where
+    Message: 'static + Clone,
+    Renderer: self::Renderer + color_picker::Renderer,
+{
+    /// Creates a new [`GradientPicker`](GradientPicker).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`GradientPicker`](GradientPicker)'s
+    ///         [`State`](State).
+    ///     * the stops of the gradient.
+    ///     * a function that will be called with the full list of stops when
+    ///         they change.
+    pub fn new<F>(state: &'a mut State, stops: Vec<ColorStop>, on_change: F) -> Self
+    where
+        F: 'static + Fn(Vec<ColorStop>) -> Message,
+    {
+        let mut stops = stops;
+        gradient::sort(&mut stops);
+
+        if state.selected.map_or(false, |index| index >= stops.len()) {
+            state.selected = None;
+            state.editing = false;
+        }
+
+        let on_change: Rc<dyn Fn(Vec<ColorStop>) -> Message> = Rc::new(on_change);
+        let on_color_submit = {
+            let on_change = Rc::clone(&on_change);
+            let stops = stops.clone();
+            let selected = state.selected;
+
+            Box::new(move |color| {
+                let mut stops = stops.clone();
+                if let Some(stop) = selected.and_then(|index| stops.get_mut(index)) {
+                    stop.color = color;
+                }
+
+                on_change(stops)
+            })
+        };
+
+        GradientPicker {
+            state,
+            stops,
+            on_change,
+            on_color_submit,
+            width: Length::Fill,
+            height: <Renderer as self::Renderer>::DEFAULT_HEIGHT,
+            style: <Renderer as self::Renderer>::Style::default(),
+            color_picker_style: <Renderer as color_picker::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`GradientPicker`](GradientPicker).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the bar of the [`GradientPicker`](GradientPicker).
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`GradientPicker`](GradientPicker).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the color picker editing the color of a stop.
+    pub fn color_picker_style(
+        mut self,
+        style: impl Into<<Renderer as color_picker::Renderer>::Style>,
+    ) -> Self {
+        self.color_picker_style = style.into();
+        self
+    }
+
+    /// Produces a message with the current stops.
+    fn change(&self, messages: &mut Vec<Message>) {
+        #[cfg(feature = "instrumentation")]
+        instrumentation::record(WidgetKind::GradientPicker, Action::Change);
+        messages.push((self.on_change)(self.stops.clone()));
+    }
+
+    /// Removes the stop at the given index if there are enough stops left.
+    fn remove(&mut self, index: usize, messages: &mut Vec<Message>) {
+        if self.stops.len() > MIN_STOPS && index < self.stops.len() {
+            let _ = self.stops.remove(index);
+            self.state.selected = None;
+            self.change(messages);
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for GradientPicker<'a, Message, Renderer>
+where
+    Message: 'static + Clone,
+    Renderer: 'a
+        + self::Renderer
+        + color_picker::Renderer
+        + button::Renderer
+        + column::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer
+        + text_input::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let handle_size = f32::from(<Renderer as self::Renderer>::HANDLE_SIZE);
+        let height = f32::from(self.height);
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let size = limits.resolve(Size::new(0.0, height + handle_size));
+
+        // The bar is inset by half a handle, so the handles at its ends stay
+        // within the bounds.
+        let width = (size.width - handle_size).max(0.0);
+
+        let mut bar = layout::Node::new(Size::new(width, height));
+        bar.move_to(Point::new(handle_size / 2.0, 0.0));
+
+        let mut handles = layout::Node::new(Size::new(width, handle_size));
+        handles.move_to(Point::new(handle_size / 2.0, height));
+
+        layout::Node::with_children(size, vec![bar, handles])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let (bar, _) = bar_and_handles(layout);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) =
+                    handle_at(layout, &self.stops, self.state.selected, cursor_position)
+                {
+                    self.state.drag = Some(Drag {
+                        index,
+                        moved: false,
+                        was_selected: self.state.selected == Some(index),
+                    });
+                    self.state.selected = Some(index);
+
+                    event::Status::Captured
+                } else if bar.contains(cursor_position) {
+                    let index = gradient::insert(&mut self.stops, offset_at(bar, cursor_position));
+                    self.state.drag = Some(Drag {
+                        index,
+                        moved: false,
+                        was_selected: false,
+                    });
+                    self.state.selected = Some(index);
+                    self.change(messages);
+
+                    event::Status::Captured
+                } else {
+                    self.state.selected = None;
+
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                match handle_at(layout, &self.stops, self.state.selected, cursor_position) {
+                    Some(index) => {
+                        self.remove(index, messages);
+
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => match self.state.drag {
+                Some(drag) => {
+                    let index = gradient::move_stop(
+                        &mut self.stops,
+                        drag.index,
+                        offset_at(bar, cursor_position),
+                    );
+                    self.state.drag = Some(Drag {
+                        index,
+                        moved: true,
+                        ..drag
+                    });
+                    self.state.selected = Some(index);
+                    self.change(messages);
+
+                    event::Status::Captured
+                }
+                None => event::Status::Ignored,
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => match self.state.drag.take() {
+                Some(drag) => {
+                    if !drag.moved && drag.was_selected {
+                        if let Some(stop) = self.stops.get(drag.index) {
+                            self.state.editing = true;
+                            self.state.picker.color = stop.color;
+                            self.state.picker.focus = Focus::Overlay;
+                            self.state.picker.sat_value_canvas_cache.clear();
+                            self.state.picker.hue_canvas_cache.clear();
+                        }
+                    }
+
+                    event::Status::Captured
+                }
+                None => event::Status::Ignored,
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if !self.state.editing =>
+            {
+                match (key_code, self.state.selected) {
+                    (keyboard::KeyCode::Delete, Some(index))
+                    | (keyboard::KeyCode::Backspace, Some(index)) => {
+                        self.remove(index, messages);
+
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        <Renderer as self::Renderer>::draw(
+            renderer,
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: None,
+                focus: self.state.selected,
+            },
+            &self.stops,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.state.editing {
+            return None;
+        }
+
+        let (bar, handles) = bar_and_handles(layout);
+        let stop = self.stops.get(self.state.selected?)?;
+        let handle = handle_bounds(bar, handles, stop.offset);
+        let position = Point::new(handle.center_x(), handle.center_y());
+
+        Some(
+            GradientPickerOverlay::new(
+                self.state,
+                (self.on_change)(self.stops.clone()),
+                &self.on_color_submit,
+                position,
+                &self.color_picker_style,
+            )
+            .overlay(),
+        )
+    }
+}
+
+/// Returns the bounds of the bar and of the area of the handles of a
+/// [`GradientPicker`](GradientPicker).
+pub(crate) fn bar_and_handles(layout: Layout<'_>) -> (Rectangle, Rectangle) {
+    let mut children = layout.children();
+    let bar = children
+        .next()
+        .expect("Native: Layout should have a bar layout for a GradientPicker")
+        .bounds();
+    let handles = children
+        .next()
+        .expect("Native: Layout should have a handles layout for a GradientPicker")
+        .bounds();
+
+    (bar, handles)
+}
+
+/// Returns the bounds of the handle of a stop at the given offset.
+pub(crate) fn handle_bounds(bar: Rectangle, handles: Rectangle, offset: f32) -> Rectangle {
+    Rectangle {
+        x: bar.x + offset * bar.width - handles.height / 2.0,
+        y: handles.y,
+        width: handles.height,
+        height: handles.height,
+    }
+}
+
+/// Returns the index of the stop whose handle is at the given position.
+///
+/// The handle of the selected stop is drawn on top of the others, followed by
+/// the handles of the later stops.
+pub(crate) fn handle_at(
+    layout: Layout<'_>,
+    stops: &[ColorStop],
+    selected: Option<usize>,
+    position: Point,
+) -> Option<usize> {
+    let (bar, handles) = bar_and_handles(layout);
+    let contains = |index: &usize| {
+        stops.get(*index).map_or(false, |stop| {
+            handle_bounds(bar, handles, stop.offset).contains(position)
+        })
+    };
+
+    selected
+        .filter(contains)
+        .or_else(|| (0..stops.len()).rev().find(contains))
+}
+
+/// Returns the offset in the gradient at the horizontal position of the
+/// given point.
+fn offset_at(bar: Rectangle, position: Point) -> f32 {
+    if bar.width <= 0.0 {
+        0.0
+    } else {
+        ((position.x - bar.x) / bar.width).max(0.0).min(1.0)
+    }
+}
+
+/// A drag of a stop of a [`GradientPicker`](GradientPicker).
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The current index of the dragged stop.
+    index: usize,
+    /// Whether the stop was moved since the drag started.
+    moved: bool,
+    /// Whether the stop was already selected when the drag started.
+    was_selected: bool,
+}
+
+/// The state of a [`GradientPicker`](GradientPicker).
+#[derive(Debug, Default)]
+pub struct State {
+    /// The index of the selected stop.
+    pub(crate) selected: Option<usize>,
+    /// The current drag of a stop.
+    drag: Option<Drag>,
+    /// Whether the color of the selected stop is edited.
+    pub(crate) editing: bool,
+    /// The state of the color picker.
+    pub(crate) picker: color_picker::State,
+    /// The state of the cancel button of the color picker.
+    pub(crate) cancel_button: button::State,
+    /// The state of the submit button of the color picker.
+    pub(crate) submit_button: button::State,
+}
+
+impl State {
+    /// Creates a new [`State`](State).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index of the selected stop.
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Closes the color picker editing the color of the selected stop.
+    pub fn close_picker(&mut self) {
+        self.editing = false;
+        self.picker.focus = Focus::None;
+    }
+}
+
+/// The renderer of a [`GradientPicker`](GradientPicker).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`GradientPicker`](GradientPicker) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default height of the bar of a [`GradientPicker`](GradientPicker).
+    const DEFAULT_HEIGHT: u16;
+
+    /// The width and height of the handles of the stops.
+    const HANDLE_SIZE: u16;
+
+    /// Draws a [`GradientPicker`](GradientPicker).
+    ///
+    /// The focus of the environment is the index of the selected stop. The
+    /// layout has the layouts of the bar and of the area of the handles as
+    /// children.
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, Option<usize>>,
+        stops: &[ColorStop],
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_HEIGHT: u16 = 0;
+
+    const HANDLE_SIZE: u16 = 0;
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, Option<usize>>,
+        _stops: &[ColorStop],
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<GradientPicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'static + Clone,
+    Renderer: 'a
+        + self::Renderer
+        + color_picker::Renderer
+        + button::Renderer
+        + column::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer
+        + text_input::Renderer,
+{
+    fn from(gradient_picker: GradientPicker<'a, Message, Renderer>) -> Self {
+        Element::new(gradient_picker)
+    }
+}
@@ -194,6 +194,36 @@ pub trait Renderer: iced_native::Renderer {
     ) -> Self::Output;
 }
 
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Font = iced_native::Font;
+
+    fn default_size(&self) -> u16 {
+        20
+    }
+
+    fn default_font(&self) -> Self::Font {
+        iced_native::Font::Default
+    }
+
+    fn measure(&self, _content: &str, _size: u16, _font: Self::Font, _bounds: Size) -> (f32, f32) {
+        (0.0, 20.0)
+    }
+
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _content: &str,
+        _size: u16,
+        _font: Option<Self::Font>,
+        _color: Option<Color>,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+    ) -> Self::Output {
+    }
+}
+
 impl<'a, Message, Renderer> From<IconText<Renderer>> for Element<'a, Message, Renderer>
 where
     Renderer: self::Renderer + 'a,
@@ -0,0 +1,400 @@
+//! Use a knob to adjust a value within a range by dragging or scrolling.
+//!
+//! *This API requires the following crate features to be activated: `knob`*
+use std::{hash::Hash, ops::RangeInclusive};
+
+use iced_native::{
+    event, layout, mouse, touch, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+use num_traits::FromPrimitive;
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::renderer::DrawEnvironment;
+
+/// The vertical distance the cursor has to be dragged to go through the whole
+/// range.
+const DRAG_DISTANCE: f32 = 200.0;
+
+/// The part of the range a scrolled line moves the value if there is no step.
+const SCROLL_STEP: f32 = 0.01;
+
+/// The part of the range around the center detent in which the value snaps to
+/// it.
+const DETENT_RANGE: f32 = 0.03;
+
+/// A circular control adjusting a value within a range, e.g. the parameters
+/// of an audio effect.
+///
+/// The value is increased by dragging the [`Knob`](Knob) up or by scrolling
+/// and decreased by dragging it down. An arc around the [`Knob`](Knob)
+/// indicates the current value.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::knob;
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Knob<'a, T, Message> = iced_aw::native::Knob<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     PanChanged(f32),
+/// }
+///
+/// let mut state = knob::State::new();
+///
+/// let knob = Knob::new(&mut state, -1.0..=1.0, 0.25, Message::PanChanged)
+///     .step(0.05)
+///     .center_detent(0.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Knob<'a, T, Message, Renderer: self::Renderer> {
+    /// The state of the [`Knob`](Knob).
+    state: &'a mut State,
+    /// The range of selectable values.
+    range: RangeInclusive<T>,
+    /// The current value.
+    value: T,
+    /// The step the value is snapped to.
+    step: Option<T>,
+    /// The optional value the [`Knob`](Knob) snaps to and its arc starts at.
+    detent: Option<T>,
+    /// The function that produces a message when the value changes.
+    on_change: Box<dyn Fn(T) -> Message>,
+    /// The optional message that is produced when the [`Knob`](Knob) is
+    /// released.
+    on_release: Option<Message>,
+    /// The size of the [`Knob`](Knob).
+    size: u16,
+    /// The style of the [`Knob`](Knob).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> Knob<'a, T, Message, Renderer>
+where
+    T: Copy + PartialOrd + Into<f64> + FromPrimitive,
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Knob`](Knob).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`Knob`](Knob)'s [`State`](State).
+    ///     * the range of selectable values.
+    ///     * the current value.
+    ///     * a function that will be called with the new value when the
+    ///         [`Knob`](Knob) is turned.
+    pub fn new<F>(state: &'a mut State, range: RangeInclusive<T>, value: T, on_change: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        let value = if value < *range.start() {
+            *range.start()
+        } else if value > *range.end() {
+            *range.end()
+        } else {
+            value
+        };
+
+        Knob {
+            state,
+            range,
+            value,
+            step: None,
+            detent: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            size: Renderer::DEFAULT_SIZE,
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the step the value of the [`Knob`](Knob) is snapped to.
+    ///
+    /// Scrolling a line moves the value by one step.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Sets a center detent at the given value.
+    ///
+    /// The value snaps to the detent when it is turned close to it, and the
+    /// arc indicating the value starts at the detent instead of the start of
+    /// the range. This is useful for bipolar values like the panning of an
+    /// audio channel.
+    pub fn center_detent(mut self, value: T) -> Self {
+        self.detent = Some(value);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Knob`](Knob) is
+    /// released.
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+
+    /// Sets the size of the [`Knob`](Knob).
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the style of the [`Knob`](Knob).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the position of the given value in the range between 0.0 and
+    /// 1.0.
+    #[allow(clippy::cast_possible_truncation)]
+    fn percent(&self, value: T) -> f32 {
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+
+        if end <= start {
+            0.0
+        } else {
+            ((value.into() - start) / (end - start)) as f32
+        }
+    }
+
+    /// Sets the value at the given position in the range, snapped to the
+    /// detent or the step, and produces a message if it changed.
+    fn turn_to(&mut self, percent: f32, messages: &mut Vec<Message>) {
+        let percent = percent.max(0.0).min(1.0);
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+
+        let value = match self.detent {
+            Some(detent) if (percent - self.percent(detent)).abs() < DETENT_RANGE => detent.into(),
+            _ => {
+                let value = start + f64::from(percent) * (end - start);
+
+                match self.step.map(Into::into).filter(|step| *step > 0.0) {
+                    Some(step) => (start + ((value - start) / step).round() * step).min(end),
+                    None => value,
+                }
+            }
+        };
+
+        if let Some(value) = T::from_f64(value) {
+            if value != self.value {
+                self.value = value;
+                #[cfg(feature = "instrumentation")]
+                instrumentation::record(WidgetKind::Knob, Action::Change);
+                messages.push((self.on_change)(value));
+            }
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for Knob<'a, T, Message, Renderer>
+where
+    T: Copy + PartialOrd + Into<f64> + FromPrimitive,
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits
+            .width(Length::Units(self.size))
+            .height(Length::Units(self.size));
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if bounds.contains(cursor_position) =>
+            {
+                self.state.drag = Some(Drag {
+                    origin_y: cursor_position.y,
+                    origin_percent: self.percent(self.value),
+                });
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => match self.state.drag {
+                Some(drag) => {
+                    let percent =
+                        drag.origin_percent + (drag.origin_y - cursor_position.y) / DRAG_DISTANCE;
+                    self.turn_to(percent, messages);
+
+                    event::Status::Captured
+                }
+                None => event::Status::Ignored,
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if self.state.drag.is_some() =>
+            {
+                self.state.drag = None;
+
+                if let Some(on_release) = self.on_release.clone() {
+                    messages.push(on_release);
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let line = self.step.map_or(SCROLL_STEP, |step| {
+                    let start = (*self.range.start()).into();
+                    let end = (*self.range.end()).into();
+
+                    if end <= start {
+                        0.0
+                    } else {
+                        (step.into() / (end - start)) as f32
+                    }
+                });
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * line,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / DRAG_DISTANCE,
+                };
+
+                self.turn_to(self.percent(self.value) + delta, messages);
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: None,
+                focus: self.state.drag.is_some(),
+            },
+            self.percent(self.value),
+            self.detent.map(|detent| self.percent(detent)),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.size.hash(state);
+    }
+}
+
+/// A drag of a [`Knob`](Knob).
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The vertical position of the cursor when the drag started.
+    origin_y: f32,
+    /// The position of the value in the range when the drag started.
+    origin_percent: f32,
+}
+
+/// The state of a [`Knob`](Knob).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct State {
+    /// The current drag of the [`Knob`](Knob).
+    drag: Option<Drag>,
+}
+
+impl State {
+    /// Creates a new [`State`](State).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`Knob`](Knob) is currently dragged.
+    pub const fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+}
+
+/// The renderer of a [`Knob`](Knob).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Knob`](Knob) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default size of a [`Knob`](Knob).
+    const DEFAULT_SIZE: u16;
+
+    /// Draws a [`Knob`](Knob).
+    ///
+    /// The focus of the environment is true while the [`Knob`](Knob) is
+    /// dragged. The value and the optional center detent are given as
+    /// positions in the range between 0.0 and 1.0.
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, bool>,
+        value: f32,
+        detent: Option<f32>,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 0;
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, bool>,
+        _value: f32,
+        _detent: Option<f32>,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, T, Message, Renderer> From<Knob<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + PartialOrd + Into<f64> + FromPrimitive,
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(knob: Knob<'a, T, Message, Renderer>) -> Self {
+        Element::new(knob)
+    }
+}
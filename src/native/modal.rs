@@ -1,18 +1,31 @@
 //! A modal for showing elements as an overlay on top of another.
 //!
 //! *This API requires the following crate features to be activated: modal*
-use std::hash::Hash;
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
 
 use iced_native::{event, overlay, Clipboard, Element, Event, Layout, Point, Widget};
 
 pub use super::overlay::modal::Renderer;
-use super::overlay::modal::{self, ModalOverlay};
+use super::{
+    overlay::modal::{self, ModalOverlay},
+    stateful_overlay::{OverlayState, StatefulOverlayWidget},
+};
+use crate::core::accessibility::Accessibility;
+
+pub mod modal_stack;
+pub use modal_stack::{ModalLayer, ModalStack};
 
 /// A modal content as an overlay.
 ///
 /// Can be used in combination with the [`Card`](crate::native::card::Card)
 /// widget to form dialog elements.
 ///
+/// A [`Modal`](Modal) inside the content of another one is never shown. Use
+/// a [`ModalStack`](ModalStack) to show modals on top of each other.
+///
 /// # Example
 /// ```
 /// # use iced_aw::native::modal;
@@ -52,6 +65,14 @@ where
     backdrop: Option<Message>,
     /// The optional message that will be send when the ESC key was pressed.
     esc: Option<Message>,
+    /// Whether the keyboard events are kept from the underlay while the
+    /// [`Modal`](Modal) is open.
+    trap_focus: bool,
+    /// The optional duration of the open and close animation of the
+    /// [`ModalOverlay`](ModalOverlay).
+    animation: Option<Duration>,
+    /// The accessibility information of the [`ModalOverlay`](ModalOverlay).
+    accessibility: Accessibility,
     /// The style of the [`ModalOverlay`](ModalOverlay).
     style: Renderer::Style,
 }
@@ -84,12 +105,19 @@ where
             content,
             backdrop: None,
             esc: None,
+            trap_focus: true,
+            animation: None,
+            accessibility: Accessibility::default(),
             style: Renderer::Style::default(),
         }
     }
 
     /// Sets the message that will be produced when the backdrop of the
     /// [`Modal`](Modal) is clicked.
+    ///
+    /// The [`Modal`](Modal) stays open until it is hidden through its
+    /// [`State`](State), so the message can be treated as a close request,
+    /// e.g. to ask for confirmation before closing.
     pub fn backdrop(mut self, message: Message) -> Self {
         self.backdrop = Some(message);
         self
@@ -98,12 +126,51 @@ where
     /// Sets the message that will be produced when the Escape Key is
     /// pressed when the modal is open.
     ///
-    /// This can be used to close the modal on ESC.
+    /// This can be used to close the modal on ESC. Like the
+    /// [`backdrop`](Modal::backdrop) message it is only a close request.
     pub fn on_esc(mut self, message: Message) -> Self {
         self.esc = Some(message);
         self
     }
 
+    /// Sets whether the keyboard focus is trapped inside of the open
+    /// [`Modal`](Modal).
+    ///
+    /// While trapped, keyboard events only go to the content of the
+    /// [`Modal`](Modal) and not to the underlay, so e.g. a focused text input
+    /// behind the [`Modal`](Modal) does not receive any key presses. This is
+    /// enabled by default.
+    pub fn trap_focus(mut self, trap_focus: bool) -> Self {
+        self.trap_focus = trap_focus;
+        self
+    }
+
+    /// Animates the opening and closing of the [`Modal`](Modal) over the
+    /// given duration.
+    ///
+    /// The backdrop fades in and the content slides into place when the
+    /// [`Modal`](Modal) is shown, and the other way around when it is hidden.
+    /// The animation only advances while the application redraws, e.g. by
+    /// subscribing to a timer. Without an animation the [`Modal`](Modal) is
+    /// shown and hidden instantly.
+    pub fn animation(mut self, duration: Duration) -> Self {
+        self.animation = Some(duration);
+        self
+    }
+
+    /// Sets the label announced for the open [`Modal`](Modal), e.g. the
+    /// title of the dialog.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.accessibility.label = Some(label.into());
+        self
+    }
+
+    /// Sets the description announced for the open [`Modal`](Modal).
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.accessibility.description = Some(description.into());
+        self
+    }
+
     /// Sets the style of the [`Modal`](Modal).
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
         self.style = style.into();
@@ -116,6 +183,9 @@ where
 pub struct State<S> {
     /// The visibility of the [`Modal`](Modal) overlay.
     show: bool,
+    /// The point in time the [`Modal`](Modal) overlay was last shown or
+    /// hidden.
+    changed_at: Option<Instant>,
     /// The state of the content of the [`Modal`](Modal) overlay.
     state: S,
 }
@@ -125,6 +195,7 @@ impl<S> State<S> {
     pub const fn new(s: S) -> Self {
         Self {
             show: false,
+            changed_at: None,
             state: s,
         }
     }
@@ -132,6 +203,9 @@ impl<S> State<S> {
     /// Setting this to true shows the modal (the modal is open), false means
     /// the modal is hidden (closed).
     pub fn show(&mut self, b: bool) {
+        if b != self.show {
+            self.changed_at = Some(Instant::now());
+        }
         self.show = b;
     }
 
@@ -151,6 +225,20 @@ impl<S> State<S> {
     }
 }
 
+impl<S> OverlayState for State<S> {
+    fn is_shown(&self) -> bool {
+        self.show
+    }
+
+    fn show(&mut self, b: bool) {
+        Self::show(self, b);
+    }
+
+    fn changed_at(&self) -> Option<Instant> {
+        self.changed_at
+    }
+}
+
 impl<'a, S, Content, Message, Renderer> Widget<Message, Renderer>
     for Modal<'a, S, Content, Message, Renderer>
 where
@@ -184,6 +272,10 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        if self.trap_focus && self.state.show && matches!(event, Event::Keyboard(_)) {
+            return event::Status::Ignored;
+        }
+
         self.underlay.on_event(
             event,
             layout,
@@ -216,23 +308,47 @@ where
     }
 
     fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
-        if !self.state.show {
-            return self.underlay.overlay(layout);
-        }
+        self.stateful_overlay(layout)
+    }
+}
+
+impl<'a, S, Content, Message, Renderer> StatefulOverlayWidget<'a, Message, Renderer>
+    for Modal<'a, S, Content, Message, Renderer>
+where
+    S: 'a,
+    Content: 'a + Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: 'a + Clone,
+    Renderer: 'a + modal::Renderer + iced_native::container::Renderer,
+{
+    type State = State<S>;
+
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    fn underlay_mut(&mut self) -> &mut Element<'a, Message, Renderer> {
+        &mut self.underlay
+    }
+
+    fn animation(&self) -> Option<Duration> {
+        self.animation
+    }
 
+    fn build_overlay(&mut self, layout: Layout<'_>) -> overlay::Element<'_, Message, Renderer> {
         let bounds = layout.bounds();
         let position = Point::new(bounds.x, bounds.y);
+        let visibility = self.visibility();
 
-        Some(
-            ModalOverlay::new(
-                &mut self.state.state,
-                &self.content,
-                self.backdrop.clone(),
-                self.esc.clone(),
-                &self.style,
-            )
-            .overlay(position),
+        ModalOverlay::new(
+            &mut self.state.state,
+            &self.content,
+            self.backdrop.clone(),
+            self.esc.clone(),
+            &self.style,
         )
+        .visibility(visibility)
+        .accessibility(&self.accessibility)
+        .overlay(position)
     }
 }
 
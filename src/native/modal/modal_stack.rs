@@ -0,0 +1,232 @@
+//! A modal for showing elements as an overlay on top of another.
+//!
+//! *This API requires the following crate features to be activated: modal*
+use std::hash::Hash;
+
+use iced_native::{
+    event, overlay, Clipboard, Container, Element, Event, Layout, Length, Point, Widget,
+};
+
+use crate::native::overlay::modal::{self, ModalStackOverlay};
+
+/// A stack of modal contents shown as overlays on top of another element.
+///
+/// Every layer gets its own backdrop and is drawn on top of the layers pushed
+/// before it, e.g. a confirmation dialog on top of an open form dialog. Only
+/// the topmost layer receives events.
+///
+/// The stack is rebuilt on every view, so the application decides which
+/// layers are open by pushing them.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::modal::ModalLayer;
+/// # use iced_native::{Text, renderer::Null};
+/// #
+/// # pub type ModalStack<'a, Message> = iced_aw::native::ModalStack<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CloseForm,
+///     CloseConfirmation,
+/// }
+///
+/// let modal_stack = ModalStack::new(Text::new("Underlay"))
+///     .push(ModalLayer::new(Text::new("Form")).backdrop(Message::CloseForm))
+///     .push(ModalLayer::new(Text::new("Confirmation")).on_esc(Message::CloseConfirmation));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ModalStack<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: modal::Renderer,
+{
+    /// The underlying element.
+    underlay: Element<'a, Message, Renderer>,
+    /// The layers of the [`ModalStack`](ModalStack) from bottom to top.
+    layers: Vec<ModalLayer<'a, Message, Renderer>>,
+    /// Whether the keyboard events are kept from the underlay while a layer
+    /// is open.
+    trap_focus: bool,
+    /// The style of the layers of the [`ModalStack`](ModalStack).
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer> ModalStack<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: modal::Renderer,
+{
+    /// Creates a new [`ModalStack`](ModalStack) without any layers wrapping
+    /// the underlying element.
+    pub fn new<U>(underlay: U) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+    {
+        ModalStack {
+            underlay: underlay.into(),
+            layers: Vec::new(),
+            trap_focus: true,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Pushes a [`ModalLayer`](ModalLayer) on top of the
+    /// [`ModalStack`](ModalStack).
+    pub fn push(mut self, layer: ModalLayer<'a, Message, Renderer>) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Sets whether the keyboard focus is trapped inside of the topmost layer
+    /// of the [`ModalStack`](ModalStack).
+    ///
+    /// See [`Modal::trap_focus`](crate::native::Modal::trap_focus). This is
+    /// enabled by default.
+    pub fn trap_focus(mut self, trap_focus: bool) -> Self {
+        self.trap_focus = trap_focus;
+        self
+    }
+
+    /// Sets the style of the layers of the [`ModalStack`](ModalStack).
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// A layer of a [`ModalStack`](ModalStack) showing its content centered on
+/// top of its own backdrop.
+#[allow(missing_debug_implementations)]
+pub struct ModalLayer<'a, Message, Renderer> {
+    /// The content of the [`ModalLayer`](ModalLayer).
+    pub(crate) content: Element<'a, Message, Renderer>,
+    /// The optional message that will be send when the user clicked on the backdrop.
+    pub(crate) backdrop: Option<Message>,
+    /// The optional message that will be send when the ESC key was pressed.
+    pub(crate) esc: Option<Message>,
+}
+
+impl<'a, Message, Renderer> ModalLayer<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::container::Renderer,
+{
+    /// Creates a new [`ModalLayer`](ModalLayer) showing the given content.
+    pub fn new<C>(content: C) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        ModalLayer {
+            content: Container::new(content)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .center_x()
+                .center_y()
+                .into(),
+            backdrop: None,
+            esc: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the backdrop of the
+    /// [`ModalLayer`](ModalLayer) is clicked while it is the topmost layer.
+    pub fn backdrop(mut self, message: Message) -> Self {
+        self.backdrop = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the Escape Key is pressed
+    /// while the [`ModalLayer`](ModalLayer) is the topmost layer.
+    pub fn on_esc(mut self, message: Message) -> Self {
+        self.esc = Some(message);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for ModalStack<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + modal::Renderer,
+{
+    fn width(&self) -> iced_native::Length {
+        self.underlay.width()
+    }
+
+    fn height(&self) -> iced_native::Length {
+        self.underlay.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.underlay.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if self.trap_focus && !self.layers.is_empty() && matches!(event, Event::Keyboard(_)) {
+            return event::Status::Ignored;
+        }
+
+        self.underlay.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: iced_native::Layout<'_>,
+        cursor_position: iced_graphics::Point,
+        viewport: &iced_graphics::Rectangle,
+    ) -> Renderer::Output {
+        self.underlay
+            .draw(renderer, defaults, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.layers.len().hash(state);
+        self.underlay.hash_layout(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if self.layers.is_empty() {
+            return self.underlay.overlay(layout);
+        }
+
+        let bounds = layout.bounds();
+        let position = Point::new(bounds.x, bounds.y);
+
+        Some(ModalStackOverlay::new(&mut self.layers, &self.style).overlay(position))
+    }
+}
+
+impl<'a, Message, Renderer> From<ModalStack<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + modal::Renderer,
+{
+    fn from(modal_stack: ModalStack<'a, Message, Renderer>) -> Self {
+        Element::new(modal_stack)
+    }
+}
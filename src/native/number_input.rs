@@ -0,0 +1,518 @@
+//! A text field for entering numbers, with buttons to increase and decrease
+//! the value.
+//!
+//! *This API requires the following crate features to be activated: `number_input`*
+use std::{fmt::Display, hash::Hash, str::FromStr};
+
+use iced_native::{
+    button, event, keyboard, layout, text, text_input, Button, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Rectangle, Size, TextInput, Widget,
+};
+use num_traits::{Bounded, Num};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::{
+    core::renderer::DrawEnvironment,
+    graphics::icons::Icon,
+    native::{icon_text, IconText},
+};
+
+/// The padding around the text field and the buttons.
+const PADDING: u16 = 5;
+/// The spacing between the text field and the buttons.
+const SPACING: u16 = 5;
+
+/// The messages of the inner elements of the [`NumberInput`](NumberInput).
+#[derive(Clone, Debug)]
+enum InputMessage {
+    /// The text of the text field was edited.
+    Edit(String),
+    /// The button decreasing the value was pressed.
+    Decrease,
+    /// The button increasing the value was pressed.
+    Increase,
+}
+
+/// A text field for entering numbers, with buttons to increase and decrease
+/// the value by a step.
+///
+/// Only text that parses into a number within the bounds of the
+/// [`NumberInput`](NumberInput) produces a message. While the text field is
+/// focused, the arrow keys up and down increase and decrease the value.
+/// Stepping is clamped to the bounds without overflowing, so the value can
+/// be of any primitive integer or floating point type.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::number_input;
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type NumberInput<'a, T, Message> = iced_aw::native::NumberInput<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     VolumeChanged(u8),
+/// }
+///
+/// let mut state = number_input::State::new();
+///
+/// let number_input = NumberInput::new(
+///     &mut state,
+///     42,
+///     Message::VolumeChanged,
+/// )
+/// .max(100)
+/// .step(5);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message, Renderer> {
+    /// The current value of the [`NumberInput`](NumberInput).
+    value: T,
+    /// The value added or subtracted by the buttons.
+    step: T,
+    /// The minimum value of the [`NumberInput`](NumberInput).
+    min: T,
+    /// The maximum value of the [`NumberInput`](NumberInput).
+    max: T,
+    /// The text of the text field.
+    text: &'a mut String,
+    /// Whether the text field is focused.
+    focused: bool,
+    /// The width of the [`NumberInput`](NumberInput).
+    width: Length,
+    /// The text field of the [`NumberInput`](NumberInput).
+    text_input: Element<'a, InputMessage, Renderer>,
+    /// The button decreasing the value of the [`NumberInput`](NumberInput).
+    decrease_button: Element<'a, InputMessage, Renderer>,
+    /// The button increasing the value of the [`NumberInput`](NumberInput).
+    increase_button: Element<'a, InputMessage, Renderer>,
+    /// The function that produces a message when the value changes.
+    on_change: Box<dyn Fn(T) -> Message>,
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Num + Bounded + PartialOrd + Display + FromStr + Copy,
+    Renderer: 'a + button::Renderer + icon_text::Renderer + text_input::Renderer,
+{
+    /// Creates a new [`NumberInput`](NumberInput).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`NumberInput`](NumberInput)'s
+    ///         [`State`](State).
+    ///     * the current value.
+    ///     * a function that will be called when a new valid value is typed
+    ///         into the text field or set by the buttons.
+    pub fn new<F>(state: &'a mut State, value: T, on_change: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        let State {
+            text_input,
+            decrease_button,
+            increase_button,
+            text,
+        } = state;
+
+        // Keep incomplete input like "-" while typing, but show the value if it
+        // was changed from the outside.
+        let focused = text_input.is_focused();
+        let parsed = text.parse::<T>().ok();
+        if parsed.map_or(true, |parsed| parsed != value) && (!focused || parsed.is_some()) {
+            *text = value.to_string();
+        }
+
+        Self {
+            value,
+            step: T::one(),
+            min: T::min_value(),
+            max: T::max_value(),
+            focused,
+            width: Length::Fill,
+            text_input: TextInput::new(text_input, "", text, InputMessage::Edit)
+                .width(Length::Fill)
+                .padding(PADDING)
+                .into(),
+            decrease_button: Button::new(decrease_button, IconText::new(Icon::CaretDownFill))
+                .padding(PADDING)
+                .on_press(InputMessage::Decrease)
+                .into(),
+            increase_button: Button::new(increase_button, IconText::new(Icon::CaretUpFill))
+                .padding(PADDING)
+                .on_press(InputMessage::Increase)
+                .into(),
+            text,
+            on_change: Box::new(on_change),
+        }
+    }
+
+    /// Sets the step by which the buttons change the value of the
+    /// [`NumberInput`](NumberInput).
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the minimum value of the [`NumberInput`](NumberInput).
+    pub fn min(mut self, min: T) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`](NumberInput).
+    pub fn max(mut self, max: T) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`](NumberInput).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Returns the value decreased by one step without falling below the
+    /// minimum.
+    fn decreased(&self) -> T {
+        decrease(self.value, self.step, self.min)
+    }
+
+    /// Returns the value increased by one step without exceeding the maximum.
+    fn increased(&self) -> T {
+        increase(self.value, self.step, self.max)
+    }
+
+    /// Changes the value to the given value and produces a message if it
+    /// differs from the current one.
+    fn change(&mut self, value: T, messages: &mut Vec<Message>) {
+        if value != self.value {
+            *self.text = value.to_string();
+            #[cfg(feature = "instrumentation")]
+            instrumentation::record(WidgetKind::NumberInput, Action::Change);
+            messages.push((self.on_change)(value));
+        }
+    }
+}
+
+/// Decreases the value by the step, clamped to the minimum.
+///
+/// The comparison is done before subtracting, so values near the bounds of
+/// `T` are clamped instead of overflowing.
+fn decrease<T>(value: T, step: T, min: T) -> T
+where
+    T: Num + Bounded + PartialOrd + Copy,
+{
+    // `min + step` would overflow, so every value is below it.
+    if min > T::max_value() - step || value < min + step {
+        min
+    } else {
+        value - step
+    }
+}
+
+/// Increases the value by the step, clamped to the maximum.
+///
+/// The comparison is done before adding, so values near the bounds of `T`
+/// are clamped instead of overflowing.
+fn increase<T>(value: T, step: T, max: T) -> T
+where
+    T: Num + Bounded + PartialOrd + Copy,
+{
+    // `max - step` would overflow, so every value is above it.
+    if max < T::min_value() + step || value > max - step {
+        max
+    } else {
+        value + step
+    }
+}
+
+/// The state of the [`NumberInput`](NumberInput).
+#[derive(Debug, Default)]
+pub struct State {
+    /// The state of the text field.
+    pub(crate) text_input: text_input::State,
+    /// The state of the button decreasing the value.
+    pub(crate) decrease_button: button::State,
+    /// The state of the button increasing the value.
+    pub(crate) increase_button: button::State,
+    /// The text of the text field.
+    pub(crate) text: String,
+}
+
+impl State {
+    /// Creates a new [`State`](State).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for NumberInput<'a, T, Message, Renderer>
+where
+    T: Num + Bounded + PartialOrd + Display + FromStr + Copy,
+    Renderer: 'a
+        + self::Renderer
+        + button::Renderer
+        + icon_text::Renderer
+        + text::Renderer
+        + text_input::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let mut decrease_button = self.decrease_button.layout(renderer, &limits);
+        let mut increase_button = self.increase_button.layout(renderer, &limits);
+        let buttons_width =
+            decrease_button.bounds().width + f32::from(SPACING) + increase_button.bounds().width;
+
+        let text_input_limits = limits.shrink(Size::new(buttons_width + f32::from(SPACING), 0.0));
+        let mut text_input = self.text_input.layout(renderer, &text_input_limits);
+
+        let height = text_input
+            .bounds()
+            .height
+            .max(decrease_button.bounds().height)
+            .max(increase_button.bounds().height);
+
+        text_input.move_to(Point::new(0.0, (height - text_input.bounds().height) / 2.0));
+        decrease_button.move_to(Point::new(
+            text_input.bounds().width + f32::from(SPACING),
+            (height - decrease_button.bounds().height) / 2.0,
+        ));
+        increase_button.move_to(Point::new(
+            decrease_button.bounds().x + decrease_button.bounds().width + f32::from(SPACING),
+            (height - increase_button.bounds().height) / 2.0,
+        ));
+
+        let size = limits.resolve(Size::new(
+            text_input.bounds().width + f32::from(SPACING) + buttons_width,
+            height,
+        ));
+
+        layout::Node::with_children(size, vec![text_input, decrease_button, increase_button])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let text_input_layout = children
+            .next()
+            .expect("Native: Layout should have a text input layout for a NumberInput");
+        let decrease_button_layout = children
+            .next()
+            .expect("Native: Layout should have a decrease button layout for a NumberInput");
+        let increase_button_layout = children
+            .next()
+            .expect("Native: Layout should have an increase button layout for a NumberInput");
+
+        let mut input_messages = Vec::new();
+
+        if self.focused {
+            if let Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) = &event {
+                match key_code {
+                    keyboard::KeyCode::Down => input_messages.push(InputMessage::Decrease),
+                    keyboard::KeyCode::Up => input_messages.push(InputMessage::Increase),
+                    _ => {}
+                }
+            }
+        }
+        let key_status = if input_messages.is_empty() {
+            event::Status::Ignored
+        } else {
+            event::Status::Captured
+        };
+
+        let text_input_status = self.text_input.on_event(
+            event.clone(),
+            text_input_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut input_messages,
+        );
+
+        let decrease_button_status = self.decrease_button.on_event(
+            event.clone(),
+            decrease_button_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut input_messages,
+        );
+
+        let increase_button_status = self.increase_button.on_event(
+            event,
+            increase_button_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut input_messages,
+        );
+
+        for message in input_messages {
+            match message {
+                InputMessage::Edit(text) => {
+                    if let Ok(value) = text.parse::<T>() {
+                        if value >= self.min && value <= self.max && value != self.value {
+                            #[cfg(feature = "instrumentation")]
+                            instrumentation::record(WidgetKind::NumberInput, Action::Change);
+                            messages.push((self.on_change)(value));
+                        }
+                    }
+                    *self.text = text;
+                }
+                InputMessage::Decrease => {
+                    let value = self.decreased();
+                    self.change(value, messages);
+                }
+                InputMessage::Increase => {
+                    let value = self.increased();
+                    self.change(value, messages);
+                }
+            }
+        }
+
+        key_status
+            .merge(text_input_status)
+            .merge(decrease_button_status)
+            .merge(increase_button_status)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &(),
+                viewport: Some(viewport),
+                focus: (),
+            },
+            &self.text_input,
+            &self.decrease_button,
+            &self.increase_button,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.text_input.hash_layout(state);
+        self.decrease_button.hash_layout(state);
+        self.increase_button.hash_layout(state);
+    }
+}
+
+/// The renderer of a [`NumberInput`](NumberInput).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`NumberInput`](NumberInput) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// Draws a [`NumberInput`](NumberInput).
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, (), ()>,
+        text_input: &Element<'_, Message, Self>,
+        decrease_button: &Element<'_, Message, Self>,
+        increase_button: &Element<'_, Message, Self>,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    fn draw<Message>(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, (), ()>,
+        _text_input: &Element<'_, Message, Self>,
+        _decrease_button: &Element<'_, Message, Self>,
+        _increase_button: &Element<'_, Message, Self>,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Num + Bounded + PartialOrd + Display + FromStr + Copy,
+    Message: 'a,
+    Renderer: 'a
+        + self::Renderer
+        + button::Renderer
+        + icon_text::Renderer
+        + text::Renderer
+        + text_input::Renderer,
+{
+    fn from(number_input: NumberInput<'a, T, Message, Renderer>) -> Self {
+        Element::new(number_input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decrease, increase};
+
+    #[test]
+    fn decrease_with_default_bounds() {
+        assert_eq!(decrease(0, 1, i32::MIN), -1);
+        assert_eq!(decrease(-5, 1, i32::MIN), -6);
+        assert_eq!(decrease(i32::MAX, 1, i32::MIN), i32::MAX - 1);
+        assert_eq!(decrease(i32::MIN + 1, 5, i32::MIN), i32::MIN);
+        assert_eq!(decrease(i32::MIN, 1, i32::MIN), i32::MIN);
+    }
+
+    #[test]
+    fn increase_with_default_bounds() {
+        assert_eq!(increase(0, 1, i32::MAX), 1);
+        assert_eq!(increase(-5, 1, i32::MAX), -4);
+        assert_eq!(increase(i32::MIN, 1, i32::MAX), i32::MIN + 1);
+        assert_eq!(increase(i32::MAX - 1, 5, i32::MAX), i32::MAX);
+        assert_eq!(increase(i32::MAX, 1, i32::MAX), i32::MAX);
+    }
+
+    #[test]
+    fn step_clamped_to_custom_bounds() {
+        assert_eq!(decrease(3_u8, 5, 0), 0);
+        assert_eq!(increase(98_u8, 5, 100), 100);
+        assert_eq!(decrease(10_u8, 5, 250), 250);
+        assert_eq!(increase(250_u8, 5, 3), 3);
+    }
+
+    #[test]
+    fn step_floats() {
+        assert!((decrease(0.5_f32, 0.25, f32::MIN) - 0.25).abs() < f32::EPSILON);
+        assert!((increase(0.5_f32, 0.25, f32::MAX) - 0.75).abs() < f32::EPSILON);
+        assert!((decrease(0.1_f32, 0.25, 0.0)).abs() < f32::EPSILON);
+        assert!((increase(0.9_f32, 0.25, 1.0) - 1.0).abs() < f32::EPSILON);
+
+        assert!((decrease(-1.5_f64, 0.5, f64::MIN) + 2.0).abs() < f64::EPSILON);
+        assert!((increase(-1.5_f64, 0.5, f64::MAX) + 1.0).abs() < f64::EPSILON);
+        assert!((decrease(f64::MIN, 1.0, f64::MIN) - f64::MIN).abs() < f64::EPSILON);
+        assert!((increase(f64::MAX, 1.0, f64::MAX) - f64::MAX).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,123 @@
+//! Animate the opening and closing of the overlays of the pickers.
+//!
+//! *This API requires one of the following crate features to be activated:
+//! `color_picker`, `date_picker` or `time_picker`*
+use std::hash::Hash;
+
+use iced_native::{event, layout, overlay, Clipboard, Event, Layout, Point, Size};
+
+use crate::native::stateful_overlay::Visibility;
+
+/// An overlay wrapping the overlay of a
+/// [`StatefulOverlayWidget`](crate::native::StatefulOverlayWidget) to draw it
+/// with its current [`Visibility`](Visibility).
+///
+/// The animation only advances while the application redraws, e.g. by
+/// subscribing to a timer while an overlay is opening or closing.
+#[allow(missing_debug_implementations)]
+pub struct AnimatedOverlay<'a, Message, Renderer: self::Renderer> {
+    /// The wrapped overlay.
+    overlay: overlay::Element<'a, Message, Renderer>,
+    /// The current visibility of the wrapped overlay.
+    visibility: Visibility,
+}
+
+impl<'a, Message, Renderer> AnimatedOverlay<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    /// Creates a new [`AnimatedOverlay`](AnimatedOverlay) wrapping the given
+    /// overlay.
+    pub fn new(overlay: overlay::Element<'a, Message, Renderer>, visibility: Visibility) -> Self {
+        AnimatedOverlay {
+            overlay,
+            visibility,
+        }
+    }
+
+    /// Turn this [`AnimatedOverlay`](AnimatedOverlay) into an overlay
+    /// [`Element`](overlay::Element).
+    ///
+    /// The wrapped overlay keeps its own position.
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(Point::ORIGIN, Box::new(self))
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for AnimatedOverlay<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, _position: Point) -> layout::Node {
+        self.overlay.layout(renderer, bounds)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if self.visibility.closing {
+            return event::Status::Ignored;
+        }
+
+        self.overlay.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        // A closing overlay is not hovered anymore.
+        let cursor_position = if self.visibility.closing {
+            Point::new(-1.0, -1.0)
+        } else {
+            cursor_position
+        };
+
+        let output = self
+            .overlay
+            .draw(renderer, defaults, layout, cursor_position);
+
+        renderer.animate(output, self.visibility.progress)
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, _position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.overlay.hash_layout(state);
+    }
+}
+
+/// The renderer of an [`AnimatedOverlay`](AnimatedOverlay).
+///
+/// Your renderer will need to implement this trait before being able to use
+/// the pickers of this crate in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// Applies the given visibility progress from hidden (`0.0`) to shown
+    /// (`1.0`) to the drawn output of an overlay.
+    fn animate(&mut self, output: Self::Output, progress: f32) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    fn animate(&mut self, _output: Self::Output, _progress: f32) -> Self::Output {}
+}
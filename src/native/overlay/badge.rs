@@ -0,0 +1,201 @@
+//! Use a badge for color highlighting important information.
+//!
+//! *This API requires the following crate features to be activated: badge*
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout, overlay, Clipboard, Event, Layout, Point, Rectangle, Size, Widget,
+};
+
+use crate::{
+    core::{overlay::Position, renderer::DrawEnvironment},
+    native::{badge, Badge},
+};
+
+/// The internal overlay of a [`Badge`](crate::native::Badge) showing its
+/// tooltip while the [`Badge`](crate::native::Badge) is hovered.
+#[allow(missing_debug_implementations)]
+pub struct BadgeTooltipOverlay<'a, Renderer: badge::Renderer> {
+    /// The text of the tooltip.
+    tooltip: &'a str,
+    /// The bounds of the [`Badge`](crate::native::Badge).
+    badge_bounds: Rectangle,
+    /// The style of the [`Badge`](crate::native::Badge).
+    style: &'a Renderer::Style,
+}
+
+impl<'a, Renderer> BadgeTooltipOverlay<'a, Renderer>
+where
+    Renderer: 'a + badge::Renderer,
+{
+    /// Creates a new [`BadgeTooltipOverlay`](BadgeTooltipOverlay) for a
+    /// [`Badge`](crate::native::Badge) with the given bounds.
+    pub fn new(tooltip: &'a str, badge_bounds: Rectangle, style: &'a Renderer::Style) -> Self {
+        BadgeTooltipOverlay {
+            tooltip,
+            badge_bounds,
+            style,
+        }
+    }
+
+    /// Turns the [`BadgeTooltipOverlay`](BadgeTooltipOverlay) into an overlay
+    /// [`Element`](iced_native::overlay::Element) placed below the
+    /// [`Badge`](crate::native::Badge).
+    pub fn overlay<Message>(self) -> overlay::Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+    {
+        let position = Point::new(
+            self.badge_bounds.x,
+            self.badge_bounds.y + self.badge_bounds.height,
+        );
+
+        overlay::Element::new(position, Box::new(self))
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for BadgeTooltipOverlay<'a, Renderer>
+where
+    Renderer: 'a + badge::Renderer,
+{
+    fn layout(&self, _renderer: &Renderer, _bounds: Size, position: Point) -> layout::Node {
+        // The layout is empty, so the tooltip never captures the cursor from
+        // the widgets below. The renderer draws the tooltip at its position.
+        let mut node = layout::Node::new(Size::ZERO);
+        node.move_to(position);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _messages: &mut Vec<Message>,
+    ) -> event::Status {
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let tooltip = if self.badge_bounds.contains(cursor_position) {
+            Some(self.tooltip)
+        } else {
+            None
+        };
+
+        renderer.draw_tooltip(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: self.style,
+                viewport: None,
+                focus: (),
+            },
+            tooltip,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+    }
+}
+
+/// The internal overlay of a [`BadgeAnchor`](crate::native::badge::BadgeAnchor)
+/// drawing its [`Badge`](crate::native::Badge) centered on a corner of the
+/// child.
+#[allow(missing_debug_implementations)]
+pub struct BadgeAnchorOverlay<'a, 'b, Message, Renderer: badge::Renderer> {
+    /// The [`Badge`](crate::native::Badge) drawn by the overlay.
+    badge: &'a Badge<'b, Message, Renderer>,
+}
+
+impl<'a, 'b, Message, Renderer> BadgeAnchorOverlay<'a, 'b, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + badge::Renderer,
+    'b: 'a,
+{
+    /// Creates a new [`BadgeAnchorOverlay`](BadgeAnchorOverlay) for the
+    /// given [`Badge`](crate::native::Badge).
+    pub fn new(badge: &'a Badge<'b, Message, Renderer>) -> Self {
+        BadgeAnchorOverlay { badge }
+    }
+
+    /// Turns the [`BadgeAnchorOverlay`](BadgeAnchorOverlay) into an overlay
+    /// [`Element`](iced_native::overlay::Element) centered on the given
+    /// corner position.
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+}
+
+impl<'a, 'b, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for BadgeAnchorOverlay<'a, 'b, Message, Renderer>
+where
+    Renderer: 'a + badge::Renderer,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+        let mut node = Widget::layout(self.badge, renderer, &limits);
+
+        node.center_and_bounce(position, bounds);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _messages: &mut Vec<Message>,
+    ) -> event::Status {
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        Widget::draw(
+            self.badge,
+            renderer,
+            defaults,
+            layout,
+            cursor_position,
+            &layout.bounds(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+
+        Widget::hash_layout(self.badge, state);
+    }
+}
@@ -11,8 +11,15 @@ use iced_native::{
     Event, Layout, Length, Point, Rectangle, Row, Size, Text, Widget,
 };
 
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
 use crate::{
-    core::{color::Hsv, overlay::Position, renderer::DrawEnvironment},
+    core::{
+        accessibility::{self, Accessibility, Node, Role},
+        color::{parse_hex_string, Hsv},
+        overlay::Position,
+        renderer::DrawEnvironment,
+    },
     graphics::icons::Icon,
     native::{color_picker, icon_text, IconText},
 };
@@ -23,6 +30,12 @@ const PADDING: u16 = 10;
 const SPACING: u16 = 15;
 /// The spacing between the buttons.
 const BUTTON_SPACING: u16 = 5;
+/// The size of a color swatch.
+const SWATCH_SIZE: u16 = 20;
+/// The spacing between the color swatches.
+const SWATCH_SPACING: u16 = 5;
+/// The maximum number of remembered recently submitted colors.
+const MAX_RECENT_COLORS: usize = 8;
 
 /// The step value of the keyboard change of the sat/value color values.
 const SAT_VALUE_STEP: f32 = 0.005;
@@ -30,6 +43,8 @@ const SAT_VALUE_STEP: f32 = 0.005;
 const HUE_STEP: i32 = 1;
 /// The step value of the keyboard change of the RGBA color values.
 const RGBA_STEP: i16 = 1;
+/// The factor of the keyboard change steps while shift is pressed.
+const SHIFT_STEP_FACTOR: u8 = 10;
 
 /// The overlay of the [`ColorPicker`](crate::native::ColorPicker).
 #[allow(missing_debug_implementations)]
@@ -44,10 +59,17 @@ where
     cancel_button: Element<'a, Message, Renderer>,
     /// The submit button of the [`ColorPickerOverlay`](ColorPickerOverlay).
     submit_button: Element<'a, Message, Renderer>,
+    /// The message that is send if the [`ColorPickerOverlay`](ColorPickerOverlay)
+    /// is cancelled.
+    on_cancel: Message,
     /// The function that produces a message when the submit button of the [`ColorPickerOverlay`](ColorPickerOverlay).
     on_submit: &'a dyn Fn(Color) -> Message,
+    /// The preset colors shown as swatches.
+    palette: &'a [Color],
     /// The position of the [`ColorPickerOverlay`](ColorPickerOverlay).
     position: Point,
+    /// The accessibility information of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    accessibility: Node<'a>,
     /// The style of the [`ColorPickerOverlay`](ColorPickerOverlay).
     style: &'a <Renderer as self::Renderer>::Style,
 }
@@ -92,420 +114,615 @@ where
                 IconText::new(Icon::Check).width(Length::Fill),
             )
             .width(Length::Fill)
-            .on_press(on_cancel) // Sending a fake message
+            .on_press(on_cancel.clone()) // Sending a fake message
             .into(),
+            on_cancel,
             on_submit,
+            palette: &[],
             position,
+            accessibility: Node::new(Role::Dialog),
             style,
         }
     }
 
+    /// Sets the preset colors shown as swatches next to the recently
+    /// submitted colors of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    pub fn palette(mut self, palette: &'a [Color]) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Sets the [`Accessibility`](Accessibility) information announced for
+    /// the [`ColorPickerOverlay`](ColorPickerOverlay) as a dialog.
+    #[must_use]
+    pub fn accessibility(mut self, accessibility: &'a Accessibility) -> Self {
+        self.accessibility = accessibility.node(Role::Dialog);
+        self
+    }
+
     /// Turn this [`ColorPickerOverlay`](ColorPickerOverlay) into an overlay
     /// [`Element`](overlay::Element).
     #[must_use]
     pub fn overlay(self) -> overlay::Element<'a, Message, Renderer> {
         overlay::Element::new(self.position, Box::new(self))
     }
+}
+
+/// The event handling for the HSV color area.
+fn on_event_hsv_color(
+    state: &mut State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> event::Status {
+    let mut hsv_color_children = layout.children();
+
+    let hsv_color: Hsv = state.color.to_owned().into();
+    let mut color_changed = false;
+
+    let sat_value_bounds = hsv_color_children
+        .next()
+        .expect("Native: Layout should have a sat/value layout")
+        .bounds();
+    let hue_bounds = hsv_color_children
+        .next()
+        .expect("Native: Layout should have a hue layout")
+        .bounds();
+
+    match event {
+        Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
+            mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
+                let move_value =
+                    |value: u16, y: f32| ((i32::from(value) + y as i32).rem_euclid(360)) as u16;
 
-    /// The event handling for the HSV color area.
-    fn on_event_hsv_color(
-        &mut self,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        _messages: &mut Vec<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
-    ) -> event::Status {
-        let mut hsv_color_children = layout.children();
-
-        let hsv_color: Hsv = self.state.color.to_owned().into();
-        let mut color_changed = false;
-
-        let sat_value_bounds = hsv_color_children
-            .next()
-            .expect("Native: Layout should have a sat/value layout")
-            .bounds();
-        let hue_bounds = hsv_color_children
-            .next()
-            .expect("Native: Layout should have a hue layout")
-            .bounds();
-
-        match event {
-            Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
-                mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
-                    let move_value =
-                        |value: u16, y: f32| ((i32::from(value) + y as i32).rem_euclid(360)) as u16;
-
-                    if hue_bounds.contains(cursor_position) {
-                        self.state.color = Color {
-                            a: self.state.color.a,
-                            ..Hsv {
-                                hue: move_value(hsv_color.hue, *y),
-                                ..hsv_color
-                            }
-                            .into()
-                        };
-                        color_changed = true;
-                    }
-                }
-            },
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if sat_value_bounds.contains(cursor_position) {
-                    self.state.color_bar_dragged = ColorBarDragged::SatValue;
-                    self.state.focus = Focus::SatValue;
-                }
                 if hue_bounds.contains(cursor_position) {
-                    self.state.color_bar_dragged = ColorBarDragged::Hue;
-                    self.state.focus = Focus::Hue;
+                    state.color = Color {
+                        a: state.color.a,
+                        ..Hsv {
+                            hue: move_value(hsv_color.hue, *y),
+                            ..hsv_color
+                        }
+                        .into()
+                    };
+                    color_changed = true;
                 }
             }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. })
-            | Event::Touch(touch::Event::FingerLost { .. }) => {
-                self.state.color_bar_dragged = ColorBarDragged::None;
+        },
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if sat_value_bounds.contains(cursor_position) {
+                state.color_bar_dragged = ColorBarDragged::SatValue;
+                state.focus = Focus::SatValue;
             }
-            _ => {}
-        }
-
-        let calc_percentage_sat = |bounds: &Rectangle, cursor_position: &Point| {
-            ((cursor_position.x - bounds.x).max(0.0) / bounds.width).min(1.0)
-        };
-
-        let calc_percentage_value = |bounds: &Rectangle, cursor_position: &Point| {
-            ((cursor_position.y - bounds.y).max(0.0) / bounds.height).min(1.0)
-        };
-
-        let calc_hue = |bounds: &Rectangle, cursor_position: &Point| {
-            (((cursor_position.x - bounds.x).max(0.0) / bounds.width).min(1.0) * 360.0) as u16
-        };
-
-        match self.state.color_bar_dragged {
-            ColorBarDragged::SatValue => {
-                self.state.color = Color {
-                    a: self.state.color.a,
-                    ..Hsv {
-                        saturation: calc_percentage_sat(&sat_value_bounds, &cursor_position),
-                        value: calc_percentage_value(&sat_value_bounds, &cursor_position),
-                        ..hsv_color
-                    }
-                    .into()
-                };
-                color_changed = true;
+            if hue_bounds.contains(cursor_position) {
+                state.color_bar_dragged = ColorBarDragged::Hue;
+                state.focus = Focus::Hue;
             }
-            ColorBarDragged::Hue => {
-                self.state.color = Color {
-                    a: self.state.color.a,
-                    ..Hsv {
-                        hue: calc_hue(&hue_bounds, &cursor_position),
-                        ..hsv_color
-                    }
-                    .into()
-                };
-                color_changed = true;
-            }
-            _ => {}
         }
-
-        if color_changed {
-            event::Status::Captured
-        } else {
-            event::Status::Ignored
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+        | Event::Touch(touch::Event::FingerLost { .. }) => {
+            state.color_bar_dragged = ColorBarDragged::None;
         }
+        _ => {}
     }
 
-    /// The event handling for the RGBA color area.
-    #[allow(clippy::too_many_lines)]
-    fn on_event_rgba_color(
-        &mut self,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        _messages: &mut Vec<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
-    ) -> event::Status {
-        let mut rgba_color_children = layout.children();
-        let mut color_changed = false;
-
-        let mut red_row_children = rgba_color_children
-            .next()
-            .expect("Native: Layout should have a red row layout")
-            .children();
-        let _ = red_row_children.next();
-        let red_bar_bounds = red_row_children
-            .next()
-            .expect("Native: Layout should have a red bar layout")
-            .bounds();
-
-        let mut green_row_children = rgba_color_children
-            .next()
-            .expect("Native: Layout should have a green row layout")
-            .children();
-        let _ = green_row_children.next();
-        let green_bar_bounds = green_row_children
-            .next()
-            .expect("Native: Layout should have a green bar layout")
-            .bounds();
-
-        let mut blue_row_children = rgba_color_children
-            .next()
-            .expect("Native: Layout should have a blue row layout")
-            .children();
-        let _ = blue_row_children.next();
-        let blue_bar_bounds = blue_row_children
-            .next()
-            .expect("Native: Layout should have a blue bar layout")
-            .bounds();
-
-        let mut alpha_row_children = rgba_color_children
-            .next()
-            .expect("Native: Layout should have an alpha row layout")
-            .children();
-        let _ = alpha_row_children.next();
-        let alpha_bar_bounds = alpha_row_children
-            .next()
-            .expect("Native: Layout should have an alpha bar layout")
-            .bounds();
-
-        match event {
-            Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
-                mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
-                    let move_value =
-                        //|value: f32, y: f32| (value * 255.0 + y).clamp(0.0, 255.0) / 255.0;
-                        |value: f32, y: f32| value.mul_add(255.0, y).clamp(0.0, 255.0) / 255.0;
-
-                    if red_bar_bounds.contains(cursor_position) {
-                        self.state.color = Color {
-                            r: move_value(self.state.color.r, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                    if green_bar_bounds.contains(cursor_position) {
-                        self.state.color = Color {
-                            g: move_value(self.state.color.g, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                    if blue_bar_bounds.contains(cursor_position) {
-                        self.state.color = Color {
-                            b: move_value(self.state.color.b, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                    if alpha_bar_bounds.contains(cursor_position) {
-                        self.state.color = Color {
-                            a: move_value(self.state.color.a, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
+    let calc_percentage_sat = |bounds: &Rectangle, cursor_position: &Point| {
+        ((cursor_position.x - bounds.x).max(0.0) / bounds.width).min(1.0)
+    };
+
+    let calc_percentage_value = |bounds: &Rectangle, cursor_position: &Point| {
+        ((cursor_position.y - bounds.y).max(0.0) / bounds.height).min(1.0)
+    };
+
+    let calc_hue = |bounds: &Rectangle, cursor_position: &Point| {
+        (((cursor_position.x - bounds.x).max(0.0) / bounds.width).min(1.0) * 360.0) as u16
+    };
+
+    match state.color_bar_dragged {
+        ColorBarDragged::SatValue => {
+            state.color = Color {
+                a: state.color.a,
+                ..Hsv {
+                    saturation: calc_percentage_sat(&sat_value_bounds, &cursor_position),
+                    value: calc_percentage_value(&sat_value_bounds, &cursor_position),
+                    ..hsv_color
                 }
-            },
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                .into()
+            };
+            color_changed = true;
+        }
+        ColorBarDragged::Hue => {
+            state.color = Color {
+                a: state.color.a,
+                ..Hsv {
+                    hue: calc_hue(&hue_bounds, &cursor_position),
+                    ..hsv_color
+                }
+                .into()
+            };
+            color_changed = true;
+        }
+        _ => {}
+    }
+
+    if color_changed {
+        event::Status::Captured
+    } else {
+        event::Status::Ignored
+    }
+}
+
+/// The event handling for the RGBA color area.
+#[allow(clippy::too_many_lines)]
+fn on_event_rgba_color(
+    state: &mut State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> event::Status {
+    let mut rgba_color_children = layout.children();
+    let mut color_changed = false;
+
+    let mut red_row_children = rgba_color_children
+        .next()
+        .expect("Native: Layout should have a red row layout")
+        .children();
+    let _ = red_row_children.next();
+    let red_bar_bounds = red_row_children
+        .next()
+        .expect("Native: Layout should have a red bar layout")
+        .bounds();
+
+    let mut green_row_children = rgba_color_children
+        .next()
+        .expect("Native: Layout should have a green row layout")
+        .children();
+    let _ = green_row_children.next();
+    let green_bar_bounds = green_row_children
+        .next()
+        .expect("Native: Layout should have a green bar layout")
+        .bounds();
+
+    let mut blue_row_children = rgba_color_children
+        .next()
+        .expect("Native: Layout should have a blue row layout")
+        .children();
+    let _ = blue_row_children.next();
+    let blue_bar_bounds = blue_row_children
+        .next()
+        .expect("Native: Layout should have a blue bar layout")
+        .bounds();
+
+    let mut alpha_row_children = rgba_color_children
+        .next()
+        .expect("Native: Layout should have an alpha row layout")
+        .children();
+    let _ = alpha_row_children.next();
+    let alpha_bar_bounds = alpha_row_children
+        .next()
+        .expect("Native: Layout should have an alpha bar layout")
+        .bounds();
+
+    match event {
+        Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
+            mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
+                let move_value =
+                    //|value: f32, y: f32| (value * 255.0 + y).clamp(0.0, 255.0) / 255.0;
+                    |value: f32, y: f32| value.mul_add(255.0, y).clamp(0.0, 255.0) / 255.0;
+
                 if red_bar_bounds.contains(cursor_position) {
-                    self.state.color_bar_dragged = ColorBarDragged::Red;
-                    self.state.focus = Focus::Red;
+                    state.color = Color {
+                        r: move_value(state.color.r, *y),
+                        ..state.color
+                    };
+                    color_changed = true;
                 }
                 if green_bar_bounds.contains(cursor_position) {
-                    self.state.color_bar_dragged = ColorBarDragged::Green;
-                    self.state.focus = Focus::Green;
+                    state.color = Color {
+                        g: move_value(state.color.g, *y),
+                        ..state.color
+                    };
+                    color_changed = true;
                 }
                 if blue_bar_bounds.contains(cursor_position) {
-                    self.state.color_bar_dragged = ColorBarDragged::Blue;
-                    self.state.focus = Focus::Blue;
+                    state.color = Color {
+                        b: move_value(state.color.b, *y),
+                        ..state.color
+                    };
+                    color_changed = true;
                 }
                 if alpha_bar_bounds.contains(cursor_position) {
-                    self.state.color_bar_dragged = ColorBarDragged::Alpha;
-                    self.state.focus = Focus::Alpha;
+                    state.color = Color {
+                        a: move_value(state.color.a, *y),
+                        ..state.color
+                    };
+                    color_changed = true;
                 }
             }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. })
-            | Event::Touch(touch::Event::FingerLost { .. }) => {
-                self.state.color_bar_dragged = ColorBarDragged::None;
+        },
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if red_bar_bounds.contains(cursor_position) {
+                state.color_bar_dragged = ColorBarDragged::Red;
+                state.focus = Focus::Red;
             }
-            _ => {}
-        }
-
-        let calc_percantage = |bounds: &Rectangle, cursor_position: &Point| {
-            ((cursor_position.x - bounds.x).max(0.0) / bounds.width).min(1.0)
-        };
-
-        match self.state.color_bar_dragged {
-            ColorBarDragged::Red => {
-                self.state.color = Color {
-                    r: calc_percantage(&red_bar_bounds, &cursor_position),
-                    ..self.state.color
-                };
-                color_changed = true;
+            if green_bar_bounds.contains(cursor_position) {
+                state.color_bar_dragged = ColorBarDragged::Green;
+                state.focus = Focus::Green;
             }
-            ColorBarDragged::Green => {
-                self.state.color = Color {
-                    g: calc_percantage(&green_bar_bounds, &cursor_position),
-                    ..self.state.color
-                };
-                color_changed = true;
+            if blue_bar_bounds.contains(cursor_position) {
+                state.color_bar_dragged = ColorBarDragged::Blue;
+                state.focus = Focus::Blue;
             }
-            ColorBarDragged::Blue => {
-                self.state.color = Color {
-                    b: calc_percantage(&blue_bar_bounds, &cursor_position),
-                    ..self.state.color
-                };
-                color_changed = true;
+            if alpha_bar_bounds.contains(cursor_position) {
+                state.color_bar_dragged = ColorBarDragged::Alpha;
+                state.focus = Focus::Alpha;
             }
-            ColorBarDragged::Alpha => {
-                self.state.color = Color {
-                    a: calc_percantage(&alpha_bar_bounds, &cursor_position),
-                    ..self.state.color
-                };
-                color_changed = true;
-            }
-            _ => {}
         }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+        | Event::Touch(touch::Event::FingerLost { .. }) => {
+            state.color_bar_dragged = ColorBarDragged::None;
+        }
+        _ => {}
+    }
 
-        if color_changed {
-            event::Status::Captured
-        } else {
-            event::Status::Ignored
+    let calc_percantage = |bounds: &Rectangle, cursor_position: &Point| {
+        ((cursor_position.x - bounds.x).max(0.0) / bounds.width).min(1.0)
+    };
+
+    match state.color_bar_dragged {
+        ColorBarDragged::Red => {
+            state.color = Color {
+                r: calc_percantage(&red_bar_bounds, &cursor_position),
+                ..state.color
+            };
+            color_changed = true;
+        }
+        ColorBarDragged::Green => {
+            state.color = Color {
+                g: calc_percantage(&green_bar_bounds, &cursor_position),
+                ..state.color
+            };
+            color_changed = true;
+        }
+        ColorBarDragged::Blue => {
+            state.color = Color {
+                b: calc_percantage(&blue_bar_bounds, &cursor_position),
+                ..state.color
+            };
+            color_changed = true;
+        }
+        ColorBarDragged::Alpha => {
+            state.color = Color {
+                a: calc_percantage(&alpha_bar_bounds, &cursor_position),
+                ..state.color
+            };
+            color_changed = true;
         }
+        _ => {}
     }
 
-    /// The even handling for the keyboard input.
-    fn on_event_keyboard(
-        &mut self,
-        event: &Event,
-        _layout: Layout<'_>,
-        _cursor_position: Point,
-        _messages: &mut Vec<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
-    ) -> event::Status {
-        if self.state.focus == Focus::None {
-            return event::Status::Ignored;
+    if color_changed {
+        event::Status::Captured
+    } else {
+        event::Status::Ignored
+    }
+}
+
+/// The even handling for the keyboard input.
+///
+/// Tab and Shift+Tab cycle the focus through the sat/value area, the hue
+/// bar, the RGBA bars, the hex text field and the buttons, while the arrow
+/// keys change the focused value.
+fn on_event_keyboard(state: &mut State, event: &Event) -> event::Status {
+    if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+        state.keyboard_modifiers = *modifiers;
+        return event::Status::Ignored;
+    }
+
+    if let Event::Keyboard(keyboard::Event::KeyPressed {
+        key_code: keyboard::KeyCode::Tab,
+        ..
+    }) = event
+    {
+        if state.keyboard_modifiers.shift {
+            state.focus = state.focus.previous();
+        } else {
+            state.focus = state.focus.next();
         }
+        // TODO: maybe place this better
+        state.sat_value_canvas_cache.clear();
+        state.hue_canvas_cache.clear();
+        return event::Status::Captured;
+    }
 
-        if let Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) = event {
+    if state.focus == Focus::None {
+        return event::Status::Ignored;
+    }
+
+    if let Event::Keyboard(keyboard::Event::CharacterReceived(c)) = event {
+        on_event_hex(state, *c)
+    } else if let Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) = event {
+        let factor = if state.keyboard_modifiers.shift {
+            SHIFT_STEP_FACTOR
+        } else {
+            1
+        };
+        let mut status = event::Status::Ignored;
+
+        let sat_value_handle = |key_code: &keyboard::KeyCode, color: &mut Color| {
+            let mut hsv_color: Hsv = color.to_owned().into();
             let mut status = event::Status::Ignored;
 
-            if let keyboard::KeyCode::Tab = key_code {
-                if self.state.keyboard_modifiers.shift {
-                    self.state.focus = self.state.focus.previous();
-                } else {
-                    self.state.focus = self.state.focus.next();
+            match key_code {
+                keyboard::KeyCode::Left => {
+                    hsv_color.saturation -= SAT_VALUE_STEP * f32::from(factor);
+                    status = event::Status::Captured;
                 }
-                // TODO: maybe place this better
-                self.state.sat_value_canvas_cache.clear();
-                self.state.hue_canvas_cache.clear();
-            } else {
-                let sat_value_handle = |key_code: &keyboard::KeyCode, color: &mut Color| {
-                    let mut hsv_color: Hsv = color.to_owned().into();
-                    let mut status = event::Status::Ignored;
-
-                    match key_code {
-                        keyboard::KeyCode::Left => {
-                            hsv_color.saturation -= SAT_VALUE_STEP;
-                            status = event::Status::Captured;
-                        }
-                        keyboard::KeyCode::Right => {
-                            hsv_color.saturation += SAT_VALUE_STEP;
-                            status = event::Status::Captured;
-                        }
-                        keyboard::KeyCode::Up => {
-                            hsv_color.value -= SAT_VALUE_STEP;
-                            status = event::Status::Captured;
-                        }
-                        keyboard::KeyCode::Down => {
-                            hsv_color.value += SAT_VALUE_STEP;
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    }
+                keyboard::KeyCode::Right => {
+                    hsv_color.saturation += SAT_VALUE_STEP * f32::from(factor);
+                    status = event::Status::Captured;
+                }
+                keyboard::KeyCode::Up => {
+                    hsv_color.value -= SAT_VALUE_STEP * f32::from(factor);
+                    status = event::Status::Captured;
+                }
+                keyboard::KeyCode::Down => {
+                    hsv_color.value += SAT_VALUE_STEP * f32::from(factor);
+                    status = event::Status::Captured;
+                }
+                _ => {}
+            }
 
-                    hsv_color.saturation = hsv_color.saturation.clamp(0.0, 1.0);
-                    hsv_color.value = hsv_color.value.clamp(0.0, 1.0);
+            hsv_color.saturation = hsv_color.saturation.clamp(0.0, 1.0);
+            hsv_color.value = hsv_color.value.clamp(0.0, 1.0);
 
-                    *color = Color {
-                        a: color.a,
-                        ..hsv_color.into()
-                    };
-                    status
-                };
+            *color = Color {
+                a: color.a,
+                ..hsv_color.into()
+            };
+            status
+        };
 
-                let hue_handle = |key_code: &keyboard::KeyCode, color: &mut Color| {
-                    let mut hsv_color: Hsv = color.to_owned().into();
-                    let mut status = event::Status::Ignored;
+        let hue_handle = |key_code: &keyboard::KeyCode, color: &mut Color| {
+            let mut hsv_color: Hsv = color.to_owned().into();
+            let mut status = event::Status::Ignored;
 
-                    let mut value = i32::from(hsv_color.hue);
+            let mut value = i32::from(hsv_color.hue);
 
-                    match key_code {
-                        keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
-                            value -= HUE_STEP;
-                            status = event::Status::Captured;
-                        }
-                        keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
-                            value += HUE_STEP;
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    }
+            match key_code {
+                keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                    value -= HUE_STEP * i32::from(factor);
+                    status = event::Status::Captured;
+                }
+                keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                    value += HUE_STEP * i32::from(factor);
+                    status = event::Status::Captured;
+                }
+                _ => {}
+            }
 
-                    hsv_color.hue = value.rem_euclid(360) as u16;
+            hsv_color.hue = value.rem_euclid(360) as u16;
 
-                    *color = Color {
-                        a: color.a,
-                        ..hsv_color.into()
-                    };
+            *color = Color {
+                a: color.a,
+                ..hsv_color.into()
+            };
 
-                    status
-                };
+            status
+        };
 
-                let rgba_bar_handle = |key_code: &keyboard::KeyCode, value: &mut f32| {
-                    let mut byte_value = (*value * 255.0) as i16;
-                    let mut status = event::Status::Captured;
+        let rgba_bar_handle = |key_code: &keyboard::KeyCode, value: &mut f32| {
+            let mut byte_value = (*value * 255.0) as i16;
+            let mut status = event::Status::Captured;
 
-                    match key_code {
-                        keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
-                            byte_value -= RGBA_STEP;
-                            status = event::Status::Captured;
-                        }
-                        keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
-                            byte_value += RGBA_STEP;
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    }
-                    *value = f32::from(byte_value.clamp(0, 255)) / 255.0;
-
-                    status
-                };
-
-                match self.state.focus {
-                    Focus::SatValue => status = sat_value_handle(key_code, &mut self.state.color),
-                    Focus::Hue => status = hue_handle(key_code, &mut self.state.color),
-                    Focus::Red => status = rgba_bar_handle(key_code, &mut self.state.color.r),
-                    Focus::Green => status = rgba_bar_handle(key_code, &mut self.state.color.g),
-                    Focus::Blue => status = rgba_bar_handle(key_code, &mut self.state.color.b),
-                    Focus::Alpha => status = rgba_bar_handle(key_code, &mut self.state.color.a),
-                    _ => {}
+            match key_code {
+                keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                    byte_value -= RGBA_STEP * i16::from(factor);
+                    status = event::Status::Captured;
+                }
+                keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                    byte_value += RGBA_STEP * i16::from(factor);
+                    status = event::Status::Captured;
                 }
+                _ => {}
             }
+            *value = f32::from(byte_value.clamp(0, 255)) / 255.0;
 
             status
-        } else if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
-            self.state.keyboard_modifiers = *modifiers;
-            event::Status::Ignored
-        } else {
-            event::Status::Ignored
+        };
+
+        match state.focus {
+            Focus::SatValue => status = sat_value_handle(key_code, &mut state.color),
+            Focus::Hue => status = hue_handle(key_code, &mut state.color),
+            Focus::Red => status = rgba_bar_handle(key_code, &mut state.color.r),
+            Focus::Green => status = rgba_bar_handle(key_code, &mut state.color.g),
+            Focus::Blue => status = rgba_bar_handle(key_code, &mut state.color.b),
+            Focus::Alpha => status = rgba_bar_handle(key_code, &mut state.color.a),
+            Focus::Hex => {
+                if let keyboard::KeyCode::Backspace = key_code {
+                    let _ = state.hex_input.pop();
+                    status = event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        status
+    } else {
+        event::Status::Ignored
+    }
+}
+
+/// The event handling for the keys cancelling and submitting the color
+/// picker.
+///
+/// Escape cancels the color picker, while Enter submits the picked color or
+/// cancels the color picker if the cancel button is focused.
+fn on_event_submit_keys<Message>(
+    state: &mut State,
+    on_cancel: &Message,
+    on_submit: &dyn Fn(Color) -> Message,
+    event: &Event,
+    messages: &mut Vec<Message>,
+) -> event::Status
+where
+    Message: Clone,
+{
+    if state.focus == Focus::None {
+        return event::Status::Ignored;
+    }
+
+    match event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Escape,
+            ..
+        }) => {
+            #[cfg(feature = "instrumentation")]
+            instrumentation::record(WidgetKind::ColorPicker, Action::Escape);
+
+            messages.push(on_cancel.clone());
+            event::Status::Captured
         }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Enter,
+            ..
+        }) => {
+            if state.focus == Focus::Cancel {
+                #[cfg(feature = "instrumentation")]
+                instrumentation::record(WidgetKind::ColorPicker, Action::Cancel);
+
+                messages.push(on_cancel.clone());
+            } else {
+                submit(state, on_submit, messages);
+            }
+            event::Status::Captured
+        }
+        _ => event::Status::Ignored,
     }
 }
 
+/// Produces the submit message with the picked color and remembers it as a
+/// recent color.
+fn submit<Message>(
+    state: &mut State,
+    on_submit: &dyn Fn(Color) -> Message,
+    messages: &mut Vec<Message>,
+) {
+    #[cfg(feature = "instrumentation")]
+    instrumentation::record(WidgetKind::ColorPicker, Action::Submit);
+
+    messages.push((on_submit)(state.color));
+    state.remember(state.color);
+}
+
+/// The event handling for the hexadecimal text field.
+///
+/// Pressing on the text field focuses it and starts typing a new color.
+fn on_event_hex_text(
+    state: &mut State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> event::Status {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. })
+            if layout.bounds().contains(cursor_position) =>
+        {
+            state.focus = Focus::Hex;
+            state.hex_input.clear();
+            event::Status::Captured
+        }
+        _ => event::Status::Ignored,
+    }
+}
+
+/// The event handling for the color swatches.
+///
+/// Pressing on a swatch selects its color.
+fn on_event_swatches(
+    state: &mut State,
+    palette: &[Color],
+    event: &Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> event::Status {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            let swatch = layout
+                .children()
+                .position(|layout| layout.bounds().contains(cursor_position))
+                .and_then(|index| swatches(palette, &state.recent).get(index).copied());
+
+            match swatch {
+                Some(color) => {
+                    state.color = color;
+                    state.hex_input.clear();
+                    event::Status::Captured
+                }
+                None => event::Status::Ignored,
+            }
+        }
+        _ => event::Status::Ignored,
+    }
+}
+
+/// Collects the colors of the swatches of the color picker.
+///
+/// These are the colors of the palette followed by the recently submitted
+/// colors that are not part of the palette.
+pub(crate) fn swatches(palette: &[Color], recent: &[Color]) -> Vec<Color> {
+    palette
+        .iter()
+        .copied()
+        .chain(
+            recent
+                .iter()
+                .copied()
+                .filter(|color| !palette.contains(color)),
+        )
+        .collect()
+}
+
+/// The event handling for typing a hexadecimal color.
+///
+/// Typed hexadecimal characters are collected and the color jumps to the
+/// typed color as soon as 6 (`RRGGBB`) or 8 (`RRGGBBAA`) characters have been
+/// typed. A `#` starts a new color.
+fn on_event_hex(state: &mut State, c: char) -> event::Status {
+    if c == '#' {
+        state.hex_input.clear();
+        return event::Status::Captured;
+    }
+
+    if !c.is_ascii_hexdigit() {
+        state.hex_input.clear();
+        return event::Status::Ignored;
+    }
+
+    if state.hex_input.len() == 8 {
+        state.hex_input.clear();
+    }
+    state.hex_input.push(c);
+
+    if let Some(color) = parse_hex_string(&state.hex_input) {
+        state.color = if state.hex_input.len() == 6 {
+            Color {
+                a: state.color.a,
+                ..color
+            }
+        } else {
+            color
+        };
+    }
+
+    event::Status::Captured
+}
+
 impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
     for ColorPickerOverlay<'a, Message, Renderer>
 where
@@ -525,64 +742,13 @@ where
         bounds: iced_graphics::Size,
         position: Point,
     ) -> iced_native::layout::Node {
-        let (max_width, max_height) = if bounds.width > bounds.height {
-            (600, 300)
-        } else {
-            (300, 600)
-        };
-
-        let limits = Limits::new(Size::ZERO, bounds)
-            .pad(f32::from(PADDING))
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .max_width(max_width)
-            .max_height(max_height);
-
-        let divider = if bounds.width > bounds.height {
-            Row::<(), Renderer>::new()
-                .spacing(SPACING)
-                .push(Row::new().width(Length::Fill).height(Length::Fill))
-                .push(Row::new().width(Length::Fill).height(Length::Fill))
-                .layout(renderer, &limits)
-        } else {
-            Column::<(), Renderer>::new()
-                .spacing(SPACING)
-                .push(Row::new().width(Length::Fill).height(Length::Fill))
-                .push(Row::new().width(Length::Fill).height(Length::Fill))
-                .layout(renderer, &limits)
-        };
-
-        let mut divider_children = divider.children().iter();
-
-        let block1_bounds = divider_children
-            .next()
-            .expect("Divider should have a first child")
-            .bounds();
-        let block2_bounds = divider_children
-            .next()
-            .expect("Divider should have a second child")
-            .bounds();
-
-        // ----------- Block 1 ----------------------
-        let block1_node = block1_layout(self, renderer, block1_bounds, position);
-
-        // ----------- Block 2 ----------------------
-        let block2_node = block2_layout(self, renderer, block2_bounds, position);
-
-        let (width, height) = if bounds.width > bounds.height {
-            (
-                block1_node.size().width + block2_node.size().width + f32::from(SPACING), // + (2.0 * PADDING as f32),
-                block2_node.size().height,
-            )
-        } else {
-            (
-                block2_node.size().width,
-                block1_node.size().height + block2_node.size().height + f32::from(SPACING),
-            )
-        };
-
-        let mut node =
-            layout::Node::with_children(Size::new(width, height), vec![block1_node, block2_node]);
+        let mut node = picker_layout(
+            renderer,
+            bounds,
+            swatches(self.palette, &self.state.recent).len(),
+            &self.cancel_button,
+            &self.submit_button,
+        );
 
         node.center_and_bounce(position, bounds);
 
@@ -598,105 +764,20 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
-        if let event::Status::Captured = self.on_event_keyboard(
-            &event,
+        picker_on_event(
+            self.state,
+            &mut self.cancel_button,
+            &mut self.submit_button,
+            &self.on_cancel,
+            self.on_submit,
+            self.palette,
+            event,
             layout,
             cursor_position,
-            messages,
-            renderer,
-            clipboard,
-        ) {
-            self.state.sat_value_canvas_cache.clear();
-            self.state.hue_canvas_cache.clear();
-            return event::Status::Captured;
-        }
-
-        let mut children = layout.children();
-
-        let status = event::Status::Ignored;
-
-        // ----------- Block 1 ----------------------
-        let block1_layout = children
-            .next()
-            .expect("Native: Layout should have a 1. block layout");
-        let hsv_color_status = self.on_event_hsv_color(
-            &event,
-            block1_layout,
-            cursor_position,
-            messages,
             renderer,
             clipboard,
-        );
-        // ----------- Block 1 end ------------------
-
-        // ----------- Block 2 ----------------------
-        let mut block2_children = children
-            .next()
-            .expect("Native: Layout should have a 2. block layout")
-            .children();
-
-        // ----------- RGB Color -----------------------
-        let rgba_color_layout = block2_children
-            .next()
-            .expect("Native: Layout should have a RGBA color layout");
-        let rgba_color_status = self.on_event_rgba_color(
-            &event,
-            rgba_color_layout,
-            cursor_position,
             messages,
-            renderer,
-            clipboard,
-        );
-
-        let mut fake_messages: Vec<Message> = Vec::new();
-
-        // ----------- Text input ----------------------
-        let _text_input_layout = block2_children
-            .next()
-            .expect("Native: Layout should have a hex text layout");
-
-        // ----------- Buttons -------------------------
-        let cancel_button_layout = block2_children
-            .next()
-            .expect("Native: Layout should have a cancel button layout for a ColorPicker");
-        let cancel_button_status = self.cancel_button.on_event(
-            event.clone(),
-            cancel_button_layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            &mut fake_messages,
-        );
-
-        let submit_button_layout = block2_children
-            .next()
-            .expect("Native: Layout should have a submit button layout for a ColorPicker");
-        let submit_button_status = self.submit_button.on_event(
-            event,
-            submit_button_layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            &mut fake_messages,
-        );
-
-        if !fake_messages.is_empty() {
-            messages.push((self.on_submit)(self.state.color));
-        }
-        // ----------- Block 2 end ------------------
-
-        if hsv_color_status == event::Status::Captured
-            || rgba_color_status == event::Status::Captured
-        {
-            self.state.sat_value_canvas_cache.clear();
-            self.state.hue_canvas_cache.clear();
-        }
-
-        status
-            .merge(hsv_color_status)
-            .merge(rgba_color_status)
-            .merge(cancel_button_status)
-            .merge(submit_button_status)
+        )
     }
 
     fn draw(
@@ -706,6 +787,8 @@ where
         layout: iced_native::Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        accessibility::Renderer::announce(renderer, self.accessibility, layout.bounds());
+
         <Renderer as self::Renderer>::draw(
             renderer,
             DrawEnvironment {
@@ -717,6 +800,8 @@ where
                 focus: self.state.focus,
             },
             &self.state.color,
+            &self.state.hex_input,
+            &swatches(self.palette, &self.state.recent),
             &self.state.sat_value_canvas_cache,
             &self.state.hue_canvas_cache,
             //&self.text_input,
@@ -732,15 +817,20 @@ where
 
         (position.x as u32).hash(state);
         (position.y as u32).hash(state);
+        swatches(self.palette, &self.state.recent).len().hash(state);
     }
 }
 
-/// Defines the layout of the 1. block of the color picker containing the HSV part.
-fn block1_layout<'a, Message, Renderer>(
-    _color_picker: &ColorPickerOverlay<'a, Message, Renderer>,
+/// Defines the layout of the color picker within the given bounds.
+///
+/// This is shared between the [`ColorPickerOverlay`](ColorPickerOverlay) and
+/// the [`InlineColorPicker`](crate::native::color_picker::InlineColorPicker).
+pub(crate) fn picker_layout<'a, Message, Renderer>(
     renderer: &Renderer,
-    bounds: iced_graphics::Rectangle,
-    _position: Point,
+    bounds: Size,
+    swatch_count: usize,
+    cancel_button: &Element<'a, Message, Renderer>,
+    submit_button: &Element<'a, Message, Renderer>,
 ) -> iced_native::layout::Node
 where
     Message: 'static + Clone,
@@ -752,6 +842,206 @@ where
         + row::Renderer
         + text::Renderer
         + text_input::Renderer,
+{
+    let (max_width, max_height) = if bounds.width > bounds.height {
+        (600, 300)
+    } else {
+        (300, 600)
+    };
+
+    let limits = Limits::new(Size::ZERO, bounds)
+        .pad(f32::from(PADDING))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .max_width(max_width)
+        .max_height(max_height);
+
+    let divider = if bounds.width > bounds.height {
+        Row::<(), Renderer>::new()
+            .spacing(SPACING)
+            .push(Row::new().width(Length::Fill).height(Length::Fill))
+            .push(Row::new().width(Length::Fill).height(Length::Fill))
+            .layout(renderer, &limits)
+    } else {
+        Column::<(), Renderer>::new()
+            .spacing(SPACING)
+            .push(Row::new().width(Length::Fill).height(Length::Fill))
+            .push(Row::new().width(Length::Fill).height(Length::Fill))
+            .layout(renderer, &limits)
+    };
+
+    let mut divider_children = divider.children().iter();
+
+    let block1_bounds = divider_children
+        .next()
+        .expect("Divider should have a first child")
+        .bounds();
+    let block2_bounds = divider_children
+        .next()
+        .expect("Divider should have a second child")
+        .bounds();
+
+    // ----------- Block 1 ----------------------
+    let block1_node = block1_layout(renderer, block1_bounds);
+
+    // ----------- Block 2 ----------------------
+    let block2_node = block2_layout(
+        renderer,
+        block2_bounds,
+        swatch_count,
+        cancel_button,
+        submit_button,
+    );
+
+    let (width, height) = if bounds.width > bounds.height {
+        (
+            block1_node.size().width + block2_node.size().width + f32::from(SPACING), // + (2.0 * PADDING as f32),
+            block2_node.size().height,
+        )
+    } else {
+        (
+            block2_node.size().width,
+            block1_node.size().height + block2_node.size().height + f32::from(SPACING),
+        )
+    };
+
+    layout::Node::with_children(Size::new(width, height), vec![block1_node, block2_node])
+}
+
+/// Handles the events of the color picker.
+///
+/// This is shared between the [`ColorPickerOverlay`](ColorPickerOverlay) and
+/// the [`InlineColorPicker`](crate::native::color_picker::InlineColorPicker).
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+pub(crate) fn picker_on_event<'a, Message, Renderer>(
+    state: &mut State,
+    cancel_button: &mut Element<'a, Message, Renderer>,
+    submit_button: &mut Element<'a, Message, Renderer>,
+    on_cancel: &Message,
+    on_submit: &dyn Fn(Color) -> Message,
+    palette: &[Color],
+    event: Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    messages: &mut Vec<Message>,
+) -> event::Status
+where
+    Message: 'static + Clone,
+    Renderer: 'a
+        + self::Renderer
+        + column::Renderer
+        + button::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer
+        + text_input::Renderer,
+{
+    if let event::Status::Captured =
+        on_event_submit_keys(state, on_cancel, on_submit, &event, messages)
+    {
+        return event::Status::Captured;
+    }
+
+    if let event::Status::Captured = on_event_keyboard(state, &event) {
+        state.sat_value_canvas_cache.clear();
+        state.hue_canvas_cache.clear();
+        return event::Status::Captured;
+    }
+
+    let mut children = layout.children();
+
+    let status = event::Status::Ignored;
+
+    // ----------- Block 1 ----------------------
+    let block1_layout = children
+        .next()
+        .expect("Native: Layout should have a 1. block layout");
+    let hsv_color_status = on_event_hsv_color(state, &event, block1_layout, cursor_position);
+    // ----------- Block 1 end ------------------
+
+    // ----------- Block 2 ----------------------
+    let mut block2_children = children
+        .next()
+        .expect("Native: Layout should have a 2. block layout")
+        .children();
+
+    // ----------- RGB Color -----------------------
+    let rgba_color_layout = block2_children
+        .next()
+        .expect("Native: Layout should have a RGBA color layout");
+    let rgba_color_status = on_event_rgba_color(state, &event, rgba_color_layout, cursor_position);
+
+    let mut fake_messages: Vec<Message> = Vec::new();
+
+    // ----------- Text input ----------------------
+    let hex_text_layout = block2_children
+        .next()
+        .expect("Native: Layout should have a hex text layout");
+    let hex_text_status = on_event_hex_text(state, &event, hex_text_layout, cursor_position);
+
+    // ----------- Swatches ------------------------
+    let swatches_layout = block2_children
+        .next()
+        .expect("Native: Layout should have a swatches layout");
+    let swatches_status =
+        on_event_swatches(state, palette, &event, swatches_layout, cursor_position);
+
+    // ----------- Buttons -------------------------
+    let cancel_button_layout = block2_children
+        .next()
+        .expect("Native: Layout should have a cancel button layout for a ColorPicker");
+    let cancel_button_status = cancel_button.on_event(
+        event.clone(),
+        cancel_button_layout,
+        cursor_position,
+        renderer,
+        clipboard,
+        messages,
+    );
+
+    let submit_button_layout = block2_children
+        .next()
+        .expect("Native: Layout should have a submit button layout for a ColorPicker");
+    let submit_button_status = submit_button.on_event(
+        event,
+        submit_button_layout,
+        cursor_position,
+        renderer,
+        clipboard,
+        &mut fake_messages,
+    );
+
+    if !fake_messages.is_empty() {
+        submit(state, on_submit, messages);
+    }
+    // ----------- Block 2 end ------------------
+
+    if hsv_color_status == event::Status::Captured
+        || rgba_color_status == event::Status::Captured
+        || swatches_status == event::Status::Captured
+    {
+        state.sat_value_canvas_cache.clear();
+        state.hue_canvas_cache.clear();
+    }
+
+    status
+        .merge(hsv_color_status)
+        .merge(rgba_color_status)
+        .merge(hex_text_status)
+        .merge(swatches_status)
+        .merge(cancel_button_status)
+        .merge(submit_button_status)
+}
+
+/// Defines the layout of the 1. block of the color picker containing the HSV part.
+fn block1_layout<Renderer>(
+    renderer: &Renderer,
+    bounds: iced_graphics::Rectangle,
+) -> iced_native::layout::Node
+where
+    Renderer: column::Renderer + row::Renderer,
 {
     let block1_limits = Limits::new(Size::ZERO, bounds.size())
         .width(Length::Fill)
@@ -781,10 +1071,11 @@ where
 
 /// Defines the layout of the 2. block of the color picker containing the RGBA part, Hex and buttons.
 fn block2_layout<'a, Message, Renderer>(
-    color_picker: &ColorPickerOverlay<'a, Message, Renderer>,
     renderer: &Renderer,
     bounds: iced_graphics::Rectangle,
-    _position: Point,
+    swatch_count: usize,
+    cancel_button: &Element<'a, Message, Renderer>,
+    submit_button: &Element<'a, Message, Renderer>,
 ) -> iced_native::layout::Node
 where
     Message: 'static + Clone,
@@ -803,7 +1094,7 @@ where
 
     // Pre-Buttons TODO: get rid of it
     let cancel_limits = block2_limits;
-    let cancel_button = color_picker.cancel_button.layout(renderer, &cancel_limits);
+    let cancel_button = cancel_button.layout(renderer, &cancel_limits);
 
     let hex_text_limits = block2_limits;
     let mut hex_text = Row::<(), Renderer>::new()
@@ -813,9 +1104,19 @@ where
         ))
         .layout(renderer, &hex_text_limits);
 
+    // The row of swatches is left out if there are no swatches.
+    let swatches_height = if swatch_count == 0 {
+        0.0
+    } else {
+        f32::from(SWATCH_SIZE) + f32::from(SPACING)
+    };
+
     let block2_limits = block2_limits.shrink(Size::new(
         0.0,
-        cancel_button.bounds().height + hex_text.bounds().height + 2.0 * f32::from(SPACING),
+        cancel_button.bounds().height
+            + hex_text.bounds().height
+            + swatches_height
+            + 2.0 * f32::from(SPACING),
     ));
 
     // RGBA Colors
@@ -859,24 +1160,47 @@ where
         hex_text.bounds().y + rgba_colors.bounds().height + f32::from(PADDING) + f32::from(SPACING),
     ));
 
+    // Swatches
+    let swatch_size = f32::from(SWATCH_SIZE);
+    let swatch_step = swatch_size + f32::from(SWATCH_SPACING);
+    let fitting_swatches =
+        ((rgba_colors.bounds().width + f32::from(SWATCH_SPACING)) / swatch_step).max(0.0) as usize;
+    let swatch_nodes = (0..swatch_count.min(fitting_swatches))
+        .map(|index| {
+            let mut node = layout::Node::new(Size::new(swatch_size, swatch_size));
+            node.move_to(Point::new(index as f32 * swatch_step, 0.0));
+            node
+        })
+        .collect();
+
+    let mut swatches = layout::Node::with_children(
+        Size::new(rgba_colors.bounds().width, swatches_height.min(swatch_size)),
+        swatch_nodes,
+    );
+    swatches.move_to(Point::new(
+        f32::from(PADDING),
+        hex_text.bounds().y + hex_text.bounds().height + f32::from(SPACING),
+    ));
+
     // Buttons
     let cancel_limits = block2_limits.clone().max_width(
         ((rgba_colors.bounds().width / 2.0) - f32::from(BUTTON_SPACING)).max(0.0) as u32,
     );
 
-    let mut cancel_button = color_picker.cancel_button.layout(renderer, &cancel_limits);
+    let mut cancel_button = cancel_button.layout(renderer, &cancel_limits);
 
     let submit_limits = block2_limits.clone().max_width(
         ((rgba_colors.bounds().width / 2.0) - f32::from(BUTTON_SPACING)).max(0.0) as u32,
     );
 
-    let mut submit_button = color_picker.submit_button.layout(renderer, &submit_limits);
+    let mut submit_button = submit_button.layout(renderer, &submit_limits);
 
     cancel_button.move_to(Point::new(
         cancel_button.bounds().x + f32::from(PADDING),
         cancel_button.bounds().y
             + rgba_colors.bounds().height
             + hex_text.bounds().height
+            + swatches_height
             + f32::from(PADDING)
             + 2.0 * f32::from(SPACING),
     ));
@@ -887,6 +1211,7 @@ where
         submit_button.bounds().y
             + rgba_colors.bounds().height
             + hex_text.bounds().height
+            + swatches_height
             + f32::from(PADDING)
             + 2.0 * f32::from(SPACING),
     ));
@@ -896,11 +1221,18 @@ where
             rgba_colors.bounds().width + (2.0 * f32::from(PADDING)),
             rgba_colors.bounds().height
                 + hex_text.bounds().height
+                + swatches_height
                 + cancel_button.bounds().height
                 + (2.0 * f32::from(PADDING))
                 + (2.0 * f32::from(SPACING)),
         ),
-        vec![rgba_colors, hex_text, cancel_button, submit_button],
+        vec![
+            rgba_colors,
+            hex_text,
+            swatches,
+            cancel_button,
+            submit_button,
+        ],
     );
     block2_node.move_to(Point::new(bounds.x, bounds.y));
 
@@ -912,15 +1244,22 @@ where
 /// Your renderer will need to implement this trait before being
 /// able to use a [`ColorPicker`](crate::native::ColorPicker) in your user
 /// interface.
-pub trait Renderer: iced_native::Renderer {
+pub trait Renderer: iced_native::Renderer + accessibility::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
     /// Draws a [`ColorPickerOverlay`](ColorPickerOverlay)
+    ///
+    /// The `hex_input` holds the hexadecimal characters typed into the
+    /// focused hex text field so far, the `swatches` hold the colors of the
+    /// palette and the recently submitted colors.
+    #[allow(clippy::too_many_arguments)]
     fn draw<Message>(
         &mut self,
         env: DrawEnvironment<'_, Self::Defaults, Self::Style, Focus>,
         color: &Color,
+        hex_input: &str,
+        swatches: &[Color],
         sat_value_canvas_cache: &canvas::Cache,
         hue_canvas_cache: &canvas::Cache,
         //text_input: &Element<'_, Message, Self>,
@@ -937,6 +1276,8 @@ impl Renderer for iced_native::renderer::Null {
         &mut self,
         _env: DrawEnvironment<'_, Self::Defaults, Self::Style, Focus>,
         _color: &Color,
+        _hex_input: &str,
+        _swatches: &[Color],
         _sat_value_canvas_cache: &canvas::Cache,
         _hue_canvas_cache: &canvas::Cache,
         //_text_input: &Element<'_, Message, Self>,
@@ -961,6 +1302,19 @@ pub struct State {
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The hexadecimal characters typed to jump to a color.
+    pub(crate) hex_input: String,
+    /// The recently submitted colors, starting with the most recent one.
+    pub(crate) recent: Vec<Color>,
+}
+
+impl State {
+    /// Remembers the given color as the most recently submitted color.
+    pub(crate) fn remember(&mut self, color: Color) {
+        self.recent.retain(|recent| *recent != color);
+        self.recent.insert(0, color);
+        self.recent.truncate(MAX_RECENT_COLORS);
+    }
 }
 
 impl Default for State {
@@ -972,6 +1326,8 @@ impl Default for State {
             color_bar_dragged: ColorBarDragged::None,
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            hex_input: String::new(),
+            recent: Vec::new(),
         }
     }
 }
@@ -1034,6 +1390,9 @@ pub enum Focus {
     /// The alpha bar is in focus.
     Alpha,
 
+    /// The hexadecimal text field is in focus.
+    Hex,
+
     /// The cancel button is in focus.
     Cancel,
 
@@ -1052,7 +1411,8 @@ impl Focus {
             Self::Red => Self::Green,
             Self::Green => Self::Blue,
             Self::Blue => Self::Alpha,
-            Self::Alpha => Self::Cancel,
+            Self::Alpha => Self::Hex,
+            Self::Hex => Self::Cancel,
             Self::Cancel => Self::Submit,
             Self::Submit | Self::None => Self::Overlay,
         }
@@ -1062,15 +1422,15 @@ impl Focus {
     #[must_use]
     pub const fn previous(self) -> Self {
         match self {
-            Self::None => Self::None,
-            Self::Overlay => Self::Submit,
+            Self::Overlay | Self::None => Self::Submit,
             Self::SatValue => Self::Overlay,
             Self::Hue => Self::SatValue,
             Self::Red => Self::Hue,
             Self::Green => Self::Red,
             Self::Blue => Self::Green,
             Self::Alpha => Self::Blue,
-            Self::Cancel => Self::Alpha,
+            Self::Hex => Self::Alpha,
+            Self::Cancel => Self::Hex,
             Self::Submit => Self::Cancel,
         }
     }
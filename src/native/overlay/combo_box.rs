@@ -0,0 +1,362 @@
+//! Use a combo box to select an option from a list filtered by the text typed
+//! into a text field.
+//!
+//! *This API requires the following crate features to be activated: `combo_box`*
+use std::{fmt::Display, hash::Hash};
+
+use iced_native::{event, mouse, overlay, touch, Clipboard, Event, Layout, Point, Size};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::{filter::filter, overlay::Position, renderer::DrawEnvironment};
+
+/// The list of suggestions of a [`ComboBox`](crate::native::ComboBox).
+#[allow(missing_debug_implementations)]
+pub struct ComboBoxOverlay<'a, T, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// The state of the [`ComboBoxOverlay`](ComboBoxOverlay).
+    state: &'a mut State,
+    /// The options of the [`ComboBox`](crate::native::ComboBox).
+    options: &'a [T],
+    /// The labels of the suggested options.
+    labels: Vec<String>,
+    /// The function that produces a message when an option is selected.
+    on_selected: &'a dyn Fn(T) -> Message,
+    /// The position of the [`ComboBoxOverlay`](ComboBoxOverlay).
+    position: Point,
+    /// The width of the [`ComboBoxOverlay`](ComboBoxOverlay).
+    width: f32,
+    /// The maximum number of suggestions shown at a time.
+    max_suggestions: usize,
+    /// The style of the [`ComboBoxOverlay`](ComboBoxOverlay).
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> ComboBoxOverlay<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    /// Creates a new [`ComboBoxOverlay`](ComboBoxOverlay) with its top left
+    /// corner on the given position.
+    pub fn new(
+        state: &'a mut State,
+        options: &'a [T],
+        on_selected: &'a dyn Fn(T) -> Message,
+        position: Point,
+        width: f32,
+        max_suggestions: usize,
+        style: &'a <Renderer as self::Renderer>::Style,
+    ) -> Self {
+        let labels = state
+            .filtered
+            .iter()
+            .map(|&index| options[index].to_string())
+            .collect();
+
+        ComboBoxOverlay {
+            state,
+            options,
+            labels,
+            on_selected,
+            position,
+            width,
+            max_suggestions,
+            style,
+        }
+    }
+
+    /// Turn this [`ComboBoxOverlay`](ComboBoxOverlay) into an overlay
+    /// [`Element`](overlay::Element).
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(self.position, Box::new(self))
+    }
+
+    /// Returns the index of the first shown suggestion, keeping the hovered
+    /// suggestion visible.
+    fn first_visible(&self, visible: usize) -> usize {
+        let first = self
+            .state
+            .scroll
+            .min(self.labels.len().saturating_sub(visible));
+
+        match self.state.hovered {
+            Some(hovered) if hovered < first => hovered,
+            Some(hovered) if hovered >= first + visible => hovered + 1 - visible,
+            _ => first,
+        }
+    }
+
+    /// Returns the index of the suggestion below the cursor.
+    fn suggestion_at(&self, layout: Layout<'_>, cursor_position: Point) -> Option<usize> {
+        let first = self.first_visible(layout.children().count());
+
+        layout
+            .children()
+            .position(|suggestion| suggestion.bounds().contains(cursor_position))
+            .map(|position| first + position)
+    }
+}
+
+impl<'a, T, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for ComboBoxOverlay<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> iced_native::layout::Node {
+        let height = f32::from(renderer.default_size() + 2 * Renderer::DEFAULT_PADDING);
+
+        // Only as many suggestions as fit into the window are shown at a time,
+        // the others are reached by scrolling.
+        let fitting = ((bounds.height / height) as usize).max(1);
+        let visible = self.labels.len().min(self.max_suggestions).min(fitting);
+
+        let suggestions = (0..visible)
+            .map(|i| {
+                let mut node = iced_native::layout::Node::new(Size::new(self.width, height));
+                node.move_to(Point::new(0.0, i as f32 * height));
+                node
+            })
+            .collect::<Vec<_>>();
+
+        let mut node = iced_native::layout::Node::with_children(
+            Size::new(self.width, suggestions.len() as f32 * height),
+            suggestions,
+        );
+
+        node.place_and_bounce(position, bounds);
+
+        node
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let visible = layout.children().count();
+        self.state.scroll = self.first_visible(visible);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if layout.bounds().contains(cursor_position) =>
+            {
+                let rows = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => {
+                        y / (layout.bounds().height / visible.max(1) as f32)
+                    }
+                }
+                .round() as isize;
+                let max_scroll = self.labels.len().saturating_sub(visible);
+
+                self.state.scroll = if rows > 0 {
+                    self.state.scroll.saturating_sub(rows as usize)
+                } else {
+                    (self.state.scroll + (-rows) as usize).min(max_scroll)
+                };
+                // The previously hovered suggestion must not scroll back.
+                self.state.hovered = None;
+                self.state.hovered = self.suggestion_at(layout, cursor_position);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(index) = self.suggestion_at(layout, cursor_position) {
+                    self.state.hovered = Some(index);
+                }
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match self.suggestion_at(layout, cursor_position) {
+                    Some(index) => {
+                        if let Some(message) =
+                            self.state.select(index, self.options, self.on_selected)
+                        {
+                            messages.push(message);
+                        }
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let visible = layout.children().count();
+        let first = self.first_visible(visible);
+
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: self.style,
+                viewport: None,
+                focus: self
+                    .state
+                    .hovered
+                    .and_then(|hovered| hovered.checked_sub(first)),
+            },
+            &self.labels[first..first + visible],
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        (self.width as u32).hash(state);
+        self.max_suggestions.hash(state);
+        self.labels.len().hash(state);
+    }
+}
+
+/// The renderer of a [`ComboBoxOverlay`](ComboBoxOverlay).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`ComboBox`](crate::native::ComboBox) in your user
+/// interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default padding of a suggestion.
+    const DEFAULT_PADDING: u16;
+
+    /// The default text size of a suggestion.
+    fn default_size(&self) -> u16;
+
+    /// Draws a [`ComboBoxOverlay`](ComboBoxOverlay).
+    ///
+    /// The labels are the ones of the shown suggestions. The focus of the
+    /// environment is the index of the hovered suggestion among them.
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, Option<usize>>,
+        labels: &[String],
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 0;
+
+    fn default_size(&self) -> u16 {
+        0
+    }
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, Option<usize>>,
+        _labels: &[String],
+    ) -> Self::Output {
+    }
+}
+
+/// The state of the [`ComboBoxOverlay`](ComboBoxOverlay).
+#[derive(Debug, Default)]
+pub struct State {
+    /// The text typed into the text field of the
+    /// [`ComboBox`](crate::native::ComboBox).
+    pub(crate) text: String,
+    /// Whether the list of suggestions is shown.
+    pub(crate) is_open: bool,
+    /// The index of the hovered suggestion.
+    pub(crate) hovered: Option<usize>,
+    /// The index of the first shown suggestion.
+    pub(crate) scroll: usize,
+    /// The indices of the options matching the text.
+    pub(crate) filtered: Vec<usize>,
+}
+
+impl State {
+    /// Filters the given options by the text.
+    pub(crate) fn filter<T: Display>(&mut self, options: &[T]) {
+        self.filtered = filter(options, &self.text);
+
+        if self.hovered.map_or(false, |i| i >= self.filtered.len()) {
+            self.hovered = None;
+        }
+
+        self.scroll = self.scroll.min(self.filtered.len().saturating_sub(1));
+    }
+
+    /// Hovers the next suggestion, opening the list if it is closed.
+    pub(crate) fn hover_next(&mut self) {
+        self.is_open = true;
+
+        if !self.filtered.is_empty() {
+            self.hovered = Some(
+                self.hovered
+                    .map_or(0, |i| (i + 1).min(self.filtered.len() - 1)),
+            );
+        }
+    }
+
+    /// Hovers the previous suggestion.
+    pub(crate) fn hover_previous(&mut self) {
+        self.hovered = self.hovered.map(|i| i.saturating_sub(1));
+    }
+
+    /// Selects the suggestion at the given index and closes the list.
+    ///
+    /// Returns the message produced for the selected option.
+    pub(crate) fn select<T: Clone + Display, Message>(
+        &mut self,
+        index: usize,
+        options: &[T],
+        on_selected: &dyn Fn(T) -> Message,
+    ) -> Option<Message> {
+        let selected = *self.filtered.get(index)?;
+        let option = options[selected].clone();
+
+        #[cfg(feature = "instrumentation")]
+        instrumentation::record(WidgetKind::ComboBox, Action::Select(selected));
+
+        self.text = option.to_string();
+        self.is_open = false;
+        self.hovered = None;
+        self.scroll = 0;
+        self.filter(options);
+
+        Some(on_selected(option))
+    }
+
+    /// Closes the list of suggestions.
+    pub(crate) fn close(&mut self) {
+        self.is_open = false;
+        self.hovered = None;
+        self.scroll = 0;
+    }
+}
@@ -0,0 +1,232 @@
+//! A context menu for showing actions on right click.
+//!
+//! *This API requires the following crate features to be activated: `context_menu`*
+use std::hash::Hash;
+
+use iced_native::{
+    event, keyboard, layout::Limits, mouse, overlay, touch, Clipboard, Element, Event, Layout,
+    Point, Size,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::{
+    core::{overlay::Position, renderer::DrawEnvironment},
+    native::context_menu,
+};
+
+/// The overlay of the [`ContextMenu`](crate::native::ContextMenu).
+#[allow(missing_debug_implementations)]
+pub struct ContextMenuOverlay<'a, S, Content, Message, Renderer>
+where
+    S: 'a,
+    Content: Fn(&'a mut S) -> Element<'a, Message, Renderer>,
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    /// The state of the [`ContextMenuOverlay`](ContextMenuOverlay).
+    state: &'a mut context_menu::State<S>,
+    /// The content of the [`Overlay`](Overlay).
+    content: Content,
+    /// The style of the [`Overlay`](Overlay).
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, S, Content, Message, Renderer> ContextMenuOverlay<'a, S, Content, Message, Renderer>
+where
+    S: 'a,
+    Content: Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`ContextMenuOverlay`](ContextMenuOverlay).
+    pub fn new(
+        state: &'a mut context_menu::State<S>,
+        content: Content,
+        style: &'a <Renderer as self::Renderer>::Style,
+    ) -> Self {
+        ContextMenuOverlay {
+            state,
+            content,
+            style,
+        }
+    }
+
+    /// Turn this [`ContextMenuOverlay`] into an overlay
+    /// [`Element`](iced_native::overlay::Element) at the given position.
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(Overlay::new(self)))
+    }
+}
+
+/// The [`Overlay`](Overlay) of the [`ContextMenu`](crate::native::ContextMenu).
+struct Overlay<'a, Message, Renderer: self::Renderer> {
+    /// The visibility of the [`ContextMenu`](crate::native::ContextMenu).
+    show: &'a mut bool,
+    /// The content of the [`Overlay`](Overlay).
+    content: Element<'a, Message, Renderer>,
+    /// The style of the [`Overlay`](Overlay).
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Overlay<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    /// Creates a new [`Overlay`](Overlay) from the given
+    /// [`ContextMenuOverlay`](ContextMenuOverlay).
+    pub fn new<S, Content>(
+        context_menu: ContextMenuOverlay<'a, S, Content, Message, Renderer>,
+    ) -> Self
+    where
+        Content: Fn(&mut S) -> Element<'_, Message, Renderer>,
+    {
+        let ContextMenuOverlay {
+            state,
+            content,
+            style,
+        } = context_menu;
+
+        let context_menu::State { show, state, .. } = state;
+
+        Self {
+            show,
+            content: content(state),
+            style,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> iced_native::layout::Node {
+        let limits = Limits::new(Size::ZERO, bounds);
+
+        let mut content = self.content.layout(renderer, &limits);
+
+        content.place_and_bounce(position, bounds);
+
+        content
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                #[cfg(feature = "instrumentation")]
+                instrumentation::record(WidgetKind::ContextMenu, Action::Escape);
+                *self.show = false;
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !layout.bounds().contains(cursor_position) =>
+            {
+                #[cfg(feature = "instrumentation")]
+                instrumentation::record(WidgetKind::ContextMenu, Action::Backdrop);
+                *self.show = false;
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let messages_before = messages.len();
+
+        let status = self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        );
+
+        // An entry of the menu was selected.
+        if messages.len() > messages_before {
+            #[cfg(feature = "instrumentation")]
+            instrumentation::record(WidgetKind::ContextMenu, Action::Submit);
+            *self.show = false;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: self.style,
+                viewport: None,
+                focus: (),
+            },
+            &self.content,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        self.content.hash_layout(state);
+    }
+}
+
+/// The renderer of a [`ContextMenuOverlay`](ContextMenuOverlay).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`ContextMenu`](crate::native::ContextMenu) in your user
+/// interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`ContextMenuOverlay`](ContextMenuOverlay).
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        content: &Element<'_, Message, Self>,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _content: &Element<'_, Message, Self>,
+    ) -> Self::Output {
+    }
+}
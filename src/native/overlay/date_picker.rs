@@ -3,7 +3,7 @@
 //! *This API requires the following crate features to be activated: `date_picker`*
 use std::hash::Hash;
 
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Local, NaiveDate};
 use iced_native::{
     button, column, container, event, keyboard,
     layout::{self, Limits},
@@ -11,9 +11,12 @@ use iced_native::{
     Layout, Length, Point, Row, Size, Text, Widget,
 };
 
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
 use crate::{
     core::{
-        date::{Date, IsInMonth},
+        accessibility::{self, Accessibility, Node, Role},
+        date::{Date, Locale},
         overlay::Position,
         renderer::DrawEnvironment,
     },
@@ -29,6 +32,71 @@ const SPACING: u16 = 15;
 const DAY_CELL_PADDING: u16 = 7;
 /// The spacing between the buttons.
 const BUTTON_SPACING: u16 = 5;
+/// The maximum number of days skipped while stepping over disabled dates.
+const MAX_SKIPPED_DAYS: usize = 366;
+
+/// The function that produces a message when the submit button of a
+/// [`DatePickerOverlay`](DatePickerOverlay) is pressed.
+#[allow(missing_debug_implementations)]
+pub enum OnSubmit<'a, Message> {
+    /// Submits the picked date.
+    Date(&'a dyn Fn(Date) -> Message),
+
+    /// Submits the start and end date of the picked range.
+    Range(&'a dyn Fn(Date, Date) -> Message),
+}
+
+/// The dates that can be picked in a [`DatePickerOverlay`](DatePickerOverlay).
+#[allow(missing_debug_implementations)]
+#[derive(Clone, Copy, Default)]
+pub struct DateBounds<'a> {
+    /// The earliest date that can be picked.
+    pub min: Option<NaiveDate>,
+
+    /// The latest date that can be picked.
+    pub max: Option<NaiveDate>,
+
+    /// The predicate returning true for the dates that can't be picked.
+    pub disabled: Option<&'a dyn Fn(Date) -> bool>,
+}
+
+impl<'a> DateBounds<'a> {
+    /// Checks if the given date lies between the earliest and the latest date.
+    #[must_use]
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.min.map_or(true, |min| min <= date) && self.max.map_or(true, |max| date <= max)
+    }
+
+    /// Checks if the given date can be picked.
+    #[must_use]
+    pub fn is_enabled(&self, date: NaiveDate) -> bool {
+        self.contains(date)
+            && !self
+                .disabled
+                .map_or(false, |disabled| disabled(date.into()))
+    }
+
+    /// Clamps the given date between the earliest and the latest date.
+    #[must_use]
+    pub fn clamp(&self, date: NaiveDate) -> NaiveDate {
+        let date = self.min.map_or(date, |min| date.max(min));
+        self.max.map_or(date, |max| date.min(max))
+    }
+
+    /// Steps from the given date with the given function to the next date that
+    /// can be picked, skipping disabled dates.
+    ///
+    /// Stays on the given date if there is no such date between the earliest
+    /// and the latest date.
+    #[must_use]
+    pub fn step(&self, date: NaiveDate, f: fn(NaiveDate) -> NaiveDate) -> NaiveDate {
+        std::iter::successors(Some(f(date)), |next| Some(f(*next)))
+            .take(MAX_SKIPPED_DAYS)
+            .take_while(|next| self.contains(*next))
+            .find(|next| self.is_enabled(*next))
+            .unwrap_or(date)
+    }
+}
 
 /// The overlay of the [`DatePicker`](crate::native::DatePicker).
 #[allow(missing_debug_implementations)]
@@ -43,10 +111,20 @@ where
     cancel_button: Element<'a, Message, Renderer>,
     /// The submit button of the [`DatePickerOverlay`](DatePickerOverlay).
     submit_button: Element<'a, Message, Renderer>,
+    /// The message that is send if the [`DatePickerOverlay`](DatePickerOverlay)
+    /// is cancelled.
+    on_cancel: Message,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`](DatePickerOverlay) is pressed.
-    on_submit: &'a dyn Fn(Date) -> Message,
+    on_submit: OnSubmit<'a, Message>,
     /// The position of the [`DatePickerOverlay`](DatePickerOverlay).
     position: Point,
+    /// The dates that can be picked in the [`DatePickerOverlay`](DatePickerOverlay).
+    bounds: DateBounds<'a>,
+    /// Whether the position is the top left corner of the
+    /// [`DatePickerOverlay`](DatePickerOverlay) instead of its center.
+    anchored: bool,
+    /// The accessibility information of the [`DatePickerOverlay`](DatePickerOverlay).
+    accessibility: Node<'a>,
     /// The style of teh [`DatePickerOverlay`](DatePickerOverlay).
     style: &'a <Renderer as self::Renderer>::Style,
 }
@@ -65,10 +143,13 @@ where
 {
     /// Creates a new [`DatePickerOverlay`](DatePickerOverlay) on the given
     /// position.
+    ///
+    /// If the overlay submits a range, the user picks the start and the end
+    /// date of the range by selecting two days in the calendar.
     pub fn new(
         state: &'a mut date_picker::State,
         on_cancel: Message,
-        on_submit: &'a dyn Fn(Date) -> Message,
+        on_submit: OnSubmit<'a, Message>,
         position: Point,
         style: &'a <Renderer as self::Renderer>::Style,
         //button_style: impl Clone +  Into<<Renderer as button::Renderer>::Style>, // clone not satisfied
@@ -92,15 +173,47 @@ where
                 IconText::new(Icon::Check).width(Length::Fill),
             )
             .width(Length::Fill)
-            .on_press(on_cancel) // Sending a fake message
+            .on_press(on_cancel.clone()) // Sending a fake message
             //.style(button_style)
             .into(),
+            on_cancel,
             on_submit,
             position,
+            bounds: DateBounds::default(),
+            anchored: false,
+            accessibility: Node::new(Role::Dialog),
             style,
         }
     }
 
+    /// Restricts the dates that can be picked in the
+    /// [`DatePickerOverlay`](DatePickerOverlay) to the given
+    /// [`DateBounds`](DateBounds).
+    ///
+    /// The selected date is clamped between the earliest and the latest date.
+    #[must_use]
+    pub fn bounds(mut self, bounds: DateBounds<'a>) -> Self {
+        self.state.date = bounds.clamp(self.state.date);
+        self.bounds = bounds;
+        self
+    }
+
+    /// Places the top left corner of the [`DatePickerOverlay`](DatePickerOverlay)
+    /// on its position instead of centering it around the position.
+    #[must_use]
+    pub fn anchored(mut self) -> Self {
+        self.anchored = true;
+        self
+    }
+
+    /// Sets the [`Accessibility`](Accessibility) information announced for
+    /// the [`DatePickerOverlay`](DatePickerOverlay) as a dialog.
+    #[must_use]
+    pub fn accessibility(mut self, accessibility: &'a Accessibility) -> Self {
+        self.accessibility = accessibility.node(Role::Dialog);
+        self
+    }
+
     /// Turn this [`DatePickerOverlay`](DatePickerOverlay) into an overlay
     /// [`Element`](overlay::Element).
     #[must_use]
@@ -108,6 +221,11 @@ where
         overlay::Element::new(self.position, Box::new(self))
     }
 
+    /// Checks if the [`DatePickerOverlay`](DatePickerOverlay) picks a range.
+    const fn is_range(&self) -> bool {
+        matches!(self.on_submit, OnSubmit::Range(_))
+    }
+
     /// String representation of the current year.
     fn year_as_string(&self) -> String {
         crate::core::date::year_as_string(self.state.date)
@@ -115,181 +233,47 @@ where
 
     /// String representation of the current month.
     fn month_as_string(&self) -> String {
-        crate::core::date::month_as_string(self.state.date)
-    }
-
-    /// The event handling for the month / year bar.
-    fn on_event_month_year(
-        &mut self,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        _messages: &mut Vec<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
-    ) -> event::Status {
-        let mut children = layout.children();
-
-        let mut status = event::Status::Ignored;
-
-        // ----------- Month ----------------------
-        let month_layout = children
-            .next()
-            .expect("Native: Layout should have a month layout");
-        let mut month_children = month_layout.children();
-
-        let left_bounds = month_children
-            .next()
-            .expect("Native: Layout should have a left month arrow layout")
-            .bounds();
-        let _center_bounds = month_children
-            .next()
-            .expect("Native: Layout should have a center month layout")
-            .bounds();
-        let right_bounds = month_children
-            .next()
-            .expect("Native: Layout should have a right month arrow layout")
-            .bounds();
-
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if month_layout.bounds().contains(cursor_position) {
-                    self.state.focus = Focus::Month;
-                }
-
-                if left_bounds.contains(cursor_position) {
-                    self.state.date = crate::core::date::pred_month(self.state.date);
-                    status = event::Status::Captured;
-                } else if right_bounds.contains(cursor_position) {
-                    self.state.date = crate::core::date::succ_month(self.state.date);
-                    status = event::Status::Captured;
-                }
-            }
-            _ => {}
-        }
-
-        // ----------- Year -----------------------
-        let year_layout = children
-            .next()
-            .expect("Native: Layout should have a year layout");
-        let mut year_children = year_layout.children();
-
-        let left_bounds = year_children
-            .next()
-            .expect("Native: Layout should have a left year arrow layout")
-            .bounds();
-        let _center_bounds = year_children
-            .next()
-            .expect("Native: Layout should have a center year layout")
-            .bounds();
-        let right_bounds = year_children
-            .next()
-            .expect("Native: Layout should have a right year arrow layout")
-            .bounds();
-
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if year_layout.bounds().contains(cursor_position) {
-                    self.state.focus = Focus::Year;
-                }
-
-                if left_bounds.contains(cursor_position) {
-                    self.state.date = crate::core::date::pred_year(self.state.date);
-                    status = event::Status::Captured;
-                } else if right_bounds.contains(cursor_position) {
-                    self.state.date = crate::core::date::succ_year(self.state.date);
-                    status = event::Status::Captured;
-                }
-            }
-            _ => {}
-        }
-
-        status
+        self.state.locale.month_name(self.state.date).to_owned()
     }
 
-    /// The event handling for the calendar days.
-    fn on_event_days(
-        &mut self,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        _messages: &mut Vec<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
-    ) -> event::Status {
-        let mut children = layout.children();
-
-        let _day_labels_layout = children
-            .next()
-            .expect("Native: Layout should have a day label layout");
-
-        let mut status = event::Status::Ignored;
-
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if layout.bounds().contains(cursor_position) {
-                    self.state.focus = Focus::Day;
-                }
-
-                'outer: for (y, row) in children.enumerate() {
-                    for (x, label) in row.children().enumerate() {
-                        let bounds = label.bounds();
-                        if bounds.contains(cursor_position) {
-                            let (day, is_in_month) = crate::core::date::position_to_day(
-                                x,
-                                y,
-                                self.state.date.year(),
-                                self.state.date.month(),
-                            );
-
-                            self.state.date = match is_in_month {
-                                IsInMonth::Previous => {
-                                    crate::core::date::pred_month(self.state.date)
-                                        .with_day(day as u32)
-                                        .expect("Previous month with day should be valid")
-                                }
-                                IsInMonth::Same => self
-                                    .state
-                                    .date
-                                    .with_day(day as u32)
-                                    .expect("Same month with day should be valid"),
-                                IsInMonth::Next => crate::core::date::succ_month(self.state.date)
-                                    .with_day(day as u32)
-                                    .expect("Succeeding month with day should be valid"),
-                            };
-
-                            status = event::Status::Captured;
-                            break 'outer;
-                        }
-                    }
-                }
-            }
-            _ => {}
+    /// Produces the submit message with the picked date or range of dates,
+    /// if they can be picked.
+    fn submit(&self, messages: &mut Vec<Message>) {
+        let (start, end) = match self.on_submit {
+            OnSubmit::Date(_) => (self.state.date, self.state.date),
+            OnSubmit::Range(_) => self
+                .state
+                .range()
+                .unwrap_or((self.state.date, self.state.date)),
+        };
+
+        if self.bounds.is_enabled(start) && self.bounds.is_enabled(end) {
+            #[cfg(feature = "instrumentation")]
+            instrumentation::record(WidgetKind::DatePicker, Action::Submit);
+
+            messages.push(match self.on_submit {
+                OnSubmit::Date(on_submit) => on_submit(start.into()),
+                OnSubmit::Range(on_submit) => on_submit(start.into(), end.into()),
+            });
         }
-
-        status
     }
 
     /// The event handling for the keyboard input.
+    ///
+    /// Tab and Shift+Tab cycle the focus through the month, the year, the
+    /// days and the buttons, while the arrow keys change the focused part.
+    /// Escape cancels the [`DatePickerOverlay`](DatePickerOverlay) and Enter
+    /// submits it, unless the cancel button is focused.
     fn on_event_keyboard(
         &mut self,
         event: &Event,
         _layout: Layout<'_>,
         _cursor_position: Point,
-        _messages: &mut Vec<Message>,
+        messages: &mut Vec<Message>,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
     ) -> event::Status {
-        if self.state.focus == Focus::None {
-            return event::Status::Ignored;
-        }
-
         if let Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) = event {
-            let mut status = event::Status::Ignored;
-
             match key_code {
                 keyboard::KeyCode::Tab => {
                     if self.state.keyboard_modifiers.shift {
@@ -297,54 +281,35 @@ where
                     } else {
                         self.state.focus = self.state.focus.next();
                     }
+                    event::Status::Captured
                 }
-                _ => match self.state.focus {
-                    Focus::Month => match key_code {
-                        keyboard::KeyCode::Left => {
-                            self.state.date = crate::core::date::pred_month(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        keyboard::KeyCode::Right => {
-                            self.state.date = crate::core::date::succ_month(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    },
-                    Focus::Year => match key_code {
-                        keyboard::KeyCode::Left => {
-                            self.state.date = crate::core::date::pred_year(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        keyboard::KeyCode::Right => {
-                            self.state.date = crate::core::date::succ_year(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    },
-                    Focus::Day => match key_code {
-                        keyboard::KeyCode::Left => {
-                            self.state.date = crate::core::date::pred_day(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        keyboard::KeyCode::Right => {
-                            self.state.date = crate::core::date::succ_day(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        keyboard::KeyCode::Up => {
-                            self.state.date = crate::core::date::pred_week(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        keyboard::KeyCode::Down => {
-                            self.state.date = crate::core::date::succ_week(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    },
-                    _ => {}
-                },
-            }
+                keyboard::KeyCode::Escape => {
+                    #[cfg(feature = "instrumentation")]
+                    instrumentation::record(WidgetKind::DatePicker, Action::Escape);
+
+                    messages.push(self.on_cancel.clone());
+                    event::Status::Captured
+                }
+                _ if self.state.focus == Focus::None => event::Status::Ignored,
+                keyboard::KeyCode::Enter if self.state.focus == Focus::Cancel => {
+                    #[cfg(feature = "instrumentation")]
+                    instrumentation::record(WidgetKind::DatePicker, Action::Cancel);
 
-            status
+                    messages.push(self.on_cancel.clone());
+                    event::Status::Captured
+                }
+                _ => {
+                    let status =
+                        calendar_on_key(self.state, &self.bounds, self.is_range(), key_code);
+
+                    if status == event::Status::Ignored && *key_code == keyboard::KeyCode::Enter {
+                        self.submit(messages);
+                        event::Status::Captured
+                    } else {
+                        status
+                    }
+                }
+            }
         } else if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
             self.state.keyboard_modifiers = *modifiers;
             event::Status::Ignored
@@ -390,85 +355,7 @@ where
             cancel_button.bounds().height + f32::from(SPACING),
         ));
 
-        // Month/Year
-        let font_size = u32::from(text::Renderer::default_size(renderer));
-
-        let month_year = Row::<(), Renderer>::new()
-            .width(Length::Fill)
-            .spacing(SPACING)
-            .push(
-                Row::new()
-                    .width(Length::Fill)
-                    .push(
-                        Row::new() // Left Month arrow
-                            .width(Length::Units(font_size as u16))
-                            .height(Length::Fill)
-                            .max_height(font_size),
-                    )
-                    .push(
-                        // Month
-                        Text::new("")
-                            .width(Length::Fill)
-                            .height(Length::Units(font_size as u16)),
-                    )
-                    .push(
-                        // Right Month arrow
-                        Row::new()
-                            .width(Length::Units(font_size as u16))
-                            .height(Length::Fill)
-                            .max_height(font_size),
-                    ),
-            )
-            .push(
-                Row::new()
-                    .width(Length::Fill)
-                    .push(
-                        Row::new() // Left Year arrow
-                            .width(Length::Units(font_size as u16))
-                            .height(Length::Fill)
-                            .max_height(font_size),
-                    )
-                    .push(
-                        // Year
-                        Text::new("")
-                            .width(Length::Fill)
-                            .height(Length::Units(font_size as u16)),
-                    )
-                    .push(
-                        // Right Year arrow
-                        Row::new()
-                            .width(Length::Units(font_size as u16))
-                            .height(Length::Fill)
-                            .max_height(font_size),
-                    ),
-            );
-
-        let days = Container::<(), Renderer>::new((0..7).into_iter().fold(
-            Column::new().height(Length::Fill),
-            |column, _y| {
-                column.push((0..7).into_iter().fold(
-                    Row::new().height(Length::Fill).padding(DAY_CELL_PADDING),
-                    |row, _x| {
-                        row.push(
-                            Row::new()
-                                .width(Length::Fill)
-                                .height(Length::Fill)
-                                .max_width(font_size)
-                                .max_height(font_size),
-                        )
-                    },
-                ))
-            },
-        ))
-        .height(Length::Fill)
-        .center_y();
-
-        let mut col = Column::<(), Renderer>::new()
-            .spacing(SPACING)
-            .align_items(Align::Center)
-            .push(month_year)
-            .push(days)
-            .layout(renderer, &limits);
+        let mut col = calendar_layout(renderer, &limits);
 
         col.move_to(Point::new(
             col.bounds().x + f32::from(PADDING),
@@ -516,7 +403,11 @@ where
             vec![col, cancel_button, submit_button],
         );
 
-        node.center_and_bounce(position, bounds);
+        if self.anchored {
+            node.place_and_bounce(position, bounds);
+        } else {
+            node.center_and_bounce(position, bounds);
+        }
 
         node
     }
@@ -543,38 +434,17 @@ where
 
         let mut children = layout.children();
 
-        let mut date_children = children
-            .next()
-            .expect("Native: Layout should have date children")
-            .children();
-
-        // ----------- Year/Month----------------------
-        let month_year_layout = date_children
-            .next()
-            .expect("Native: Layout should have a month/year layout");
-        let month_year_status = self.on_event_month_year(
-            &event,
-            month_year_layout,
-            cursor_position,
-            messages,
-            renderer,
-            clipboard,
-        );
-
-        // ----------- Days ----------------------
-        let days_layout = date_children
+        // ----------- Calendar ----------------------
+        let calendar_layout = children
             .next()
-            .expect("Native: Layout should have a days table parent")
-            .children()
-            .next()
-            .expect("Native: Layout should have a days table layout");
-        let days_status = self.on_event_days(
+            .expect("Native: Layout should have a calendar layout");
+        let calendar_status = calendar_on_event(
+            self.state,
+            &self.bounds,
+            self.is_range(),
             &event,
-            days_layout,
+            calendar_layout,
             cursor_position,
-            messages,
-            renderer,
-            clipboard,
         );
 
         // ----------- Buttons ------------------------
@@ -582,6 +452,9 @@ where
             .next()
             .expect("Native: Layout should have a cancel button layout for a DatePicker");
 
+        #[cfg(feature = "instrumentation")]
+        let message_count = messages.len();
+
         let cancel_status = self.cancel_button.on_event(
             event.clone(),
             cancel_button_layout,
@@ -591,6 +464,11 @@ where
             messages,
         );
 
+        #[cfg(feature = "instrumentation")]
+        if messages.len() > message_count {
+            instrumentation::record(WidgetKind::DatePicker, Action::Cancel);
+        }
+
         let submit_button_layout = children
             .next()
             .expect("Native: Layout should have a submit button layout for a DatePicker");
@@ -607,13 +485,10 @@ where
         );
 
         if !fake_messages.is_empty() {
-            messages.push((self.on_submit)(self.state.date.into()));
+            self.submit(messages);
         }
 
-        month_year_status
-            .merge(days_status)
-            .merge(cancel_status)
-            .merge(submit_status)
+        calendar_status.merge(cancel_status).merge(submit_status)
     }
 
     fn draw(
@@ -623,6 +498,8 @@ where
         layout: iced_native::Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        accessibility::Renderer::announce(renderer, self.accessibility, layout.bounds());
+
         <Renderer as self::Renderer>::draw(
             renderer,
             DrawEnvironment {
@@ -634,6 +511,13 @@ where
                 focus: self.state.focus,
             },
             self.state.date,
+            if self.is_range() {
+                self.state.range()
+            } else {
+                None
+            },
+            &|date| self.bounds.is_enabled(date),
+            &self.state.locale,
             &self.year_as_string(),
             &self.month_as_string(),
             &self.cancel_button,
@@ -648,23 +532,361 @@ where
 
         (position.x as u32).hash(state);
         (position.y as u32).hash(state);
+        self.anchored.hash(state);
     }
 }
 
+/// Defines the layout of the calendar, i.e. the month / year bar and the day
+/// table, within the given limits.
+///
+/// This is shared between the [`DatePickerOverlay`](DatePickerOverlay) and
+/// the [`Calendar`](crate::native::date_picker::Calendar).
+pub(crate) fn calendar_layout<Renderer>(renderer: &Renderer, limits: &Limits) -> layout::Node
+where
+    Renderer: column::Renderer + container::Renderer + row::Renderer + text::Renderer,
+{
+    // Month/Year
+    let font_size = u32::from(text::Renderer::default_size(renderer));
+
+    let month_year = Row::<(), Renderer>::new()
+        .width(Length::Fill)
+        .spacing(SPACING)
+        .push(
+            Row::new()
+                .width(Length::Fill)
+                .push(
+                    Row::new() // Left Month arrow
+                        .width(Length::Units(font_size as u16))
+                        .height(Length::Fill)
+                        .max_height(font_size),
+                )
+                .push(
+                    // Month
+                    Text::new("")
+                        .width(Length::Fill)
+                        .height(Length::Units(font_size as u16)),
+                )
+                .push(
+                    // Right Month arrow
+                    Row::new()
+                        .width(Length::Units(font_size as u16))
+                        .height(Length::Fill)
+                        .max_height(font_size),
+                ),
+        )
+        .push(
+            Row::new()
+                .width(Length::Fill)
+                .push(
+                    Row::new() // Left Year arrow
+                        .width(Length::Units(font_size as u16))
+                        .height(Length::Fill)
+                        .max_height(font_size),
+                )
+                .push(
+                    // Year
+                    Text::new("")
+                        .width(Length::Fill)
+                        .height(Length::Units(font_size as u16)),
+                )
+                .push(
+                    // Right Year arrow
+                    Row::new()
+                        .width(Length::Units(font_size as u16))
+                        .height(Length::Fill)
+                        .max_height(font_size),
+                ),
+        );
+
+    let days = Container::<(), Renderer>::new((0..7).into_iter().fold(
+        Column::new().height(Length::Fill),
+        |column, _y| {
+            column.push((0..7).into_iter().fold(
+                Row::new().height(Length::Fill).padding(DAY_CELL_PADDING),
+                |row, _x| {
+                    row.push(
+                        Row::new()
+                            .width(Length::Fill)
+                            .height(Length::Fill)
+                            .max_width(font_size)
+                            .max_height(font_size),
+                    )
+                },
+            ))
+        },
+    ))
+    .height(Length::Fill)
+    .center_y();
+
+    Column::<(), Renderer>::new()
+        .spacing(SPACING)
+        .align_items(Align::Center)
+        .push(month_year)
+        .push(days)
+        .layout(renderer, limits)
+}
+
+/// Handles the mouse and touch events of the calendar.
+///
+/// This is shared between the [`DatePickerOverlay`](DatePickerOverlay) and
+/// the [`Calendar`](crate::native::date_picker::Calendar).
+pub(crate) fn calendar_on_event(
+    state: &mut State,
+    bounds: &DateBounds<'_>,
+    is_range: bool,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> event::Status {
+    let mut children = layout.children();
+
+    let month_year_layout = children
+        .next()
+        .expect("Native: Layout should have a month/year layout");
+    let month_year_status =
+        on_event_month_year(state, bounds, event, month_year_layout, cursor_position);
+
+    let days_layout = children
+        .next()
+        .expect("Native: Layout should have a days table parent")
+        .children()
+        .next()
+        .expect("Native: Layout should have a days table layout");
+    let days_status = on_event_days(state, bounds, is_range, event, days_layout, cursor_position);
+
+    month_year_status.merge(days_status)
+}
+
+/// Handles the pressed key for the focused part of the calendar.
+///
+/// This is shared between the [`DatePickerOverlay`](DatePickerOverlay) and
+/// the [`Calendar`](crate::native::date_picker::Calendar).
+pub(crate) fn calendar_on_key(
+    state: &mut State,
+    bounds: &DateBounds<'_>,
+    is_range: bool,
+    key_code: &keyboard::KeyCode,
+) -> event::Status {
+    let mut status = event::Status::Ignored;
+
+    match state.focus {
+        Focus::Month => match key_code {
+            keyboard::KeyCode::Left => {
+                state.date = bounds.clamp(crate::core::date::pred_month(state.date));
+                status = event::Status::Captured;
+            }
+            keyboard::KeyCode::Right => {
+                state.date = bounds.clamp(crate::core::date::succ_month(state.date));
+                status = event::Status::Captured;
+            }
+            _ => {}
+        },
+        Focus::Year => match key_code {
+            keyboard::KeyCode::Left => {
+                state.date = bounds.clamp(crate::core::date::pred_year(state.date));
+                status = event::Status::Captured;
+            }
+            keyboard::KeyCode::Right => {
+                state.date = bounds.clamp(crate::core::date::succ_year(state.date));
+                status = event::Status::Captured;
+            }
+            _ => {}
+        },
+        Focus::Day => match key_code {
+            keyboard::KeyCode::Left => {
+                state.date = bounds.step(state.date, crate::core::date::pred_day);
+                status = event::Status::Captured;
+            }
+            keyboard::KeyCode::Right => {
+                state.date = bounds.step(state.date, crate::core::date::succ_day);
+                status = event::Status::Captured;
+            }
+            keyboard::KeyCode::Up => {
+                state.date = bounds.step(state.date, crate::core::date::pred_week);
+                status = event::Status::Captured;
+            }
+            keyboard::KeyCode::Down => {
+                state.date = bounds.step(state.date, crate::core::date::succ_week);
+                status = event::Status::Captured;
+            }
+            keyboard::KeyCode::Enter if is_range && bounds.is_enabled(state.date) => {
+                state.pick_range_bound(state.date);
+                status = event::Status::Captured;
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+
+    status
+}
+
+/// The event handling for the month / year bar.
+fn on_event_month_year(
+    state: &mut State,
+    bounds: &DateBounds<'_>,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> event::Status {
+    let mut children = layout.children();
+
+    let mut status = event::Status::Ignored;
+
+    // ----------- Month ----------------------
+    let month_layout = children
+        .next()
+        .expect("Native: Layout should have a month layout");
+    let mut month_children = month_layout.children();
+
+    let left_bounds = month_children
+        .next()
+        .expect("Native: Layout should have a left month arrow layout")
+        .bounds();
+    let _center_bounds = month_children
+        .next()
+        .expect("Native: Layout should have a center month layout")
+        .bounds();
+    let right_bounds = month_children
+        .next()
+        .expect("Native: Layout should have a right month arrow layout")
+        .bounds();
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if month_layout.bounds().contains(cursor_position) {
+                state.focus = Focus::Month;
+            }
+
+            if left_bounds.contains(cursor_position) {
+                state.date = bounds.clamp(crate::core::date::pred_month(state.date));
+                status = event::Status::Captured;
+            } else if right_bounds.contains(cursor_position) {
+                state.date = bounds.clamp(crate::core::date::succ_month(state.date));
+                status = event::Status::Captured;
+            }
+        }
+        _ => {}
+    }
+
+    // ----------- Year -----------------------
+    let year_layout = children
+        .next()
+        .expect("Native: Layout should have a year layout");
+    let mut year_children = year_layout.children();
+
+    let left_bounds = year_children
+        .next()
+        .expect("Native: Layout should have a left year arrow layout")
+        .bounds();
+    let _center_bounds = year_children
+        .next()
+        .expect("Native: Layout should have a center year layout")
+        .bounds();
+    let right_bounds = year_children
+        .next()
+        .expect("Native: Layout should have a right year arrow layout")
+        .bounds();
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if year_layout.bounds().contains(cursor_position) {
+                state.focus = Focus::Year;
+            }
+
+            if left_bounds.contains(cursor_position) {
+                state.date = bounds.clamp(crate::core::date::pred_year(state.date));
+                status = event::Status::Captured;
+            } else if right_bounds.contains(cursor_position) {
+                state.date = bounds.clamp(crate::core::date::succ_year(state.date));
+                status = event::Status::Captured;
+            }
+        }
+        _ => {}
+    }
+
+    status
+}
+
+/// The event handling for the calendar days.
+fn on_event_days(
+    state: &mut State,
+    bounds: &DateBounds<'_>,
+    is_range: bool,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> event::Status {
+    let mut children = layout.children();
+
+    let _day_labels_layout = children
+        .next()
+        .expect("Native: Layout should have a day label layout");
+
+    let mut status = event::Status::Ignored;
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if layout.bounds().contains(cursor_position) {
+                state.focus = Focus::Day;
+            }
+
+            'outer: for (y, row) in children.enumerate() {
+                for (x, label) in row.children().enumerate() {
+                    if label.bounds().contains(cursor_position) {
+                        let date = crate::core::date::position_to_date(
+                            x,
+                            y,
+                            state.date,
+                            state.locale.first_weekday,
+                        );
+
+                        if bounds.is_enabled(date) {
+                            state.date = date;
+
+                            if is_range {
+                                state.pick_range_bound(date);
+                            }
+                        }
+
+                        status = event::Status::Captured;
+                        break 'outer;
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    status
+}
+
 /// The renderer of a [`DatePickerOverlay`](DatePickerOverlay).
 ///
 /// Your renderer will need to implement this trait before being
 /// able to use a [`DatePicker`](crate::native::DatePicker) in your user
 /// interface.
-pub trait Renderer: iced_native::Renderer {
+pub trait Renderer: iced_native::Renderer + accessibility::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
     /// Draws a [`DatePickerOverlay`](DatePickerOverlay).
+    ///
+    /// The range contains the start and end date of the range picked so far,
+    /// if the [`DatePickerOverlay`](DatePickerOverlay) picks a range. The days
+    /// for which `is_enabled` returns false can't be picked. The calendar
+    /// follows the conventions of the given [`Locale`](Locale).
+    #[allow(clippy::too_many_arguments)]
     fn draw<Message>(
         &mut self,
         env: DrawEnvironment<'_, Self::Defaults, Self::Style, Focus>,
         date: NaiveDate,
+        range: Option<(NaiveDate, NaiveDate)>,
+        is_enabled: &dyn Fn(NaiveDate) -> bool,
+        locale: &Locale,
         year_str: &str,
         month_str: &str,
         cancel_button: &Element<'_, Message, Self>,
@@ -680,6 +902,9 @@ impl Renderer for iced_native::renderer::Null {
         &mut self,
         _env: DrawEnvironment<'_, Self::Defaults, Self::Style, Focus>,
         _date: NaiveDate,
+        _range: Option<(NaiveDate, NaiveDate)>,
+        _is_enabled: &dyn Fn(NaiveDate) -> bool,
+        _locale: &Locale,
         _year_str: &str,
         _month_str: &str,
         _cancel_button: &Element<'_, Message, Self>,
@@ -697,6 +922,37 @@ pub struct State {
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The start date of the picked range.
+    pub(crate) range_start: Option<NaiveDate>,
+    /// The end date of the picked range.
+    pub(crate) range_end: Option<NaiveDate>,
+    /// The locale of the calendar.
+    pub(crate) locale: Locale,
+}
+
+impl State {
+    /// Gets the start and end date of the range picked so far.
+    ///
+    /// While only the start date is picked, the range covers that single day.
+    pub(crate) fn range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        self.range_start
+            .map(|start| (start, self.range_end.unwrap_or(start)))
+    }
+
+    /// Picks the given date as the start of a new range or as the end of the
+    /// started range, swapping the bounds if the end is before the start.
+    pub(crate) fn pick_range_bound(&mut self, date: NaiveDate) {
+        match (self.range_start, self.range_end) {
+            (Some(start), None) => {
+                self.range_start = Some(start.min(date));
+                self.range_end = Some(start.max(date));
+            }
+            _ => {
+                self.range_start = Some(date);
+                self.range_end = None;
+            }
+        }
+    }
 }
 
 impl Default for State {
@@ -705,6 +961,9 @@ impl Default for State {
             date: Local::today().naive_local(),
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            range_start: None,
+            range_end: None,
+            locale: Locale::default(),
         }
     }
 }
@@ -752,8 +1011,7 @@ impl Focus {
     #[must_use]
     pub const fn previous(self) -> Self {
         match self {
-            Self::None => Self::None,
-            Self::Overlay => Self::Submit,
+            Self::Overlay | Self::None => Self::Submit,
             Self::Month => Self::Overlay,
             Self::Year => Self::Month,
             Self::Day => Self::Year,
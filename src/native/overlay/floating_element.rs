@@ -0,0 +1,236 @@
+//! Use a floating element to overlay an element over some content
+//!
+//! *This API requires the following crate features to be activated: `floating_element`*
+
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout::Limits, overlay, Clipboard, Element, Event, Layout, Point, Rectangle, Size,
+    Vector,
+};
+
+use crate::native::floating_element::{Anchor, Offset};
+
+/// The internal overlay of a [`FloatingElement`](crate::native::FloatingElement)
+/// for rendering an [`Element`](iced_native::Element) as an overlay.
+#[allow(missing_debug_implementations)]
+pub struct FloatingElementOverlay<'a, 'b, Message, Renderer: iced_native::Renderer> {
+    /// The floating element.
+    element: &'a mut Element<'b, Message, Renderer>,
+    /// The anchor of the element.
+    anchor: &'a Anchor,
+    /// The offset of the element.
+    offset: &'a Offset,
+    /// The optional badge attached to the element.
+    badge: Option<&'a Element<'b, Message, Renderer>>,
+}
+
+impl<'a, 'b, Message, Renderer> FloatingElementOverlay<'a, 'b, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    'b: 'a,
+{
+    /// Creates a new [`FloatingElementOverlay`] containing the given
+    /// [`Element`](iced_native::Element).
+    pub fn new(
+        element: &'a mut Element<'b, Message, Renderer>,
+        anchor: &'a Anchor,
+        offset: &'a Offset,
+    ) -> Self {
+        FloatingElementOverlay {
+            element,
+            anchor,
+            offset,
+            badge: None,
+        }
+    }
+
+    /// Attaches the given badge to the top trailing corner of the
+    /// [`Element`](iced_native::Element).
+    pub fn badge(mut self, badge: Option<&'a Element<'b, Message, Renderer>>) -> Self {
+        self.badge = badge;
+        self
+    }
+
+    /// Turns the [`FloatingElementOverlay`](FloatingElementOverlay) into an
+    /// overlay [`Element`](iced_native::overlay::Element) at the given target
+    /// position.
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+
+    /// Maps the cursor position onto the element if it is over the badge, so
+    /// the badge behaves like a part of the element.
+    fn cursor_position(layout: Layout<'_>, cursor_position: Point) -> Point {
+        let mut children = layout.children();
+        let element_bounds = children
+            .next()
+            .expect("Native: Layout should have an element layout")
+            .bounds();
+
+        children
+            .next()
+            .map(|badge_layout| badge_layout.bounds())
+            .filter(|badge_bounds| {
+                badge_bounds.contains(cursor_position) && !element_bounds.contains(cursor_position)
+            })
+            .map_or(cursor_position, |_| element_bounds.center())
+    }
+}
+
+impl<'a, 'b, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for FloatingElementOverlay<'a, 'b, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    'b: 'a,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> iced_native::layout::Node {
+        let limits = Limits::new(Size::ZERO, bounds);
+        let mut element = self.element.layout(renderer, &limits);
+
+        match self.anchor {
+            Anchor::NorthWest => element.move_to(Point::new(
+                position.x + self.offset.x,
+                position.y + self.offset.y,
+            )),
+            Anchor::NorthEast => element.move_to(Point::new(
+                position.x - element.bounds().width - self.offset.x,
+                position.y + self.offset.y,
+            )),
+            Anchor::SouthWest => element.move_to(Point::new(
+                position.x + self.offset.x,
+                position.y - element.bounds().height - self.offset.y,
+            )),
+            Anchor::SouthEast => element.move_to(Point::new(
+                position.x - element.bounds().width - self.offset.x,
+                position.y - element.bounds().height - self.offset.y,
+            )),
+        }
+
+        let element_bounds = element.bounds();
+
+        let mut badge = match self.badge {
+            Some(badge) => badge.layout(renderer, &limits),
+            None => {
+                element.move_to(Point::ORIGIN);
+                let mut node =
+                    iced_native::layout::Node::with_children(element_bounds.size(), vec![element]);
+                node.move_to(Point::new(element_bounds.x, element_bounds.y));
+
+                return node;
+            }
+        };
+
+        let badge_size = badge.size();
+        badge.move_to(Point::new(
+            element_bounds.x + element_bounds.width - badge_size.width / 2.0,
+            element_bounds.y - badge_size.height / 2.0,
+        ));
+
+        // The overlay covers the element and the badge sticking out of it.
+        let badge_bounds = badge.bounds();
+        let x = element_bounds.x.min(badge_bounds.x);
+        let y = element_bounds.y.min(badge_bounds.y);
+        let bounds = Rectangle {
+            x,
+            y,
+            width: (element_bounds.x + element_bounds.width)
+                .max(badge_bounds.x + badge_bounds.width)
+                - x,
+            height: (element_bounds.y + element_bounds.height)
+                .max(badge_bounds.y + badge_bounds.height)
+                - y,
+        };
+
+        let offset = Vector::new(bounds.x, bounds.y);
+        element.move_to(Point::new(element_bounds.x, element_bounds.y) - offset);
+        badge.move_to(Point::new(badge_bounds.x, badge_bounds.y) - offset);
+
+        let mut node =
+            iced_native::layout::Node::with_children(bounds.size(), vec![element, badge]);
+        node.move_to(Point::new(bounds.x, bounds.y));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        self.element.on_event(
+            event,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have an element layout"),
+            Self::cursor_position(layout, cursor_position),
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let cursor_position = Self::cursor_position(layout, cursor_position);
+        let mut children = layout.children();
+
+        let element_layout = children
+            .next()
+            .expect("Native: Layout should have an element layout");
+        let element = self.element.draw(
+            renderer,
+            defaults,
+            element_layout,
+            cursor_position,
+            &element_layout.bounds(),
+        );
+
+        match self.badge.zip(children.next()) {
+            Some((badge, badge_layout)) => {
+                let badge_bounds = badge_layout.bounds();
+                let badge = badge.draw(
+                    renderer,
+                    defaults,
+                    badge_layout,
+                    cursor_position,
+                    &badge_bounds,
+                );
+
+                renderer.overlay(element, badge, badge_bounds)
+            }
+            None => element,
+        }
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        self.element.hash_layout(state);
+
+        if let Some(badge) = self.badge {
+            badge.hash_layout(state);
+        }
+    }
+}
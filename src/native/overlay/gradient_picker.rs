@@ -0,0 +1,211 @@
+//! Use a gradient picker to edit the color stops of a linear gradient.
+//!
+//! *This API requires the following crate features to be activated: `gradient_picker`*
+use std::hash::Hash;
+
+use iced_native::{
+    button, column, event, overlay, row, text, text_input, Button, Clipboard, Color, Element,
+    Event, Layout, Length, Point,
+};
+
+use crate::{
+    core::renderer::DrawEnvironment,
+    graphics::icons::Icon,
+    native::{
+        gradient_picker, icon_text,
+        overlay::color_picker::{self, picker_layout, picker_on_event, swatches},
+        IconText,
+    },
+};
+
+/// The overlay of the [`GradientPicker`](crate::native::GradientPicker)
+/// editing the color of the selected stop.
+///
+/// It shows the same color picker as the
+/// [`ColorPickerOverlay`](crate::native::overlay::ColorPickerOverlay) and
+/// closes itself when one of its buttons is pressed.
+#[allow(missing_debug_implementations)]
+pub struct GradientPickerOverlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: color_picker::Renderer,
+{
+    /// The state of the color picker.
+    state: &'a mut color_picker::State,
+    /// Whether the color of the selected stop is edited.
+    editing: &'a mut bool,
+    /// The cancel button of the [`GradientPickerOverlay`](GradientPickerOverlay).
+    cancel_button: Element<'a, Message, Renderer>,
+    /// The submit button of the [`GradientPickerOverlay`](GradientPickerOverlay).
+    submit_button: Element<'a, Message, Renderer>,
+    /// The function that produces a message when the submit button of the
+    /// [`GradientPickerOverlay`](GradientPickerOverlay) is pressed.
+    on_submit: &'a dyn Fn(Color) -> Message,
+    /// The position of the [`GradientPickerOverlay`](GradientPickerOverlay).
+    position: Point,
+    /// The style of the [`GradientPickerOverlay`](GradientPickerOverlay).
+    style: &'a <Renderer as color_picker::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> GradientPickerOverlay<'a, Message, Renderer>
+where
+    Message: 'static + Clone,
+    Renderer: 'a
+        + color_picker::Renderer
+        + column::Renderer
+        + button::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer
+        + text_input::Renderer,
+{
+    /// Creates a new [`GradientPickerOverlay`](GradientPickerOverlay) on the
+    /// given position.
+    ///
+    /// The cancel button produces the `on_cancel` message, the submit button
+    /// the message produced by `on_submit` with the picked color.
+    pub fn new(
+        state: &'a mut gradient_picker::State,
+        on_cancel: Message,
+        on_submit: &'a dyn Fn(Color) -> Message,
+        position: Point,
+        style: &'a <Renderer as color_picker::Renderer>::Style,
+    ) -> Self {
+        let gradient_picker::State {
+            picker,
+            editing,
+            cancel_button,
+            submit_button,
+            ..
+        } = state;
+
+        GradientPickerOverlay {
+            state: picker,
+            editing,
+            cancel_button: Button::new(cancel_button, IconText::new(Icon::X).width(Length::Fill))
+                .width(Length::Fill)
+                .on_press(on_cancel.clone())
+                .into(),
+            submit_button: Button::new(
+                submit_button,
+                IconText::new(Icon::Check).width(Length::Fill),
+            )
+            .width(Length::Fill)
+            .on_press(on_cancel) // Sending a fake message
+            .into(),
+            on_submit,
+            position,
+            style,
+        }
+    }
+
+    /// Turn this [`GradientPickerOverlay`](GradientPickerOverlay) into an
+    /// overlay [`Element`](overlay::Element).
+    #[must_use]
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(self.position, Box::new(self))
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for GradientPickerOverlay<'a, Message, Renderer>
+where
+    Message: 'static + Clone,
+    Renderer: 'a
+        + color_picker::Renderer
+        + column::Renderer
+        + button::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer
+        + text_input::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: iced_graphics::Size,
+        position: Point,
+    ) -> iced_native::layout::Node {
+        let mut node = picker_layout(
+            renderer,
+            bounds,
+            self.state.recent.len(),
+            &self.cancel_button,
+            &self.submit_button,
+        );
+
+        node.center_and_bounce(position, bounds);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let message_count = messages.len();
+
+        let status = picker_on_event(
+            self.state,
+            &mut self.cancel_button,
+            &mut self.submit_button,
+            self.on_submit,
+            &[],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        );
+
+        if messages.len() > message_count {
+            *self.editing = false;
+            self.state.focus = color_picker::Focus::None;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: iced_native::Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        <Renderer as color_picker::Renderer>::draw(
+            renderer,
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: self.style,
+                viewport: None,
+                focus: self.state.focus,
+            },
+            &self.state.color,
+            &self.state.hex_input,
+            &swatches(&[], &self.state.recent),
+            &self.state.sat_value_canvas_cache,
+            &self.state.hue_canvas_cache,
+            &self.cancel_button,
+            &self.submit_button,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        self.state.recent.len().hash(state);
+    }
+}
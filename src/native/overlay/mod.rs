@@ -1,10 +1,38 @@
 //! Display interactive elements on top of other widgets.
 
+#[cfg(any(
+    feature = "color_picker",
+    feature = "date_picker",
+    feature = "time_picker"
+))]
+pub mod animated;
+#[cfg(any(
+    feature = "color_picker",
+    feature = "date_picker",
+    feature = "time_picker"
+))]
+pub use animated::AnimatedOverlay;
+
+#[cfg(feature = "badge")]
+pub mod badge;
+#[cfg(feature = "badge")]
+pub use badge::{BadgeAnchorOverlay, BadgeTooltipOverlay};
+
 #[cfg(feature = "color_picker")]
 pub mod color_picker;
 #[cfg(feature = "color_picker")]
 pub use color_picker::ColorPickerOverlay;
 
+#[cfg(feature = "combo_box")]
+pub mod combo_box;
+#[cfg(feature = "combo_box")]
+pub use combo_box::ComboBoxOverlay;
+
+#[cfg(feature = "context_menu")]
+pub mod context_menu;
+#[cfg(feature = "context_menu")]
+pub use context_menu::ContextMenuOverlay;
+
 #[cfg(feature = "date_picker")]
 pub mod date_picker;
 #[cfg(feature = "date_picker")]
@@ -13,14 +41,45 @@ pub use date_picker::DatePickerOverlay;
 #[cfg(feature = "floating_button")]
 pub mod floating_button;
 #[cfg(feature = "floating_button")]
+#[allow(deprecated)]
 pub use floating_button::FloatingButtonOverlay;
 
+#[cfg(feature = "floating_element")]
+pub mod floating_element;
+#[cfg(feature = "floating_element")]
+pub use floating_element::FloatingElementOverlay;
+
+#[cfg(feature = "gradient_picker")]
+pub mod gradient_picker;
+#[cfg(feature = "gradient_picker")]
+pub use gradient_picker::GradientPickerOverlay;
+
 #[cfg(feature = "modal")]
 pub mod modal;
 #[cfg(feature = "modal")]
-pub use modal::ModalOverlay;
+pub use modal::{ModalOverlay, ModalStackOverlay};
+
+#[cfg(feature = "overlay_corner")]
+pub mod overlay_corner;
+#[cfg(feature = "overlay_corner")]
+pub use overlay_corner::OverlayCornerOverlay;
+
+#[cfg(feature = "popover")]
+pub mod popover;
+#[cfg(feature = "popover")]
+pub use popover::PopoverOverlay;
+
+#[cfg(feature = "reorderable_list")]
+pub mod reorderable_list;
+#[cfg(feature = "reorderable_list")]
+pub use reorderable_list::ReorderableListOverlay;
 
 #[cfg(feature = "time_picker")]
 pub mod time_picker;
 #[cfg(feature = "time_picker")]
 pub use time_picker::TimePickerOverlay;
+
+#[cfg(feature = "toast")]
+pub mod toast;
+#[cfg(feature = "toast")]
+pub use toast::ToastOverlay;
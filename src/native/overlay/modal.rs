@@ -5,11 +5,19 @@
 use std::hash::Hash;
 
 use iced_native::{
-    event, keyboard, layout::Limits, mouse, overlay, touch, Clipboard, Container, Element, Event,
-    Layout, Length, Point, Size,
+    event, keyboard, layout, layout::Limits, mouse, overlay, touch, Clipboard, Container, Element,
+    Event, Layout, Length, Point, Size,
 };
 
-use crate::core::renderer::DrawEnvironment;
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::{
+    core::{
+        accessibility::{self, Accessibility, Node, Role},
+        renderer::DrawEnvironment,
+    },
+    native::{modal::ModalLayer, stateful_overlay::Visibility},
+};
 
 /// The overlay of the modal.
 #[allow(missing_debug_implementations)]
@@ -28,6 +36,10 @@ where
     backdrop: Option<Message>,
     /// The optional message that will be send when the ESC key was pressed.
     esc: Option<Message>,
+    /// The visibility of the [`Overlay`](Overlay) during its animation.
+    visibility: Visibility,
+    /// The accessibility information of the [`Overlay`](Overlay).
+    accessibility: Node<'a>,
     /// The style of the [`Overlay`](Overlay).
     style: &'a <Renderer as self::Renderer>::Style,
 }
@@ -52,10 +64,26 @@ where
             content,
             backdrop,
             esc,
+            visibility: Visibility::default(),
+            accessibility: Node::new(Role::Dialog),
             style,
         }
     }
 
+    /// Sets the [`Visibility`](Visibility) of the [`ModalOverlay`](ModalOverlay)
+    /// during its open or close animation.
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    /// Sets the [`Accessibility`](Accessibility) information announced for
+    /// the [`ModalOverlay`](ModalOverlay) as a dialog.
+    pub fn accessibility(mut self, accessibility: &'a Accessibility) -> Self {
+        self.accessibility = accessibility.node(Role::Dialog);
+        self
+    }
+
     /// Turn this [`ModalOverlay`] into an overlay
     /// [`Element`](iced_native::overlay::Element).
     pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
@@ -71,6 +99,10 @@ struct Overlay<'a, Message, Renderer: self::Renderer> {
     backdrop: Option<Message>,
     /// The optional message that will be send when the ESC key was pressed.
     esc: Option<Message>,
+    /// The visibility of the [`Overlay`](Overlay) during its animation.
+    visibility: Visibility,
+    /// The accessibility information of the [`Overlay`](Overlay).
+    accessibility: Node<'a>,
     /// The style of the [`Overlay`](Overlay).
     style: &'a <Renderer as self::Renderer>::Style,
 }
@@ -90,6 +122,8 @@ where
             content,
             backdrop,
             esc,
+            visibility,
+            accessibility,
             style,
         } = modal;
 
@@ -102,6 +136,8 @@ where
                 .into(),
             backdrop,
             esc,
+            visibility,
+            accessibility,
             style,
         }
     }
@@ -137,49 +173,21 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
-        // TODO clean this up
-        let esc_status = self
-            .esc
-            .as_ref()
-            .map_or(event::Status::Ignored, |esc| match event {
-                Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
-                    if key_code == keyboard::KeyCode::Escape {
-                        messages.push(esc.to_owned());
-                        event::Status::Captured
-                    } else {
-                        event::Status::Ignored
-                    }
-                }
-                _ => event::Status::Ignored,
-            });
-
-        let backdrop_status = self.backdrop.as_ref().zip(layout.children().next()).map_or(
-            event::Status::Ignored,
-            |(backdrop, layout)| match event {
-                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    if layout.bounds().contains(cursor_position) {
-                        event::Status::Ignored
-                    } else {
-                        messages.push(backdrop.to_owned());
-                        event::Status::Captured
-                    }
-                }
-                _ => event::Status::Ignored,
-            },
-        );
-
-        match esc_status.merge(backdrop_status) {
-            event::Status::Ignored => self.content.on_event(
-                event,
-                layout,
-                cursor_position,
-                renderer,
-                clipboard,
-                messages,
-            ),
-            event::Status::Captured => event::Status::Captured,
+        if self.visibility.closing {
+            return event::Status::Ignored;
         }
+
+        layer_on_event(
+            &mut self.content,
+            self.backdrop.as_ref(),
+            self.esc.as_ref(),
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
     }
 
     fn draw(
@@ -189,6 +197,15 @@ where
         layout: iced_native::Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        // A closing overlay is not hovered anymore.
+        let cursor_position = if self.visibility.closing {
+            Point::new(-1.0, -1.0)
+        } else {
+            cursor_position
+        };
+
+        accessibility::Renderer::announce(renderer, self.accessibility, layout.bounds());
+
         renderer.draw(
             DrawEnvironment {
                 defaults,
@@ -199,6 +216,7 @@ where
                 focus: (),
             },
             &self.content,
+            self.visibility.progress,
         )
     }
 
@@ -213,19 +231,218 @@ where
     }
 }
 
+/// Processes an event for a modal layer with the given content.
+///
+/// The Escape key and clicks on the backdrop produce their messages, all
+/// other events go to the content.
+#[allow(clippy::too_many_arguments)]
+fn layer_on_event<Message, Renderer>(
+    content: &mut Element<'_, Message, Renderer>,
+    backdrop: Option<&Message>,
+    esc: Option<&Message>,
+    event: Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    messages: &mut Vec<Message>,
+) -> event::Status
+where
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+{
+    // TODO clean this up
+    let esc_status = esc.map_or(event::Status::Ignored, |esc| match event {
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+            if key_code == keyboard::KeyCode::Escape {
+                #[cfg(feature = "instrumentation")]
+                instrumentation::record(WidgetKind::Modal, Action::Escape);
+                messages.push(esc.to_owned());
+                event::Status::Captured
+            } else {
+                event::Status::Ignored
+            }
+        }
+        _ => event::Status::Ignored,
+    });
+
+    let backdrop_status = backdrop.zip(layout.children().next()).map_or(
+        event::Status::Ignored,
+        |(backdrop, layout)| match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if layout.bounds().contains(cursor_position) {
+                    event::Status::Ignored
+                } else {
+                    #[cfg(feature = "instrumentation")]
+                    instrumentation::record(WidgetKind::Modal, Action::Backdrop);
+                    messages.push(backdrop.to_owned());
+                    event::Status::Captured
+                }
+            }
+            _ => event::Status::Ignored,
+        },
+    );
+
+    match esc_status.merge(backdrop_status) {
+        event::Status::Ignored => content.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        ),
+        event::Status::Captured => event::Status::Captured,
+    }
+}
+
+/// The overlay of a [`ModalStack`](crate::native::ModalStack) showing its
+/// layers on top of each other.
+#[allow(missing_debug_implementations)]
+pub struct ModalStackOverlay<'a, 'b, Message, Renderer: self::Renderer> {
+    /// The layers of the [`ModalStack`](crate::native::ModalStack) from
+    /// bottom to top.
+    layers: &'a mut [ModalLayer<'b, Message, Renderer>],
+    /// The style of the layers.
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, 'b, Message, Renderer> ModalStackOverlay<'a, 'b, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+    'b: 'a,
+{
+    /// Creates a new [`ModalStackOverlay`](ModalStackOverlay) showing the
+    /// given layers.
+    pub fn new(
+        layers: &'a mut [ModalLayer<'b, Message, Renderer>],
+        style: &'a <Renderer as self::Renderer>::Style,
+    ) -> Self {
+        ModalStackOverlay { layers, style }
+    }
+
+    /// Turn this [`ModalStackOverlay`] into an overlay
+    /// [`Element`](iced_native::overlay::Element).
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+}
+
+impl<'a, 'b, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for ModalStackOverlay<'a, 'b, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> layout::Node {
+        let limits = Limits::new(Size::ZERO, bounds);
+
+        let mut node = layout::Node::with_children(
+            bounds,
+            self.layers
+                .iter()
+                .map(|layer| layer.content.layout(renderer, &limits))
+                .collect(),
+        );
+
+        node.move_to(position);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        // Only the topmost layer receives events.
+        let (layer, layout) = match self.layers.last_mut().zip(layout.children().last()) {
+            Some(top) => top,
+            None => return event::Status::Ignored,
+        };
+
+        layer_on_event(
+            &mut layer.content,
+            layer.backdrop.as_ref(),
+            layer.esc.as_ref(),
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw_stack(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: self.style,
+                viewport: None,
+                focus: (),
+            },
+            &self
+                .layers
+                .iter()
+                .map(|layer| &layer.content)
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        for layer in self.layers.iter() {
+            layer.content.hash_layout(state);
+        }
+    }
+}
+
 /// The renderer of a [`ModalOverlay`](ModalOverlay).
 ///
 /// Your renderer will need to implement this trait before being
 /// able to use a [`Modal`](crate::native::Modal) in your user interface.
-pub trait Renderer: iced_native::Renderer {
+pub trait Renderer: iced_native::Renderer + accessibility::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Draws a [`ModalOverlay`](ModalOverlay).
+    /// Draws a [`ModalOverlay`](ModalOverlay) with the given visibility
+    /// progress of its animation from hidden (`0.0`) to shown (`1.0`).
     fn draw<Message>(
         &mut self,
         env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
         modal: &Element<'_, Message, Self>,
+        visibility: f32,
+    ) -> Self::Output;
+
+    /// Draws the layers of a [`ModalStackOverlay`](ModalStackOverlay) from
+    /// bottom to top.
+    ///
+    /// Each layer is drawn like a [`ModalOverlay`](ModalOverlay) with the
+    /// corresponding child of the layout. Only the topmost layer is hovered.
+    fn draw_stack<Message>(
+        &mut self,
+        env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
+        layers: &[&Element<'_, Message, Self>],
     ) -> Self::Output;
 }
 
@@ -237,6 +454,14 @@ impl Renderer for iced_native::renderer::Null {
         &mut self,
         _env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
         _modal: &Element<'_, Message, Self>,
+        _visibility: f32,
+    ) -> Self::Output {
+    }
+
+    fn draw_stack<Message>(
+        &mut self,
+        _env: DrawEnvironment<Self::Defaults, Self::Style, ()>,
+        _layers: &[&Element<'_, Message, Self>],
     ) -> Self::Output {
     }
 }
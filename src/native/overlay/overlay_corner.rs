@@ -0,0 +1,231 @@
+//! Use an overlay corner to pin a small element to a corner of the window.
+//!
+//! *This API requires the following crate features to be activated: `overlay_corner`*
+use std::hash::Hash;
+
+use iced_native::{event, layout::Limits, overlay, Clipboard, Element, Event, Layout, Point, Size};
+
+use crate::native::overlay_corner::{Anchor, Offset};
+
+/// The internal overlay of an [`OverlayCorner`](crate::native::OverlayCorner)
+/// for rendering the pinned element in a corner of the window.
+///
+/// The overlay of the underlying content, if any, is shown below the pinned
+/// element.
+#[allow(missing_debug_implementations)]
+pub struct OverlayCornerOverlay<'a, 'b, Message, Renderer: self::Renderer> {
+    /// The pinned element.
+    element: &'a mut Element<'b, Message, Renderer>,
+    /// The anchor of the pinned element.
+    anchor: &'a Anchor,
+    /// The offset of the pinned element.
+    offset: &'a Offset,
+    /// The overlay of the underlying content.
+    underlay: Option<overlay::Element<'a, Message, Renderer>>,
+}
+
+impl<'a, 'b, Message, Renderer> OverlayCornerOverlay<'a, 'b, Message, Renderer>
+where
+    'b: 'a,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    /// Creates a new [`OverlayCornerOverlay`](OverlayCornerOverlay) containing
+    /// the given [`Element`](iced_native::Element).
+    pub fn new(
+        element: &'a mut Element<'b, Message, Renderer>,
+        anchor: &'a Anchor,
+        offset: &'a Offset,
+    ) -> Self {
+        OverlayCornerOverlay {
+            element,
+            anchor,
+            offset,
+            underlay: None,
+        }
+    }
+
+    /// Sets the overlay of the underlying content that is shown below the
+    /// pinned element.
+    pub fn underlay(mut self, underlay: Option<overlay::Element<'a, Message, Renderer>>) -> Self {
+        self.underlay = underlay;
+        self
+    }
+
+    /// Turns the [`OverlayCornerOverlay`](OverlayCornerOverlay) into an
+    /// overlay [`Element`](iced_native::overlay::Element).
+    ///
+    /// The position is ignored, as the element is always placed relative to
+    /// the corners of the window.
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(Point::ORIGIN, Box::new(self))
+    }
+}
+
+impl<'a, 'b, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for OverlayCornerOverlay<'a, 'b, Message, Renderer>
+where
+    'b: 'a,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+    ) -> iced_native::layout::Node {
+        let limits = Limits::new(Size::ZERO, bounds);
+        let mut element = self.element.layout(renderer, &limits);
+        let size = element.bounds();
+
+        element.move_to(match self.anchor {
+            Anchor::NorthWest => Point::new(self.offset.x, self.offset.y),
+            Anchor::NorthEast => {
+                Point::new(bounds.width - size.width - self.offset.x, self.offset.y)
+            }
+            Anchor::SouthWest => {
+                Point::new(self.offset.x, bounds.height - size.height - self.offset.y)
+            }
+            Anchor::SouthEast => Point::new(
+                bounds.width - size.width - self.offset.x,
+                bounds.height - size.height - self.offset.y,
+            ),
+        });
+
+        let mut children = vec![element];
+        if let Some(underlay) = &self.underlay {
+            children.push(underlay.layout(renderer, bounds));
+        }
+
+        iced_native::layout::Node::with_children(bounds, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let element_layout = children
+            .next()
+            .expect("Native: Layout should have a pinned element layout");
+
+        let status = self.element.on_event(
+            event.clone(),
+            element_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match (&mut self.underlay, children.next()) {
+            (Some(underlay), Some(underlay_layout)) => {
+                // The underlying overlay is covered by the pinned element.
+                let cursor_position = if element_layout.bounds().contains(cursor_position) {
+                    Point::new(-1.0, -1.0)
+                } else {
+                    cursor_position
+                };
+
+                underlay.on_event(
+                    event,
+                    underlay_layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    messages,
+                )
+            }
+            _ => status,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+        let element_layout = children
+            .next()
+            .expect("Native: Layout should have a pinned element layout");
+
+        let element = self.element.draw(
+            renderer,
+            defaults,
+            element_layout,
+            cursor_position,
+            &element_layout.bounds(),
+        );
+
+        match (&self.underlay, children.next()) {
+            (Some(underlay), Some(underlay_layout)) => {
+                // The underlying overlay is covered by the pinned element.
+                let cursor_position = if element_layout.bounds().contains(cursor_position) {
+                    Point::new(-1.0, -1.0)
+                } else {
+                    cursor_position
+                };
+
+                let underlay = underlay.draw(renderer, defaults, underlay_layout, cursor_position);
+
+                renderer.stack(underlay, element, element_layout.bounds())
+            }
+            _ => element,
+        }
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, _position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.anchor.hash(state);
+        (self.offset.x as u32).hash(state);
+        (self.offset.y as u32).hash(state);
+        self.element.hash_layout(state);
+
+        if let Some(underlay) = &self.underlay {
+            underlay.hash_layout(state);
+        }
+    }
+}
+
+/// The renderer of an [`OverlayCorner`](crate::native::OverlayCorner).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use an [`OverlayCorner`](crate::native::OverlayCorner) in your
+/// user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// Stacks the drawn pinned element with the given bounds on top of the
+    /// drawn overlay of the underlying content.
+    fn stack(
+        &mut self,
+        underlay: Self::Output,
+        element: Self::Output,
+        bounds: iced_native::Rectangle,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    fn stack(
+        &mut self,
+        _underlay: Self::Output,
+        _element: Self::Output,
+        _bounds: iced_native::Rectangle,
+    ) -> Self::Output {
+    }
+}
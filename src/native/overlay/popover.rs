@@ -0,0 +1,311 @@
+//! A popover for showing a floating panel next to a target element.
+//!
+//! *This API requires the following crate features to be activated: `popover`*
+use std::hash::Hash;
+
+use iced_native::{
+    event, keyboard, layout::Limits, mouse, overlay, touch, Clipboard, Element, Event, Layout,
+    Point, Rectangle, Size,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::{
+    core::{
+        overlay::{Placement, Position},
+        renderer::DrawEnvironment,
+    },
+    native::popover::{self, Trigger},
+};
+
+/// The overlay of the [`Popover`](crate::native::Popover).
+#[allow(missing_debug_implementations)]
+pub struct PopoverOverlay<'a, S, Content, Message, Renderer>
+where
+    S: 'a,
+    Content: Fn(&'a mut S) -> Element<'a, Message, Renderer>,
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    /// The state of the [`PopoverOverlay`](PopoverOverlay).
+    state: &'a mut popover::State<S>,
+    /// The content of the [`Overlay`](Overlay).
+    content: Content,
+    /// The bounds of the target the [`Overlay`](Overlay) is anchored to.
+    target: Rectangle,
+    /// The way the [`Overlay`](Overlay) is shown.
+    trigger: Trigger,
+    /// The preferred side of the target the [`Overlay`](Overlay) is placed on.
+    placement: Placement,
+    /// The spacing between the target and the [`Overlay`](Overlay).
+    spacing: f32,
+    /// The optional message that will be send when the user clicks outside
+    /// of the target and the [`Overlay`](Overlay).
+    backdrop: Option<Message>,
+    /// The optional message that will be send when the ESC key was pressed.
+    esc: Option<Message>,
+    /// The style of the [`Overlay`](Overlay).
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, S, Content, Message, Renderer> PopoverOverlay<'a, S, Content, Message, Renderer>
+where
+    S: 'a,
+    Content: Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`PopoverOverlay`](PopoverOverlay) anchored to the given
+    /// bounds of the target.
+    pub fn new(
+        state: &'a mut popover::State<S>,
+        content: Content,
+        target: Rectangle,
+        backdrop: Option<Message>,
+        esc: Option<Message>,
+        style: &'a <Renderer as self::Renderer>::Style,
+    ) -> Self {
+        PopoverOverlay {
+            state,
+            content,
+            target,
+            trigger: Trigger::Hover,
+            placement: Placement::Bottom,
+            spacing: 0.0,
+            backdrop,
+            esc,
+            style,
+        }
+    }
+
+    /// Sets the [`Trigger`](Trigger) showing the
+    /// [`PopoverOverlay`](PopoverOverlay).
+    pub fn trigger(mut self, trigger: Trigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// Sets the preferred side of the target the
+    /// [`PopoverOverlay`](PopoverOverlay) is placed on and its spacing to
+    /// the target.
+    pub fn placement(mut self, placement: Placement, spacing: f32) -> Self {
+        self.placement = placement;
+        self.spacing = spacing;
+        self
+    }
+
+    /// Turn this [`PopoverOverlay`] into an overlay
+    /// [`Element`](iced_native::overlay::Element) at the position of the
+    /// target.
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(Overlay::new(self)))
+    }
+}
+
+/// The [`Overlay`](Overlay) of the [`Popover`](crate::native::Popover).
+struct Overlay<'a, Message, Renderer: self::Renderer> {
+    /// The visibility of the [`Popover`](crate::native::Popover).
+    show: &'a mut bool,
+    /// The content of the [`Overlay`](Overlay).
+    content: Element<'a, Message, Renderer>,
+    /// The bounds of the target the [`Overlay`](Overlay) is anchored to.
+    target: Rectangle,
+    /// The way the [`Overlay`](Overlay) is shown.
+    trigger: Trigger,
+    /// The preferred side of the target the [`Overlay`](Overlay) is placed on.
+    placement: Placement,
+    /// The spacing between the target and the [`Overlay`](Overlay).
+    spacing: f32,
+    /// The optional message that will be send when the user clicks outside
+    /// of the target and the [`Overlay`](Overlay).
+    backdrop: Option<Message>,
+    /// The optional message that will be send when the ESC key was pressed.
+    esc: Option<Message>,
+    /// The style of the [`Overlay`](Overlay).
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Overlay<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    /// Creates a new [`Overlay`](Overlay) from the given
+    /// [`PopoverOverlay`](PopoverOverlay).
+    pub fn new<S, Content>(popover: PopoverOverlay<'a, S, Content, Message, Renderer>) -> Self
+    where
+        Content: Fn(&mut S) -> Element<'_, Message, Renderer>,
+    {
+        let PopoverOverlay {
+            state,
+            content,
+            target,
+            trigger,
+            placement,
+            spacing,
+            backdrop,
+            esc,
+            style,
+        } = popover;
+
+        let popover::State { show, state } = state;
+
+        Self {
+            show,
+            content: content(state),
+            target,
+            trigger,
+            placement,
+            spacing,
+            backdrop,
+            esc,
+            style,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> iced_native::layout::Node {
+        let limits = Limits::new(Size::ZERO, bounds);
+
+        let mut content = self.content.layout(renderer, &limits);
+
+        let target = Rectangle {
+            x: position.x,
+            y: position.y,
+            ..self.target
+        };
+
+        let _ = content.anchor_and_bounce(target, self.placement, self.spacing, bounds);
+
+        content
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let outside =
+            !layout.bounds().contains(cursor_position) && !self.target.contains(cursor_position);
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                if let Some(esc) = &self.esc {
+                    #[cfg(feature = "instrumentation")]
+                    instrumentation::record(WidgetKind::Popover, Action::Escape);
+                    messages.push(esc.clone());
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorLeft) if self.trigger == Trigger::Hover => {
+                *self.show = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if self.trigger == Trigger::Hover && outside =>
+            {
+                *self.show = false;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if outside =>
+            {
+                if let Some(backdrop) = &self.backdrop {
+                    #[cfg(feature = "instrumentation")]
+                    instrumentation::record(WidgetKind::Popover, Action::Backdrop);
+                    messages.push(backdrop.clone());
+                }
+            }
+            _ => {}
+        }
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: self.style,
+                viewport: None,
+                focus: (),
+            },
+            &self.content,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        (self.target.width as u32).hash(state);
+        (self.target.height as u32).hash(state);
+        self.placement.hash(state);
+        (self.spacing as u32).hash(state);
+        self.content.hash_layout(state);
+    }
+}
+
+/// The renderer of a [`PopoverOverlay`](PopoverOverlay).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Popover`](crate::native::Popover) in your user
+/// interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`PopoverOverlay`](PopoverOverlay).
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        content: &Element<'_, Message, Self>,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _content: &Element<'_, Message, Self>,
+    ) -> Self::Output {
+    }
+}
@@ -0,0 +1,197 @@
+//! Use a reorderable list to let the user rearrange elements by dragging them.
+//!
+//! *This API requires the following crate features to be activated: `reorderable_list`*
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout::Limits, mouse, overlay, touch, Clipboard, Element, Event, Layout, Length, Point,
+    Rectangle, Size,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::{
+    core::renderer::DrawEnvironment,
+    native::reorderable_list::{drop_target, Renderer, State},
+};
+
+/// The overlay of a [`ReorderableList`](crate::native::ReorderableList)
+/// drawing the dragged element at the position of the cursor.
+///
+/// While the element is dragged, the overlay tracks the cursor and drops the
+/// element when the mouse button is released.
+#[allow(missing_debug_implementations)]
+pub struct ReorderableListOverlay<'a, 'b, Message, Renderer: self::Renderer> {
+    /// The state of the [`ReorderableList`](crate::native::ReorderableList).
+    state: &'a mut State,
+    /// The dragged element.
+    element: &'a Element<'b, Message, Renderer>,
+    /// The function that produces a message when the element is dropped at a
+    /// new position.
+    on_reorder: &'a dyn Fn(usize, usize) -> Message,
+    /// The bounds of the [`ReorderableList`](crate::native::ReorderableList).
+    bounds: Rectangle,
+    /// The bounds of the elements of the
+    /// [`ReorderableList`](crate::native::ReorderableList).
+    children: Vec<Rectangle>,
+    /// The spacing between the elements.
+    spacing: u16,
+    /// The style of the [`ReorderableList`](crate::native::ReorderableList).
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, 'b, Message, Renderer> ReorderableListOverlay<'a, 'b, Message, Renderer>
+where
+    'b: 'a,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    /// Creates a new [`ReorderableListOverlay`](ReorderableListOverlay)
+    /// drawing the given dragged element.
+    pub fn new(
+        state: &'a mut State,
+        element: &'a Element<'b, Message, Renderer>,
+        on_reorder: &'a dyn Fn(usize, usize) -> Message,
+        style: &'a <Renderer as self::Renderer>::Style,
+    ) -> Self {
+        ReorderableListOverlay {
+            state,
+            element,
+            on_reorder,
+            bounds: Rectangle::default(),
+            children: Vec::new(),
+            spacing: 0,
+            style,
+        }
+    }
+
+    /// Sets the bounds of the list and of its elements and the spacing
+    /// between them, used to find the position the element is dropped at.
+    pub fn list(mut self, bounds: Rectangle, children: Vec<Rectangle>, spacing: u16) -> Self {
+        self.bounds = bounds;
+        self.children = children;
+        self.spacing = spacing;
+        self
+    }
+
+    /// Returns the width of the dragged element.
+    fn width(&self) -> f32 {
+        self.state
+            .drag
+            .and_then(|drag| self.children.get(drag.index))
+            .map_or(0.0, |child| child.width)
+    }
+
+    /// Turns the [`ReorderableListOverlay`](ReorderableListOverlay) into an
+    /// overlay [`Element`](iced_native::overlay::Element) with its top left
+    /// corner at the given position.
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+}
+
+impl<'a, 'b, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for ReorderableListOverlay<'a, 'b, Message, Renderer>
+where
+    'b: 'a,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> iced_native::layout::Node {
+        let limits = Limits::new(Size::ZERO, bounds)
+            .width(Length::Units(self.width() as u16))
+            .height(self.element.height());
+
+        let mut element = self.element.layout(renderer, &limits);
+        element.move_to(position);
+
+        element
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        _layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let drag = match self.state.drag {
+            Some(drag) if drag.is_dragging => drag,
+            _ => return event::Status::Ignored,
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag) = &mut self.state.drag {
+                    drag.cursor_position = cursor_position;
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.drag = None;
+
+                let (to, _) = drop_target(
+                    self.bounds,
+                    self.children.iter().copied(),
+                    drag.index,
+                    self.spacing,
+                    cursor_position,
+                );
+
+                if to != drag.index {
+                    #[cfg(feature = "instrumentation")]
+                    instrumentation::record(
+                        WidgetKind::ReorderableList,
+                        Action::Reorder(drag.index, to),
+                    );
+                    messages.push((self.on_reorder)(drag.index, to));
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw_dragged(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: self.style,
+                viewport: None,
+                focus: (),
+            },
+            self.element,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        (self.width() as u32).hash(state);
+        self.element.hash_layout(state);
+    }
+}
@@ -3,13 +3,20 @@
 //! *This API requires the following crate features to be activated: `time_picker`*
 use std::hash::Hash;
 
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
 use crate::{
     core::clock::{
         NearestRadius, HOUR_RADIUS_PERCENTAGE, HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
         MINUTE_RADIUS_PERCENTAGE, MINUTE_RADIUS_PERCENTAGE_NO_SECONDS, PERIOD_PERCENTAGE,
         SECOND_RADIUS_PERCENTAGE,
     },
-    core::{overlay::Position, renderer::DrawEnvironment, time::Period},
+    core::{
+        accessibility::{self, Accessibility, Node, Role},
+        overlay::Position,
+        renderer::DrawEnvironment,
+        time::Period,
+    },
     graphics::icons::Icon,
     native::{
         icon_text,
@@ -32,6 +39,19 @@ const PADDING: u16 = 10;
 const SPACING: u16 = 15;
 /// The spacing between the buttons.
 const BUTTON_SPACING: u16 = 5;
+/// The maximum number of hours of a picked duration.
+const MAX_DURATION_HOURS: u32 = 99;
+
+/// The function that produces a message when the submit button of a
+/// [`TimePickerOverlay`](TimePickerOverlay) is pressed.
+#[allow(missing_debug_implementations)]
+pub enum OnSubmit<'a, Message> {
+    /// Submits the picked time.
+    Time(&'a dyn Fn(Time) -> Message),
+
+    /// Submits the picked duration.
+    Duration(&'a dyn Fn(std::time::Duration) -> Message),
+}
 
 /// The overlay of the [`TimePicker`](crate::native::TimePicker).
 #[allow(missing_debug_implementations)]
@@ -46,10 +66,15 @@ where
     cancel_button: Element<'a, Message, Renderer>,
     /// The submit button of the [`TimePickerOverlay`](TimePickerOverlay).
     submit_button: Element<'a, Message, Renderer>,
+    /// The message that is send if the [`TimePickerOverlay`](TimePickerOverlay)
+    /// is cancelled.
+    on_cancel: Message,
     /// The function that produces a message when the submit button of the [`TimePickerOverlay`] is pressed.
-    on_submit: &'a dyn Fn(Time) -> Message,
+    on_submit: OnSubmit<'a, Message>,
     /// The position of the [`TimePickerOverlay`](TimePickerOverlay).
     position: Point,
+    /// The accessibility information of the [`TimePickerOverlay`](TimePickerOverlay).
+    accessibility: Node<'a>,
     /// The style of the [`TimePickerOverlay`](TimePickerOverlay).
     style: &'a <Renderer as self::Renderer>::Style,
 }
@@ -68,10 +93,13 @@ where
 {
     /// Creates a new [`TimePickerOverlay`](TimePickerOverlay) on the given
     /// position.
+    ///
+    /// If the overlay submits a duration, it only shows the digital clock
+    /// counting the hours beyond 24h without AM/PM, starting at zero.
     pub fn new(
         state: &'a mut time_picker::State,
         on_cancel: Message,
-        on_submit: &'a dyn Fn(Time) -> Message,
+        on_submit: OnSubmit<'a, Message>,
         position: Point,
         style: &'a <Renderer as self::Renderer>::Style,
     ) -> Self {
@@ -82,6 +110,17 @@ where
             ..
         } = state;
 
+        if let OnSubmit::Duration(_) = on_submit {
+            overlay_state.use_24h = true;
+            overlay_state.digital_only = true;
+
+            if overlay_state.duration_hours.is_none() {
+                overlay_state.duration_hours = Some(0);
+                overlay_state.time = NaiveTime::from_hms(0, 0, 0);
+                overlay_state.clock_cache.clear();
+            }
+        }
+
         TimePickerOverlay {
             state: overlay_state,
             cancel_button: Button::new(cancel_button, IconText::new(Icon::X).width(Length::Fill))
@@ -93,14 +132,24 @@ where
                 IconText::new(Icon::Check).width(Length::Fill),
             )
             .width(Length::Fill)
-            .on_press(on_cancel) // Sending a fake message
+            .on_press(on_cancel.clone()) // Sending a fake message
             .into(),
+            on_cancel,
             on_submit,
             position,
+            accessibility: Node::new(Role::Dialog),
             style,
         }
     }
 
+    /// Sets the [`Accessibility`](Accessibility) information announced for
+    /// the [`TimePickerOverlay`](TimePickerOverlay) as a dialog.
+    #[must_use]
+    pub fn accessibility(mut self, accessibility: &'a Accessibility) -> Self {
+        self.accessibility = accessibility.node(Role::Dialog);
+        self
+    }
+
     /// Turn this [`TimePickerOverlay`](TimePickerOverlay) into an overlay
     /// [`Element`](overlay::Element).
     #[must_use]
@@ -108,171 +157,6 @@ where
         overlay::Element::new(self.position, Box::new(self))
     }
 
-    /// The event handling for the clock.
-    #[allow(clippy::too_many_lines)]
-    fn on_event_clock(
-        &mut self,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        _messages: &mut Vec<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
-    ) -> event::Status {
-        if layout.bounds().contains(cursor_position) {
-            self.state.clock_cache_needs_clearance = true;
-            self.state.clock_cache.clear();
-        } else if self.state.clock_cache_needs_clearance {
-            self.state.clock_cache.clear();
-            self.state.clock_cache_needs_clearance = false;
-        }
-
-        let clock_bounds = layout.bounds();
-        if clock_bounds.contains(cursor_position) {
-            let center = clock_bounds.center();
-            let radius = clock_bounds.width.min(clock_bounds.height) * 0.5;
-
-            let period_radius = radius * PERIOD_PERCENTAGE;
-
-            let (hour_radius, minute_radius, second_radius) = if self.state.show_seconds {
-                (
-                    radius * HOUR_RADIUS_PERCENTAGE,
-                    radius * MINUTE_RADIUS_PERCENTAGE,
-                    radius * SECOND_RADIUS_PERCENTAGE,
-                )
-            } else {
-                (
-                    radius * HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
-                    radius * MINUTE_RADIUS_PERCENTAGE_NO_SECONDS,
-                    f32::MAX,
-                )
-            };
-
-            let nearest_radius = crate::core::clock::nearest_radius(
-                &if self.state.show_seconds {
-                    vec![
-                        (period_radius, NearestRadius::Period),
-                        (hour_radius, NearestRadius::Hour),
-                        (minute_radius, NearestRadius::Minute),
-                        (second_radius, NearestRadius::Second),
-                    ]
-                } else {
-                    vec![
-                        (period_radius, NearestRadius::Period),
-                        (hour_radius, NearestRadius::Hour),
-                        (minute_radius, NearestRadius::Minute),
-                    ]
-                },
-                cursor_position,
-                center,
-            );
-
-            let clock_clicked_status = match event {
-                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerPressed { .. }) => match nearest_radius {
-                    NearestRadius::Period => {
-                        let (pm, hour) = self.state.time.hour12();
-                        let hour = if hour == 12 {
-                            if pm {
-                                12
-                            } else {
-                                0
-                            }
-                        } else {
-                            hour
-                        };
-
-                        self.state.time = self
-                            .state
-                            .time
-                            .with_hour(if pm && hour != 12 { hour } else { hour + 12 } % 24)
-                            .expect("New time with hour should be valid");
-                        event::Status::Captured
-                    }
-                    NearestRadius::Hour => {
-                        self.state.focus = Focus::DigitalHour;
-                        self.state.clock_dragged = ClockDragged::Hour;
-                        event::Status::Captured
-                    }
-                    NearestRadius::Minute => {
-                        self.state.focus = Focus::DigitalMinute;
-                        self.state.clock_dragged = ClockDragged::Minute;
-                        event::Status::Captured
-                    }
-                    NearestRadius::Second => {
-                        self.state.focus = Focus::DigitalSecond;
-                        self.state.clock_dragged = ClockDragged::Second;
-                        event::Status::Captured
-                    }
-                    NearestRadius::None => event::Status::Ignored,
-                },
-                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerLifted { .. })
-                | Event::Touch(touch::Event::FingerLost { .. }) => {
-                    self.state.clock_dragged = ClockDragged::None;
-                    event::Status::Captured
-                }
-                _ => event::Status::Ignored,
-            };
-
-            let clock_dragged_status = match self.state.clock_dragged {
-                ClockDragged::Hour => {
-                    let hour_points = crate::core::clock::circle_points(hour_radius, center, 12);
-                    let nearest_point =
-                        crate::core::clock::nearest_point(&hour_points, cursor_position);
-
-                    let (pm, _) = self.state.time.hour12();
-
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_hour((nearest_point as u32 + if pm { 12 } else { 0 }) % 24)
-                        .expect("New time with hour should be valid");
-                    event::Status::Captured
-                }
-                ClockDragged::Minute => {
-                    let minute_points =
-                        crate::core::clock::circle_points(minute_radius, center, 60);
-                    let nearest_point =
-                        crate::core::clock::nearest_point(&minute_points, cursor_position);
-
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_minute(nearest_point as u32)
-                        .expect("New time with minute should be valid");
-                    event::Status::Captured
-                }
-                ClockDragged::Second => {
-                    let second_points =
-                        crate::core::clock::circle_points(second_radius, center, 60);
-                    let nearest_point =
-                        crate::core::clock::nearest_point(&second_points, cursor_position);
-
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_second(nearest_point as u32)
-                        .expect("New time with second should be valid");
-                    event::Status::Captured
-                }
-                ClockDragged::None => event::Status::Ignored,
-            };
-
-            clock_clicked_status.merge(clock_dragged_status)
-        } else {
-            match event {
-                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerLifted { .. })
-                | Event::Touch(touch::Event::FingerLost { .. }) => {
-                    self.state.clock_dragged = ClockDragged::None;
-                    event::Status::Captured
-                }
-                _ => event::Status::Ignored,
-            }
-        }
-    }
-
     /// The event handling for the digital clock.
     #[allow(clippy::too_many_lines)]
     fn on_event_digital_clock(
@@ -319,15 +203,15 @@ where
             .next()
             .expect("Native: Layout should have a down arrow for minutes");
 
-        let calculate_time = |time: &mut NaiveTime,
+        let calculate_time = |state: &mut State,
                               up_arrow: Layout<'_>,
                               down_arrow: Layout<'_>,
                               duration: Duration| {
             if up_arrow.bounds().contains(cursor_position) {
-                *time += duration;
+                state.add(duration);
                 event::Status::Captured
             } else if down_arrow.bounds().contains(cursor_position) {
-                *time -= duration;
+                state.add(-duration);
                 event::Status::Captured
             } else {
                 event::Status::Ignored
@@ -341,7 +225,7 @@ where
                     self.state.focus = Focus::DigitalHour;
 
                     calculate_time(
-                        &mut self.state.time,
+                        self.state,
                         hour_up_arrow,
                         hour_down_arrow,
                         Duration::hours(1),
@@ -350,7 +234,7 @@ where
                     self.state.focus = Focus::DigitalMinute;
 
                     calculate_time(
-                        &mut self.state.time,
+                        self.state,
                         minute_up_arrow,
                         minute_down_arrow,
                         Duration::minutes(1),
@@ -385,7 +269,7 @@ where
                         self.state.focus = Focus::DigitalSecond;
 
                         calculate_time(
-                            &mut self.state.time,
+                            self.state,
                             second_up_arrow,
                             second_down_arrow,
                             Duration::seconds(1),
@@ -409,57 +293,114 @@ where
         digital_clock_status
     }
 
+    /// Produces the submit message with the picked time or duration.
+    fn submit(&self, messages: &mut Vec<Message>) {
+        #[cfg(feature = "instrumentation")]
+        instrumentation::record(WidgetKind::TimePicker, Action::Submit);
+
+        messages.push(match self.on_submit {
+            OnSubmit::Time(on_submit) => on_submit(self.state.picked_time()),
+            OnSubmit::Duration(on_submit) => on_submit(self.state.picked_duration()),
+        });
+    }
+
     /// The event handling for the keyboard input.
+    ///
+    /// Tab and Shift+Tab cycle the focus through the clock, the digital
+    /// hour, minute and second and the buttons, while the arrow keys change
+    /// the focused value. Escape cancels the
+    /// [`TimePickerOverlay`](TimePickerOverlay) and Enter submits it, unless
+    /// the cancel button is focused.
     fn on_event_keyboard(
         &mut self,
         event: &Event,
         _layout: Layout<'_>,
         _cursor_position: Point,
-        _messages: &mut Vec<Message>,
+        messages: &mut Vec<Message>,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
     ) -> event::Status {
-        if self.state.focus == Focus::None {
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            self.state.keyboard_modifiers = *modifiers;
             return event::Status::Ignored;
         }
 
         if let Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) = event {
-            let mut status = event::Status::Ignored;
+            match key_code {
+                keyboard::KeyCode::Tab => {
+                    self.state.digit_input.clear();
+                    let show_clock = !self.state.digital_only;
+                    if self.state.keyboard_modifiers.shift {
+                        self.state.focus = self
+                            .state
+                            .focus
+                            .previous(show_clock, self.state.show_seconds);
+                    } else {
+                        self.state.focus =
+                            self.state.focus.next(show_clock, self.state.show_seconds);
+                    }
+                    return event::Status::Captured;
+                }
+                keyboard::KeyCode::Escape => {
+                    #[cfg(feature = "instrumentation")]
+                    instrumentation::record(WidgetKind::TimePicker, Action::Escape);
 
-            if let keyboard::KeyCode::Tab = key_code {
-                if self.state.keyboard_modifiers.shift {
-                    self.state.focus = self.state.focus.previous(self.state.show_seconds);
-                } else {
-                    self.state.focus = self.state.focus.next(self.state.show_seconds);
+                    messages.push(self.on_cancel.clone());
+                    return event::Status::Captured;
                 }
-            } else {
-                let mut keyboard_handle =
-                    |key_code: &keyboard::KeyCode, time: &mut NaiveTime, duration: Duration| {
-                        match key_code {
-                            keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
-                                *time -= duration;
-                                status = event::Status::Captured;
-                            }
-                            keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
-                                *time += duration;
-                                status = event::Status::Captured;
-                            }
-                            _ => {}
-                        }
-                    };
+                keyboard::KeyCode::Enter if self.state.focus != Focus::None => {
+                    self.state.digit_input.clear();
+                    if self.state.focus == Focus::Cancel {
+                        #[cfg(feature = "instrumentation")]
+                        instrumentation::record(WidgetKind::TimePicker, Action::Cancel);
 
-                match self.state.focus {
-                    Focus::DigitalHour => {
-                        keyboard_handle(key_code, &mut self.state.time, Duration::hours(1))
+                        messages.push(self.on_cancel.clone());
+                    } else {
+                        self.submit(messages);
                     }
-                    Focus::DigitalMinute => {
-                        keyboard_handle(key_code, &mut self.state.time, Duration::minutes(1))
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        if self.state.focus == Focus::None {
+            return event::Status::Ignored;
+        }
+
+        if let Event::Keyboard(keyboard::Event::CharacterReceived(c)) = event {
+            on_event_digit(self.state, *c)
+        } else if let Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) = event {
+            let mut status = event::Status::Ignored;
+            self.state.digit_input.clear();
+
+            let mut keyboard_handle = |key_code: &keyboard::KeyCode,
+                                       state: &mut State,
+                                       duration: Duration| {
+                match key_code {
+                    keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                        state.add(-duration);
+                        status = event::Status::Captured;
                     }
-                    Focus::DigitalSecond => {
-                        keyboard_handle(key_code, &mut self.state.time, Duration::seconds(1))
+                    keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                        state.add(duration);
+                        status = event::Status::Captured;
                     }
                     _ => {}
                 }
+            };
+
+            match self.state.focus {
+                Focus::Clock => match key_code {
+                    keyboard::KeyCode::Left | keyboard::KeyCode::Right => {
+                        keyboard_handle(key_code, self.state, Duration::minutes(1))
+                    }
+                    _ => keyboard_handle(key_code, self.state, Duration::hours(1)),
+                },
+                Focus::DigitalHour => keyboard_handle(key_code, self.state, Duration::hours(1)),
+                Focus::DigitalMinute => keyboard_handle(key_code, self.state, Duration::minutes(1)),
+                Focus::DigitalSecond => keyboard_handle(key_code, self.state, Duration::seconds(1)),
+                _ => {}
             }
 
             if status == event::Status::Captured {
@@ -467,15 +408,78 @@ where
             }
 
             status
-        } else if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
-            self.state.keyboard_modifiers = *modifiers;
-            event::Status::Ignored
         } else {
             event::Status::Ignored
         }
     }
 }
 
+/// The event handling for the digits typed into the focused digital hour,
+/// minute or second.
+///
+/// The first digit sets the focused value and the second digit completes it,
+/// moving the focus on to the next digital value. A second digit making the
+/// value out of range starts a new value instead.
+fn on_event_digit(state: &mut State, c: char) -> event::Status {
+    let digit = match c.to_digit(10) {
+        Some(digit) => digit,
+        None => {
+            state.digit_input.clear();
+            return event::Status::Ignored;
+        }
+    };
+
+    let max = match state.focus {
+        Focus::DigitalHour if state.duration_hours.is_some() => MAX_DURATION_HOURS,
+        Focus::DigitalHour if state.use_24h => 23,
+        Focus::DigitalHour => 12,
+        Focus::DigitalMinute | Focus::DigitalSecond => 59,
+        _ => return event::Status::Ignored,
+    };
+
+    if state.digit_input.len() == 2 {
+        state.digit_input.clear();
+    }
+    state.digit_input.push(c);
+
+    let mut value = state
+        .digit_input
+        .parse::<u32>()
+        .expect("Native: Typed digits should be a number");
+    if value > max {
+        state.digit_input.clear();
+        state.digit_input.push(c);
+        value = digit;
+    }
+
+    let time = match state.focus {
+        Focus::DigitalHour if state.duration_hours.is_some() => {
+            state.duration_hours = Some(value);
+            Some(state.time)
+        }
+        Focus::DigitalHour if state.use_24h => state.time.with_hour(value),
+        Focus::DigitalHour => {
+            let (pm, _) = state.time.hour12();
+            state.time.with_hour(value % 12 + if pm { 12 } else { 0 })
+        }
+        Focus::DigitalMinute => state.time.with_minute(value),
+        _ => state.time.with_second(value),
+    };
+    state.time = time.expect("New time with typed value should be valid");
+    state.clock_cache.clear();
+
+    if state.digit_input.len() == 2 {
+        state.digit_input.clear();
+
+        let next = state.focus.next(!state.digital_only, state.show_seconds);
+        if let Focus::DigitalMinute | Focus::DigitalSecond = next {
+            state.focus = next;
+        }
+    }
+
+    event::Status::Captured
+}
+
 impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
     for TimePickerOverlay<'a, Message, Renderer>
 where
@@ -517,10 +521,21 @@ where
                 + 2.0 * f32::from(SPACING),
         ));
 
+        // The spacing between the clock and the digital clock.
+        let clock_spacing = if self.state.digital_only {
+            0.0
+        } else {
+            f32::from(SPACING)
+        };
+
         // Clock-Canvas
         let mut clock = Row::<(), Renderer>::new()
             .width(Length::Fill)
-            .height(Length::Fill)
+            .height(if self.state.digital_only {
+                Length::Units(0)
+            } else {
+                Length::Fill
+            })
             .layout(renderer, &limits);
 
         clock.move_to(Point::new(
@@ -530,10 +545,7 @@ where
 
         digital_clock.move_to(Point::new(
             digital_clock.bounds().x + f32::from(PADDING),
-            digital_clock.bounds().y
-                + f32::from(PADDING)
-                + f32::from(SPACING)
-                + clock.bounds().height,
+            digital_clock.bounds().y + f32::from(PADDING) + clock_spacing + clock.bounds().height,
         ));
 
         // Buttons
@@ -555,7 +567,8 @@ where
                 + clock.bounds().height
                 + f32::from(PADDING)
                 + digital_clock.bounds().height
-                + 2.0 * f32::from(SPACING),
+                + clock_spacing
+                + f32::from(SPACING),
         });
 
         submit_button.move_to(Point {
@@ -565,7 +578,8 @@ where
                 + clock.bounds().height
                 + f32::from(PADDING)
                 + digital_clock.bounds().height
-                + 2.0 * f32::from(SPACING),
+                + clock_spacing
+                + f32::from(SPACING),
         });
 
         let mut node = layout::Node::with_children(
@@ -575,7 +589,8 @@ where
                     + digital_clock.bounds().height
                     + cancel_button.bounds().height
                     + (2.0 * f32::from(PADDING))
-                    + 2.0 * f32::from(SPACING),
+                    + clock_spacing
+                    + f32::from(SPACING),
             ),
             vec![clock, digital_clock, cancel_button, submit_button],
         );
@@ -605,20 +620,19 @@ where
             return event::Status::Captured;
         }
 
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            self.state.digit_input.clear();
+        }
+
         let mut children = layout.children();
 
         // Clock canvas
         let clock_layout = children
             .next()
             .expect("Native: Layout should have a clock canvas layout");
-        let clock_status = self.on_event_clock(
-            &event,
-            clock_layout,
-            cursor_position,
-            messages,
-            renderer,
-            clipboard,
-        );
+        let clock_status = clock_on_event(self.state, &event, clock_layout, cursor_position);
 
         // ----------- Digital clock ------------------
         let digital_clock_layout = children
@@ -641,6 +655,9 @@ where
             .next()
             .expect("Native: Layout should have a cancel button layout for a TimePicker");
 
+        #[cfg(feature = "instrumentation")]
+        let message_count = messages.len();
+
         let cancel_status = self.cancel_button.on_event(
             event.clone(),
             cancel_button_layout,
@@ -650,6 +667,11 @@ where
             messages,
         );
 
+        #[cfg(feature = "instrumentation")]
+        if messages.len() > message_count {
+            instrumentation::record(WidgetKind::TimePicker, Action::Cancel);
+        }
+
         let submit_button_layout = children
             .next()
             .expect("Native: Layout should have a submit button layout for a TimePicker");
@@ -666,29 +688,7 @@ where
         );
 
         if !fake_messages.is_empty() {
-            let (hour, period) = if self.state.use_24h {
-                (self.state.time.hour(), Period::H24)
-            } else {
-                let (period, hour) = self.state.time.hour12();
-                (hour, if period { Period::Pm } else { Period::Am })
-            };
-
-            let time = if self.state.show_seconds {
-                Time::Hms {
-                    hour,
-                    minute: self.state.time.minute(),
-                    second: self.state.time.second(),
-                    period,
-                }
-            } else {
-                Time::Hm {
-                    hour,
-                    minute: self.state.time.minute(),
-                    period,
-                }
-            };
-
-            messages.push((self.on_submit)(time))
+            self.submit(messages);
         }
 
         clock_status
@@ -704,6 +704,8 @@ where
         layout: iced_native::Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        accessibility::Renderer::announce(renderer, self.accessibility, layout.bounds());
+
         <Renderer as self::Renderer>::draw(
             renderer,
             DrawEnvironment {
@@ -729,6 +731,166 @@ where
         (position.y as u32).hash(state);
         self.state.show_seconds.hash(state);
         self.state.use_24h.hash(state);
+        self.state.digital_only.hash(state);
+    }
+}
+
+/// Handles the mouse and touch events of the clock.
+///
+/// This is shared between the [`TimePickerOverlay`](TimePickerOverlay) and
+/// the [`Clock`](crate::native::time_picker::Clock).
+#[allow(clippy::too_many_lines)]
+pub(crate) fn clock_on_event(
+    state: &mut State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> event::Status {
+    if layout.bounds().contains(cursor_position) {
+        state.clock_cache_needs_clearance = true;
+        state.clock_cache.clear();
+    } else if state.clock_cache_needs_clearance {
+        state.clock_cache.clear();
+        state.clock_cache_needs_clearance = false;
+    }
+
+    let clock_bounds = layout.bounds();
+    if clock_bounds.contains(cursor_position) {
+        let center = clock_bounds.center();
+        let radius = clock_bounds.width.min(clock_bounds.height) * 0.5;
+
+        let period_radius = radius * PERIOD_PERCENTAGE;
+
+        let (hour_radius, minute_radius, second_radius) = if state.show_seconds {
+            (
+                radius * HOUR_RADIUS_PERCENTAGE,
+                radius * MINUTE_RADIUS_PERCENTAGE,
+                radius * SECOND_RADIUS_PERCENTAGE,
+            )
+        } else {
+            (
+                radius * HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
+                radius * MINUTE_RADIUS_PERCENTAGE_NO_SECONDS,
+                f32::MAX,
+            )
+        };
+
+        let nearest_radius = crate::core::clock::nearest_radius(
+            &if state.show_seconds {
+                vec![
+                    (period_radius, NearestRadius::Period),
+                    (hour_radius, NearestRadius::Hour),
+                    (minute_radius, NearestRadius::Minute),
+                    (second_radius, NearestRadius::Second),
+                ]
+            } else {
+                vec![
+                    (period_radius, NearestRadius::Period),
+                    (hour_radius, NearestRadius::Hour),
+                    (minute_radius, NearestRadius::Minute),
+                ]
+            },
+            cursor_position,
+            center,
+        );
+
+        let clock_clicked_status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => match nearest_radius {
+                NearestRadius::Period => {
+                    let (pm, hour) = state.time.hour12();
+                    let hour = if hour == 12 {
+                        if pm {
+                            12
+                        } else {
+                            0
+                        }
+                    } else {
+                        hour
+                    };
+
+                    state.time = state
+                        .time
+                        .with_hour(if pm && hour != 12 { hour } else { hour + 12 } % 24)
+                        .expect("New time with hour should be valid");
+                    event::Status::Captured
+                }
+                NearestRadius::Hour => {
+                    state.focus = Focus::DigitalHour;
+                    state.clock_dragged = ClockDragged::Hour;
+                    event::Status::Captured
+                }
+                NearestRadius::Minute => {
+                    state.focus = Focus::DigitalMinute;
+                    state.clock_dragged = ClockDragged::Minute;
+                    event::Status::Captured
+                }
+                NearestRadius::Second => {
+                    state.focus = Focus::DigitalSecond;
+                    state.clock_dragged = ClockDragged::Second;
+                    event::Status::Captured
+                }
+                NearestRadius::None => event::Status::Ignored,
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.clock_dragged = ClockDragged::None;
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        };
+
+        let clock_dragged_status = match state.clock_dragged {
+            ClockDragged::Hour => {
+                let hour_points = crate::core::clock::circle_points(hour_radius, center, 12);
+                let nearest_point =
+                    crate::core::clock::nearest_point(&hour_points, cursor_position);
+
+                let (pm, _) = state.time.hour12();
+
+                state.time = state
+                    .time
+                    .with_hour((nearest_point as u32 + if pm { 12 } else { 0 }) % 24)
+                    .expect("New time with hour should be valid");
+                event::Status::Captured
+            }
+            ClockDragged::Minute => {
+                let minute_points = crate::core::clock::circle_points(minute_radius, center, 60);
+                let nearest_point =
+                    crate::core::clock::nearest_point(&minute_points, cursor_position);
+
+                state.time = state
+                    .time
+                    .with_minute(nearest_point as u32)
+                    .expect("New time with minute should be valid");
+                event::Status::Captured
+            }
+            ClockDragged::Second => {
+                let second_points = crate::core::clock::circle_points(second_radius, center, 60);
+                let nearest_point =
+                    crate::core::clock::nearest_point(&second_points, cursor_position);
+
+                state.time = state
+                    .time
+                    .with_second(nearest_point as u32)
+                    .expect("New time with second should be valid");
+                event::Status::Captured
+            }
+            ClockDragged::None => event::Status::Ignored,
+        };
+
+        clock_clicked_status.merge(clock_dragged_status)
+    } else {
+        match event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.clock_dragged = ClockDragged::None;
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
     }
 }
 
@@ -860,7 +1022,7 @@ where
 /// Your renderer fill need to implement this trait before being
 /// able to use a [`TimePicker`](crate::native::TimePicker) in your user
 /// interface.
-pub trait Renderer: iced_native::Renderer {
+pub trait Renderer: iced_native::Renderer + accessibility::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
@@ -901,12 +1063,87 @@ pub struct State {
     pub(crate) use_24h: bool,
     /// Toggle the use of the seconds of the [`TimePickerOverlay`](TimePickerOverlay).
     pub(crate) show_seconds: bool,
+    /// Toggle the hiding of the analog clock of the [`TimePickerOverlay`](TimePickerOverlay).
+    pub(crate) digital_only: bool,
     /// The dragged clock element of the [`TimePickerOverlay`](TimePickerOverlay).
     pub(crate) clock_dragged: ClockDragged,
     /// The focus of the [`TimePickerOverlay`](TimePickerOverlay).
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The digits typed into the focused digital hour, minute or second.
+    pub(crate) digit_input: String,
+    /// The hours of the picked duration, if the
+    /// [`TimePickerOverlay`](TimePickerOverlay) picks a duration.
+    ///
+    /// The minutes and seconds of the duration are kept in the time.
+    pub(crate) duration_hours: Option<u32>,
+}
+
+impl State {
+    /// Gets the hour shown by the digital clock.
+    pub(crate) fn digital_hour(&self) -> u32 {
+        match self.duration_hours {
+            Some(hours) => hours,
+            None if self.use_24h => self.time.hour(),
+            None => self.time.hour12().1,
+        }
+    }
+
+    /// Adds the given duration to the selected time.
+    ///
+    /// The time wraps around midnight, while a picked duration is kept
+    /// between zero and its maximum.
+    pub(crate) fn add(&mut self, duration: Duration) {
+        match self.duration_hours {
+            Some(hours) => {
+                let seconds = (i64::from(hours) * 3600
+                    + i64::from(self.time.num_seconds_from_midnight())
+                    + duration.num_seconds())
+                .max(0)
+                .min(i64::from(MAX_DURATION_HOURS) * 3600 + 3599);
+
+                self.duration_hours = Some((seconds / 3600) as u32);
+                self.time = NaiveTime::from_num_seconds_from_midnight((seconds % 3600) as u32, 0);
+            }
+            None => self.time += duration,
+        }
+    }
+
+    /// Gets the picked duration of the [`TimePickerOverlay`](TimePickerOverlay).
+    pub(crate) fn picked_duration(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(
+            u64::from(self.duration_hours.unwrap_or_else(|| self.time.hour())) * 3600
+                + u64::from(self.time.minute()) * 60
+                + u64::from(self.time.second()),
+        )
+    }
+
+    /// Gets the selected time of the [`TimePickerOverlay`](TimePickerOverlay)
+    /// as a [`Time`](Time), in the 12h or 24h format of the state.
+    pub(crate) fn picked_time(&self) -> Time {
+        let (hour, period) = if self.use_24h {
+            (self.time.hour(), Period::H24)
+        } else {
+            let (period, hour) = self.time.hour12();
+            (hour, if period { Period::Pm } else { Period::Am })
+        };
+
+        if self.show_seconds {
+            Time::Hms {
+                hour,
+                minute: self.time.minute(),
+                second: self.time.second(),
+                period,
+            }
+        } else {
+            Time::Hm {
+                hour,
+                minute: self.time.minute(),
+                period,
+            }
+        }
+    }
 }
 
 impl Default for State {
@@ -917,9 +1154,12 @@ impl Default for State {
             clock_cache: canvas::Cache::new(),
             use_24h: false,
             show_seconds: false,
+            digital_only: false,
             clock_dragged: ClockDragged::None,
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            digit_input: String::new(),
+            duration_hours: None,
         }
     }
 }
@@ -949,6 +1189,9 @@ pub enum Focus {
     /// The overlay itself is in focus.
     Overlay,
 
+    /// The analog clock is in focus.
+    Clock,
+
     /// The digital hour is in focus.
     DigitalHour,
 
@@ -967,10 +1210,19 @@ pub enum Focus {
 
 impl Focus {
     /// Gets the next focusable element.
+    ///
+    /// The clock and the digital second are skipped if they are not shown.
     #[must_use]
-    pub const fn next(self, show_seconds: bool) -> Self {
+    pub const fn next(self, show_clock: bool, show_seconds: bool) -> Self {
         match self {
-            Self::Overlay => Self::DigitalHour,
+            Self::Overlay => {
+                if show_clock {
+                    Self::Clock
+                } else {
+                    Self::DigitalHour
+                }
+            }
+            Self::Clock => Self::DigitalHour,
             Self::DigitalHour => Self::DigitalMinute,
             Self::DigitalMinute => {
                 if show_seconds {
@@ -986,12 +1238,20 @@ impl Focus {
     }
 
     /// Gets the previous focusable element.
+    ///
+    /// The clock and the digital second are skipped if they are not shown.
     #[must_use]
-    pub const fn previous(self, show_seconds: bool) -> Self {
+    pub const fn previous(self, show_clock: bool, show_seconds: bool) -> Self {
         match self {
-            Self::None => Self::None,
-            Self::Overlay => Self::Submit,
-            Self::DigitalHour => Self::Overlay,
+            Self::Overlay | Self::None => Self::Submit,
+            Self::Clock => Self::Overlay,
+            Self::DigitalHour => {
+                if show_clock {
+                    Self::Clock
+                } else {
+                    Self::Overlay
+                }
+            }
             Self::DigitalMinute => Self::DigitalHour,
             Self::DigitalSecond => Self::DigitalMinute,
             Self::Cancel => {
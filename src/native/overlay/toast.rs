@@ -0,0 +1,305 @@
+//! Use a toast to display short notifications stacked in a corner of the
+//! window.
+//!
+//! *This API requires the following crate features to be activated: `toast`*
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout::Node, mouse, overlay, touch, Clipboard, Event, Layout, Point, Size,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::{
+    core::renderer::DrawEnvironment,
+    native::toast::{Anchor, Notification, Offset, State},
+};
+
+/// The overlay of a [`Toast`](crate::native::Toast) displaying the stacked
+/// notification cards.
+#[allow(missing_debug_implementations)]
+pub struct ToastOverlay<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// The state of the [`Toast`](crate::native::Toast).
+    state: &'a mut State,
+    /// The corner of the window the notifications are stacked in.
+    anchor: &'a Anchor,
+    /// The offset of the notifications from the corner of the window.
+    offset: &'a Offset,
+    /// The width of the notification cards.
+    width: f32,
+    /// The spacing between the notification cards.
+    spacing: u16,
+    /// The padding of the notification cards.
+    padding: u16,
+    /// The optional text size of the notification cards.
+    text_size: Option<u16>,
+    /// The optional function that produces a message when a notification is
+    /// closed by the user.
+    on_close: Option<&'a dyn Fn(usize) -> Message>,
+    /// The style of the notification cards.
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> ToastOverlay<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    /// Creates a new [`ToastOverlay`](ToastOverlay) displaying the
+    /// notifications of the given [`State`](State).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        state: &'a mut State,
+        anchor: &'a Anchor,
+        offset: &'a Offset,
+        width: f32,
+        spacing: u16,
+        padding: u16,
+        text_size: Option<u16>,
+        on_close: Option<&'a dyn Fn(usize) -> Message>,
+        style: &'a <Renderer as self::Renderer>::Style,
+    ) -> Self {
+        ToastOverlay {
+            state,
+            anchor,
+            offset,
+            width,
+            spacing,
+            padding,
+            text_size,
+            on_close,
+            style,
+        }
+    }
+
+    /// Turns the [`ToastOverlay`](ToastOverlay) into an overlay
+    /// [`Element`](iced_native::overlay::Element).
+    ///
+    /// The position is ignored, as the notifications are always placed
+    /// relative to the corners of the window.
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(Point::ORIGIN, Box::new(self))
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for ToastOverlay<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, _position: Point) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let padding = f32::from(self.padding);
+        let close_size = f32::from(text_size);
+        let text_width = (self.width - 3.0 * padding - close_size).max(0.0);
+        let text_bounds = Size::new(text_width, f32::INFINITY);
+
+        let mut y = 0.0;
+        let cards: Vec<Node> = self
+            .state
+            .notifications
+            .iter()
+            .map(|(_, notification, _)| {
+                let (_, title_height) =
+                    renderer.measure(&notification.title, text_size, text_bounds);
+                let (_, body_height) = renderer.measure(&notification.body, text_size, text_bounds);
+
+                let mut title = Node::new(Size::new(text_width, title_height));
+                title.move_to(Point::new(padding, padding));
+
+                let mut body = Node::new(Size::new(text_width, body_height));
+                body.move_to(Point::new(padding, padding + title_height + padding / 2.0));
+
+                let mut close = Node::new(Size::new(close_size, close_size));
+                close.move_to(Point::new(self.width - padding - close_size, padding));
+
+                let height = 2.5 * padding + title_height + body_height;
+                let mut card =
+                    Node::with_children(Size::new(self.width, height), vec![title, body, close]);
+                card.move_to(Point::new(0.0, y));
+
+                y += height + f32::from(self.spacing);
+
+                card
+            })
+            .collect();
+
+        let height = (y - f32::from(self.spacing)).max(0.0);
+        let mut node = Node::with_children(Size::new(self.width, height), cards);
+
+        node.move_to(match self.anchor {
+            Anchor::NorthWest => Point::new(self.offset.x, self.offset.y),
+            Anchor::NorthEast => {
+                Point::new(bounds.width - self.width - self.offset.x, self.offset.y)
+            }
+            Anchor::SouthWest => Point::new(self.offset.x, bounds.height - height - self.offset.y),
+            Anchor::SouthEast => Point::new(
+                bounds.width - self.width - self.offset.x,
+                bounds.height - height - self.offset.y,
+            ),
+        });
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let closed = layout
+                    .children()
+                    .zip(self.state.notifications.iter())
+                    .find_map(|(card, (id, _, _))| {
+                        card.children()
+                            .nth(2)
+                            .filter(|close| close.bounds().contains(cursor_position))
+                            .map(|_| *id)
+                    });
+
+                if let Some(id) = closed {
+                    self.state.dismiss(id);
+
+                    #[cfg(feature = "instrumentation")]
+                    instrumentation::record(WidgetKind::Toast, Action::Dismiss(id));
+
+                    if let Some(on_close) = self.on_close {
+                        messages.push(on_close(id));
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                // Clicks on the cards should not reach the underlying content.
+                if layout
+                    .children()
+                    .any(|card| card.bounds().contains(cursor_position))
+                {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let notifications: Vec<&Notification> = self
+            .state
+            .notifications
+            .iter()
+            .map(|(_, notification, _)| notification)
+            .collect();
+
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: self.style,
+                viewport: None,
+                focus: (),
+            },
+            &notifications,
+            self.text_size.unwrap_or_else(|| renderer.default_size()),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, _position: Point) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.anchor.hash(state);
+        (self.offset.x as u32).hash(state);
+        (self.offset.y as u32).hash(state);
+        (self.width as u32).hash(state);
+        self.spacing.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+
+        for (id, notification, _) in &self.state.notifications {
+            id.hash(state);
+            notification.title.hash(state);
+            notification.body.hash(state);
+        }
+    }
+}
+
+/// The renderer of a [`ToastOverlay`](ToastOverlay).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Toast`](crate::native::Toast) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default width of a notification card.
+    const DEFAULT_WIDTH: f32;
+
+    /// The default spacing between the notification cards.
+    const DEFAULT_SPACING: u16;
+
+    /// The default padding of a notification card.
+    const DEFAULT_PADDING: u16;
+
+    /// The default text size of a notification card.
+    fn default_size(&self) -> u16;
+
+    /// Measures the given text in the given bounds and returns the minimum
+    /// boundaries that can fit it.
+    fn measure(&self, content: &str, size: u16, bounds: Size) -> (f32, f32);
+
+    /// Draws a [`ToastOverlay`](ToastOverlay).
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        notifications: &[&Notification],
+        text_size: u16,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_WIDTH: f32 = 0.0;
+
+    const DEFAULT_SPACING: u16 = 0;
+
+    const DEFAULT_PADDING: u16 = 0;
+
+    fn default_size(&self) -> u16 {
+        0
+    }
+
+    fn measure(&self, _content: &str, _size: u16, _bounds: Size) -> (f32, f32) {
+        (0.0, 0.0)
+    }
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _notifications: &[&Notification],
+        _text_size: u16,
+    ) -> Self::Output {
+    }
+}
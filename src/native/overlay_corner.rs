@@ -0,0 +1,191 @@
+//! Use an overlay corner to pin a small element to a corner of the window.
+//!
+//! *This API requires the following crate features to be activated: `overlay_corner`*
+use std::hash::Hash;
+
+use iced_native::{
+    event, overlay, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Widget,
+};
+
+pub use crate::native::floating_element::{Anchor, Offset};
+
+pub use super::overlay::overlay_corner::Renderer;
+use super::overlay::overlay_corner::{self, OverlayCornerOverlay};
+
+/// An element pinned to a corner of the window, regardless of the layout of
+/// the underlying content.
+///
+/// This is useful for global status indicators like a badge, a spinner or a
+/// connection indicator.
+///
+/// The overlays of the underlying content, e.g. an opened
+/// [`Modal`](crate::native::Modal) or picker, are shown below the pinned
+/// element.
+///
+/// # Example
+/// ```
+/// # use iced_native::{Column, renderer::Null, Text};
+/// #
+/// # pub type OverlayCorner<'a, Message> = iced_aw::native::OverlayCorner<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+/// }
+///
+/// let content = Column::<Message, Null>::new();
+/// let overlay_corner = OverlayCorner::new(
+///     content,
+///     Text::new("Online"),
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct OverlayCorner<'a, Message, Renderer: overlay_corner::Renderer> {
+    /// The anchor of the pinned element.
+    anchor: Anchor,
+    /// The offset of the pinned element.
+    offset: Offset,
+    /// The visibility of the pinned element.
+    hidden: bool,
+    /// The underlying element.
+    underlay: Element<'a, Message, Renderer>,
+    /// The element pinned to the corner of the window.
+    element: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> OverlayCorner<'a, Message, Renderer>
+where
+    Renderer: overlay_corner::Renderer,
+{
+    /// Creates a new [`OverlayCorner`](OverlayCorner) wrapped around the given
+    /// content, pinning the given element to a corner of the window.
+    ///
+    /// It expects:
+    ///     * the underlay [`Element`](iced_native::Element) on which this
+    ///         [`OverlayCorner`](OverlayCorner) will be wrapped around.
+    ///     * the [`Element`](iced_native::Element) that will be pinned to the
+    ///         corner of the window.
+    pub fn new<U, E>(underlay: U, element: E) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        OverlayCorner {
+            anchor: Anchor::NorthEast,
+            offset: 5.0.into(),
+            hidden: false,
+            underlay: underlay.into(),
+            element: element.into(),
+        }
+    }
+
+    /// Sets the [`Anchor`](Anchor) of the [`OverlayCorner`](OverlayCorner).
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the [`Offset`](Offset) of the [`OverlayCorner`](OverlayCorner).
+    pub fn offset<O>(mut self, offset: O) -> Self
+    where
+        O: Into<Offset>,
+    {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Hide or unhide the pinned element of the
+    /// [`OverlayCorner`](OverlayCorner).
+    pub fn hide(mut self, hide: bool) -> Self {
+        self.hidden = hide;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for OverlayCorner<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + overlay_corner::Renderer,
+{
+    fn width(&self) -> Length {
+        self.underlay.width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.underlay.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        self.underlay.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: iced_native::Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.underlay
+            .draw(renderer, defaults, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.anchor.hash(state);
+        (self.offset.x as u32).hash(state);
+        (self.offset.y as u32).hash(state);
+        self.hidden.hash(state);
+        self.underlay.hash_layout(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let underlay = self.underlay.overlay(layout);
+
+        if self.hidden {
+            return underlay;
+        }
+
+        Some(
+            OverlayCornerOverlay::new(&mut self.element, &self.anchor, &self.offset)
+                .underlay(underlay)
+                .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<OverlayCorner<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + overlay_corner::Renderer,
+{
+    fn from(overlay_corner: OverlayCorner<'a, Message, Renderer>) -> Self {
+        Element::new(overlay_corner)
+    }
+}
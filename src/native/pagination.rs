@@ -0,0 +1,359 @@
+//! Use a pagination to navigate between the pages of some content.
+//!
+//! *This API requires the following crate features to be activated: `pagination`*
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout, mouse, touch, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+pub use crate::core::pagination::Page;
+use crate::core::{
+    pagination::{compact_label, pages, ELLIPSIS},
+    renderer::DrawEnvironment,
+};
+
+/// A row of buttons to navigate between pages, framed by buttons to go to the
+/// previous and the next page.
+///
+/// If there are many pages, only the first and the last pages and the pages
+/// around the current page are shown, the others are collapsed into an
+/// ellipsis. In compact mode, only the current page and the number of pages
+/// are shown between the previous and the next button.
+///
+/// The pages are counted from zero, but shown counting from one.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Pagination<Message> = iced_aw::native::Pagination<Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     PageChanged(usize),
+/// }
+///
+/// let pagination = Pagination::new(4, 20, Message::PageChanged)
+///     .siblings(2)
+///     .boundaries(1);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Pagination<Message, Renderer: self::Renderer> {
+    /// The index of the current page.
+    current: usize,
+    /// The number of pages.
+    count: usize,
+    /// The number of pages shown on each side of the current page.
+    siblings: usize,
+    /// The number of pages shown at the beginning and the end.
+    boundaries: usize,
+    /// Whether only the current page and the number of pages are shown.
+    compact: bool,
+    /// The spacing between the buttons.
+    spacing: u16,
+    /// The padding of the buttons.
+    padding: u16,
+    /// The optional text size of the buttons.
+    text_size: Option<u16>,
+    /// The function that produces a message when another page is selected.
+    on_page_change: Box<dyn Fn(usize) -> Message>,
+    /// The style of the [`Pagination`](Pagination).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<Message, Renderer> Pagination<Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Pagination`](Pagination).
+    ///
+    /// It expects:
+    ///     * the index of the current page.
+    ///     * the number of pages.
+    ///     * a function that will be called with the index of the page the
+    ///         user navigated to.
+    pub fn new<F>(current: usize, count: usize, on_page_change: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        Pagination {
+            current,
+            count,
+            siblings: 1,
+            boundaries: 1,
+            compact: false,
+            spacing: <Renderer as self::Renderer>::DEFAULT_SPACING,
+            padding: <Renderer as self::Renderer>::DEFAULT_PADDING,
+            text_size: None,
+            on_page_change: Box::new(on_page_change),
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the number of pages shown on each side of the current page.
+    pub fn siblings(mut self, siblings: usize) -> Self {
+        self.siblings = siblings;
+        self
+    }
+
+    /// Sets the number of pages always shown at the beginning and the end.
+    pub fn boundaries(mut self, boundaries: usize) -> Self {
+        self.boundaries = boundaries;
+        self
+    }
+
+    /// Sets whether only the current page and the number of pages are shown
+    /// instead of the page buttons, e.g. "Page 2 of 10".
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Sets the spacing between the buttons.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding of the buttons.
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the buttons.
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`Pagination`](Pagination).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the items shown between the previous and the next button.
+    ///
+    /// In compact mode, no items are shown.
+    fn items(&self) -> Vec<Page> {
+        if self.compact {
+            Vec::new()
+        } else {
+            pages(self.current, self.count, self.siblings, self.boundaries)
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Pagination<Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let padding = f32::from(self.padding);
+        let spacing = f32::from(self.spacing);
+        let height = f32::from(text_size) + 2.0 * padding;
+
+        // Every button is at least as wide as it is high.
+        let button_width =
+            |label: &str| (renderer.measure(label, text_size).0 + 2.0 * padding).max(height);
+
+        let mut widths = vec![height];
+        if self.compact {
+            widths.push(button_width(&compact_label(self.current, self.count)));
+        } else {
+            widths.extend(self.items().iter().map(|item| match item {
+                Page::Number(page) => button_width(&(page + 1).to_string()),
+                Page::Ellipsis => button_width(ELLIPSIS),
+            }));
+        }
+        widths.push(height);
+
+        let mut x = 0.0;
+        let children = widths
+            .iter()
+            .map(|width| {
+                let mut node = layout::Node::new(Size::new(*width, height));
+                node.move_to(Point::new(x, 0.0));
+                x += width + spacing;
+                node
+            })
+            .collect();
+
+        let size = limits
+            .width(Length::Shrink)
+            .height(Length::Shrink)
+            .resolve(Size::new(x - spacing, height));
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let count = layout.children().count();
+                let clicked = layout
+                    .children()
+                    .position(|child| child.bounds().contains(cursor_position));
+
+                let page = match clicked {
+                    None => return event::Status::Ignored,
+                    Some(0) => self.current.checked_sub(1),
+                    Some(index) if index + 1 == count => {
+                        Some(self.current + 1).filter(|page| *page < self.count)
+                    }
+                    Some(index) => match self.items().get(index - 1) {
+                        Some(Page::Number(page)) if *page != self.current => Some(*page),
+                        _ => None,
+                    },
+                };
+
+                if let Some(page) = page {
+                    #[cfg(feature = "instrumentation")]
+                    instrumentation::record(WidgetKind::Pagination, Action::Select(page));
+                    messages.push((self.on_page_change)(page));
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: None,
+                focus: (),
+            },
+            &self.items(),
+            self.current,
+            self.count,
+            self.compact,
+            text_size,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.current.hash(state);
+        self.count.hash(state);
+        self.siblings.hash(state);
+        self.boundaries.hash(state);
+        self.compact.hash(state);
+        self.spacing.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+}
+
+/// The renderer of a [`Pagination`](Pagination).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Pagination`](Pagination) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default spacing between the buttons of a
+    /// [`Pagination`](Pagination).
+    const DEFAULT_SPACING: u16;
+
+    /// The default padding of the buttons of a [`Pagination`](Pagination).
+    const DEFAULT_PADDING: u16;
+
+    /// The default text size of a [`Pagination`](Pagination).
+    fn default_size(&self) -> u16;
+
+    /// Measures the given text and returns its width and height.
+    fn measure(&self, text: &str, size: u16) -> (f32, f32);
+
+    /// Draws a [`Pagination`](Pagination).
+    ///
+    /// The children of the layout are the previous button, the buttons of
+    /// the items or the compact label, and the next button.
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        items: &[Page],
+        current: usize,
+        count: usize,
+        compact: bool,
+        text_size: u16,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_SPACING: u16 = 0;
+
+    const DEFAULT_PADDING: u16 = 0;
+
+    fn default_size(&self) -> u16 {
+        0
+    }
+
+    fn measure(&self, _text: &str, _size: u16) -> (f32, f32) {
+        (0.0, 0.0)
+    }
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _items: &[Page],
+        _current: usize,
+        _count: usize,
+        _compact: bool,
+        _text_size: u16,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<Pagination<Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(pagination: Pagination<Message, Renderer>) -> Self {
+        Element::new(pagination)
+    }
+}
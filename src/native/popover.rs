@@ -0,0 +1,344 @@
+//! A popover for showing a floating panel next to a target element.
+//!
+//! *This API requires the following crate features to be activated: `popover`*
+use std::hash::Hash;
+
+use iced_native::{event, mouse, overlay, Clipboard, Element, Event, Layout, Point, Widget};
+
+pub use super::overlay::popover::Renderer;
+use super::{
+    overlay::popover::{self, PopoverOverlay},
+    stateful_overlay::{OverlayState, StatefulOverlayWidget},
+};
+pub use crate::core::overlay::Placement;
+
+/// The way a [`Popover`](Popover) is shown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Trigger {
+    /// The popover is shown while the cursor is over the target or the
+    /// popover itself, like a tooltip.
+    Hover,
+    /// The popover is only shown and hidden through its [`State`](State),
+    /// e.g. when handling a message of the application.
+    Manual,
+}
+
+/// A popover showing a floating panel anchored to a target element.
+///
+/// The panel is placed on the side of the target given by its
+/// [`Placement`](Placement). It's flipped over to the opposite side if it
+/// doesn't fit on the screen.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::popover;
+/// # use iced_native::{Text, renderer::Null};
+/// #
+/// # pub type Popover<'a, S, Content, Message>
+/// #  = iced_aw::native::Popover<'a, S, Content, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+/// }
+///
+/// let mut state = popover::State::new(());
+///
+/// let popover = Popover::new(
+///     &mut state,
+///     Text::new("Hover me"),
+///     |_state| Text::new("A helpful tooltip").into(),
+/// )
+/// .placement(popover::Placement::Top);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Popover<'a, S, Content, Message, Renderer>
+where
+    S: 'a,
+    Content: Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: Clone,
+    Renderer: popover::Renderer,
+{
+    /// The state of the [`Popover`](Popover).
+    state: &'a mut State<S>,
+    /// The target element the popover is anchored to.
+    target: Element<'a, Message, Renderer>,
+    /// The content of the [`PopoverOverlay`](PopoverOverlay).
+    content: Content,
+    /// The way the [`Popover`](Popover) is shown.
+    trigger: Trigger,
+    /// The preferred side of the target the popover is placed on.
+    placement: Placement,
+    /// The spacing between the target and the popover.
+    spacing: u16,
+    /// The optional message that will be send when the user clicks outside
+    /// of the target and the popover.
+    backdrop: Option<Message>,
+    /// The optional message that will be send when the ESC key was pressed.
+    esc: Option<Message>,
+    /// The style of the [`PopoverOverlay`](PopoverOverlay).
+    style: Renderer::Style,
+}
+
+impl<'a, S, Content, Message, Renderer> Popover<'a, S, Content, Message, Renderer>
+where
+    S: 'a,
+    Content: Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: Clone,
+    Renderer: popover::Renderer,
+{
+    /// Creates a new [`Popover`](Popover) anchored to the given target.
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`Popover`](Popover)'s
+    ///         [`State`](State).
+    ///     * the target [`Element`](iced_native::Element) the popover is
+    ///         anchored to.
+    ///     * a function that creates the content of the popover from the
+    ///         inner state.
+    pub fn new<T>(state: &'a mut State<S>, target: T, content: Content) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Popover {
+            state,
+            target: target.into(),
+            content,
+            trigger: Trigger::Hover,
+            placement: Placement::Bottom,
+            spacing: 5,
+            backdrop: None,
+            esc: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the [`Trigger`](Trigger) showing the [`Popover`](Popover).
+    ///
+    /// The [`Popover`](Popover) is shown on hover by default.
+    pub fn trigger(mut self, trigger: Trigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// Sets the preferred side of the target the [`Popover`](Popover) is
+    /// placed on.
+    ///
+    /// The [`Popover`](Popover) is placed below the target by default.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the spacing between the target and the [`Popover`](Popover).
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the message that will be produced when the user clicks outside
+    /// of the target and the [`Popover`](Popover).
+    ///
+    /// This can be used to close a [`Manual`](Trigger::Manual) popover.
+    pub fn backdrop(mut self, message: Message) -> Self {
+        self.backdrop = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the Escape Key is
+    /// pressed while the [`Popover`](Popover) is shown.
+    ///
+    /// This can be used to close a [`Manual`](Trigger::Manual) popover.
+    pub fn on_esc(mut self, message: Message) -> Self {
+        self.esc = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`Popover`](Popover).
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The state of the popover.
+#[derive(Debug)]
+pub struct State<S> {
+    /// The visibility of the [`Popover`](Popover) overlay.
+    pub(crate) show: bool,
+    /// The state of the content of the [`Popover`](Popover) overlay.
+    pub(crate) state: S,
+}
+
+impl<S> State<S> {
+    /// Creates a new [`State`](State) containing the given state data.
+    pub const fn new(s: S) -> Self {
+        Self {
+            show: false,
+            state: s,
+        }
+    }
+
+    /// Setting this to true shows the popover, false means the popover is
+    /// hidden.
+    pub fn show(&mut self, b: bool) {
+        self.show = b;
+    }
+
+    /// See if this popover will be shown or not.
+    pub const fn is_shown(&self) -> bool {
+        self.show
+    }
+
+    /// Get a mutable reference to the inner state data.
+    pub fn inner_mut(&mut self) -> &mut S {
+        &mut self.state
+    }
+
+    /// Get a reference to the inner state data.
+    pub const fn inner(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<S: Default> Default for State<S> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S> OverlayState for State<S> {
+    fn is_shown(&self) -> bool {
+        self.show
+    }
+
+    fn show(&mut self, b: bool) {
+        Self::show(self, b);
+    }
+}
+
+impl<'a, S, Content, Message, Renderer> Widget<Message, Renderer>
+    for Popover<'a, S, Content, Message, Renderer>
+where
+    S: 'a,
+    Content: 'a + Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: 'a + Clone,
+    Renderer: 'a + popover::Renderer,
+{
+    fn width(&self) -> iced_native::Length {
+        self.target.width()
+    }
+
+    fn height(&self) -> iced_native::Length {
+        self.target.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.target.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        // The overlay hides a hovered popover again once the cursor left
+        // both the target and the popover.
+        if let (Trigger::Hover, Event::Mouse(mouse::Event::CursorMoved { .. })) =
+            (self.trigger, &event)
+        {
+            if layout.bounds().contains(cursor_position) {
+                self.state.show = true;
+            }
+        }
+
+        self.target.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: iced_native::Layout<'_>,
+        cursor_position: iced_graphics::Point,
+        viewport: &iced_graphics::Rectangle,
+    ) -> Renderer::Output {
+        self.target
+            .draw(renderer, defaults, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.state.show.hash(state);
+        self.target.hash_layout(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.stateful_overlay(layout)
+    }
+}
+
+impl<'a, S, Content, Message, Renderer> StatefulOverlayWidget<'a, Message, Renderer>
+    for Popover<'a, S, Content, Message, Renderer>
+where
+    S: 'a,
+    Content: 'a + Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: 'a + Clone,
+    Renderer: 'a + popover::Renderer,
+{
+    type State = State<S>;
+
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    fn underlay_mut(&mut self) -> &mut Element<'a, Message, Renderer> {
+        &mut self.target
+    }
+
+    fn build_overlay(&mut self, layout: Layout<'_>) -> overlay::Element<'_, Message, Renderer> {
+        let bounds = layout.bounds();
+        let position = Point::new(bounds.x, bounds.y);
+
+        PopoverOverlay::new(
+            &mut self.state,
+            &self.content,
+            bounds,
+            self.backdrop.clone(),
+            self.esc.clone(),
+            &self.style,
+        )
+        .trigger(self.trigger)
+        .placement(self.placement, f32::from(self.spacing))
+        .overlay(position)
+    }
+}
+
+impl<'a, S, Content, Message, Renderer> From<Popover<'a, S, Content, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    S: 'a,
+    Content: 'a + Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: 'a + Clone,
+    Renderer: 'a + popover::Renderer,
+{
+    fn from(popover: Popover<'a, S, Content, Message, Renderer>) -> Self {
+        Element::new(popover)
+    }
+}
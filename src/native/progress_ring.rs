@@ -0,0 +1,257 @@
+//! Use a progress ring to show the progress of some work as an arc around a
+//! circle.
+//!
+//! *This API requires the following crate features to be activated: `progress_ring`*
+use std::{hash::Hash, ops::RangeInclusive};
+
+use iced_native::{
+    event, layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle,
+    Size, Widget,
+};
+
+use crate::core::renderer::DrawEnvironment;
+
+/// A determinate progress indicator drawing an arc around a circle.
+///
+/// The arc starts at the top and runs clockwise. An optional label, e.g. the
+/// progress as a percentage, is centered inside of the ring. It is the
+/// circular counterpart of the `ProgressBar` of iced.
+///
+/// # Example
+/// ```
+/// # use iced_native::{Text, renderer::Null};
+/// #
+/// # pub type ProgressRing<'a, Message> = iced_aw::native::ProgressRing<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+/// }
+///
+/// let progress_ring = ProgressRing::<Message>::new(0.0..=100.0, 42.0)
+///     .size(64)
+///     .stroke_width(6.0)
+///     .label(Text::new("42%"));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ProgressRing<'a, Message, Renderer: self::Renderer> {
+    /// The range of the progress.
+    range: RangeInclusive<f32>,
+    /// The current progress.
+    value: f32,
+    /// The width and height of the [`ProgressRing`](ProgressRing).
+    size: u16,
+    /// The thickness of the ring.
+    stroke_width: f32,
+    /// The optional label centered inside of the ring.
+    label: Option<Element<'a, Message, Renderer>>,
+    /// The style of the [`ProgressRing`](ProgressRing).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> ProgressRing<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`ProgressRing`](ProgressRing).
+    ///
+    /// It expects:
+    ///     * the range of the progress.
+    ///     * the current progress, which is clamped to the range.
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        ProgressRing {
+            value: value.max(*range.start()).min(*range.end()),
+            range,
+            size: Renderer::DEFAULT_SIZE,
+            stroke_width: Renderer::DEFAULT_STROKE_WIDTH,
+            label: None,
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the width and height of the [`ProgressRing`](ProgressRing).
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the thickness of the ring of the [`ProgressRing`](ProgressRing).
+    pub fn stroke_width(mut self, stroke_width: f32) -> Self {
+        self.stroke_width = stroke_width;
+        self
+    }
+
+    /// Sets the label [`Element`](iced_native::Element) centered inside of
+    /// the ring.
+    pub fn label<T>(mut self, label: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the style of the [`ProgressRing`](ProgressRing).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the position of the value in the range between 0.0 and 1.0.
+    fn progress(&self) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        if end <= start {
+            0.0
+        } else {
+            (self.value - start) / (end - start)
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for ProgressRing<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let size = f32::from(self.size);
+        let size = limits.resolve(Size::new(size, size));
+
+        let children = self
+            .label
+            .iter()
+            .map(|label| {
+                let inner = (size.width.min(size.height) - 2.0 * self.stroke_width).max(0.0);
+                let mut label = label.layout(
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, Size::new(inner, inner)),
+                );
+                label.align(Align::Center, Align::Center, size);
+
+                label
+            })
+            .collect();
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match (&mut self.label, layout.children().next()) {
+            (Some(label), Some(label_layout)) => label.on_event(
+                event,
+                label_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                messages,
+            ),
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: Some(viewport),
+                focus: (),
+            },
+            self.progress(),
+            self.stroke_width,
+            self.label.as_ref(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.size.hash(state);
+        self.stroke_width.to_bits().hash(state);
+
+        if let Some(label) = &self.label {
+            label.hash_layout(state);
+        }
+    }
+}
+
+/// The renderer of a [`ProgressRing`](ProgressRing).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`ProgressRing`](ProgressRing) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default width and height of a [`ProgressRing`](ProgressRing).
+    const DEFAULT_SIZE: u16;
+
+    /// The default thickness of the ring of a [`ProgressRing`](ProgressRing).
+    const DEFAULT_STROKE_WIDTH: f32;
+
+    /// Draws a [`ProgressRing`](ProgressRing).
+    ///
+    /// The progress is between 0.0 and 1.0. If there is a label, its layout
+    /// is the only child of the layout.
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        progress: f32,
+        stroke_width: f32,
+        label: Option<&Element<'_, Message, Self>>,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 0;
+
+    const DEFAULT_STROKE_WIDTH: f32 = 0.0;
+
+    fn draw<Message>(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _progress: f32,
+        _stroke_width: f32,
+        _label: Option<&Element<'_, Message, Self>>,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<ProgressRing<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(progress_ring: ProgressRing<'a, Message, Renderer>) -> Self {
+        Element::new(progress_ring)
+    }
+}
@@ -0,0 +1,422 @@
+//! Use a range slider to select an interval of values with two handles.
+//!
+//! *This API requires the following crate features to be activated: `range_slider`*
+use std::{hash::Hash, ops::RangeInclusive};
+
+use iced_native::{
+    event, layout, mouse, touch, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+use num_traits::FromPrimitive;
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::renderer::DrawEnvironment;
+
+/// A slider with two handles on a single rail to select an interval of
+/// values.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::range_slider;
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type RangeSlider<'a, T, Message> = iced_aw::native::RangeSlider<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     PriceChanged(std::ops::RangeInclusive<f32>),
+/// }
+///
+/// let mut state = range_slider::State::new();
+///
+/// let range_slider = RangeSlider::new(
+///     &mut state,
+///     0.0..=100.0,
+///     20.0..=80.0,
+///     Message::PriceChanged,
+/// )
+/// .step(5.0)
+/// .ticks(25.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, T, Message, Renderer: self::Renderer> {
+    /// The state of the [`RangeSlider`](RangeSlider).
+    state: &'a mut State,
+    /// The range of selectable values.
+    range: RangeInclusive<T>,
+    /// The selected interval.
+    value: RangeInclusive<T>,
+    /// The step the values are snapped to.
+    step: T,
+    /// The optional interval between the tick marks.
+    ticks: Option<T>,
+    /// The function that produces a message when the selected interval
+    /// changes.
+    on_change: Box<dyn Fn(RangeInclusive<T>) -> Message>,
+    /// The optional message that is produced when a handle is released.
+    on_release: Option<Message>,
+    /// The width of the [`RangeSlider`](RangeSlider).
+    width: Length,
+    /// The height of the [`RangeSlider`](RangeSlider).
+    height: u16,
+    /// The style of the [`RangeSlider`](RangeSlider).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> RangeSlider<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd + Into<f64> + FromPrimitive,
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`RangeSlider`](RangeSlider).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`RangeSlider`](RangeSlider)'s
+    ///         [`State`](State).
+    ///     * the range of selectable values.
+    ///     * the selected interval.
+    ///     * a function that will be called with the new interval when a
+    ///         handle is dragged.
+    pub fn new<F>(
+        state: &'a mut State,
+        range: RangeInclusive<T>,
+        value: RangeInclusive<T>,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(RangeInclusive<T>) -> Message,
+    {
+        let clamp = |v: T| {
+            if v < *range.start() {
+                *range.start()
+            } else if v > *range.end() {
+                *range.end()
+            } else {
+                v
+            }
+        };
+        let value = clamp(*value.start())..=clamp(*value.end());
+
+        RangeSlider {
+            state,
+            range,
+            value,
+            step: T::from(1),
+            ticks: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: Renderer::DEFAULT_HEIGHT,
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the step the values of the [`RangeSlider`](RangeSlider) are
+    /// snapped to.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the interval between the tick marks drawn along the rail.
+    pub fn ticks(mut self, interval: T) -> Self {
+        self.ticks = Some(interval);
+        self
+    }
+
+    /// Sets the message that will be produced when a handle is released.
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`](RangeSlider).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`](RangeSlider).
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`](RangeSlider).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the position of the given value on the rail between 0.0 and
+    /// 1.0.
+    #[allow(clippy::cast_possible_truncation)]
+    fn percent(&self, value: T) -> f32 {
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+
+        if end <= start {
+            0.0
+        } else {
+            ((value.into() - start) / (end - start)) as f32
+        }
+    }
+
+    /// Returns the value below the cursor, snapped to the step.
+    fn value_at(&self, bounds: Rectangle, cursor_position: Point) -> Option<T> {
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+        let step = self.step.into();
+
+        let percent = f64::from(
+            ((cursor_position.x - bounds.x) / bounds.width)
+                .max(0.0)
+                .min(1.0),
+        );
+        let value = start + percent * (end - start);
+        let snapped = if step > 0.0 {
+            (start + ((value - start) / step).round() * step).min(end)
+        } else {
+            value
+        };
+
+        T::from_f64(snapped)
+    }
+
+    /// Moves the dragged handle to the cursor and produces a message if the
+    /// interval changed.
+    fn drag(&mut self, bounds: Rectangle, cursor_position: Point, messages: &mut Vec<Message>) {
+        let value = match self.value_at(bounds, cursor_position) {
+            Some(value) => value,
+            None => return,
+        };
+
+        let (start, end) = (*self.value.start(), *self.value.end());
+        let changed = match self.state.dragging {
+            Some(Handle::Start) => {
+                let value = if value > end { end } else { value };
+                value..=end
+            }
+            Some(Handle::End) => {
+                let value = if value < start { start } else { value };
+                start..=value
+            }
+            None => return,
+        };
+
+        if changed != self.value {
+            self.value = changed.clone();
+            #[cfg(feature = "instrumentation")]
+            instrumentation::record(WidgetKind::RangeSlider, Action::Change);
+            messages.push((self.on_change)(changed));
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for RangeSlider<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd + Into<f64> + FromPrimitive,
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Units(self.height));
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if bounds.contains(cursor_position) =>
+            {
+                let start_x = bounds.x + self.percent(*self.value.start()) * bounds.width;
+                let end_x = bounds.x + self.percent(*self.value.end()) * bounds.width;
+
+                // The nearest handle is dragged, the end handle if both are
+                // at the same position and the cursor is to the right.
+                let handle = if (cursor_position.x - start_x).abs()
+                    < (cursor_position.x - end_x).abs()
+                    || (start_x - end_x).abs() < f32::EPSILON && cursor_position.x < start_x
+                {
+                    Handle::Start
+                } else {
+                    Handle::End
+                };
+
+                self.state.dragging = Some(handle);
+                self.drag(bounds, cursor_position, messages);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if self.state.dragging.is_some() =>
+            {
+                self.drag(bounds, cursor_position, messages);
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if self.state.dragging.is_some() =>
+            {
+                self.state.dragging = None;
+
+                if let Some(on_release) = self.on_release.clone() {
+                    messages.push(on_release);
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let ticks: Vec<f32> = self
+            .ticks
+            .map(Into::into)
+            .filter(|interval: &f64| *interval > 0.0)
+            .map(|interval| {
+                let start = (*self.range.start()).into();
+                let end = (*self.range.end()).into();
+
+                std::iter::successors(Some(start), |tick| Some(tick + interval))
+                    .take_while(|tick| *tick <= end)
+                    .filter_map(T::from_f64)
+                    .map(|tick| self.percent(tick))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: None,
+                focus: self.state.dragging,
+            },
+            self.percent(*self.value.start())..=self.percent(*self.value.end()),
+            &ticks,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// A handle of a [`RangeSlider`](RangeSlider).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Handle {
+    /// The handle of the start of the interval.
+    Start,
+    /// The handle of the end of the interval.
+    End,
+}
+
+/// The state of a [`RangeSlider`](RangeSlider).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct State {
+    /// The handle that is currently dragged.
+    dragging: Option<Handle>,
+}
+
+impl State {
+    /// Creates a new [`State`](State).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the handle that is currently dragged, if any.
+    pub const fn dragging(&self) -> Option<Handle> {
+        self.dragging
+    }
+}
+
+/// The renderer of a [`RangeSlider`](RangeSlider).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`RangeSlider`](RangeSlider) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default height of a [`RangeSlider`](RangeSlider).
+    const DEFAULT_HEIGHT: u16;
+
+    /// Draws a [`RangeSlider`](RangeSlider).
+    ///
+    /// The focus of the environment is the dragged handle. The selected
+    /// interval and the tick marks are given as positions on the rail between
+    /// 0.0 and 1.0.
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, Option<Handle>>,
+        value: RangeInclusive<f32>,
+        ticks: &[f32],
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_HEIGHT: u16 = 0;
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, Option<Handle>>,
+        _value: RangeInclusive<f32>,
+        _ticks: &[f32],
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, T, Message, Renderer> From<RangeSlider<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + From<u8> + PartialOrd + Into<f64> + FromPrimitive,
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(range_slider: RangeSlider<'a, T, Message, Renderer>) -> Self {
+        Element::new(range_slider)
+    }
+}
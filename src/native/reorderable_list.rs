@@ -0,0 +1,463 @@
+//! Use a reorderable list to let the user rearrange elements by dragging them.
+//!
+//! *This API requires the following crate features to be activated: `reorderable_list`*
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout, mouse, overlay, touch, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+use super::overlay::reorderable_list::ReorderableListOverlay;
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::renderer::DrawEnvironment;
+
+/// The distance the cursor has to move before a pressed element is picked up.
+const DRAG_THRESHOLD: f32 = 5.0;
+
+/// A column of elements that can be rearranged by dragging them with the
+/// mouse.
+///
+/// While an element is dragged, it follows the cursor and an indicator shows
+/// where it will be dropped. Dropping it produces a message with its old and
+/// its new index.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::reorderable_list;
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type ReorderableList<'a, Message> =
+/// #  iced_aw::native::ReorderableList<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Reordered(usize, usize),
+/// }
+///
+/// let mut state = reorderable_list::State::new();
+///
+/// let list = ReorderableList::new(&mut state, Message::Reordered)
+///     .spacing(5)
+///     .push(Text::new("First"))
+///     .push(Text::new("Second"))
+///     .push(Text::new("Third"));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ReorderableList<'a, Message, Renderer: self::Renderer> {
+    /// The state of the [`ReorderableList`](ReorderableList).
+    state: &'a mut State,
+    /// The function that produces a message when an element is dropped at a
+    /// new position.
+    on_reorder: Box<dyn Fn(usize, usize) -> Message>,
+    /// The spacing between the elements.
+    spacing: u16,
+    /// The width of the [`ReorderableList`](ReorderableList).
+    width: Length,
+    /// The elements of the [`ReorderableList`](ReorderableList).
+    elements: Vec<Element<'a, Message, Renderer>>,
+    /// The style of the [`ReorderableList`](ReorderableList).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> ReorderableList<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new empty [`ReorderableList`](ReorderableList).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`ReorderableList`](ReorderableList)'s
+    ///         [`State`](State).
+    ///     * a function that will be called with the old and the new index of
+    ///         an element when it is dropped at a new position. Removing the
+    ///         element at the old index and inserting it at the new index
+    ///         results in the new order.
+    pub fn new<F>(state: &'a mut State, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(usize, usize) -> Message,
+    {
+        ReorderableList {
+            state,
+            on_reorder: Box::new(on_reorder),
+            spacing: 0,
+            width: Length::Fill,
+            elements: Vec::new(),
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the spacing between the elements.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the width of the [`ReorderableList`](ReorderableList).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Adds an element to the [`ReorderableList`](ReorderableList).
+    pub fn push<E>(mut self, element: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.elements.push(element.into());
+        self
+    }
+
+    /// Sets the style of the [`ReorderableList`](ReorderableList).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// Returns the index the dragged element would be dropped at and the
+/// vertical position of the drop indicator.
+///
+/// It expects the bounds of the list, the bounds of its elements, the index
+/// of the dragged element and the spacing between the elements.
+pub(crate) fn drop_target(
+    bounds: Rectangle,
+    children: impl Iterator<Item = Rectangle>,
+    from: usize,
+    spacing: u16,
+    cursor_position: Point,
+) -> (usize, f32) {
+    let half_spacing = f32::from(spacing) / 2.0;
+
+    let others = children
+        .enumerate()
+        .filter(|(index, _)| *index != from)
+        .map(|(_, child)| child);
+
+    let mut to = 0;
+    let mut indicator = bounds.y;
+
+    for child in others {
+        if child.center_y() > cursor_position.y {
+            return (to, (child.y - half_spacing).max(bounds.y));
+        }
+
+        to += 1;
+        indicator = (child.y + child.height + half_spacing).min(bounds.y + bounds.height);
+    }
+
+    (to, indicator)
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for ReorderableList<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let max_width = limits.max().width;
+
+        let mut y = 0.0;
+        let mut width = 0.0_f32;
+
+        let children = self
+            .elements
+            .iter()
+            .map(|element| {
+                let element_limits =
+                    layout::Limits::new(Size::ZERO, Size::new(max_width, f32::INFINITY))
+                        .width(element.width())
+                        .height(element.height());
+                let mut node = element.layout(renderer, &element_limits);
+                node.move_to(Point::new(0.0, y));
+
+                y += node.size().height + f32::from(self.spacing);
+                width = width.max(node.size().width);
+
+                node
+            })
+            .collect();
+
+        let height = (y - f32::from(self.spacing)).max(0.0);
+        let size = limits.resolve(Size::new(width, height));
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if let Some(drag) = &mut self.state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    // Once the element is picked up, the cursor is tracked by
+                    // the overlay. The position passed to the list is invalid
+                    // while the cursor is over the overlay.
+                    if drag.is_dragging {
+                        return event::Status::Captured;
+                    }
+
+                    let distance = (cursor_position.x - drag.origin.x)
+                        .hypot(cursor_position.y - drag.origin.y);
+
+                    drag.is_dragging = distance > DRAG_THRESHOLD;
+                    drag.cursor_position = cursor_position;
+
+                    if drag.is_dragging {
+                        return event::Status::Captured;
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    let drag = *drag;
+                    self.state.drag = None;
+
+                    if drag.is_dragging {
+                        let (to, _) = drop_target(
+                            layout.bounds(),
+                            layout.children().map(|child| child.bounds()),
+                            drag.index,
+                            self.spacing,
+                            cursor_position,
+                        );
+
+                        if to != drag.index {
+                            #[cfg(feature = "instrumentation")]
+                            instrumentation::record(
+                                WidgetKind::ReorderableList,
+                                Action::Reorder(drag.index, to),
+                            );
+                            messages.push((self.on_reorder)(drag.index, to));
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let status = self
+            .elements
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(element, layout)| {
+                element.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    messages,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if status == event::Status::Ignored =>
+            {
+                if let Some((index, bounds)) = layout
+                    .children()
+                    .map(|child| child.bounds())
+                    .enumerate()
+                    .find(|(_, bounds)| bounds.contains(cursor_position))
+                {
+                    self.state.drag = Some(Drag {
+                        index,
+                        origin: cursor_position,
+                        grab_offset: cursor_position.y - bounds.y,
+                        cursor_position,
+                        is_dragging: false,
+                    });
+
+                    return event::Status::Captured;
+                }
+
+                status
+            }
+            _ => status,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let dragged = self.state.drag.filter(|drag| drag.is_dragging).map(|drag| {
+            let (_, indicator) = drop_target(
+                layout.bounds(),
+                layout.children().map(|child| child.bounds()),
+                drag.index,
+                self.spacing,
+                drag.cursor_position,
+            );
+            (drag.index, indicator)
+        });
+
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: Some(viewport),
+                focus: dragged,
+            },
+            &self.elements,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.spacing.hash(state);
+        self.width.hash(state);
+
+        for element in &self.elements {
+            element.hash_layout(state);
+        }
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let drag = self.state.drag.filter(|drag| drag.is_dragging)?;
+        let bounds = layout.children().nth(drag.index)?.bounds();
+
+        let position = Point::new(bounds.x, drag.cursor_position.y - drag.grab_offset);
+
+        Some(
+            ReorderableListOverlay::new(
+                &mut self.state,
+                &self.elements[drag.index],
+                self.on_reorder.as_ref(),
+                &self.style,
+            )
+            .list(
+                layout.bounds(),
+                layout.children().map(|child| child.bounds()).collect(),
+                self.spacing,
+            )
+            .overlay(position),
+        )
+    }
+}
+
+/// An element of a [`ReorderableList`](ReorderableList) that is pressed or
+/// dragged.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Drag {
+    /// The index of the element.
+    pub(crate) index: usize,
+    /// The position of the cursor when the element was pressed.
+    origin: Point,
+    /// The vertical distance between the cursor and the top of the element.
+    grab_offset: f32,
+    /// The current position of the cursor.
+    pub(crate) cursor_position: Point,
+    /// Whether the cursor moved far enough to pick up the element.
+    pub(crate) is_dragging: bool,
+}
+
+/// The state of a [`ReorderableList`](ReorderableList).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct State {
+    /// The element that is pressed or dragged.
+    pub(crate) drag: Option<Drag>,
+}
+
+impl State {
+    /// Creates a new [`State`](State).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index of the dragged element, if any.
+    pub fn dragged(&self) -> Option<usize> {
+        self.drag
+            .filter(|drag| drag.is_dragging)
+            .map(|drag| drag.index)
+    }
+}
+
+/// The renderer of a [`ReorderableList`](ReorderableList).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`ReorderableList`](ReorderableList) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`ReorderableList`](ReorderableList).
+    ///
+    /// The focus of the environment is the index of the dragged element and
+    /// the vertical position of the drop indicator while an element is
+    /// dragged.
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, Option<(usize, f32)>>,
+        elements: &[Element<'_, Message, Self>],
+    ) -> Self::Output;
+
+    /// Draws the dragged element of a [`ReorderableList`](ReorderableList)
+    /// following the cursor.
+    fn draw_dragged<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        element: &Element<'_, Message, Self>,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, Option<(usize, f32)>>,
+        _elements: &[Element<'_, Message, Self>],
+    ) -> Self::Output {
+    }
+
+    fn draw_dragged<Message>(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _element: &Element<'_, Message, Self>,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<ReorderableList<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(list: ReorderableList<'a, Message, Renderer>) -> Self {
+        Element::new(list)
+    }
+}
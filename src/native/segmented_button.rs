@@ -0,0 +1,316 @@
+//! Use a segmented button to select one of a few mutually exclusive options.
+//!
+//! *This API requires the following crate features to be activated: `segmented_button`*
+use std::{fmt::Display, hash::Hash};
+
+use iced_native::{
+    event, layout, mouse, touch, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::renderer::DrawEnvironment;
+
+/// A horizontal group of joined buttons of which exactly one can be selected.
+///
+/// It is a lighter alternative to a [`TabBar`](crate::native::TabBar) for
+/// filters or switching between views.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type SegmentedButton<'a, T, Message> =
+/// #  iced_aw::native::SegmentedButton<'a, T, Message, Null>;
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// enum View {
+///     List,
+///     Grid,
+/// }
+///
+/// impl std::fmt::Display for View {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{:?}", self)
+///     }
+/// }
+///
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     ViewSelected(View),
+/// }
+///
+/// let segmented_button = SegmentedButton::new(
+///     &[View::List, View::Grid],
+///     Some(View::List),
+///     Message::ViewSelected,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SegmentedButton<'a, T, Message, Renderer: self::Renderer> {
+    /// The options of the [`SegmentedButton`](SegmentedButton).
+    options: &'a [T],
+    /// The selected option.
+    selected: Option<T>,
+    /// The function that produces a message when an option is selected.
+    on_select: Box<dyn Fn(T) -> Message>,
+    /// The width of the [`SegmentedButton`](SegmentedButton).
+    width: Length,
+    /// The height of the [`SegmentedButton`](SegmentedButton).
+    height: Length,
+    /// The padding of the segments.
+    padding: u16,
+    /// The optional text size of the segments.
+    text_size: Option<u16>,
+    /// The style of the [`SegmentedButton`](SegmentedButton).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> SegmentedButton<'a, T, Message, Renderer>
+where
+    T: Clone + Display + Eq,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`SegmentedButton`](SegmentedButton).
+    ///
+    /// It expects:
+    ///     * the options displayed as segments.
+    ///     * the selected option, if any.
+    ///     * a function that will be called when a segment is clicked.
+    pub fn new<F>(options: &'a [T], selected: Option<T>, on_select: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        SegmentedButton {
+            options,
+            selected,
+            on_select: Box::new(on_select),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`SegmentedButton`](SegmentedButton).
+    ///
+    /// The available width is shared equally by the segments.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`SegmentedButton`](SegmentedButton).
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding of the segments.
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the segments.
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`SegmentedButton`](SegmentedButton).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for SegmentedButton<'a, T, Message, Renderer>
+where
+    T: Clone + Display + Eq,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let padding = f32::from(self.padding);
+        let limits = limits.width(self.width).height(self.height);
+
+        let label_width = self
+            .options
+            .iter()
+            .map(|option| {
+                renderer
+                    .measure(&option.to_string(), text_size, limits.max())
+                    .0
+            })
+            .fold(0.0_f32, f32::max);
+
+        let count = self.options.len().max(1) as f32;
+        let size = limits.resolve(Size::new(
+            (label_width + 2.0 * padding) * count,
+            f32::from(text_size) + 2.0 * padding,
+        ));
+        let segment_width = size.width / count;
+
+        let segments = (0..self.options.len())
+            .map(|i| {
+                let mut node = layout::Node::new(Size::new(segment_width, size.height));
+                node.move_to(Point::new(i as f32 * segment_width, 0.0));
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(size, segments)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match layout
+                    .children()
+                    .position(|segment| segment.bounds().contains(cursor_position))
+                {
+                    Some(index) => {
+                        #[cfg(feature = "instrumentation")]
+                        instrumentation::record(WidgetKind::SegmentedButton, Action::Select(index));
+                        messages.push((self.on_select)(self.options[index].clone()));
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let labels: Vec<String> = self.options.iter().map(ToString::to_string).collect();
+        let selected = self
+            .selected
+            .as_ref()
+            .and_then(|selected| self.options.iter().position(|option| option == selected));
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: None,
+                focus: (),
+            },
+            &labels,
+            selected,
+            text_size,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+
+        for option in self.options {
+            option.to_string().hash(state);
+        }
+    }
+}
+
+/// The renderer of a [`SegmentedButton`](SegmentedButton).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`SegmentedButton`](SegmentedButton) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default padding of the segments of a
+    /// [`SegmentedButton`](SegmentedButton).
+    const DEFAULT_PADDING: u16;
+
+    /// The default text size of the segments of a
+    /// [`SegmentedButton`](SegmentedButton).
+    fn default_size(&self) -> u16;
+
+    /// Measures the given label in the given bounds and returns the minimum
+    /// boundaries that can fit it.
+    fn measure(&self, label: &str, size: u16, bounds: Size) -> (f32, f32);
+
+    /// Draws a [`SegmentedButton`](SegmentedButton).
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        labels: &[String],
+        selected: Option<usize>,
+        text_size: u16,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 0;
+
+    fn default_size(&self) -> u16 {
+        0
+    }
+
+    fn measure(&self, _label: &str, _size: u16, _bounds: Size) -> (f32, f32) {
+        (0.0, 0.0)
+    }
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _labels: &[String],
+        _selected: Option<usize>,
+        _text_size: u16,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, T, Message, Renderer> From<SegmentedButton<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Clone + Display + Eq,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(segmented_button: SegmentedButton<'a, T, Message, Renderer>) -> Self {
+        Element::new(segmented_button)
+    }
+}
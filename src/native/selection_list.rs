@@ -0,0 +1,436 @@
+//! Use a selection list to select one or more entries of a scrollable list.
+//!
+//! *This API requires the following crate features to be activated: `selection_list`*
+use std::{fmt::Display, hash::Hash};
+
+use iced_native::{
+    event, layout, mouse, touch, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::renderer::DrawEnvironment;
+
+/// A scrollable list of entries highlighting the selected ones.
+///
+/// Unlike the menu of a pick list, the list is always shown.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::selection_list;
+/// # use iced_native::{renderer::Null, Length};
+/// #
+/// # pub type SelectionList<'a, T, Message> =
+/// #  iced_aw::native::SelectionList<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     LanguageSelected(usize, &'static str),
+/// }
+///
+/// let mut state = selection_list::State::new();
+///
+/// let selection_list = SelectionList::new(
+///     &mut state,
+///     &["Rust", "Haskell", "OCaml"],
+///     Message::LanguageSelected,
+/// )
+/// .height(Length::Units(100));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SelectionList<'a, T, Message, Renderer: self::Renderer> {
+    /// The state of the [`SelectionList`](SelectionList).
+    state: &'a mut State,
+    /// The entries of the [`SelectionList`](SelectionList).
+    options: &'a [T],
+    /// Whether multiple entries can be selected.
+    multi_select: bool,
+    /// The width of the [`SelectionList`](SelectionList).
+    width: Length,
+    /// The height of the [`SelectionList`](SelectionList).
+    height: Length,
+    /// The padding of the entries of the [`SelectionList`](SelectionList).
+    padding: u16,
+    /// The optional text size of the entries of the
+    /// [`SelectionList`](SelectionList).
+    text_size: Option<u16>,
+    /// The function that produces a message when an entry is selected.
+    on_selected: Box<dyn Fn(usize, T) -> Message>,
+    /// The optional function that produces a message when an entry is
+    /// deselected.
+    on_deselected: Option<Box<dyn Fn(usize, T) -> Message>>,
+    /// The style of the [`SelectionList`](SelectionList).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> SelectionList<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`SelectionList`](SelectionList).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`SelectionList`](SelectionList)'s
+    ///         [`State`](State).
+    ///     * the entries of the list.
+    ///     * a function that will be called with the index and the entry when
+    ///         an entry is selected.
+    pub fn new<F>(state: &'a mut State, options: &'a [T], on_selected: F) -> Self
+    where
+        F: 'static + Fn(usize, T) -> Message,
+    {
+        SelectionList {
+            state,
+            options,
+            multi_select: false,
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: <Renderer as self::Renderer>::DEFAULT_PADDING,
+            text_size: None,
+            on_selected: Box::new(on_selected),
+            on_deselected: None,
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets whether multiple entries can be selected.
+    ///
+    /// If enabled, clicking on an entry toggles its selection instead of
+    /// replacing the selection.
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Sets the function that will be called with the index and the entry
+    /// when a selected entry is clicked again in multi-select mode, removing
+    /// it from the selection.
+    ///
+    /// Without it, no message is produced when an entry is deselected. The
+    /// [`State`](State) always holds the current selection.
+    pub fn on_deselected<F>(mut self, on_deselected: F) -> Self
+    where
+        F: 'static + Fn(usize, T) -> Message,
+    {
+        self.on_deselected = Some(Box::new(on_deselected));
+        self
+    }
+
+    /// Sets the width of the [`SelectionList`](SelectionList).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`SelectionList`](SelectionList).
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding of the entries of the [`SelectionList`](SelectionList).
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the entries of the
+    /// [`SelectionList`](SelectionList).
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`SelectionList`](SelectionList).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the height of an entry.
+    fn entry_height(&self, renderer: &Renderer) -> f32 {
+        f32::from(self.text_size.unwrap_or_else(|| renderer.default_size()) + 2 * self.padding)
+    }
+
+    /// Returns the maximum scroll offset, at which the last entry is at the
+    /// bottom of the given bounds.
+    #[allow(clippy::cast_precision_loss)]
+    fn max_offset(&self, bounds: Rectangle, entry_height: f32) -> f32 {
+        (self.options.len() as f32 * entry_height - bounds.height).max(0.0)
+    }
+
+    /// Returns the index of the entry below the cursor.
+    fn entry_at(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        entry_height: f32,
+    ) -> Option<usize> {
+        if !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let index = ((cursor_position.y - bounds.y + self.state.offset) / entry_height) as usize;
+
+        if index < self.options.len() {
+            Some(index)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for SelectionList<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let content_height = self.options.len() as f32 * self.entry_height(renderer);
+
+        layout::Node::new(limits.resolve(Size::new(0.0, content_height)))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let entry_height = self.entry_height(renderer);
+        let max_offset = self.max_offset(bounds, entry_height);
+
+        // The options may have shrunk since the last scroll.
+        self.state.offset = self.state.offset.min(max_offset);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * entry_height,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                self.state.offset = (self.state.offset - delta).max(0.0).min(max_offset);
+                self.state.hovered = self.entry_at(bounds, cursor_position, entry_height);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                self.state.hovered = self.entry_at(bounds, cursor_position, entry_height);
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match self.entry_at(bounds, cursor_position, entry_height) {
+                    Some(index) => {
+                        let option = self.options[index].clone();
+
+                        if self.multi_select && self.state.is_selected(index) {
+                            self.state.toggle(index);
+
+                            #[cfg(feature = "instrumentation")]
+                            instrumentation::record(
+                                WidgetKind::SelectionList,
+                                Action::Deselect(index),
+                            );
+
+                            if let Some(on_deselected) = &self.on_deselected {
+                                messages.push(on_deselected(index, option));
+                            }
+                        } else {
+                            if self.multi_select {
+                                self.state.toggle(index);
+                            } else {
+                                self.state.selected = vec![index];
+                            }
+
+                            #[cfg(feature = "instrumentation")]
+                            instrumentation::record(
+                                WidgetKind::SelectionList,
+                                Action::Select(index),
+                            );
+
+                            messages.push((self.on_selected)(index, option));
+                        }
+
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let labels: Vec<String> = self.options.iter().map(ToString::to_string).collect();
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let offset = self
+            .state
+            .offset
+            .min(self.max_offset(layout.bounds(), self.entry_height(renderer)));
+
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: Some(viewport),
+                focus: (),
+            },
+            &labels,
+            self.state,
+            offset,
+            text_size,
+            self.padding,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.options.len().hash(state);
+    }
+}
+
+/// The state of a [`SelectionList`](SelectionList).
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    /// The scroll offset of the [`SelectionList`](SelectionList).
+    pub(crate) offset: f32,
+    /// The index of the hovered entry.
+    pub(crate) hovered: Option<usize>,
+    /// The indices of the selected entries in the order of their selection.
+    pub(crate) selected: Vec<usize>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) without a selection.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the indices of the selected entries in the order of their
+    /// selection.
+    pub fn selected(&self) -> &[usize] {
+        &self.selected
+    }
+
+    /// Returns whether the entry at the given index is selected.
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    /// Sets the indices of the selected entries.
+    pub fn select(&mut self, selected: Vec<usize>) {
+        self.selected = selected;
+    }
+
+    /// Toggles the selection of the entry at the given index.
+    pub(crate) fn toggle(&mut self, index: usize) {
+        match self.selected.iter().position(|&i| i == index) {
+            Some(position) => {
+                let _ = self.selected.remove(position);
+            }
+            None => self.selected.push(index),
+        }
+    }
+}
+
+/// The renderer of a [`SelectionList`](SelectionList).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`SelectionList`](SelectionList) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default padding of the entries of a
+    /// [`SelectionList`](SelectionList).
+    const DEFAULT_PADDING: u16;
+
+    /// The default text size of the entries of a
+    /// [`SelectionList`](SelectionList).
+    fn default_size(&self) -> u16;
+
+    /// Draws a [`SelectionList`](SelectionList).
+    ///
+    /// The entries are scrolled by the given offset, which is clamped to the
+    /// content, instead of the offset of the [`State`](State).
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        labels: &[String],
+        state: &State,
+        offset: f32,
+        text_size: u16,
+        padding: u16,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 0;
+
+    fn default_size(&self) -> u16 {
+        0
+    }
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _labels: &[String],
+        _state: &State,
+        _offset: f32,
+        _text_size: u16,
+        _padding: u16,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, T, Message, Renderer> From<SelectionList<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Clone + Display,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(selection_list: SelectionList<'a, T, Message, Renderer>) -> Self {
+        Element::new(selection_list)
+    }
+}
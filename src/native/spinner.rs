@@ -0,0 +1,250 @@
+//! Use a spinner to indicate that some work is running in the background.
+//!
+//! *This API requires the following crate features to be activated: `spinner`*
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+use iced_native::{
+    event, layout, Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
+};
+
+use crate::core::renderer::DrawEnvironment;
+
+/// An indeterminate loading indicator drawing a rotating arc.
+///
+/// The rotation is computed from the time elapsed since the creation of its
+/// [`State`](State). The [`Spinner`](Spinner) is only animated while the
+/// application is redrawn, e.g. by a time subscription, whose ticks can be
+/// passed to [`State::tick`](State::tick).
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use iced_aw::native::spinner;
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Spinner<'a> = iced_aw::native::Spinner<'a, Null>;
+/// let state = spinner::State::new();
+///
+/// let spinner = Spinner::new(&state)
+///     .size(32)
+///     .stroke_width(3.0)
+///     .cycle_duration(Duration::from_millis(800));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Spinner<'a, Renderer: self::Renderer> {
+    /// The state of the [`Spinner`](Spinner).
+    state: &'a State,
+    /// The width and height of the [`Spinner`](Spinner).
+    size: u16,
+    /// The width of the arc of the [`Spinner`](Spinner).
+    stroke_width: f32,
+    /// The duration of a full rotation of the [`Spinner`](Spinner).
+    cycle_duration: Duration,
+    /// The style of the [`Spinner`](Spinner).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Renderer> Spinner<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Spinner`](Spinner) animated by the given
+    /// [`State`](State).
+    pub fn new(state: &'a State) -> Self {
+        Spinner {
+            state,
+            size: Renderer::DEFAULT_SIZE,
+            stroke_width: Renderer::DEFAULT_STROKE_WIDTH,
+            cycle_duration: Duration::from_secs(1),
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the width and height of the [`Spinner`](Spinner).
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the width of the arc of the [`Spinner`](Spinner).
+    pub fn stroke_width(mut self, stroke_width: f32) -> Self {
+        self.stroke_width = stroke_width;
+        self
+    }
+
+    /// Sets the duration of a full rotation of the [`Spinner`](Spinner).
+    pub fn cycle_duration(mut self, cycle_duration: Duration) -> Self {
+        self.cycle_duration = cycle_duration;
+        self
+    }
+
+    /// Sets the style of the [`Spinner`](Spinner).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Spinner<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let size = f32::from(self.size);
+
+        layout::Node::new(limits.resolve(Size::new(size, size)))
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _messages: &mut Vec<Message>,
+    ) -> event::Status {
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: None,
+                focus: (),
+            },
+            self.state.progress(self.cycle_duration),
+            self.stroke_width,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.size.hash(state);
+    }
+}
+
+/// The state of a [`Spinner`](Spinner) keeping track of its animation.
+#[derive(Clone, Copy, Debug)]
+pub struct State {
+    /// The time the animation started.
+    start: Instant,
+    /// The time of the last tick, if the animation is driven by ticks.
+    now: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) starting the animation now.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            now: None,
+        }
+    }
+
+    /// Advances the animation to the given time, e.g. of a tick of a time
+    /// subscription.
+    ///
+    /// Without any tick, the animation follows the current time whenever the
+    /// [`Spinner`](Spinner) is drawn.
+    pub fn tick(&mut self, now: Instant) {
+        self.now = Some(now);
+    }
+
+    /// Returns the progress of the current rotation between 0.0 and 1.0.
+    #[allow(clippy::cast_precision_loss)]
+    fn progress(&self, cycle_duration: Duration) -> f32 {
+        let cycle = cycle_duration.as_millis().max(1);
+        let elapsed = self
+            .now
+            .unwrap_or_else(Instant::now)
+            .saturating_duration_since(self.start)
+            .as_millis();
+
+        (elapsed % cycle) as f32 / cycle as f32
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The renderer of a [`Spinner`](Spinner).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Spinner`](Spinner) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default width and height of a [`Spinner`](Spinner).
+    const DEFAULT_SIZE: u16;
+
+    /// The default width of the arc of a [`Spinner`](Spinner).
+    const DEFAULT_STROKE_WIDTH: f32;
+
+    /// Draws a [`Spinner`](Spinner).
+    ///
+    /// The progress of the current rotation is between 0.0 and 1.0.
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        progress: f32,
+        stroke_width: f32,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 0;
+
+    const DEFAULT_STROKE_WIDTH: f32 = 0.0;
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _progress: f32,
+        _stroke_width: f32,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<Spinner<'a, Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+{
+    fn from(spinner: Spinner<'a, Renderer>) -> Self {
+        Element::new(spinner)
+    }
+}
@@ -0,0 +1,490 @@
+//! Use a split to split the available space in two parts to display two
+//! different elements separated by a draggable divider.
+//!
+//! *This API requires the following crate features to be activated: split*
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout::Limits, mouse, overlay, touch, Clipboard, Element, Event, Layout, Length, Point,
+    Size, Widget,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::renderer::DrawEnvironment;
+
+/// A split can divide the available space by a horizontal or vertical divider
+/// into two different panes, each displaying its own element.
+///
+/// The divider can be dragged to resize the panes.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::split::{self, Axis};
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type Split<'a, Message> = iced_aw::native::Split<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Resized(f32),
+/// }
+///
+/// let mut state = split::State::new(0.3).min_size_first(100.0);
+///
+/// let split = Split::new(
+///     &mut state,
+///     Text::new("First"),
+///     Text::new("Second"),
+///     Axis::Vertical,
+/// )
+/// .on_resize(Message::Resized);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Split<'a, Message, Renderer: self::Renderer> {
+    /// The state of the [`Split`](Split).
+    state: &'a mut State,
+    /// The first element of the [`Split`](Split).
+    first: Element<'a, Message, Renderer>,
+    /// The second element of the [`Split`](Split).
+    second: Element<'a, Message, Renderer>,
+    /// The axis of the divider of the [`Split`](Split).
+    axis: Axis,
+    /// The width of the divider of the [`Split`](Split).
+    divider_width: f32,
+    /// The width of the [`Split`](Split).
+    width: Length,
+    /// The height of the [`Split`](Split).
+    height: Length,
+    /// The optional message that is send with the new ratio when the divider
+    /// of the [`Split`](Split) is dragged.
+    on_resize: Option<Box<dyn Fn(f32) -> Message>>,
+    /// The style of the [`Split`](Split).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Split<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Split`](Split).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`Split`](Split)'s [`State`](State).
+    ///     * the first [`Element`](iced_native::Element) to display at the
+    ///         left or top of the [`Split`](Split).
+    ///     * the second [`Element`](iced_native::Element) to display at the
+    ///         right or bottom of the [`Split`](Split).
+    ///     * the [`Axis`](Axis) of the divider.
+    pub fn new<A, B>(state: &'a mut State, first: A, second: B, axis: Axis) -> Self
+    where
+        A: Into<Element<'a, Message, Renderer>>,
+        B: Into<Element<'a, Message, Renderer>>,
+    {
+        Split {
+            state,
+            first: first.into(),
+            second: second.into(),
+            axis,
+            divider_width: <Renderer as self::Renderer>::DEFAULT_DIVIDER_WIDTH,
+            width: Length::Fill,
+            height: Length::Fill,
+            on_resize: None,
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the divider of the [`Split`](Split).
+    pub fn divider_width(mut self, width: f32) -> Self {
+        self.divider_width = width;
+        self
+    }
+
+    /// Sets the width of the [`Split`](Split).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Split`](Split).
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the function that will be called with the new ratio when the
+    /// divider of the [`Split`](Split) is dragged.
+    ///
+    /// The ratio is already stored in the [`State`](State), so this is only
+    /// needed if the application wants to react to the resizing.
+    pub fn on_resize<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        self.on_resize = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`Split`](Split).
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Split<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        let (first_size, divider_size, second_size, second_position) = match self.axis {
+            Axis::Horizontal => {
+                let (first, second) = self.state.pane_sizes(size.height, self.divider_width);
+                (
+                    Size::new(size.width, first),
+                    Size::new(size.width, self.divider_width),
+                    Size::new(size.width, second),
+                    Point::new(0.0, first + self.divider_width),
+                )
+            }
+            Axis::Vertical => {
+                let (first, second) = self.state.pane_sizes(size.width, self.divider_width);
+                (
+                    Size::new(first, size.height),
+                    Size::new(self.divider_width, size.height),
+                    Size::new(second, size.height),
+                    Point::new(first + self.divider_width, 0.0),
+                )
+            }
+        };
+
+        let first = pane_node(renderer, &self.first, first_size);
+
+        let mut divider = iced_native::layout::Node::new(divider_size);
+        divider.move_to(match self.axis {
+            Axis::Horizontal => Point::new(0.0, first_size.height),
+            Axis::Vertical => Point::new(first_size.width, 0.0),
+        });
+
+        let mut second = pane_node(renderer, &self.second, second_size);
+        second.move_to(second_position);
+
+        iced_native::layout::Node::with_children(size, vec![first, divider, second])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+
+        let first_layout = children
+            .next()
+            .expect("Native: Layout should have a first pane layout");
+        let divider_layout = children
+            .next()
+            .expect("Native: Layout should have a divider layout");
+        let second_layout = children
+            .next()
+            .expect("Native: Layout should have a second pane layout");
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if divider_layout.bounds().contains(cursor_position) =>
+            {
+                self.state.dragging = true;
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if self.state.dragging =>
+            {
+                self.state.dragging = false;
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if self.state.dragging =>
+            {
+                let bounds = layout.bounds();
+                let (position, total) = match self.axis {
+                    Axis::Horizontal => (cursor_position.y - bounds.y, bounds.height),
+                    Axis::Vertical => (cursor_position.x - bounds.x, bounds.width),
+                };
+
+                let available = (total - self.divider_width).max(0.0);
+                if available > 0.0 {
+                    // Keep the ratio in the range allowed by the minimum sizes.
+                    let first = (position - self.divider_width / 2.0)
+                        .max(self.state.min_size_first)
+                        .min((available - self.state.min_size_second).max(0.0));
+
+                    self.state.set_ratio(first / available);
+
+                    #[cfg(feature = "instrumentation")]
+                    instrumentation::record(WidgetKind::Split, Action::Change);
+
+                    if let Some(on_resize) = &self.on_resize {
+                        messages.push(on_resize(self.state.ratio));
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let first_status = self.first.on_event(
+            event.clone(),
+            first_layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a first content layout"),
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        );
+
+        let second_status = self.second.on_event(
+            event,
+            second_layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a second content layout"),
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        );
+
+        first_status.merge(second_status)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: iced_native::Layout<'_>,
+        cursor_position: iced_graphics::Point,
+        viewport: &iced_graphics::Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: Some(viewport),
+                focus: (),
+            },
+            &self.first,
+            &self.second,
+            self.axis,
+            self.state.dragging,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.axis.hash(state);
+        (self.divider_width as u32).hash(state);
+        self.state.ratio.to_bits().hash(state);
+        self.state.min_size_first.to_bits().hash(state);
+        self.state.min_size_second.to_bits().hash(state);
+        self.first.hash_layout(state);
+        self.second.hash_layout(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let mut children = layout.children();
+        let first_layout = children
+            .next()
+            .expect("Native: Layout should have a first pane layout");
+        let second_layout = children
+            .nth(1)
+            .expect("Native: Layout should have a second pane layout");
+
+        let (first, second) = (&mut self.first, &mut self.second);
+
+        first
+            .overlay(first_layout)
+            .or_else(|| second.overlay(second_layout))
+    }
+}
+
+/// Calculates the layout of a pane.
+fn pane_node<'a, Message, Renderer>(
+    renderer: &Renderer,
+    content: &Element<'a, Message, Renderer>,
+    size: Size,
+) -> iced_native::layout::Node
+where
+    Renderer: self::Renderer,
+{
+    let limits = Limits::new(Size::ZERO, size);
+    let content = content.layout(renderer, &limits);
+
+    iced_native::layout::Node::with_children(size, vec![content])
+}
+
+/// The axis of the divider of a [`Split`](Split).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Axis {
+    /// A horizontal divider placing the panes above each other.
+    Horizontal,
+    /// A vertical divider placing the panes next to each other.
+    Vertical,
+}
+
+/// The state of a [`Split`](Split).
+#[derive(Clone, Copy, Debug)]
+pub struct State {
+    /// The ratio of the size of the first pane to the available space.
+    pub(crate) ratio: f32,
+    /// The minimum size of the first pane.
+    pub(crate) min_size_first: f32,
+    /// The minimum size of the second pane.
+    pub(crate) min_size_second: f32,
+    /// Whether the divider is currently dragged.
+    pub(crate) dragging: bool,
+}
+
+impl State {
+    /// Creates a new [`State`](State) for a [`Split`](Split) with the given
+    /// ratio of the first pane to the available space.
+    ///
+    /// The ratio is clamped between 0.0 and 1.0.
+    pub fn new(ratio: f32) -> Self {
+        Self {
+            ratio: ratio.max(0.0).min(1.0),
+            min_size_first: 0.0,
+            min_size_second: 0.0,
+            dragging: false,
+        }
+    }
+
+    /// Sets the minimum size of the first pane.
+    pub fn min_size_first(mut self, size: f32) -> Self {
+        self.min_size_first = size;
+        self
+    }
+
+    /// Sets the minimum size of the second pane.
+    pub fn min_size_second(mut self, size: f32) -> Self {
+        self.min_size_second = size;
+        self
+    }
+
+    /// Gets the ratio of the first pane to the available space.
+    pub const fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Sets the ratio of the first pane to the available space.
+    ///
+    /// The ratio is clamped between 0.0 and 1.0.
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.ratio = ratio.max(0.0).min(1.0);
+    }
+
+    /// Returns whether the divider is currently dragged.
+    pub const fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Calculates the sizes of the first and second pane for the given total
+    /// size and divider width, respecting the minimum sizes.
+    ///
+    /// If both minimum sizes do not fit, the minimum size of the second pane
+    /// wins.
+    fn pane_sizes(&self, total: f32, divider_width: f32) -> (f32, f32) {
+        let available = (total - divider_width).max(0.0);
+        let first = (available * self.ratio)
+            .max(self.min_size_first)
+            .min((available - self.min_size_second).max(0.0));
+
+        (first, available - first)
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+/// The renderer of a [`Split`](Split).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Split`](Split) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default width of the divider of a [`Split`](Split).
+    const DEFAULT_DIVIDER_WIDTH: f32;
+
+    /// Draws a [`Split`](Split).
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        first: &Element<'_, Message, Self>,
+        second: &Element<'_, Message, Self>,
+        axis: Axis,
+        dragging: bool,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_DIVIDER_WIDTH: f32 = 0.0;
+
+    fn draw<Message>(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _first: &Element<'_, Message, Self>,
+        _second: &Element<'_, Message, Self>,
+        _axis: Axis,
+        _dragging: bool,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<Split<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(split: Split<'a, Message, Renderer>) -> Self {
+        Element::new(split)
+    }
+}
@@ -0,0 +1,118 @@
+//! A common interface for widgets wrapping an underlay and showing an overlay
+//! controlled by their state.
+//!
+//! This is the pattern shared by the [`Modal`](crate::native::Modal) and the
+//! pickers of this crate. New pickers can implement these traits to behave in
+//! the same way.
+use std::time::{Duration, Instant};
+
+use iced_native::{overlay, Element, Layout};
+
+use crate::core::animation;
+
+/// The state of a [`StatefulOverlayWidget`](StatefulOverlayWidget) controlling
+/// the visibility of its overlay.
+pub trait OverlayState {
+    /// Returns whether the overlay is shown.
+    fn is_shown(&self) -> bool;
+
+    /// Sets the visibility of the overlay.
+    fn show(&mut self, b: bool);
+
+    /// Returns the point in time the overlay was last shown or hidden, if
+    /// the state keeps track of it.
+    ///
+    /// This drives the open and close animation of the overlay.
+    fn changed_at(&self) -> Option<Instant> {
+        None
+    }
+}
+
+/// The visibility of the overlay of a
+/// [`StatefulOverlayWidget`](StatefulOverlayWidget) during its open or close
+/// animation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Visibility {
+    /// The eased progress from hidden (`0.0`) to shown (`1.0`).
+    pub progress: f32,
+    /// Whether the overlay is closing. A closing overlay is still drawn, but
+    /// does not receive any events.
+    pub closing: bool,
+}
+
+impl Default for Visibility {
+    /// Returns the [`Visibility`](Visibility) of a fully shown overlay.
+    fn default() -> Self {
+        Self {
+            progress: 1.0,
+            closing: false,
+        }
+    }
+}
+
+/// A widget wrapping an underlay [`Element`](iced_native::Element) that shows
+/// an overlay on top of it while its [`State`](StatefulOverlayWidget::State)
+/// says so.
+///
+/// While the overlay is hidden, the overlay of the underlay is shown instead.
+/// The [`Widget::overlay`](iced_native::Widget::overlay) implementation of
+/// such a widget can simply call
+/// [`stateful_overlay`](StatefulOverlayWidget::stateful_overlay).
+pub trait StatefulOverlayWidget<'a, Message, Renderer: iced_native::Renderer> {
+    /// The state of the widget.
+    type State: OverlayState;
+
+    /// Returns the state of the widget.
+    fn state(&self) -> &Self::State;
+
+    /// Returns the underlay [`Element`](iced_native::Element) of the widget.
+    fn underlay_mut(&mut self) -> &mut Element<'a, Message, Renderer>;
+
+    /// Builds the overlay of the widget placed relative to the given layout
+    /// of the underlay.
+    fn build_overlay(&mut self, layout: Layout<'_>) -> overlay::Element<'_, Message, Renderer>;
+
+    /// Returns the duration of the open and close animation of the overlay.
+    ///
+    /// `None` shows and hides the overlay instantly.
+    fn animation(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Returns the current [`Visibility`](Visibility) of the overlay.
+    fn visibility(&self) -> Visibility {
+        let state = self.state();
+        let closing = !state.is_shown();
+
+        let progress =
+            self.animation()
+                .zip(state.changed_at())
+                .map_or(1.0, |(duration, changed_at)| {
+                    animation::ease_out_cubic(animation::progress(
+                        changed_at,
+                        duration,
+                        Instant::now(),
+                    ))
+                });
+
+        Visibility {
+            progress: if closing { 1.0 - progress } else { progress },
+            closing,
+        }
+    }
+
+    /// Returns the overlay of the widget if it is shown or still closing, or
+    /// else the overlay of the underlay.
+    fn stateful_overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let visibility = self.visibility();
+
+        if !visibility.closing || visibility.progress > 0.0 {
+            Some(self.build_overlay(layout))
+        } else {
+            self.underlay_mut().overlay(layout)
+        }
+    }
+}
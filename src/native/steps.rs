@@ -0,0 +1,416 @@
+//! Use steps to show the progress through a sequence of numbered steps, like
+//! the pages of a wizard.
+//!
+//! *This API requires the following crate features to be activated: `steps`*
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout, mouse, touch, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::renderer::DrawEnvironment;
+pub use crate::style::steps::StepState;
+
+/// A sequence of numbered steps marking the completed steps, the current step
+/// and the pending steps.
+///
+/// The steps are connected by lines and laid out horizontally or vertically.
+/// If enabled, clicking on a step produces a message to jump to it. The
+/// content of the current step, e.g. the page of a wizard, is placed by the
+/// application next to the [`Steps`](Steps).
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Steps<Message> = iced_aw::native::Steps<Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     StepSelected(usize),
+/// }
+///
+/// let steps = Steps::new(1)
+///     .push("Account")
+///     .push("Profile")
+///     .push("Confirm")
+///     .on_select(Message::StepSelected);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Steps<Message, Renderer: self::Renderer> {
+    /// The labels of the steps.
+    labels: Vec<String>,
+    /// The index of the current step.
+    current: usize,
+    /// The orientation of the [`Steps`](Steps).
+    orientation: Orientation,
+    /// The optional function that produces a message when a step is clicked.
+    on_select: Option<Box<dyn Fn(usize) -> Message>>,
+    /// The width of the [`Steps`](Steps).
+    width: Length,
+    /// The spacing between the indicators and the labels and between the
+    /// steps.
+    spacing: u16,
+    /// The padding of the indicators.
+    padding: u16,
+    /// The optional text size of the [`Steps`](Steps).
+    text_size: Option<u16>,
+    /// The style of the [`Steps`](Steps).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<Message, Renderer> Steps<Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates new [`Steps`](Steps) without any step.
+    ///
+    /// It expects the index of the current step. The steps before it are
+    /// completed, the steps after it are pending.
+    pub fn new(current: usize) -> Self {
+        Steps {
+            labels: Vec::new(),
+            current,
+            orientation: Orientation::Horizontal,
+            on_select: None,
+            width: Length::Fill,
+            spacing: <Renderer as self::Renderer>::DEFAULT_SPACING,
+            padding: <Renderer as self::Renderer>::DEFAULT_PADDING,
+            text_size: None,
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Adds a step with the given label to the [`Steps`](Steps).
+    pub fn push(mut self, label: impl Into<String>) -> Self {
+        self.labels.push(label.into());
+        self
+    }
+
+    /// Sets the [`Orientation`](Orientation) of the [`Steps`](Steps).
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the function that will be called with the index of a step when it
+    /// is clicked.
+    ///
+    /// Without it, the steps can't be clicked.
+    pub fn on_select<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`Steps`](Steps).
+    ///
+    /// Horizontal steps share the width equally.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between the indicators and the labels and between
+    /// vertical steps.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding of the indicators.
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`Steps`](Steps).
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`Steps`](Steps).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Steps<Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let spacing = f32::from(self.spacing);
+        let diameter = f32::from(text_size + 2 * self.padding);
+        let indicator = layout::Node::new(Size::new(diameter, diameter));
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let (children, content_size): (Vec<layout::Node>, Size) = match self.orientation {
+            Orientation::Horizontal => {
+                let step_width = limits.max().width / self.labels.len().max(1) as f32;
+                let mut height = 0.0_f32;
+
+                let children = self
+                    .labels
+                    .iter()
+                    .enumerate()
+                    .map(|(index, label)| {
+                        let (label_width, label_height) = renderer.measure(
+                            label,
+                            text_size,
+                            Size::new(step_width, f32::INFINITY),
+                        );
+
+                        let mut indicator = indicator.clone();
+                        indicator.move_to(Point::new((step_width - diameter) / 2.0, 0.0));
+
+                        let mut label = layout::Node::new(Size::new(label_width, label_height));
+                        label.move_to(Point::new(
+                            (step_width - label_width) / 2.0,
+                            diameter + spacing,
+                        ));
+
+                        let step_height = diameter + spacing + label_height;
+                        height = height.max(step_height);
+
+                        let mut step = layout::Node::with_children(
+                            Size::new(step_width, step_height),
+                            vec![indicator, label],
+                        );
+                        step.move_to(Point::new(index as f32 * step_width, 0.0));
+
+                        step
+                    })
+                    .collect();
+
+                (
+                    children,
+                    Size::new(step_width * self.labels.len() as f32, height),
+                )
+            }
+            Orientation::Vertical => {
+                let label_bounds = Size::new(
+                    (limits.max().width - diameter - spacing).max(0.0),
+                    f32::INFINITY,
+                );
+                let mut y = 0.0;
+                let mut width = 0.0_f32;
+
+                let children = self
+                    .labels
+                    .iter()
+                    .map(|label| {
+                        let (label_width, label_height) =
+                            renderer.measure(label, text_size, label_bounds);
+                        let step_height = diameter.max(label_height);
+
+                        let mut indicator = indicator.clone();
+                        indicator.move_to(Point::new(0.0, (step_height - diameter) / 2.0));
+
+                        let mut label = layout::Node::new(Size::new(label_width, label_height));
+                        label.move_to(Point::new(
+                            diameter + spacing,
+                            (step_height - label_height) / 2.0,
+                        ));
+
+                        let step_width = diameter + spacing + label_width;
+                        width = width.max(step_width);
+
+                        let mut step = layout::Node::with_children(
+                            Size::new(step_width, step_height),
+                            vec![indicator, label],
+                        );
+                        step.move_to(Point::new(0.0, y));
+
+                        y += step_height + spacing;
+
+                        step
+                    })
+                    .collect();
+
+                (children, Size::new(width, (y - spacing).max(0.0)))
+            }
+        };
+
+        layout::Node::with_children(limits.resolve(content_size), children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let on_select = match &self.on_select {
+            Some(on_select) => on_select,
+            None => return event::Status::Ignored,
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match layout
+                    .children()
+                    .position(|step| step.bounds().contains(cursor_position))
+                {
+                    Some(index) => {
+                        if index != self.current {
+                            #[cfg(feature = "instrumentation")]
+                            instrumentation::record(WidgetKind::Steps, Action::Select(index));
+                            messages.push(on_select(index));
+                        }
+
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: None,
+                focus: (),
+            },
+            &self.labels,
+            self.current,
+            self.orientation,
+            self.on_select.is_some(),
+            text_size,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.labels.hash(state);
+        self.orientation.hash(state);
+        self.width.hash(state);
+        self.spacing.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+}
+
+/// The orientation of [`Steps`](Steps).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Orientation {
+    /// The steps are placed next to each other with their labels below the
+    /// indicators.
+    Horizontal,
+    /// The steps are placed above each other with their labels next to the
+    /// indicators.
+    Vertical,
+}
+
+/// The renderer of [`Steps`](Steps).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use [`Steps`](Steps) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default spacing of [`Steps`](Steps).
+    const DEFAULT_SPACING: u16;
+
+    /// The default padding of the indicators of [`Steps`](Steps).
+    const DEFAULT_PADDING: u16;
+
+    /// The default text size of [`Steps`](Steps).
+    fn default_size(&self) -> u16;
+
+    /// Measures the given text in the given bounds and returns the minimum
+    /// boundaries that can fit it.
+    fn measure(&self, text: &str, size: u16, bounds: Size) -> (f32, f32);
+
+    /// Draws [`Steps`](Steps).
+    ///
+    /// Each child of the layout is a step with the layouts of its indicator
+    /// and its label.
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        labels: &[String],
+        current: usize,
+        orientation: Orientation,
+        is_clickable: bool,
+        text_size: u16,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_SPACING: u16 = 0;
+
+    const DEFAULT_PADDING: u16 = 0;
+
+    fn default_size(&self) -> u16 {
+        0
+    }
+
+    fn measure(&self, _text: &str, _size: u16, _bounds: Size) -> (f32, f32) {
+        (0.0, 0.0)
+    }
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _labels: &[String],
+        _current: usize,
+        _orientation: Orientation,
+        _is_clickable: bool,
+        _text_size: u16,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<Steps<Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(steps: Steps<Message, Renderer>) -> Self {
+        Element::new(steps)
+    }
+}
@@ -4,18 +4,43 @@
 //! to use the [`Tabs`](super::tabs::Tabs) widget instead.
 //!
 //! *This API requires the following crate features to be activated: `tab_bar`*
-use iced_native::{touch, Element};
+use iced_native::{keyboard, touch, Element};
 use std::hash::Hash;
 
 use iced_native::{
     column, event, layout, mouse, row, text, Align, Clipboard, Column, Event, Font, Hasher, Layout,
-    Length, Point, Rectangle, Row, Text, Widget,
+    Length, Point, Rectangle, Row, Size, Text, Vector, Widget,
 };
 
 pub mod tab_label;
-pub use tab_label::TabLabel;
+pub use tab_label::{TabLabel, TabLabelLayout};
 
-use crate::core::renderer::DrawEnvironment;
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::{
+    accessibility::{self, Accessibility, Role},
+    renderer::DrawEnvironment,
+};
+
+/// The icon size of a compact [`TabBar`](TabBar).
+const COMPACT_ICON_SIZE: u16 = 16;
+
+/// The text size of a compact [`TabBar`](TabBar).
+const COMPACT_TEXT_SIZE: u16 = 12;
+
+/// The close size of a compact [`TabBar`](TabBar).
+const COMPACT_CLOSE_SIZE: u16 = 10;
+
+/// The padding of a compact [`TabBar`](TabBar).
+const COMPACT_PADDING: u16 = 2;
+
+/// The distance the cursor has to move to pick up a tab of a reorderable
+/// [`TabBar`](TabBar).
+const DRAG_THRESHOLD: f32 = 5.0;
+
+/// The distance a scrollable [`TabBar`](TabBar) is scrolled per scrolled
+/// line.
+const SCROLL_LINE: f32 = 30.0;
 
 /// A tab bar to show tabs.
 ///
@@ -24,7 +49,7 @@ use crate::core::renderer::DrawEnvironment;
 /// # use iced_aw::{TabLabel};
 /// # use iced_native::{renderer::Null};
 /// #
-/// # pub type TabBar<Message> = iced_aw::native::TabBar<Message, Null>;
+/// # pub type TabBar<'a, Message> = iced_aw::native::TabBar<'a, Message, Null>;
 /// #[derive(Debug, Clone)]
 /// enum Message {
 ///     TabSelected(usize),
@@ -41,11 +66,11 @@ use crate::core::renderer::DrawEnvironment;
 /// .push(TabLabel::Text(String::from("Three")));
 /// ```
 #[allow(missing_debug_implementations)]
-pub struct TabBar<Message, Renderer: self::Renderer> {
+pub struct TabBar<'a, Message, Renderer: self::Renderer> {
     /// The currently active tab.
     active_tab: usize,
     /// The vector containing the labels of the tabs.
-    tab_labels: Vec<TabLabel>,
+    tab_labels: Vec<Tab<'a, Message, Renderer>>,
     /// The function that produces the message when a tab is selected.
     on_select: Box<dyn Fn(usize) -> Message>,
     /// The function that produces the message when the close icon was pressed.
@@ -68,15 +93,139 @@ pub struct TabBar<Message, Renderer: self::Renderer> {
     padding: u16,
     /// The spacing of the tabs of the [`TabBar`](TabBar).
     spacing: u16,
+    /// The tab width below which the tabs only show their icons.
+    icon_only_below: Option<u32>,
     /// The optional icon font of the [`TabBar`](TabBar).
     icon_font: Option<Font>,
     /// The optional text font of the [`TabBar`](TabBar).
     text_font: Option<Font>,
+    /// Whether the tabs of the [`TabBar`](TabBar) are stacked vertically.
+    vertical: bool,
+    /// The arrangement of the icons and texts of the tabs.
+    label_layout: TabLabelLayout,
+    /// The overflow mode of a scrollable [`TabBar`](TabBar).
+    overflow: Option<Overflow<Message>>,
+    /// The state of a focusable or reorderable [`TabBar`](TabBar).
+    state: Option<&'a mut State>,
+    /// The function that produces the message when a tab is dropped.
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message>>,
+    /// The accessibility information of the [`TabBar`](TabBar).
+    accessibility: Accessibility,
     /// The style of the [`TabBar`](TabBar).
     style: Renderer::Style,
 }
 
-impl<Message, Renderer> TabBar<Message, Renderer>
+/// The overflow mode of a scrollable [`TabBar`](TabBar).
+struct Overflow<Message> {
+    /// The minimum width of the tabs.
+    min_tab_width: u16,
+    /// The scroll offset of the tabs.
+    offset: f32,
+    /// The function that produces the message when the tabs are scrolled.
+    on_scroll: Box<dyn Fn(f32) -> Message>,
+    /// Whether the scroll buttons are shown.
+    buttons: bool,
+}
+
+/// The label of a tab of a [`TabBar`](TabBar).
+enum Tab<'a, Message, Renderer> {
+    /// A [`TabLabel`](TabLabel) showing an icon and/or a text.
+    Label(TabLabel),
+    /// A custom [`Element`](Element).
+    Element(Element<'a, Message, Renderer>),
+}
+
+/// Borrows the custom [`Element`](Element) of a tab to lay it out as part
+/// of the tabs.
+struct LabelElement<'b, 'a, Message, Renderer>(&'b Element<'a, Message, Renderer>);
+
+impl<'b, 'a, Message, Renderer> Widget<Message, Renderer>
+    for LabelElement<'b, 'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn width(&self) -> Length {
+        self.0.width()
+    }
+
+    fn height(&self) -> Length {
+        self.0.height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.0.layout(renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.0
+            .draw(renderer, defaults, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.0.hash_layout(state);
+    }
+}
+
+/// The state of a focusable or reorderable [`TabBar`](TabBar).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct State {
+    /// The tab that is pressed or dragged.
+    drag: Option<Drag>,
+    /// Whether the [`TabBar`](TabBar) has the keyboard focus.
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`](State).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`TabBar`](TabBar) has the keyboard focus.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Gives the keyboard focus to the [`TabBar`](TabBar).
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Removes the keyboard focus from the [`TabBar`](TabBar).
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    /// Returns the index of the dragged tab, if any.
+    pub fn dragged(&self) -> Option<usize> {
+        self.drag
+            .filter(|drag| drag.is_dragging)
+            .map(|drag| drag.index)
+    }
+}
+
+/// A tab of a reorderable [`TabBar`](TabBar) that is pressed or dragged.
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The index of the tab.
+    index: usize,
+    /// The position of the cursor along the tabs when the tab was pressed.
+    origin: f32,
+    /// The current position of the cursor along the tabs.
+    position: f32,
+    /// Whether the cursor moved far enough to pick up the tab.
+    is_dragging: bool,
+}
+
+impl<'a, Message, Renderer> TabBar<'a, Message, Renderer>
 where
     Renderer: self::Renderer,
 {
@@ -108,7 +257,7 @@ where
     {
         Self {
             active_tab,
-            tab_labels,
+            tab_labels: tab_labels.into_iter().map(Tab::Label).collect(),
             on_select: Box::new(on_select),
             on_close: None,
             width: Length::Fill,
@@ -120,8 +269,15 @@ where
             close_size: <Renderer as self::Renderer>::DEFAULT_CLOSE_SIZE,
             padding: <Renderer as self::Renderer>::DEFAULT_PADDING,
             spacing: <Renderer as self::Renderer>::DEFAULT_SPACING,
+            icon_only_below: None,
             icon_font: None,
             text_font: None,
+            vertical: false,
+            label_layout: TabLabelLayout::default(),
+            overflow: None,
+            state: None,
+            on_reorder: None,
+            accessibility: Accessibility::default(),
             style: Renderer::Style::default(),
         }
     }
@@ -131,10 +287,19 @@ where
         self.active_tab
     }
 
+    /// Gets the style of the [`TabBar`](TabBar).
+    pub(crate) fn get_style(&self) -> &Renderer::Style {
+        &self.style
+    }
+
     /// Sets the message that will be produced when the close icon of a tab
     /// on the [`TabBar`](TabBar) is pressed.
     ///
     /// Setting this enables the drawing of a close icon on the tabs.
+    ///
+    /// The tab is not closed by the [`TabBar`](TabBar) itself, so the message
+    /// can be treated as a close request, e.g. to ask for confirmation before
+    /// removing the tab.
     pub fn on_close<F>(mut self, on_close: F) -> Self
     where
         F: 'static + Fn(usize) -> Message,
@@ -210,6 +375,50 @@ where
         self
     }
 
+    /// Turns the [`TabBar`](TabBar) into its compact variant with reduced
+    /// icon, text and close sizes and a reduced padding.
+    ///
+    /// This is useful for tool windows with many tabs.
+    pub fn compact(mut self) -> Self {
+        self.icon_size = COMPACT_ICON_SIZE;
+        self.text_size = COMPACT_TEXT_SIZE;
+        self.close_size = COMPACT_CLOSE_SIZE;
+        self.padding = COMPACT_PADDING;
+        self
+    }
+
+    /// Sets whether the tabs of the [`TabBar`](TabBar) are stacked
+    /// vertically, e.g. for a side panel.
+    ///
+    /// A vertical [`TabBar`](TabBar) is as wide as its widest tab, so this
+    /// also resets the width of the [`TabBar`](TabBar). A
+    /// [`scrollable`](TabBar::scrollable) [`TabBar`](TabBar) can only be
+    /// scrolled horizontally.
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+        self.width = if vertical {
+            Length::Shrink
+        } else {
+            Length::Fill
+        };
+        self
+    }
+
+    /// Sets the [`TabLabelLayout`](TabLabelLayout) arranging the icons and
+    /// texts of the tabs of the [`TabBar`](TabBar).
+    pub fn label_layout(mut self, label_layout: TabLabelLayout) -> Self {
+        self.label_layout = label_layout;
+        self
+    }
+
+    /// Sets the available width per tab below which the
+    /// [`TabLabel::IconText`](tab_label::TabLabel::IconText)s of the
+    /// [`TabBar`](TabBar) will only show their icon.
+    pub fn icon_only_below(mut self, width: u32) -> Self {
+        self.icon_only_below = Some(width);
+        self
+    }
+
     /// Sets the font of the icons of the
     /// [`TabLabel`](tab_label::TabLabel)s of the [`TabBar`](TabBar).
     pub fn icon_font(mut self, icon_font: Font) -> Self {
@@ -224,20 +433,409 @@ where
         self
     }
 
+    /// Turns on the overflow mode of the [`TabBar`](TabBar).
+    ///
+    /// Instead of being squeezed, the tabs keep the given minimum width and
+    /// can be scrolled horizontally with the mouse wheel. Like the active tab,
+    /// the scroll offset is kept by the application: scrolling produces the
+    /// message of the given function with the new offset. Selecting a tab
+    /// that is only partially visible scrolls it into view.
+    pub fn scrollable<F>(mut self, min_tab_width: u16, offset: f32, on_scroll: F) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        self.overflow = Some(Overflow {
+            min_tab_width,
+            offset,
+            on_scroll: Box::new(on_scroll),
+            buttons: false,
+        });
+        self
+    }
+
+    /// Shows buttons to scroll the tabs of a scrollable [`TabBar`](TabBar)
+    /// to the left and to the right.
+    ///
+    /// This has no effect unless [`scrollable`](TabBar::scrollable) is set.
+    pub fn scroll_buttons(mut self) -> Self {
+        if let Some(overflow) = &mut self.overflow {
+            overflow.buttons = true;
+        }
+        self
+    }
+
+    /// Makes the tabs of the [`TabBar`](TabBar) reorderable.
+    ///
+    /// A pressed tab can be dragged along the [`TabBar`](TabBar). When it is
+    /// released, the message of the given function is produced with the
+    /// index of the dragged tab and the index the tab should be moved to,
+    /// i.e. the index it has after being removed and inserted again. The tabs
+    /// are not reordered by the [`TabBar`](TabBar) itself.
+    ///
+    /// This also makes the [`TabBar`](TabBar) [`focusable`](TabBar::focusable).
+    pub fn reorderable<F>(mut self, state: &'a mut State, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(usize, usize) -> Message,
+    {
+        self.state = Some(state);
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Makes the [`TabBar`](TabBar) focusable by clicking it.
+    ///
+    /// While it has the focus, the arrow keys select the previous or the next
+    /// tab and Home and End select the first or the last tab. Regardless of
+    /// the focus, Ctrl+Tab and Ctrl+Shift+Tab cycle through the tabs of every
+    /// [`TabBar`](TabBar).
+    pub fn focusable(mut self, state: &'a mut State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the label announced for the [`TabBar`](TabBar).
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.accessibility.label = Some(label.into());
+        self
+    }
+
+    /// Sets the description announced for the [`TabBar`](TabBar).
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.accessibility.description = Some(description.into());
+        self
+    }
+
     /// Sets the style of the [`TabBar`](TabBar).
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
         self.style = style.into();
         self
     }
 
+    /// Returns the [`ScrollInfo`](ScrollInfo) of a scrollable
+    /// [`TabBar`](TabBar) with the given layout.
+    fn scroll_info(&self, layout: Layout<'_>) -> Option<ScrollInfo> {
+        let overflow = self.overflow.as_ref().filter(|_| !self.vertical)?;
+        let bounds = layout.bounds();
+        let button_width = if overflow.buttons {
+            f32::from(<Renderer as self::Renderer>::SCROLL_BUTTON_WIDTH)
+        } else {
+            0.0
+        };
+        let viewport = Rectangle {
+            x: bounds.x + button_width,
+            width: (bounds.width - 2.0 * button_width).max(0.0),
+            ..bounds
+        };
+
+        let (offset, max_offset) = match (layout.children().next(), layout.children().last()) {
+            (Some(first), Some(last)) => {
+                let (first, last) = (first.bounds(), last.bounds());
+
+                (
+                    viewport.x - first.x,
+                    (last.x + last.width - first.x - viewport.width).max(0.0),
+                )
+            }
+            _ => (0.0, 0.0),
+        };
+
+        Some(ScrollInfo {
+            viewport,
+            offset,
+            max_offset,
+            buttons: overflow.buttons,
+        })
+    }
+
+    /// Produces the message to scroll a scrollable [`TabBar`](TabBar) to the
+    /// given offset if it differs from the current offset.
+    fn scroll_to(&self, offset: f32, scroll: ScrollInfo, messages: &mut Vec<Message>) {
+        let offset = clamp_offset(offset, scroll.max_offset);
+
+        if let Some(overflow) = &self.overflow {
+            if (offset - scroll.offset).abs() > f32::EPSILON {
+                messages.push((overflow.on_scroll)(offset));
+            }
+        }
+    }
+
+    /// Scrolls a scrollable [`TabBar`](TabBar) so that the tab with the given
+    /// bounds is fully visible.
+    fn scroll_into_view(&self, tab: Rectangle, scroll: ScrollInfo, messages: &mut Vec<Message>) {
+        let viewport = scroll.viewport;
+
+        if tab.x < viewport.x {
+            self.scroll_to(scroll.offset - (viewport.x - tab.x), scroll, messages);
+        } else if tab.x + tab.width > viewport.x + viewport.width {
+            self.scroll_to(
+                scroll.offset + (tab.x + tab.width - viewport.x - viewport.width),
+                scroll,
+                messages,
+            );
+        }
+    }
+
+    /// Returns the [`DraggedTab`](DraggedTab) of a reorderable
+    /// [`TabBar`](TabBar) with the given layout.
+    fn dragged_tab(&self, layout: Layout<'_>) -> Option<DraggedTab> {
+        let drag = self.state.as_ref()?.drag.filter(|drag| drag.is_dragging)?;
+        let tab = layout.children().nth(drag.index)?.bounds();
+        let offset = drag.position - drag.origin;
+        let (start, length) = self.span(tab);
+        let center = start + length / 2.0 + offset;
+
+        let others: Vec<(f32, f32)> = layout
+            .children()
+            .enumerate()
+            .filter(|(index, _)| *index != drag.index)
+            .map(|(_, layout)| self.span(layout.bounds()))
+            .collect();
+        let target = others
+            .iter()
+            .filter(|(start, length)| start + length / 2.0 < center)
+            .count();
+
+        let insertion = match (
+            target.checked_sub(1).and_then(|i| others.get(i)),
+            others.get(target),
+        ) {
+            (Some((start, length)), Some(_)) => start + length + f32::from(self.spacing) / 2.0,
+            (Some((start, length)), None) => start + length,
+            (None, Some((start, _))) => *start,
+            (None, None) => start,
+        };
+
+        let bounds = layout.bounds();
+        let (offset, indicator) = if self.vertical {
+            (
+                Vector::new(0.0, offset),
+                Rectangle {
+                    y: insertion,
+                    height: 0.0,
+                    ..bounds
+                },
+            )
+        } else {
+            (
+                Vector::new(offset, 0.0),
+                Rectangle {
+                    x: insertion,
+                    width: 0.0,
+                    ..bounds
+                },
+            )
+        };
+
+        Some(DraggedTab {
+            index: drag.index,
+            offset,
+            target,
+            indicator,
+        })
+    }
+
+    /// Returns the index of the tab selected by the given key, if any.
+    fn key_target(
+        &self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+    ) -> Option<usize> {
+        let last = self.tab_labels.len().checked_sub(1)?;
+        let active_tab = self.active_tab.min(last);
+        let is_focused = self.state.as_ref().map_or(false, |state| state.is_focused);
+        let (previous_key, next_key) = if self.vertical {
+            (keyboard::KeyCode::Up, keyboard::KeyCode::Down)
+        } else {
+            (keyboard::KeyCode::Left, keyboard::KeyCode::Right)
+        };
+
+        let previous = if active_tab == 0 {
+            last
+        } else {
+            active_tab - 1
+        };
+        let next = if active_tab == last {
+            0
+        } else {
+            active_tab + 1
+        };
+
+        match key_code {
+            keyboard::KeyCode::Tab if modifiers.control => {
+                Some(if modifiers.shift { previous } else { next })
+            }
+            _ if !is_focused => None,
+            keyboard::KeyCode::Home => Some(0),
+            keyboard::KeyCode::End => Some(last),
+            key_code if key_code == previous_key => Some(previous),
+            key_code if key_code == next_key => Some(next),
+            _ => None,
+        }
+    }
+
+    /// Returns the position of the given point along the direction of the
+    /// tabs of the [`TabBar`](TabBar).
+    fn main_axis(&self, point: Point) -> f32 {
+        if self.vertical {
+            point.y
+        } else {
+            point.x
+        }
+    }
+
+    /// Returns the start and the length of the given bounds along the
+    /// direction of the tabs of the [`TabBar`](TabBar).
+    fn span(&self, bounds: Rectangle) -> (f32, f32) {
+        if self.vertical {
+            (bounds.y, bounds.height)
+        } else {
+            (bounds.x, bounds.width)
+        }
+    }
+
+    /// Builds the tabs of the [`TabBar`](TabBar) with the given
+    /// width of the tabs and of their labels.
+    fn tabs(
+        &self,
+        icon_only: bool,
+        tab_width: Length,
+        inner_width: Length,
+    ) -> Vec<Element<'_, Message, Renderer>>
+    where
+        Renderer: column::Renderer + text::Renderer + row::Renderer,
+    {
+        self.tab_labels
+            .iter()
+            .map(|tab| {
+                let tab_label = match tab {
+                    Tab::Label(tab_label) => tab_label,
+                    Tab::Element(element) => {
+                        return self.tab(
+                            Column::new()
+                                .align_items(Align::Center)
+                                .push(Element::new(LabelElement(element)))
+                                .width(inner_width)
+                                .height(self.height)
+                                .into(),
+                            tab_width,
+                        )
+                    }
+                };
+
+                let label: Element<'_, Message, Renderer> = match tab_label {
+                    TabLabel::IconText(_icon, text)
+                        if !icon_only && self.label_layout == TabLabelLayout::Inline =>
+                    {
+                        Row::new()
+                            .align_items(Align::Center)
+                            .spacing(self.padding)
+                            .push(
+                                Row::new()
+                                    .width(Length::Units(self.icon_size))
+                                    .height(Length::Units(self.icon_size)),
+                            )
+                            .push(Text::new(text).size(self.text_size).width(inner_width))
+                            .width(inner_width)
+                            .height(self.height)
+                            .into()
+                    }
+                    _ => self.stacked_label(tab_label, icon_only, inner_width).into(),
+                };
+
+                self.tab(label, tab_width)
+            })
+            .collect()
+    }
+
+    /// Builds a tab with the given label and, if the tabs can be closed, its
+    /// close icon.
+    fn tab<'b>(
+        &self,
+        label: Element<'b, Message, Renderer>,
+        tab_width: Length,
+    ) -> Element<'b, Message, Renderer>
+    where
+        Message: 'b,
+        Renderer: 'b + row::Renderer,
+    {
+        let mut label_row = Row::new()
+            .align_items(Align::Center)
+            .padding(self.padding)
+            .width(tab_width)
+            .push(label);
+
+        if self.on_close.is_some() {
+            label_row = label_row.push(
+                Row::new()
+                    .width(Length::Units(self.close_size))
+                    .height(Length::Units(self.close_size))
+                    .align_items(Align::Center),
+            );
+        }
+
+        label_row.into()
+    }
+
+    /// Builds the label of a tab with its icon stacked above its text.
+    fn stacked_label(
+        &self,
+        tab_label: &TabLabel,
+        icon_only: bool,
+        inner_width: Length,
+    ) -> Column<'_, Message, Renderer>
+    where
+        Renderer: column::Renderer + text::Renderer + row::Renderer,
+    {
+        match tab_label {
+            TabLabel::Icon(_icon) => Column::new().align_items(Align::Center).push(
+                Row::new()
+                    .width(Length::Units(self.icon_size))
+                    .height(Length::Units(self.icon_size)),
+            ),
+            TabLabel::IconText(_icon, _text) if icon_only => {
+                Column::new().align_items(Align::Center).push(
+                    Row::new()
+                        .width(Length::Units(self.icon_size))
+                        .height(Length::Units(self.icon_size)),
+                )
+            }
+            TabLabel::Text(text) => Column::new()
+                .align_items(Align::Center)
+                .push(Text::new(text).size(self.text_size).width(inner_width)),
+            TabLabel::IconText(_icon, text) => Column::new()
+                .align_items(Align::Center)
+                .push(
+                    Row::new()
+                        .width(Length::Units(self.icon_size))
+                        .height(Length::Units(self.icon_size)),
+                )
+                .push(Text::new(text).size(self.text_size).width(inner_width)),
+        }
+        .width(inner_width)
+        .height(self.height)
+    }
+
     /// Pushes a [`TabLabel`](tab_label::TabLabel) to the [`TabBar`](TabBar).
     pub fn push(mut self, tab_label: TabLabel) -> Self {
-        self.tab_labels.push(tab_label);
+        self.tab_labels.push(Tab::Label(tab_label));
+        self
+    }
+
+    /// Pushes a tab with a custom [`Element`](Element) as its label to the
+    /// [`TabBar`](TabBar), e.g. to show a badge or an image.
+    ///
+    /// The [`TabBar`](TabBar) still draws the background and the close icon
+    /// of the tab. The label doesn't receive any events.
+    pub fn push_element<E>(mut self, label: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.tab_labels.push(Tab::Element(label.into()));
         self
     }
 }
 
-impl<Message, Renderer> Widget<Message, Renderer> for TabBar<Message, Renderer>
+impl<'a, Message, Renderer> Widget<Message, Renderer> for TabBar<'a, Message, Renderer>
 where
     Renderer: self::Renderer + column::Renderer + text::Renderer + row::Renderer,
 {
@@ -250,51 +848,90 @@ where
     }
 
     fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
-        self.tab_labels
-            .iter()
-            .fold(Row::<Message, Renderer>::new(), |row, tab_label| {
-                let label = match tab_label {
-                    TabLabel::Icon(_icon) => Column::new().align_items(Align::Center).push(
-                        Row::new()
-                            .width(Length::Units(self.icon_size))
-                            .height(Length::Units(self.icon_size)),
+        let icon_only = self.icon_only_below.map_or(false, |threshold| {
+            let width_per_tab = if self.vertical {
+                limits.max().width
+            } else {
+                limits.max().width / (self.tab_labels.len() as f32)
+            };
+
+            !self.tab_labels.is_empty() && width_per_tab < threshold as f32
+        });
+
+        if self.vertical {
+            let limits = limits.width(self.width);
+
+            // The tabs of a vertical TabBar share the width of the widest tab.
+            let natural_width =
+                Column::with_children(self.tabs(icon_only, Length::Shrink, Length::Shrink))
+                    .spacing(self.spacing)
+                    .layout(renderer, &layout::Limits::new(Size::ZERO, limits.max()))
+                    .size()
+                    .width;
+            let width = limits.resolve(Size::new(natural_width, 0.0)).width;
+
+            return Column::with_children(self.tabs(icon_only, Length::Fill, Length::Fill))
+                .width(Length::Fill)
+                .spacing(self.spacing)
+                .layout(
+                    renderer,
+                    &layout::Limits::new(
+                        Size::new(width, 0.0),
+                        Size::new(width, limits.max().height),
                     ),
-                    TabLabel::Text(text) => Column::new()
-                        .align_items(Align::Center)
-                        .push(Text::new(text).size(self.text_size).width(self.tab_width)),
-                    TabLabel::IconText(_icon, text) => Column::new()
-                        .align_items(Align::Center)
-                        .push(
-                            Row::new()
-                                .width(Length::Units(self.icon_size))
-                                .height(Length::Units(self.icon_size)),
-                        )
-                        .push(Text::new(text).size(self.text_size).width(self.tab_width)),
-                }
-                .width(self.tab_width)
-                .height(self.height);
+                );
+        }
 
-                let mut label_row = Row::new()
-                    .align_items(Align::Center)
-                    .padding(self.padding)
-                    .width(self.tab_width)
-                    .push(label);
+        let overflow = match &self.overflow {
+            Some(overflow) => overflow,
+            None => {
+                return Row::with_children(self.tabs(icon_only, self.tab_width, self.tab_width))
+                    .width(self.width)
+                    .height(self.height)
+                    .spacing(self.spacing)
+                    .layout(renderer, limits)
+            }
+        };
 
-                if self.on_close.is_some() {
-                    label_row = label_row.push(
-                        Row::new()
-                            .width(Length::Units(self.close_size))
-                            .height(Length::Units(self.close_size))
-                            .align_items(Align::Center),
-                    );
-                }
+        let limits = limits.width(self.width).height(self.height);
+        let width = limits.max().width;
+        let button_width = if overflow.buttons {
+            f32::from(<Renderer as self::Renderer>::SCROLL_BUTTON_WIDTH)
+        } else {
+            0.0
+        };
+        let visible_width = (width - 2.0 * button_width).max(0.0);
+
+        let count = self.tab_labels.len().max(1) as f32;
+        let tab_width = ((visible_width - f32::from(self.spacing) * (count - 1.0)) / count)
+            .max(f32::from(overflow.min_tab_width));
 
-                row.push(label_row)
+        let row =
+            Row::with_children(self.tabs(icon_only, Length::Units(tab_width as u16), Length::Fill))
+                .height(self.height)
+                .spacing(self.spacing)
+                .layout(
+                    renderer,
+                    &layout::Limits::new(
+                        Size::new(0.0, limits.min().height),
+                        Size::new(f32::INFINITY, limits.max().height),
+                    ),
+                );
+
+        let offset = clamp_offset(overflow.offset, row.size().width - visible_width);
+        let children = row
+            .children()
+            .iter()
+            .map(|tab| {
+                let mut tab = tab.clone();
+                let bounds = tab.bounds();
+                tab.move_to(Point::new(bounds.x + button_width - offset, bounds.y));
+
+                tab
             })
-            .width(self.width)
-            .height(self.height)
-            .spacing(self.spacing)
-            .layout(renderer, limits)
+            .collect();
+
+        layout::Node::with_children(Size::new(width, row.size().height), children)
     }
 
     fn on_event(
@@ -306,35 +943,177 @@ where
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        let scroll = self.scroll_info(layout);
+
+        if matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) {
+            if let Some(state) = &mut self.state {
+                state.is_focused = layout.bounds().contains(cursor_position);
+            }
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 if layout.bounds().contains(cursor_position) {
+                    if let Some(scroll) = scroll {
+                        // Tabs scrolled out of the visible area can't be
+                        // selected, so outside of it only the scroll buttons
+                        // can be pressed.
+                        if !scroll.viewport.contains(cursor_position) {
+                            if !scroll.buttons {
+                                return event::Status::Ignored;
+                            }
+
+                            let step = layout
+                                .children()
+                                .next()
+                                .map_or(0.0, |tab| tab.bounds().width + f32::from(self.spacing));
+                            let offset = if cursor_position.x < scroll.viewport.x {
+                                scroll.offset - step
+                            } else {
+                                scroll.offset + step
+                            };
+                            self.scroll_to(offset, scroll, messages);
+
+                            return event::Status::Captured;
+                        }
+                    }
+
                     let tabs_map: Vec<bool> = layout
                         .children()
                         .map(|layout| layout.bounds().contains(cursor_position))
                         .collect();
 
                     if let Some(new_selected) = tabs_map.iter().position(|b| *b) {
-                        messages.push(
-                            self.on_close
-                                .as_ref()
-                                .filter(|_on_close| {
-                                    let tab_layout = layout.children().nth(new_selected).expect("Native: Layout should have a tab layout at the selected index");
-                                    let cross_layout = tab_layout.children().nth(1).expect("Native: Layout should have a close layout");
-
-                                    cross_layout.bounds().contains(cursor_position)
-                                })
-                                .map_or_else(
-                                    || (self.on_select)(new_selected),
-                                    |on_close| (on_close)(new_selected),
-                                ),
+                        let tab_layout = layout.children().nth(new_selected).expect(
+                            "Native: Layout should have a tab layout at the selected index",
                         );
+                        let on_close = self.on_close.as_ref().filter(|_on_close| {
+                            let cross_layout = tab_layout
+                                .children()
+                                .nth(1)
+                                .expect("Native: Layout should have a close layout");
+
+                            cross_layout.bounds().contains(cursor_position)
+                        });
+
+                        if let Some(on_close) = on_close {
+                            #[cfg(feature = "instrumentation")]
+                            instrumentation::record(
+                                WidgetKind::TabBar,
+                                Action::CloseIndex(new_selected),
+                            );
+                            messages.push((on_close)(new_selected));
+                        } else {
+                            #[cfg(feature = "instrumentation")]
+                            instrumentation::record(
+                                WidgetKind::TabBar,
+                                Action::Select(new_selected),
+                            );
+                            messages.push((self.on_select)(new_selected));
+
+                            if let Some(scroll) = scroll {
+                                self.scroll_into_view(tab_layout.bounds(), scroll, messages);
+                            }
+
+                            let position = self.main_axis(cursor_position);
+
+                            if let (Some(state), Some(_)) = (&mut self.state, &self.on_reorder) {
+                                state.drag = Some(Drag {
+                                    index: new_selected,
+                                    origin: position,
+                                    position,
+                                    is_dragging: false,
+                                });
+                            }
+                        }
+
                         return event::Status::Captured;
                     }
                 }
                 event::Status::Ignored
             }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if layout.bounds().contains(cursor_position) =>
+            {
+                match scroll {
+                    Some(scroll) => {
+                        let (x, y) = match delta {
+                            mouse::ScrollDelta::Lines { x, y } => {
+                                (x * SCROLL_LINE, y * SCROLL_LINE)
+                            }
+                            mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                        };
+                        let delta = if x.abs() > y.abs() { x } else { y };
+                        self.scroll_to(scroll.offset - delta, scroll, messages);
+
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let position = self.main_axis(cursor_position);
+
+                match self.state.as_mut().and_then(|state| state.drag.as_mut()) {
+                    Some(drag) => {
+                        drag.is_dragging |= (position - drag.origin).abs() > DRAG_THRESHOLD;
+                        drag.position = position;
+
+                        if drag.is_dragging {
+                            event::Status::Captured
+                        } else {
+                            event::Status::Ignored
+                        }
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                let dragged = self.dragged_tab(layout);
+
+                match (&mut self.state, &self.on_reorder) {
+                    (Some(state), Some(on_reorder))
+                        if state.drag.take().map_or(false, |drag| drag.is_dragging) =>
+                    {
+                        if let Some(dragged) =
+                            dragged.filter(|dragged| dragged.target != dragged.index)
+                        {
+                            messages.push((on_reorder)(dragged.index, dragged.target));
+                        }
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => match self.key_target(key_code, modifiers) {
+                Some(new_selected) => {
+                    if new_selected != self.active_tab {
+                        #[cfg(feature = "instrumentation")]
+                        instrumentation::record(WidgetKind::TabBar, Action::Select(new_selected));
+                        messages.push((self.on_select)(new_selected));
+
+                        if let (Some(scroll), Some(tab_layout)) =
+                            (scroll, layout.children().nth(new_selected))
+                        {
+                            self.scroll_into_view(tab_layout.bounds(), scroll, messages);
+                        }
+                    }
+
+                    event::Status::Captured
+                }
+                None => event::Status::Ignored,
+            },
             _ => event::Status::Ignored,
         }
     }
@@ -347,6 +1126,36 @@ where
         cursor_position: Point,
         viewport: &Rectangle,
     ) -> Renderer::Output {
+        accessibility::Renderer::announce(
+            renderer,
+            self.accessibility.node(Role::TabList),
+            layout.bounds(),
+        );
+
+        let tab_labels = self
+            .tab_labels
+            .iter()
+            .zip(layout.children())
+            .map(|(tab, tab_layout)| match tab {
+                Tab::Label(tab_label) => RenderedLabel::Label(tab_label),
+                Tab::Element(element) => {
+                    let element_layout = tab_layout
+                        .children()
+                        .next()
+                        .and_then(|label_layout| label_layout.children().next())
+                        .expect("Native: Layout should have a layout for a custom label");
+
+                    RenderedLabel::Element(element.draw(
+                        renderer,
+                        defaults,
+                        element_layout,
+                        cursor_position,
+                        viewport,
+                    ))
+                }
+            })
+            .collect();
+
         self::Renderer::draw(
             renderer,
             DrawEnvironment {
@@ -355,12 +1164,14 @@ where
                 cursor_position,
                 style_sheet: &self.style,
                 viewport: Some(viewport),
-                focus: (),
+                focus: self.state.as_ref().map_or(false, |state| state.is_focused),
             },
             self.active_tab,
-            &self.tab_labels,
+            tab_labels,
             self.icon_font,
             self.text_font,
+            self.scroll_info(layout),
+            self.dragged_tab(layout),
         )
     }
 
@@ -370,21 +1181,78 @@ where
         std::any::TypeId::of::<Marker>().hash(state);
 
         self.active_tab.hash(state);
-        self.tab_labels.iter().for_each(|tab| tab.hash(state));
+        self.tab_labels.iter().for_each(|tab| match tab {
+            Tab::Label(tab_label) => tab_label.hash(state),
+            Tab::Element(element) => element.hash_layout(state),
+        });
         self.width.hash(state);
         self.height.hash(state);
         self.max_height.hash(state);
         self.icon_size.hash(state);
         self.text_size.hash(state);
         self.close_size.hash(state);
+        self.padding.hash(state);
+        self.icon_only_below.hash(state);
+        self.vertical.hash(state);
+        self.label_layout.hash(state);
+
+        if let Some(overflow) = &self.overflow {
+            overflow.min_tab_width.hash(state);
+            overflow.offset.to_bits().hash(state);
+            overflow.buttons.hash(state);
+        }
     }
 }
 
+/// The scroll state of a scrollable [`TabBar`](TabBar) passed to its
+/// renderer.
+#[derive(Clone, Copy, Debug)]
+pub struct ScrollInfo {
+    /// The bounds of the area in which the tabs are visible.
+    pub viewport: Rectangle,
+    /// The current scroll offset of the tabs.
+    pub offset: f32,
+    /// The maximum scroll offset of the tabs.
+    pub max_offset: f32,
+    /// Whether the scroll buttons are shown next to the visible area.
+    pub buttons: bool,
+}
+
+/// The label of a tab passed to the renderer of a [`TabBar`](TabBar).
+#[allow(missing_debug_implementations)]
+pub enum RenderedLabel<'b, Output> {
+    /// A [`TabLabel`](TabLabel) to be drawn by the renderer.
+    Label(&'b TabLabel),
+    /// The output of a custom label that has already been drawn.
+    Element(Output),
+}
+
+/// A tab dragged along a reorderable [`TabBar`](TabBar) passed to its
+/// renderer.
+#[derive(Clone, Copy, Debug)]
+pub struct DraggedTab {
+    /// The index of the dragged tab.
+    pub index: usize,
+    /// The distance the tab has been dragged by.
+    pub offset: Vector,
+    /// The index the tab will be moved to when it is dropped.
+    pub target: usize,
+    /// The line at which the tab will be inserted, given as bounds with
+    /// either no width or no height.
+    pub indicator: Rectangle,
+}
+
+/// Clamps the scroll offset of a scrollable [`TabBar`](TabBar) between zero
+/// and the given maximum offset.
+fn clamp_offset(offset: f32, max_offset: f32) -> f32 {
+    offset.min(max_offset).max(0.0)
+}
+
 /// The renderer of a [`TabBar`](TabBar).
 ///
 /// Your renderer will need to implement this trait before being
 /// able to use a [`TabBar`](TabBar) in your user interface.
-pub trait Renderer: iced_native::Renderer {
+pub trait Renderer: iced_native::Renderer + accessibility::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
@@ -403,14 +1271,25 @@ pub trait Renderer: iced_native::Renderer {
     /// The default spacing of a [`TabBar`](TabBar).
     const DEFAULT_SPACING: u16;
 
+    /// The width of the scroll buttons of a scrollable [`TabBar`](TabBar).
+    const SCROLL_BUTTON_WIDTH: u16;
+
     /// Draws a [`TabBar`](TabBar).
+    ///
+    /// The [`ScrollInfo`](ScrollInfo) is only given if the
+    /// [`TabBar`](TabBar) is scrollable. The tabs should then be clipped to
+    /// its visible area. The [`DraggedTab`](DraggedTab) is only given while
+    /// a tab of a reorderable [`TabBar`](TabBar) is dragged. The focus of the
+    /// environment is whether the [`TabBar`](TabBar) has the keyboard focus.
     fn draw(
         &mut self,
-        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, bool>,
         active_tab: usize,
-        tab_labels: &[TabLabel],
+        tab_labels: Vec<RenderedLabel<'_, Self::Output>>,
         icon_font: Option<Font>,
         text_font: Option<Font>,
+        scroll: Option<ScrollInfo>,
+        dragged: Option<DraggedTab>,
     ) -> Self::Output;
 }
 
@@ -428,23 +1307,27 @@ impl Renderer for iced_native::renderer::Null {
 
     const DEFAULT_SPACING: u16 = 0;
 
+    const SCROLL_BUTTON_WIDTH: u16 = 0;
+
     fn draw(
         &mut self,
-        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, bool>,
         _active_tab: usize,
-        _tab_labels: &[TabLabel],
+        _tab_labels: Vec<RenderedLabel<'_, Self::Output>>,
         _icon_font: Option<Font>,
         _text_font: Option<Font>,
+        _scroll: Option<ScrollInfo>,
+        _dragged: Option<DraggedTab>,
     ) -> Self::Output {
     }
 }
 
-impl<'a, Message, Renderer> From<TabBar<Message, Renderer>> for Element<'a, Message, Renderer>
+impl<'a, Message, Renderer> From<TabBar<'a, Message, Renderer>> for Element<'a, Message, Renderer>
 where
     Renderer: 'a + self::Renderer + column::Renderer + text::Renderer + row::Renderer,
     Message: 'a,
 {
-    fn from(tab_bar: TabBar<Message, Renderer>) -> Self {
+    fn from(tab_bar: TabBar<'a, Message, Renderer>) -> Self {
         Element::new(tab_bar)
     }
 }
@@ -15,5 +15,22 @@ pub enum TabLabel {
 
     /// A [`TabLabel`](TabLabel) showing an icon and a text on the tab.
     IconText(char, String),
-    // TODO: Support any element as a label.
+}
+
+/// The arrangement of the icon and the text of a
+/// [`TabLabel::IconText`](TabLabel::IconText).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TabLabelLayout {
+    /// The icon is stacked above the text.
+    Stacked,
+
+    /// The icon is placed next to the text, e.g. for the tabs of a vertical
+    /// [`TabBar`](super::TabBar).
+    Inline,
+}
+
+impl Default for TabLabelLayout {
+    fn default() -> Self {
+        Self::Stacked
+    }
 }
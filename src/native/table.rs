@@ -0,0 +1,496 @@
+//! Use a table to display rows of elements in columns below a header row.
+//!
+//! *This API requires the following crate features to be activated: `table`*
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout, mouse, touch, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::{renderer::DrawEnvironment, table::column_widths};
+
+/// A table displaying rows of elements in columns below a fixed header row.
+///
+/// The columns have a fixed width or share the available width by their fill
+/// factor. Clicking on the header of a column produces a sort message and the
+/// sorted column is marked with an arrow. The body scrolls below the header if
+/// the rows exceed the height of the [`Table`](Table).
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::table::{self, SortOrder};
+/// # use iced_native::{renderer::Null, Length, Text};
+/// #
+/// # pub type Table<'a, Message> = iced_aw::native::Table<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Sort(usize),
+/// }
+///
+/// let mut state = table::State::new();
+///
+/// let table = Table::new(&mut state)
+///     .column("Language", Length::Fill)
+///     .column("Year", Length::Units(60))
+///     .push_row(vec![Text::new("Rust"), Text::new("2010")])
+///     .push_row(vec![Text::new("OCaml"), Text::new("1996")])
+///     .sort(1, SortOrder::Ascending)
+///     .on_sort(Message::Sort);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer: self::Renderer> {
+    /// The state of the [`Table`](Table).
+    state: &'a mut State,
+    /// The headers of the columns.
+    headers: Vec<String>,
+    /// The widths of the columns.
+    column_widths: Vec<Length>,
+    /// The rows of the [`Table`](Table).
+    rows: Vec<Vec<Element<'a, Message, Renderer>>>,
+    /// The width of the [`Table`](Table).
+    width: Length,
+    /// The height of the [`Table`](Table).
+    height: Length,
+    /// The padding of the cells.
+    padding: u16,
+    /// The optional text size of the headers.
+    text_size: Option<u16>,
+    /// The sorted column and its order.
+    sort: Option<(usize, SortOrder)>,
+    /// The optional function that produces a message when the header of a
+    /// column is clicked.
+    on_sort: Option<Box<dyn Fn(usize) -> Message>>,
+    /// The style of the [`Table`](Table).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Table`](Table) without columns.
+    ///
+    /// It expects a mutable reference to the [`Table`](Table)'s
+    /// [`State`](State).
+    pub fn new(state: &'a mut State) -> Self {
+        Table {
+            state,
+            headers: Vec::new(),
+            column_widths: Vec::new(),
+            rows: Vec::new(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: <Renderer as self::Renderer>::DEFAULT_PADDING,
+            text_size: None,
+            sort: None,
+            on_sort: None,
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Adds a column with the given header and width to the [`Table`](Table).
+    ///
+    /// A column with a [`Length::Units`](Length::Units) keeps its width,
+    /// the other columns share the remaining width by their fill factor.
+    pub fn column(mut self, header: impl Into<String>, width: Length) -> Self {
+        self.headers.push(header.into());
+        self.column_widths.push(width);
+        self
+    }
+
+    /// Adds a row with the given cells to the [`Table`](Table).
+    ///
+    /// Cells exceeding the number of columns are ignored.
+    pub fn push_row<I, E>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the width of the [`Table`](Table).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Table`](Table).
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding of the cells.
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the headers.
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Marks the column at the given index as sorted in the given
+    /// [`SortOrder`](SortOrder).
+    pub fn sort(mut self, column: usize, order: SortOrder) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Sets the function that will be called with the index of a column when
+    /// its header is clicked.
+    pub fn on_sort<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_sort = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`Table`](Table).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Table<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let limits = limits.width(self.width).height(self.height);
+        let widths = column_widths(&self.column_widths, limits.max().width);
+
+        let header_height =
+            f32::from(self.text_size.unwrap_or_else(|| renderer.default_size())) + 2.0 * padding;
+
+        let mut x = 0.0;
+        let header_cells = widths
+            .iter()
+            .map(|width| {
+                let mut cell = layout::Node::new(Size::new(*width, header_height));
+                cell.move_to(Point::new(x, 0.0));
+                x += width;
+                cell
+            })
+            .collect();
+        let content_width = x;
+
+        let header =
+            layout::Node::with_children(Size::new(content_width, header_height), header_cells);
+
+        let mut y = header_height;
+        let rows: Vec<layout::Node> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let mut x = 0.0;
+                let mut height = 0.0_f32;
+
+                let cells = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| {
+                        let cell_limits = layout::Limits::new(
+                            Size::ZERO,
+                            Size::new((width - 2.0 * padding).max(0.0), f32::INFINITY),
+                        )
+                        .width(cell.width())
+                        .height(cell.height());
+
+                        let mut node = cell.layout(renderer, &cell_limits);
+                        node.move_to(Point::new(x + padding, padding));
+
+                        x += width;
+                        height = height.max(node.size().height);
+
+                        node
+                    })
+                    .collect();
+
+                let mut node = layout::Node::with_children(
+                    Size::new(content_width, height + 2.0 * padding),
+                    cells,
+                );
+                node.move_to(Point::new(0.0, y));
+
+                y += height + 2.0 * padding;
+
+                node
+            })
+            .collect();
+
+        let size = limits.resolve(Size::new(content_width, y));
+
+        layout::Node::with_children(size, std::iter::once(header).chain(rows).collect())
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let header = children
+            .next()
+            .expect("Native: Layout should have a header layout for a Table");
+        let header_bounds = header.bounds();
+        let body_bounds = body_bounds(layout);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if body_bounds.contains(cursor_position) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * header_bounds.height,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                let content_height = layout
+                    .children()
+                    .last()
+                    .map_or(body_bounds.y, |row| row.bounds().y + row.bounds().height)
+                    - body_bounds.y;
+                let max_offset = (content_height - body_bounds.height).max(0.0);
+
+                self.state.offset = (self.state.offset - delta).max(0.0).min(max_offset);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if header_bounds.contains(cursor_position) =>
+            {
+                if let Some(on_sort) = &self.on_sort {
+                    if let Some(column) = header
+                        .children()
+                        .position(|cell| cell.bounds().contains(cursor_position))
+                    {
+                        #[cfg(feature = "instrumentation")]
+                        instrumentation::record(WidgetKind::Table, Action::Sort(column));
+                        messages.push(on_sort(column));
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        // The rows are scrolled, so the cursor is moved by the offset. Outside
+        // of the body, the cursor must not reach the hidden rows.
+        let cursor_position = if body_bounds.contains(cursor_position) {
+            Point::new(cursor_position.x, cursor_position.y + self.state.offset)
+        } else {
+            Point::new(-1.0, -1.0)
+        };
+
+        self.rows
+            .iter_mut()
+            .zip(children)
+            .flat_map(|(row, layout)| row.iter_mut().zip(layout.children()))
+            .map(|(cell, layout)| {
+                cell.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    messages,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: Some(viewport),
+                focus: (),
+            },
+            &self.headers,
+            &self.rows,
+            self.sort,
+            self.on_sort.is_some(),
+            self.state,
+            text_size,
+            self.padding,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.column_widths.hash(state);
+
+        for row in &self.rows {
+            for cell in row {
+                cell.hash_layout(state);
+            }
+        }
+    }
+}
+
+/// Returns the bounds of the body of a [`Table`](Table) below its header.
+pub(crate) fn body_bounds(layout: Layout<'_>) -> Rectangle {
+    let bounds = layout.bounds();
+    let header_height = layout
+        .children()
+        .next()
+        .map_or(0.0, |header| header.bounds().height);
+
+    Rectangle {
+        y: bounds.y + header_height,
+        height: (bounds.height - header_height).max(0.0),
+        ..bounds
+    }
+}
+
+/// The order of a sorted column of a [`Table`](Table).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// The column is sorted from the lowest to the highest value.
+    Ascending,
+    /// The column is sorted from the highest to the lowest value.
+    Descending,
+}
+
+impl SortOrder {
+    /// Returns the opposite [`SortOrder`](SortOrder).
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        Self::Ascending
+    }
+}
+
+/// The state of a [`Table`](Table).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct State {
+    /// The scroll offset of the body of the [`Table`](Table).
+    pub(crate) offset: f32,
+}
+
+impl State {
+    /// Creates a new [`State`](State).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scrolls the body of the [`Table`](Table) back to the first row.
+    pub fn scroll_to_top(&mut self) {
+        self.offset = 0.0;
+    }
+}
+
+/// The renderer of a [`Table`](Table).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Table`](Table) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default padding of the cells of a [`Table`](Table).
+    const DEFAULT_PADDING: u16;
+
+    /// The default text size of the headers of a [`Table`](Table).
+    fn default_size(&self) -> u16;
+
+    /// Draws a [`Table`](Table).
+    #[allow(clippy::too_many_arguments)]
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        headers: &[String],
+        rows: &[Vec<Element<'_, Message, Self>>],
+        sort: Option<(usize, SortOrder)>,
+        is_sortable: bool,
+        state: &State,
+        text_size: u16,
+        padding: u16,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 0;
+
+    fn default_size(&self) -> u16 {
+        0
+    }
+
+    fn draw<Message>(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _headers: &[String],
+        _rows: &[Vec<Element<'_, Message, Self>>],
+        _sort: Option<(usize, SortOrder)>,
+        _is_sortable: bool,
+        _state: &State,
+        _text_size: u16,
+        _padding: u16,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(table: Table<'a, Message, Renderer>) -> Self {
+        Element::new(table)
+    }
+}
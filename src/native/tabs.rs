@@ -14,7 +14,7 @@ use iced_native::{
 
 use crate::{
     core::renderer::DrawEnvironment,
-    native::{TabBar, TabLabel},
+    native::{tab_bar::TabLabelLayout, TabBar, TabLabel},
 };
 
 pub mod tab_bar_position;
@@ -48,11 +48,16 @@ pub use tab_bar_position::TabBarPosition;
 #[allow(missing_debug_implementations)]
 pub struct Tabs<'a, Message, Renderer: self::Renderer> {
     /// The [`TabBar`](crate::native::TabBar) of the [`Tabs`](Tabs).
-    tab_bar: TabBar<Message, Renderer>,
+    tab_bar: TabBar<'a, Message, Renderer>,
     /// The vector containing the content of the tabs.
     tabs: Vec<Element<'a, Message, Renderer>>,
     /// The position of the [`TabBar`](crate::native::TabBar).
     tab_bar_position: TabBarPosition,
+    /// The padding around the content of the tabs.
+    content_padding: u16,
+    /// The padding around the content of the individual tabs, overriding
+    /// `content_padding`.
+    tab_content_paddings: Vec<Option<u16>>,
     /// the width of the [`Tabs`](Tabs).
     width: Length,
     /// The height of the [`Tabs`](Tabs).
@@ -105,8 +110,10 @@ where
 
         Tabs {
             tab_bar: TabBar::width_tab_labels(active_tab, tab_labels, on_select),
+            tab_content_paddings: vec![None; elements.len()],
             tabs: elements,
             tab_bar_position: TabBarPosition::Top,
+            content_padding: 0,
             width: Length::Fill,
             height: Length::Fill,
         }
@@ -116,6 +123,10 @@ where
     /// on the [`TabBar`](TabBar) is pressed.
     ///
     /// Setting this enables the drawing of a close icon on the tabs.
+    ///
+    /// The tab is not closed by the [`Tabs`](Tabs) itself, so the message
+    /// can be treated as a close request, e.g. to ask for confirmation before
+    /// removing the tab.
     pub fn on_close<F>(mut self, on_close: F) -> Self
     where
         F: 'static + Fn(usize) -> Message,
@@ -192,6 +203,65 @@ where
         self
     }
 
+    /// Turns the [`TabBar`](super::tab_bar::TabBar) of the [`Tabs`](Tabs)
+    /// into its compact variant.
+    pub fn compact_tab_bar(mut self) -> Self {
+        self.tab_bar = self.tab_bar.compact();
+        self
+    }
+
+    /// Sets the available width per tab below which the
+    /// [`TabLabel`](super::tab_bar::TabLabel)s of the
+    /// [`TabBar`](super::tab_bar::TabBar) will only show their icon.
+    pub fn tab_bar_icon_only_below(mut self, width: u32) -> Self {
+        self.tab_bar = self.tab_bar.icon_only_below(width);
+        self
+    }
+
+    /// Turns on the overflow mode of the [`TabBar`](super::tab_bar::TabBar)
+    /// of the [`Tabs`](Tabs).
+    ///
+    /// See [`TabBar::scrollable`](super::tab_bar::TabBar::scrollable).
+    pub fn scrollable_tab_bar<F>(mut self, min_tab_width: u16, offset: f32, on_scroll: F) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        self.tab_bar = self.tab_bar.scrollable(min_tab_width, offset, on_scroll);
+        self
+    }
+
+    /// Shows buttons to scroll the tabs of a scrollable
+    /// [`TabBar`](super::tab_bar::TabBar) of the [`Tabs`](Tabs).
+    pub fn tab_bar_scroll_buttons(mut self) -> Self {
+        self.tab_bar = self.tab_bar.scroll_buttons();
+        self
+    }
+
+    /// Makes the tabs of the [`Tabs`](Tabs) reorderable by dragging their
+    /// [`TabLabel`](super::tab_bar::TabLabel)s.
+    ///
+    /// See [`TabBar::reorderable`](super::tab_bar::TabBar::reorderable).
+    pub fn reorderable_tabs<F>(
+        mut self,
+        state: &'a mut crate::native::tab_bar::State,
+        on_reorder: F,
+    ) -> Self
+    where
+        F: 'static + Fn(usize, usize) -> Message,
+    {
+        self.tab_bar = self.tab_bar.reorderable(state, on_reorder);
+        self
+    }
+
+    /// Makes the [`TabBar`](super::tab_bar::TabBar) of the [`Tabs`](Tabs)
+    /// focusable, so the tabs can be selected with the keyboard.
+    ///
+    /// See [`TabBar::focusable`](super::tab_bar::TabBar::focusable).
+    pub fn focusable_tab_bar(mut self, state: &'a mut crate::native::tab_bar::State) -> Self {
+        self.tab_bar = self.tab_bar.focusable(state);
+        self
+    }
+
     /// Sets the font of the icons of the
     /// [`TabLabel`](super::tab_bar::TabLabel)s of the
     /// [`TabBar`](super::tab_bar::TabBar).
@@ -219,11 +289,44 @@ where
 
     /// Sets the [`TabBarPosition`](TabBarPosition) of the
     /// [`TabBar`](super::tab_bar::TabBar).
+    ///
+    /// On the [`Left`](TabBarPosition::Left) and the
+    /// [`Right`](TabBarPosition::Right) the tabs are stacked vertically and
+    /// the [`TabBar`](super::tab_bar::TabBar) is as wide as its widest tab,
+    /// unless its width is set afterwards.
     pub fn tab_bar_position(mut self, position: TabBarPosition) -> Self {
+        self.tab_bar = self.tab_bar.vertical(matches!(
+            position,
+            TabBarPosition::Left | TabBarPosition::Right
+        ));
         self.tab_bar_position = position;
         self
     }
 
+    /// Sets the [`TabLabelLayout`](TabLabelLayout) arranging the icons and
+    /// texts of the [`TabLabel`](super::tab_bar::TabLabel)s of the
+    /// [`TabBar`](super::tab_bar::TabBar).
+    pub fn tab_label_layout(mut self, label_layout: TabLabelLayout) -> Self {
+        self.tab_bar = self.tab_bar.label_layout(label_layout);
+        self
+    }
+
+    /// Sets the padding around the content of the tabs of the [`Tabs`](Tabs).
+    pub fn content_padding(mut self, padding: u16) -> Self {
+        self.content_padding = padding;
+        self
+    }
+
+    /// Sets the padding around the content of the tab at the given index,
+    /// overriding the padding set by
+    /// [`content_padding`](Tabs::content_padding).
+    pub fn tab_content_padding(mut self, index: usize, padding: u16) -> Self {
+        if let Some(tab_content_padding) = self.tab_content_paddings.get_mut(index) {
+            *tab_content_padding = Some(padding);
+        }
+        self
+    }
+
     /// Pushes a [`TabLabel`](super::tab_bar::TabLabel) along with the tabs
     /// content to the [`Tabs`](Tabs).
     pub fn push<E>(mut self, tab_label: TabLabel, element: E) -> Self
@@ -232,8 +335,128 @@ where
     {
         self.tab_bar = self.tab_bar.push(tab_label);
         self.tabs.push(element.into());
+        self.tab_content_paddings.push(None);
         self
     }
+
+    /// Pushes a custom [`Element`](Element) as the label of a tab along with
+    /// the tabs content to the [`Tabs`](Tabs).
+    ///
+    /// See [`TabBar::push_element`](super::tab_bar::TabBar::push_element).
+    pub fn push_element<L, E>(mut self, label: L, element: E) -> Self
+    where
+        L: Into<Element<'a, Message, Renderer>>,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.tab_bar = self.tab_bar.push_element(label);
+        self.tabs.push(element.into());
+        self.tab_content_paddings.push(None);
+        self
+    }
+
+    /// Pushes a [`TabLabel`](super::tab_bar::TabLabel) along with a function
+    /// building the tabs content to the [`Tabs`](Tabs).
+    ///
+    /// The function is only called if the tab is the active one, so the
+    /// content of the other tabs doesn't need to be built.
+    pub fn push_lazy<F, E>(mut self, tab_label: TabLabel, view: F) -> Self
+    where
+        F: FnOnce() -> E,
+        E: Into<Element<'a, Message, Renderer>>,
+        Message: 'a,
+        Renderer: 'a + row::Renderer,
+    {
+        let element = if self.tabs.len() == self.tab_bar.get_active_tab() {
+            view().into()
+        } else {
+            // Only the content of the active tab is laid out and drawn, so an
+            // empty placeholder is enough for the other tabs.
+            Row::new().into()
+        };
+
+        self.tab_bar = self.tab_bar.push(tab_label);
+        self.tabs.push(element);
+        self.tab_content_paddings.push(None);
+        self
+    }
+
+    /// Lays out the content of the active tab within the given limits.
+    fn tab_content_layout(
+        &self,
+        renderer: &Renderer,
+        tab_content_limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node
+    where
+        Renderer: row::Renderer,
+    {
+        let padding = f32::from(self.active_content_padding());
+        let element_limits = tab_content_limits.pad(padding);
+
+        let mut tab_content_node = self.tabs.get(self.tab_bar.get_active_tab()).map_or_else(
+            || {
+                Row::<Message, Renderer>::new()
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .layout(renderer, &element_limits)
+            },
+            |element| element.layout(renderer, &element_limits),
+        );
+
+        tab_content_node.move_to(Point::new(padding, padding));
+        iced_native::layout::Node::with_children(
+            tab_content_limits.resolve(tab_content_node.size().pad(padding)),
+            vec![tab_content_node],
+        )
+    }
+
+    /// Lays out the [`Tabs`](Tabs) with the vertical
+    /// [`TabBar`](super::tab_bar::TabBar) on the left or on the right of the
+    /// content.
+    fn vertical_layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node
+    where
+        Renderer: column::Renderer + text::Renderer + row::Renderer,
+    {
+        let tab_bar_limits = limits.clone().height(self.height);
+        let mut tab_bar_node = self.tab_bar.layout(renderer, &tab_bar_limits);
+
+        let tab_content_limits = limits
+            .clone()
+            .shrink(Size::new(tab_bar_node.size().width, 0.0))
+            .width(self.width)
+            .height(self.height);
+        let mut tab_content_node = self.tab_content_layout(renderer, &tab_content_limits);
+
+        let size = Size::new(
+            tab_bar_node.size().width + tab_content_node.size().width,
+            tab_bar_node
+                .size()
+                .height
+                .max(tab_content_node.size().height),
+        );
+
+        if let TabBarPosition::Right = self.tab_bar_position {
+            tab_bar_node.move_to(Point::new(tab_content_node.size().width, 0.0));
+
+            iced_native::layout::Node::with_children(size, vec![tab_content_node, tab_bar_node])
+        } else {
+            tab_content_node.move_to(Point::new(tab_bar_node.size().width, 0.0));
+
+            iced_native::layout::Node::with_children(size, vec![tab_bar_node, tab_content_node])
+        }
+    }
+
+    /// Gets the padding around the content of the active tab.
+    fn active_content_padding(&self) -> u16 {
+        self.tab_content_paddings
+            .get(self.tab_bar.get_active_tab())
+            .copied()
+            .flatten()
+            .unwrap_or(self.content_padding)
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Tabs<'a, Message, Renderer>
@@ -253,6 +476,13 @@ where
         renderer: &Renderer,
         limits: &iced_native::layout::Limits,
     ) -> iced_native::layout::Node {
+        if matches!(
+            self.tab_bar_position,
+            TabBarPosition::Left | TabBarPosition::Right
+        ) {
+            return self.vertical_layout(renderer, limits);
+        }
+
         let tab_bar_limits = limits
             .clone()
             .width(self.width)
@@ -265,16 +495,7 @@ where
             .shrink(Size::new(0.0, tab_bar_node.size().height))
             .width(self.width)
             .height(self.height);
-
-        let mut tab_content_node = self.tabs.get(self.tab_bar.get_active_tab()).map_or_else(
-            || {
-                Row::<Message, Renderer>::new()
-                    .width(Length::Fill)
-                    .height(Length::Fill)
-                    .layout(renderer, &tab_content_limits)
-            },
-            |element| element.layout(renderer, &tab_content_limits),
-        );
+        let mut tab_content_node = self.tab_content_layout(renderer, &tab_content_limits);
 
         tab_bar_node.move_to(Point::new(
             tab_bar_node.bounds().x,
@@ -300,8 +521,10 @@ where
                 tab_bar_node.size().height + tab_content_node.size().height,
             ),
             match self.tab_bar_position {
-                TabBarPosition::Top => vec![tab_bar_node, tab_content_node],
-                TabBarPosition::Bottom => vec![tab_content_node, tab_bar_node],
+                TabBarPosition::Top | TabBarPosition::Left => vec![tab_bar_node, tab_content_node],
+                TabBarPosition::Bottom | TabBarPosition::Right => {
+                    vec![tab_content_node, tab_bar_node]
+                }
             },
         )
     }
@@ -317,7 +540,7 @@ where
     ) -> event::Status {
         let mut children = layout.children();
         let (tab_bar_layout, tab_content_layout) = match self.tab_bar_position {
-            TabBarPosition::Top => {
+            TabBarPosition::Top | TabBarPosition::Left => {
                 let tab_bar_layout = children
                     .next()
                     .expect("Native: Layout should have a TabBar layout at top position");
@@ -326,7 +549,7 @@ where
                     .expect("Native: Layout should have a tab content layout at top position");
                 (tab_bar_layout, tab_content_layout)
             }
-            TabBarPosition::Bottom => {
+            TabBarPosition::Bottom | TabBarPosition::Right => {
                 let tab_content_layout = children
                     .next()
                     .expect("Native: Layout should have a tab content layout at bottom position");
@@ -346,12 +569,17 @@ where
             messages,
         );
 
+        let element_layout = tab_content_layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a tab content element layout");
+
         let status_element = self.tabs.get_mut(self.tab_bar.get_active_tab()).map_or(
             event::Status::Ignored,
             |element| {
                 element.on_event(
                     event,
-                    tab_content_layout,
+                    element_layout,
                     cursor_position,
                     renderer,
                     clipboard,
@@ -373,10 +601,10 @@ where
     ) -> Renderer::Output {
         let mut children = layout.children();
         let tab_bar_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children
+            TabBarPosition::Top | TabBarPosition::Left => children
                 .next()
                 .expect("Native: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
+            TabBarPosition::Bottom | TabBarPosition::Right => children
                 .last()
                 .expect("Native: There should be a TabBar at the bottom position"),
         };
@@ -395,7 +623,7 @@ where
                 defaults,
                 layout,
                 cursor_position,
-                style_sheet: &(),
+                style_sheet: self.tab_bar.get_style(),
                 viewport: Some(viewport),
                 focus: (),
             },
@@ -414,6 +642,8 @@ where
         self.tab_bar.hash_layout(state);
         self.tabs.iter().for_each(|tab| tab.hash_layout(state));
         self.tab_bar_position.hash(state);
+        self.content_padding.hash(state);
+        self.tab_content_paddings.hash(state);
         self.width.hash(state);
         self.height.hash(state);
     }
@@ -427,7 +657,7 @@ pub trait Renderer: iced_native::Renderer + crate::native::tab_bar::Renderer {
     /// Draws a [`Tabs`](Tabs) widget.
     fn draw<Message>(
         &mut self,
-        env: DrawEnvironment<'_, Self::Defaults, (), ()>,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
         active_tab: usize,
         tab_bar: Self::Output,
         tabs: &[Element<'_, Message, Self>],
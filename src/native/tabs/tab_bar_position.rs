@@ -15,4 +15,14 @@ pub enum TabBarPosition {
     /// A [`TabBarPosition`] for placing the
     /// [`TabBar`](crate::native::tab_bar::TabBar) on bottom of its content.
     Bottom,
+
+    /// A [`TabBarPosition`] for placing the
+    /// [`TabBar`](crate::native::tab_bar::TabBar) vertically on the left of
+    /// its content.
+    Left,
+
+    /// A [`TabBarPosition`] for placing the
+    /// [`TabBar`](crate::native::tab_bar::TabBar) vertically on the right of
+    /// its content.
+    Right,
 }
@@ -1,7 +1,10 @@
 //! Use a time picker as an input element for picking times.
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
-use std::hash::Hash;
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
 
 use chrono::Local;
 use iced_native::{
@@ -12,10 +15,19 @@ use iced_native::{
 pub use super::overlay::time_picker::Renderer;
 use super::{
     icon_text,
-    overlay::time_picker::{self, Focus, TimePickerOverlay},
+    overlay::{
+        animated::{self, AnimatedOverlay},
+        time_picker::{self, Focus, OnSubmit, TimePickerOverlay},
+    },
+    stateful_overlay::{OverlayState, StatefulOverlayWidget},
 };
 
+use crate::core::accessibility::Accessibility;
 pub use crate::core::time::{Period, Time};
+
+pub mod clock;
+pub use clock::Clock;
+
 /// An input element for picking times.
 ///
 /// # Example
@@ -56,7 +68,13 @@ where
     /// The message that is send if the cancel button of the [`TimePickerOverlay`](TimePickerOverlay) is pressed.
     on_cancel: Message,
     /// The function that produces a message when the submit button of the [`TimePickerOverlay`](TimePickerOverlay) is pressed.
-    on_submit: Box<dyn Fn(Time) -> Message>,
+    on_submit: Submit<Message>,
+    /// The optional duration of the open and close animation of the
+    /// [`TimePickerOverlay`](TimePickerOverlay).
+    animation: Option<Duration>,
+    /// The accessibility information of the
+    /// [`TimePickerOverlay`](TimePickerOverlay).
+    accessibility: Accessibility,
     /// The style of the [`TimePickerOverlay`](TimePickerOverlay).
     style: <Renderer as time_picker::Renderer>::Style,
 }
@@ -85,9 +103,47 @@ where
             state,
             underlay: underlay.into(),
             on_cancel,
-            on_submit: Box::new(on_submit),
+            on_submit: Submit::Time(Box::new(on_submit)),
             //use_24h: false,
             //show_seconds: false,
+            animation: None,
+            accessibility: Accessibility::default(),
+            style: <Renderer as time_picker::Renderer>::Style::default(),
+        }
+    }
+
+    /// Creates a new [`TimePicker`](TimePicker) picking a duration, wrapping
+    /// around the given underlay.
+    ///
+    /// The user picks the hours, minutes and seconds of the duration on the
+    /// digital clock, counting the hours beyond 24h without AM/PM. The
+    /// duration starts at zero.
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`TimePicker`](TimePicker)'s [`State`](State).
+    ///     * the underlay [`Element`](iced_native::Element) on which this [`TimePicker`](TimePicker)
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`TimePicker`](TimePicker)
+    ///         is pressed.
+    ///     * a function that will be called when the submit button of the [`TimePicker`](TimePicker)
+    ///         is pressed, which takes the picked [`Duration`](std::time::Duration).
+    pub fn duration<U, F>(
+        state: &'a mut State,
+        underlay: U,
+        on_cancel: Message,
+        on_submit: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+        F: 'static + Fn(Duration) -> Message,
+    {
+        Self {
+            state,
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: Submit::Duration(Box::new(on_submit)),
+            animation: None,
+            accessibility: Accessibility::default(),
             style: <Renderer as time_picker::Renderer>::Style::default(),
         }
     }
@@ -104,6 +160,39 @@ where
         self
     }
 
+    /// Hides the analog clock and only shows the digital clock in a compact
+    /// overlay.
+    pub fn digital_only(mut self) -> Self {
+        self.state.overlay_state.digital_only = true;
+        self
+    }
+
+    /// Animates the opening and closing of the [`TimePickerOverlay`](TimePickerOverlay)
+    /// over the given duration.
+    ///
+    /// The overlay slides into place when it is shown and out of place when
+    /// it is hidden. The animation only advances while the application
+    /// redraws, e.g. by subscribing to a timer. Without an animation the
+    /// overlay is shown and hidden instantly.
+    pub fn animation(mut self, duration: Duration) -> Self {
+        self.animation = Some(duration);
+        self
+    }
+
+    /// Sets the label announced for the open
+    /// [`TimePickerOverlay`](TimePickerOverlay).
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.accessibility.label = Some(label.into());
+        self
+    }
+
+    /// Sets the description announced for the open
+    /// [`TimePickerOverlay`](TimePickerOverlay).
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.accessibility.description = Some(description.into());
+        self
+    }
+
     /// Sets the style of the [`TimePicker`](TimePicker).
     pub fn style<S>(mut self, style: S) -> Self
     where
@@ -114,11 +203,34 @@ where
     }
 }
 
+/// The function that produces a message when the submit button of the
+/// [`TimePickerOverlay`](TimePickerOverlay) is pressed.
+enum Submit<Message> {
+    /// Submits the picked time.
+    Time(Box<dyn Fn(Time) -> Message>),
+
+    /// Submits the picked duration.
+    Duration(Box<dyn Fn(Duration) -> Message>),
+}
+
+impl<Message> Submit<Message> {
+    /// Borrows the function as the [`OnSubmit`](OnSubmit) of a
+    /// [`TimePickerOverlay`](TimePickerOverlay).
+    fn as_on_submit(&self) -> OnSubmit<'_, Message> {
+        match self {
+            Self::Time(on_submit) => OnSubmit::Time(on_submit.as_ref()),
+            Self::Duration(on_submit) => OnSubmit::Duration(on_submit.as_ref()),
+        }
+    }
+}
+
 /// The state of the [`TimePicker`](TimePicker) / [`TimePickerOverlay`](TimePickerOverlay).
 #[derive(Debug)]
 pub struct State {
     /// The visibility of the overlay.
     pub(crate) show: bool,
+    /// The point in time the overlay was last shown or hidden.
+    pub(crate) changed_at: Option<Instant>,
     /// The state of the overlay.
     pub(crate) overlay_state: time_picker::State,
     /// The state of the cancel button.
@@ -133,6 +245,7 @@ impl State {
     pub fn now() -> Self {
         Self {
             show: false,
+            changed_at: None,
             cancel_button: button::State::new(),
             submit_button: button::State::new(),
             overlay_state: time_picker::State::default(),
@@ -142,15 +255,37 @@ impl State {
     /// Sets the visibility of the [`TimePickerOverlay`](TimePickerOverlay).
     pub fn show(&mut self, b: bool) {
         self.overlay_state.focus = if b { Focus::Overlay } else { Focus::None };
+        if b != self.show {
+            self.changed_at = Some(Instant::now());
+        }
         self.show = b;
     }
 
     /// Resets the time of the state to the current time.
+    ///
+    /// The duration of a [`TimePicker`](TimePicker) picking a duration is
+    /// reset to zero.
     pub fn reset(&mut self) {
         self.overlay_state.clock_cache.clear();
         self.overlay_state.time = Local::now().naive_local().time();
         self.overlay_state.use_24h = false;
         self.overlay_state.show_seconds = false;
+        self.overlay_state.digital_only = false;
+        self.overlay_state.duration_hours = None;
+    }
+}
+
+impl OverlayState for State {
+    fn is_shown(&self) -> bool {
+        self.show
+    }
+
+    fn show(&mut self, b: bool) {
+        Self::show(self, b);
+    }
+
+    fn changed_at(&self) -> Option<Instant> {
+        self.changed_at
     }
 }
 
@@ -158,6 +293,7 @@ impl<'a, Message, Renderer> Widget<Message, Renderer> for TimePicker<'a, Message
 where
     Message: Clone,
     Renderer: time_picker::Renderer
+        + animated::Renderer
         + button::Renderer
         + column::Renderer
         + container::Renderer
@@ -221,25 +357,58 @@ where
     }
 
     fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
-        if !self.state.show {
-            return self.underlay.overlay(layout);
-        }
+        self.stateful_overlay(layout)
+    }
+}
 
+impl<'a, Message, Renderer> StatefulOverlayWidget<'a, Message, Renderer>
+    for TimePicker<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: time_picker::Renderer
+        + animated::Renderer
+        + button::Renderer
+        + column::Renderer
+        + container::Renderer
+        + icon_text::Renderer
+        + row::Renderer
+        + text::Renderer,
+{
+    type State = State;
+
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    fn underlay_mut(&mut self) -> &mut Element<'a, Message, Renderer> {
+        &mut self.underlay
+    }
+
+    fn animation(&self) -> Option<Duration> {
+        self.animation
+    }
+
+    fn build_overlay(&mut self, layout: Layout<'_>) -> overlay::Element<'_, Message, Renderer> {
         let bounds = layout.bounds();
         let position = Point::new(bounds.center_x(), bounds.center_y());
 
-        Some(
+        let visibility = self.visibility();
+
+        AnimatedOverlay::new(
             TimePickerOverlay::new(
                 &mut self.state,
                 self.on_cancel.clone(),
-                &self.on_submit,
+                self.on_submit.as_on_submit(),
                 //self.use_24h,
                 //self.show_seconds,
                 position,
                 &self.style,
             )
+            .accessibility(&self.accessibility)
             .overlay(),
+            visibility,
         )
+        .overlay()
     }
 }
 
@@ -249,6 +418,7 @@ where
     Message: 'a + Clone,
     Renderer: 'a
         + time_picker::Renderer
+        + animated::Renderer
         + button::Renderer
         + column::Renderer
         + container::Renderer
@@ -0,0 +1,259 @@
+//! A clock face for picking times, as used by the
+//! [`TimePicker`](super::TimePicker), shown inline instead of as an overlay.
+//!
+//! *This API requires the following crate features to be activated: `time_picker`*
+use std::hash::Hash;
+
+use chrono::{Local, NaiveTime};
+use iced_native::{
+    event, layout, Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
+};
+
+use crate::core::{renderer::DrawEnvironment, time::Time};
+use crate::native::overlay::time_picker;
+
+/// The state of a [`Clock`](Clock).
+#[derive(Debug)]
+pub struct State {
+    /// The state of the clock.
+    pub(crate) overlay_state: time_picker::State,
+}
+
+impl State {
+    /// Creates a new [`State`](State) with the current time.
+    #[must_use]
+    pub fn now() -> Self {
+        Self {
+            overlay_state: time_picker::State::default(),
+        }
+    }
+
+    /// Gets the selected time of the [`Clock`](Clock).
+    #[must_use]
+    pub fn time(&self) -> Time {
+        self.overlay_state.picked_time()
+    }
+
+    /// Sets the selected time of the [`Clock`](Clock).
+    pub fn set_time(&mut self, time: Time) {
+        self.overlay_state.time = time.into();
+        self.overlay_state.clock_cache.clear();
+    }
+
+    /// Resets the selected time of the [`Clock`](Clock) to the current time.
+    pub fn reset(&mut self) {
+        self.overlay_state.time = Local::now().naive_local().time();
+        self.overlay_state.clock_cache.clear();
+    }
+}
+
+/// A clock face for picking times, shown inline in the layout instead of as
+/// an overlay of a [`TimePicker`](super::TimePicker).
+///
+/// The hands of the clock are dragged to pick the hour, minute and second
+/// and the center toggles between AM and PM. A message is produced directly
+/// whenever the selected time changes.
+///
+/// # Example
+/// ```
+/// # use iced_aw::{time_picker, native::time_picker::clock};
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Clock<'a, Message> = iced_aw::native::time_picker::Clock<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Select(time_picker::Time),
+/// }
+///
+/// let mut state = clock::State::now();
+///
+/// let clock = Clock::new(
+///     &mut state,
+///     Message::Select,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Clock<'a, Message, Renderer: self::Renderer> {
+    /// The state of the [`Clock`](Clock).
+    state: &'a mut State,
+    /// The function that produces a message when a time is selected.
+    on_select: Box<dyn Fn(Time) -> Message>,
+    /// The width of the [`Clock`](Clock).
+    width: Length,
+    /// The height of the [`Clock`](Clock).
+    height: Length,
+    /// The style of the [`Clock`](Clock).
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer> Clock<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Clock`](Clock).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`Clock`](Clock)'s [`State`](State).
+    ///     * a function that will be called with the selected
+    ///         [`Time`](crate::time_picker::Time) when the selected time changes.
+    pub fn new<F>(state: &'a mut State, on_select: F) -> Self
+    where
+        F: 'static + Fn(Time) -> Message,
+    {
+        Self {
+            state,
+            on_select: Box::new(on_select),
+            width: Length::Units(Renderer::DEFAULT_SIZE),
+            height: Length::Units(Renderer::DEFAULT_SIZE),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Use 24 hour format instead of AM/PM.
+    pub fn use_24h(self) -> Self {
+        self.state.overlay_state.use_24h = true;
+        self
+    }
+
+    /// Enables the clock to also pick seconds.
+    pub fn show_seconds(self) -> Self {
+        self.state.overlay_state.show_seconds = true;
+        self
+    }
+
+    /// Sets the width of the [`Clock`](Clock).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Clock`](Clock).
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`Clock`](Clock).
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Clock<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let size = limits
+            .width(self.width)
+            .height(self.height)
+            .resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let state = &mut self.state.overlay_state;
+        let time: NaiveTime = state.time;
+
+        let status = time_picker::clock_on_event(state, &event, layout, cursor_position);
+
+        if state.time != time {
+            messages.push((self.on_select)(state.picked_time()));
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: Some(viewport),
+                focus: (),
+            },
+            &self.state.overlay_state,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`Clock`](Clock).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Clock`](Clock) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default width and height of a [`Clock`](Clock).
+    const DEFAULT_SIZE: u16;
+
+    /// Draws a [`Clock`](Clock).
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        state: &time_picker::State,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 0;
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _state: &time_picker::State,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<Clock<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(clock: Clock<'a, Message, Renderer>) -> Self {
+        Element::new(clock)
+    }
+}
@@ -0,0 +1,409 @@
+//! Use a toast to display short notifications stacked in a corner of the
+//! window.
+//!
+//! *This API requires the following crate features to be activated: `toast`*
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+use iced_native::{
+    event, overlay, Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle, Widget,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+pub use crate::native::floating_element::{Anchor, Offset};
+
+pub use super::overlay::toast::Renderer;
+use super::overlay::toast::ToastOverlay;
+
+/// A wrapper around some content displaying stacked notification cards in a
+/// corner of the window.
+///
+/// Each notification is dismissed by clicking on its close button or once its
+/// timeout expired.
+///
+/// Only one overlay can be shown at a time. While notifications are shown,
+/// the overlays of the underlying content, e.g. an opened
+/// [`Modal`](crate::native::Modal) or picker, are hidden.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::toast::{self, Notification};
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type Toast<'a, Message> = iced_aw::native::Toast<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Closed(usize),
+///     TimedOut(usize),
+/// }
+///
+/// let mut state = toast::State::new();
+/// let _id = state.push(Notification::new("Saved", "The file was saved."));
+///
+/// let toast = Toast::new(&mut state, Text::new("Content"))
+///     .on_close(Message::Closed)
+///     .on_timeout(Message::TimedOut);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Toast<'a, Message, Renderer: self::Renderer> {
+    /// The state of the [`Toast`](Toast).
+    state: &'a mut State,
+    /// The underlying element.
+    underlay: Element<'a, Message, Renderer>,
+    /// The corner of the window the notifications are stacked in.
+    anchor: Anchor,
+    /// The offset of the notifications from the corner of the window.
+    offset: Offset,
+    /// The width of the notification cards.
+    width: f32,
+    /// The spacing between the notification cards.
+    spacing: u16,
+    /// The padding of the notification cards.
+    padding: u16,
+    /// The optional text size of the notification cards.
+    text_size: Option<u16>,
+    /// The optional function that produces a message when a notification is
+    /// closed by the user.
+    on_close: Option<Box<dyn Fn(usize) -> Message>>,
+    /// The optional function that produces a message when the timeout of a
+    /// notification expired.
+    on_timeout: Option<Box<dyn Fn(usize) -> Message>>,
+    /// The style of the notification cards.
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Toast<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Toast`](Toast) wrapped around the given content.
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`Toast`](Toast)'s [`State`](State)
+    ///         holding the notifications.
+    ///     * the underlay [`Element`](iced_native::Element) on which this
+    ///         [`Toast`](Toast) will be wrapped around.
+    pub fn new<U>(state: &'a mut State, underlay: U) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+    {
+        Toast {
+            state,
+            underlay: underlay.into(),
+            anchor: Anchor::NorthEast,
+            offset: 10.0.into(),
+            width: Renderer::DEFAULT_WIDTH,
+            spacing: Renderer::DEFAULT_SPACING,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            on_close: None,
+            on_timeout: None,
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the corner of the window the notifications are stacked in.
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the [`Offset`](Offset) of the notifications from the corner of
+    /// the window.
+    pub fn offset<O>(mut self, offset: O) -> Self
+    where
+        O: Into<Offset>,
+    {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Sets the width of the notification cards.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between the notification cards.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding of the notification cards.
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the notification cards.
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the function that will be called with the id of a notification
+    /// when it is closed by the user.
+    pub fn on_close<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_close = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the function that will be called with the id of a notification
+    /// when it is dismissed because its timeout expired.
+    pub fn on_timeout<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_timeout = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the notification cards.
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Toast<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.underlay.width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.underlay.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        for id in self.state.dismiss_expired(Instant::now()) {
+            #[cfg(feature = "instrumentation")]
+            instrumentation::record(WidgetKind::Toast, Action::Timeout(id));
+
+            if let Some(on_timeout) = &self.on_timeout {
+                messages.push(on_timeout(id));
+            }
+        }
+
+        self.underlay.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.underlay
+            .draw(renderer, defaults, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.underlay.hash_layout(state);
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if self.state.notifications.is_empty() {
+            return self.underlay.overlay(layout);
+        }
+
+        Some(
+            ToastOverlay::new(
+                self.state,
+                &self.anchor,
+                &self.offset,
+                self.width,
+                self.spacing,
+                self.padding,
+                self.text_size,
+                self.on_close.as_deref(),
+                &self.style,
+            )
+            .overlay(),
+        )
+    }
+}
+
+/// The severity of a [`Notification`](Notification), determining its style.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// An informational notification.
+    Info,
+    /// A notification warning about something.
+    Warning,
+    /// A notification reporting an error.
+    Error,
+}
+
+impl Default for Severity {
+    fn default() -> Self {
+        Self::Info
+    }
+}
+
+/// A notification displayed by a [`Toast`](Toast).
+#[derive(Clone, Debug)]
+pub struct Notification {
+    /// The title of the [`Notification`](Notification).
+    pub(crate) title: String,
+    /// The body of the [`Notification`](Notification).
+    pub(crate) body: String,
+    /// The [`Severity`](Severity) of the [`Notification`](Notification).
+    pub(crate) severity: Severity,
+    /// The duration after which the [`Notification`](Notification) is
+    /// dismissed.
+    pub(crate) timeout: Option<Duration>,
+}
+
+impl Notification {
+    /// The default duration after which a [`Notification`](Notification) is
+    /// dismissed.
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Creates a new informational [`Notification`](Notification) with the
+    /// given title and body.
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+            severity: Severity::default(),
+            timeout: Some(Self::DEFAULT_TIMEOUT),
+        }
+    }
+
+    /// Sets the [`Severity`](Severity) of the [`Notification`](Notification).
+    #[must_use]
+    pub const fn severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Sets the duration after which the [`Notification`](Notification) is
+    /// dismissed.
+    ///
+    /// If `None`, the [`Notification`](Notification) stays until it is closed.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Gets the title of the [`Notification`](Notification).
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Gets the body of the [`Notification`](Notification).
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
+
+/// The state of a [`Toast`](Toast) holding the displayed notifications.
+#[derive(Debug, Default)]
+pub struct State {
+    /// The displayed notifications with their id and the time they were
+    /// pushed.
+    pub(crate) notifications: Vec<(usize, Notification, Instant)>,
+    /// The id of the next pushed notification.
+    next_id: usize,
+}
+
+impl State {
+    /// Creates a new [`State`](State) without notifications.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Displays the given [`Notification`](Notification) and returns its id.
+    pub fn push(&mut self, notification: Notification) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.notifications.push((id, notification, Instant::now()));
+
+        id
+    }
+
+    /// Dismisses the [`Notification`](Notification) with the given id.
+    pub fn dismiss(&mut self, id: usize) {
+        self.notifications.retain(|(i, _, _)| *i != id);
+    }
+
+    /// Dismisses every [`Notification`](Notification) whose timeout expired
+    /// at the given instant and returns their ids.
+    ///
+    /// A [`Toast`](Toast) checks the timeouts whenever it receives an event.
+    /// Call this on a regular tick, e.g. of a time subscription, to dismiss
+    /// the notifications without any user interaction.
+    pub fn dismiss_expired(&mut self, now: Instant) -> Vec<usize> {
+        let (expired, remaining): (Vec<_>, Vec<_>) = std::mem::take(&mut self.notifications)
+            .into_iter()
+            .partition(|(_, notification, pushed)| {
+                notification.timeout.map_or(false, |timeout| {
+                    now.saturating_duration_since(*pushed) >= timeout
+                })
+            });
+
+        self.notifications = remaining;
+
+        expired.into_iter().map(|(id, _, _)| id).collect()
+    }
+
+    /// Returns an iterator over the displayed notifications and their ids.
+    pub fn notifications(&self) -> impl Iterator<Item = (usize, &Notification)> {
+        self.notifications
+            .iter()
+            .map(|(id, notification, _)| (*id, notification))
+    }
+}
+
+impl<'a, Message, Renderer> From<Toast<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(toast: Toast<'a, Message, Renderer>) -> Self {
+        Element::new(toast)
+    }
+}
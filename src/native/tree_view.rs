@@ -0,0 +1,495 @@
+//! Use a tree view to display hierarchical data with expandable nodes.
+//!
+//! *This API requires the following crate features to be activated: `tree_view`*
+use std::{collections::HashSet, fmt::Display, hash::Hash};
+
+use iced_native::{
+    event, layout, mouse, touch, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+#[cfg(feature = "instrumentation")]
+use crate::core::instrumentation::{self, Action, WidgetKind};
+use crate::core::renderer::DrawEnvironment;
+
+/// The path of a node in a [`TreeView`](TreeView).
+///
+/// It contains the index of the node and its ancestors in their parent's
+/// children, starting with the root node.
+pub type Path = Vec<usize>;
+
+/// A tree view displaying hierarchical data.
+///
+/// Nodes with children can be expanded and collapsed with the toggle in front
+/// of their label. Clicking on the label selects the node.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::tree_view::{self, Node, Path};
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type TreeView<'a, T, Message> = iced_aw::native::TreeView<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Selected(Path),
+/// }
+///
+/// let nodes = vec![
+///     Node::new("src").push(Node::new("lib.rs")).push(Node::new("main.rs")),
+///     Node::new("Cargo.toml"),
+/// ];
+///
+/// let mut state = tree_view::State::new();
+///
+/// let tree_view = TreeView::new(&mut state, &nodes).on_select(Message::Selected);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, T, Message, Renderer: self::Renderer> {
+    /// The state of the [`TreeView`](TreeView).
+    state: &'a mut State,
+    /// The root nodes of the [`TreeView`](TreeView).
+    nodes: &'a [Node<T>],
+    /// The width of the [`TreeView`](TreeView).
+    width: Length,
+    /// The indentation of each level of the [`TreeView`](TreeView).
+    indent: f32,
+    /// The padding of the rows of the [`TreeView`](TreeView).
+    padding: u16,
+    /// The optional text size of the labels of the [`TreeView`](TreeView).
+    text_size: Option<u16>,
+    /// The optional function that produces a message when a node is expanded.
+    on_expand: Option<Box<dyn Fn(Path) -> Message>>,
+    /// The optional function that produces a message when a node is collapsed.
+    on_collapse: Option<Box<dyn Fn(Path) -> Message>>,
+    /// The optional function that produces a message when a node is selected.
+    on_select: Option<Box<dyn Fn(Path) -> Message>>,
+    /// The style of the [`TreeView`](TreeView).
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> TreeView<'a, T, Message, Renderer>
+where
+    T: Display,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`TreeView`](TreeView).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`TreeView`](TreeView)'s
+    ///         [`State`](State).
+    ///     * the root [`Node`](Node)s to display.
+    pub fn new(state: &'a mut State, nodes: &'a [Node<T>]) -> Self {
+        TreeView {
+            state,
+            nodes,
+            width: Length::Fill,
+            indent: <Renderer as self::Renderer>::DEFAULT_INDENT,
+            padding: <Renderer as self::Renderer>::DEFAULT_PADDING,
+            text_size: None,
+            on_expand: None,
+            on_collapse: None,
+            on_select: None,
+            style: <Renderer as self::Renderer>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`TreeView`](TreeView).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the indentation of each level of the [`TreeView`](TreeView).
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the padding of the rows of the [`TreeView`](TreeView).
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the labels of the [`TreeView`](TreeView).
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the function that will be called with the [`Path`](Path) of a
+    /// node when it is expanded.
+    pub fn on_expand<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(Path) -> Message,
+    {
+        self.on_expand = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the function that will be called with the [`Path`](Path) of a
+    /// node when it is collapsed.
+    pub fn on_collapse<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(Path) -> Message,
+    {
+        self.on_collapse = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the function that will be called with the [`Path`](Path) of a
+    /// node when it is selected.
+    pub fn on_select<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(Path) -> Message,
+    {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`TreeView`](TreeView).
+    pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the currently visible rows of the [`TreeView`](TreeView).
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = Vec::new();
+        push_rows(self.nodes, self.state, &mut Vec::new(), &mut rows);
+        rows
+    }
+}
+
+/// Appends the rows of the given nodes and their expanded descendants.
+fn push_rows<T: Display>(nodes: &[Node<T>], state: &State, path: &mut Path, rows: &mut Vec<Row>) {
+    for (index, node) in nodes.iter().enumerate() {
+        path.push(index);
+
+        let is_expanded = state.is_expanded(path);
+
+        rows.push(Row {
+            path: path.clone(),
+            label: node.value.to_string(),
+            depth: path.len() - 1,
+            has_children: !node.children.is_empty(),
+            is_expanded,
+            is_selected: state.selected() == Some(path.as_slice()),
+        });
+
+        if is_expanded {
+            push_rows(&node.children, state, path, rows);
+        }
+
+        let _ = path.pop();
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for TreeView<'a, T, Message, Renderer>
+where
+    T: Display,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let row_height = f32::from(text_size + 2 * self.padding);
+        let rows = self.rows();
+
+        let size = limits.resolve(Size::new(0.0, rows.len() as f32 * row_height));
+
+        let row_nodes = rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let offset = row.depth as f32 * self.indent;
+
+                let mut toggle = layout::Node::new(Size::new(self.indent, row_height));
+                let mut label = layout::Node::new(Size::new(
+                    (size.width - offset - self.indent).max(0.0),
+                    row_height,
+                ));
+
+                toggle.move_to(Point::new(offset, 0.0));
+                label.move_to(Point::new(offset + self.indent, 0.0));
+
+                let mut node = layout::Node::with_children(
+                    Size::new(size.width, row_height),
+                    vec![toggle, label],
+                );
+                node.move_to(Point::new(0.0, i as f32 * row_height));
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(size, row_nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {}
+            _ => return event::Status::Ignored,
+        }
+
+        let clicked = layout
+            .children()
+            .enumerate()
+            .find(|(_, row)| row.bounds().contains(cursor_position));
+
+        let (index, row_layout) = match clicked {
+            Some(clicked) => clicked,
+            None => return event::Status::Ignored,
+        };
+
+        let row = match self.rows().into_iter().nth(index) {
+            Some(row) => row,
+            None => return event::Status::Ignored,
+        };
+        let path = row.path;
+
+        let toggle_layout = row_layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a toggle layout for a TreeView row");
+
+        if row.has_children && toggle_layout.bounds().contains(cursor_position) {
+            if row.is_expanded {
+                self.state.collapse(&path);
+                #[cfg(feature = "instrumentation")]
+                instrumentation::record(WidgetKind::TreeView, Action::Collapse(index));
+                if let Some(on_collapse) = &self.on_collapse {
+                    messages.push(on_collapse(path));
+                }
+            } else {
+                self.state.expand(path.clone());
+                #[cfg(feature = "instrumentation")]
+                instrumentation::record(WidgetKind::TreeView, Action::Expand(index));
+                if let Some(on_expand) = &self.on_expand {
+                    messages.push(on_expand(path));
+                }
+            }
+        } else {
+            self.state.selected = Some(path.clone());
+            #[cfg(feature = "instrumentation")]
+            instrumentation::record(WidgetKind::TreeView, Action::Select(index));
+            if let Some(on_select) = &self.on_select {
+                messages.push(on_select(path));
+            }
+        }
+
+        event::Status::Captured
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &self.style,
+                viewport: Some(viewport),
+                focus: (),
+            },
+            &self.rows(),
+            self.indent,
+            text_size,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        (self.indent as u32).hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.rows().len().hash(state);
+    }
+}
+
+/// A node of a [`TreeView`](TreeView) holding a value and its children.
+#[derive(Clone, Debug)]
+pub struct Node<T> {
+    /// The value of the [`Node`](Node) displayed as its label.
+    pub value: T,
+    /// The children of the [`Node`](Node).
+    pub children: Vec<Node<T>>,
+}
+
+impl<T> Node<T> {
+    /// Creates a new [`Node`](Node) without children.
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    /// Adds a child to the [`Node`](Node).
+    #[must_use]
+    pub fn push(mut self, child: Self) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Gets the descendant of the [`Node`](Node) at the given relative path.
+    ///
+    /// An empty path returns the [`Node`](Node) itself.
+    pub fn get(&self, path: &[usize]) -> Option<&Self> {
+        match path.split_first() {
+            None => Some(self),
+            Some((&index, rest)) => self.children.get(index).and_then(|child| child.get(rest)),
+        }
+    }
+}
+
+/// A visible row of a [`TreeView`](TreeView).
+#[derive(Clone, Debug)]
+pub struct Row {
+    /// The [`Path`](Path) of the node.
+    pub path: Path,
+    /// The label of the node.
+    pub label: String,
+    /// The depth of the node, starting with 0 for the root nodes.
+    pub depth: usize,
+    /// Whether the node has children and can be expanded.
+    pub has_children: bool,
+    /// Whether the node is expanded.
+    pub is_expanded: bool,
+    /// Whether the node is selected.
+    pub is_selected: bool,
+}
+
+/// The state of a [`TreeView`](TreeView).
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    /// The paths of the expanded nodes.
+    pub(crate) expanded: HashSet<Path>,
+    /// The path of the selected node.
+    pub(crate) selected: Option<Path>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) with all nodes collapsed and no
+    /// selection.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the node at the given [`Path`](Path) is expanded.
+    pub fn is_expanded(&self, path: &[usize]) -> bool {
+        self.expanded.contains(path)
+    }
+
+    /// Expands the node at the given [`Path`](Path).
+    pub fn expand(&mut self, path: Path) {
+        let _ = self.expanded.insert(path);
+    }
+
+    /// Collapses the node at the given [`Path`](Path).
+    pub fn collapse(&mut self, path: &[usize]) {
+        let _ = self.expanded.remove(path);
+    }
+
+    /// Gets the [`Path`](Path) of the selected node.
+    pub fn selected(&self) -> Option<&[usize]> {
+        self.selected.as_deref()
+    }
+
+    /// Selects the node at the given [`Path`](Path) or clears the selection.
+    pub fn select(&mut self, path: Option<Path>) {
+        self.selected = path;
+    }
+}
+
+/// The renderer of a [`TreeView`](TreeView).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`TreeView`](TreeView) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default indentation of each level of a [`TreeView`](TreeView).
+    const DEFAULT_INDENT: f32;
+
+    /// The default padding of the rows of a [`TreeView`](TreeView).
+    const DEFAULT_PADDING: u16;
+
+    /// The default text size of the labels of a [`TreeView`](TreeView).
+    fn default_size(&self) -> u16;
+
+    /// Draws a [`TreeView`](TreeView).
+    fn draw(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        rows: &[Row],
+        indent: f32,
+        text_size: u16,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_INDENT: f32 = 0.0;
+
+    const DEFAULT_PADDING: u16 = 0;
+
+    fn default_size(&self) -> u16 {
+        0
+    }
+
+    fn draw(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, Self::Style, ()>,
+        _rows: &[Row],
+        _indent: f32,
+        _text_size: u16,
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, T, Message, Renderer> From<TreeView<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Display,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(tree_view: TreeView<'a, T, Message, Renderer>) -> Self {
+        Element::new(tree_view)
+    }
+}
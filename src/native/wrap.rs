@@ -0,0 +1,332 @@
+//! Use a wrap to lay out elements in lines, wrapping them to the next line
+//! when the available width is exceeded.
+//!
+//! *This API requires the following crate features to be activated: `wrap`*
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+use crate::core::renderer::DrawEnvironment;
+
+/// A container laying out its children from left to right, wrapping them to
+/// the next line when a line exceeds the available width.
+///
+/// Unlike a grid, every child keeps its own natural size.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type Wrap<'a, Message> = iced_aw::native::Wrap<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+/// }
+///
+/// let wrap = Wrap::<Message>::new()
+///     .spacing(10)
+///     .line_spacing(5)
+///     .push(Text::new("Rust"))
+///     .push(Text::new("Iced"))
+///     .push(Text::new("Widgets"));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Wrap<'a, Message, Renderer> {
+    /// The horizontal spacing between the elements of a line.
+    spacing: u16,
+    /// The vertical spacing between the lines.
+    line_spacing: u16,
+    /// The padding around the elements.
+    padding: u16,
+    /// The width of the [`Wrap`](Wrap).
+    width: Length,
+    /// The height of the [`Wrap`](Wrap).
+    height: Length,
+    /// The maximum width of the [`Wrap`](Wrap).
+    max_width: u32,
+    /// The maximum height of the [`Wrap`](Wrap).
+    max_height: u32,
+    /// The vertical alignment of the elements within their line.
+    align_items: Align,
+    /// The elements of the [`Wrap`](Wrap).
+    elements: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Wrap<'a, Message, Renderer> {
+    /// Creates an empty [`Wrap`](Wrap).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Wrap`](Wrap) with the given elements.
+    #[must_use]
+    pub fn with_children(elements: Vec<Element<'a, Message, Renderer>>) -> Self {
+        Wrap {
+            spacing: 0,
+            line_spacing: 0,
+            padding: 0,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            align_items: Align::Start,
+            elements,
+        }
+    }
+
+    /// Sets the horizontal spacing between the elements of a line.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing between the lines.
+    pub fn line_spacing(mut self, units: u16) -> Self {
+        self.line_spacing = units;
+        self
+    }
+
+    /// Sets the padding around the elements.
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the width of the [`Wrap`](Wrap).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Wrap`](Wrap).
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Wrap`](Wrap).
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Wrap`](Wrap).
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the vertical alignment of the elements within their line.
+    pub fn align_items(mut self, align: Align) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Adds an element to the [`Wrap`](Wrap).
+    pub fn push<E>(mut self, element: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.elements.push(element.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Wrap<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Wrap<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let spacing = f32::from(self.spacing);
+        let line_spacing = f32::from(self.line_spacing);
+
+        let limits = limits
+            .loose()
+            .width(self.width)
+            .height(self.height)
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .pad(padding);
+        let max_width = limits.max().width;
+
+        let mut nodes = Vec::with_capacity(self.elements.len());
+        // The index of the first element and the height of each line.
+        let mut lines: Vec<(usize, f32)> = Vec::new();
+        let mut x = 0.0_f32;
+        let mut y = 0.0_f32;
+        let mut content_width = 0.0_f32;
+
+        for (index, element) in self.elements.iter().enumerate() {
+            let element_limits = layout::Limits::new(Size::ZERO, limits.max())
+                .width(element.width())
+                .height(element.height());
+            let mut node = element.layout(renderer, &element_limits);
+            let size = node.size();
+
+            match lines.last_mut() {
+                Some((_, line_height)) if x + size.width <= max_width => {
+                    *line_height = line_height.max(size.height);
+                }
+                _ => {
+                    if let Some((_, line_height)) = lines.last() {
+                        y += line_height + line_spacing;
+                    }
+                    x = 0.0;
+                    lines.push((index, size.height));
+                }
+            }
+
+            node.move_to(Point::new(padding + x, padding + y));
+            content_width = content_width.max(x + size.width);
+            x += size.width + spacing;
+
+            nodes.push(node);
+        }
+
+        let content_height = lines.last().map_or(0.0, |(_, line_height)| y + line_height);
+
+        // Align the elements within their line.
+        for (line, &(start, line_height)) in lines.iter().enumerate() {
+            let end = lines.get(line + 1).map_or(nodes.len(), |&(next, _)| next);
+
+            for node in &mut nodes[start..end] {
+                let free_space = line_height - node.size().height;
+                let offset = match self.align_items {
+                    Align::Start => 0.0,
+                    Align::Center => free_space / 2.0,
+                    Align::End => free_space,
+                };
+
+                let bounds = node.bounds();
+                node.move_to(Point::new(bounds.x, bounds.y + offset));
+            }
+        }
+
+        let size = limits.resolve(Size::new(content_width, content_height));
+
+        layout::Node::with_children(size.pad(padding), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        self.elements
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(element, layout)| {
+                element.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    messages,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            DrawEnvironment {
+                defaults,
+                layout,
+                cursor_position,
+                style_sheet: &(),
+                viewport: Some(viewport),
+                focus: (),
+            },
+            &self.elements,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        #[allow(clippy::missing_docs_in_private_items)]
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.spacing.hash(state);
+        self.line_spacing.hash(state);
+        self.padding.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.align_items.hash(state);
+
+        for element in &self.elements {
+            element.hash_layout(state);
+        }
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.elements
+            .iter_mut()
+            .zip(layout.children())
+            .find_map(|(element, layout)| element.overlay(layout))
+    }
+}
+
+/// The renderer of a [`Wrap`](Wrap).
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Wrap`](Wrap) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+    /// Draws a [`Wrap`](Wrap).
+    fn draw<Message>(
+        &mut self,
+        env: DrawEnvironment<'_, Self::Defaults, (), ()>,
+        elements: &[Element<'_, Message, Self>],
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    fn draw<Message>(
+        &mut self,
+        _env: DrawEnvironment<'_, Self::Defaults, (), ()>,
+        _elements: &[Element<'_, Message, Self>],
+    ) -> Self::Output {
+    }
+}
+
+impl<'a, Message, Renderer> From<Wrap<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(wrap: Wrap<'a, Message, Renderer>) -> Self {
+        Element::new(wrap)
+    }
+}
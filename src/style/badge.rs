@@ -6,6 +6,8 @@ use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background, Color};
 
+use crate::style::theme::Theme;
+
 /// The appearance of a [`Badge`](crate::native::badge::Badge).
 #[derive(Clone, Copy, Debug)]
 pub struct Style {
@@ -55,6 +57,24 @@ impl StyleSheet for Default {
     }
 }
 
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: Background::Color(palette.surface),
+            border_radius: None,
+            border_width: 1.0,
+            border_color: Some(palette.border),
+            text_color: palette.text,
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+}
+
 #[allow(clippy::use_self)]
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
@@ -78,7 +98,7 @@ pub use predefined::*;
 /// Predefined styles for the [`Badge`](crate::native::Badge) widget.
 mod predefined {
     use crate::style::badge::StyleSheet;
-    use crate::style::{badge::Style, colors};
+    use crate::style::{badge::Style, colors, theme::Theme};
 
     /// The appearance with the [`primary`](colors::PRIMARY) color of a
     /// [`Badge`](crate::native::badge::Badge).
@@ -268,4 +288,19 @@ mod predefined {
             self.active()
         }
     }
+
+    /// The dark variant of the [`Default`](super::Default) appearance of the
+    /// [`Badge`](crate::native::badge::Badge).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+
+        fn hovered(&self) -> Style {
+            Theme::Dark.hovered()
+        }
+    }
 }
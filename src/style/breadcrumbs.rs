@@ -0,0 +1,100 @@
+//! Use breadcrumbs to show the path to the current location, e.g. in a file
+//! manager or settings hierarchy.
+//!
+//! *This API requires the following crate features to be activated: `breadcrumbs`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::Color;
+#[cfg(target_arch = "wasm32")]
+use iced_web::Color;
+
+use crate::style::theme::{mix, Theme};
+
+/// The appearance of [`Breadcrumbs`](crate::native::Breadcrumbs).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The text color of the segments.
+    pub text_color: Color,
+
+    /// The text color of the hovered segment.
+    pub hovered_text_color: Color,
+
+    /// The text color of the last segment, i.e. the current location.
+    pub current_text_color: Color,
+
+    /// The color of the dividers and the ellipsis.
+    pub divider_color: Color,
+}
+
+/// The appearance of [`Breadcrumbs`](crate::native::Breadcrumbs).
+pub trait StyleSheet {
+    /// The normal appearance of [`Breadcrumbs`](crate::native::Breadcrumbs).
+    fn active(&self) -> Style;
+}
+
+/// The default appearance of [`Breadcrumbs`](crate::native::Breadcrumbs).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            text_color: [0.0, 0.4, 0.9].into(),
+            hovered_text_color: [0.0, 0.25, 0.6].into(),
+            current_text_color: Color::BLACK,
+            divider_color: [0.5, 0.5, 0.5].into(),
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            text_color: palette.primary,
+            hovered_text_color: mix(palette.primary, palette.text, 0.4),
+            current_text_color: palette.text,
+            divider_color: palette.border,
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`Breadcrumbs`](crate::native::Breadcrumbs)
+/// widget.
+mod predefined {
+    use crate::style::{
+        breadcrumbs::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of
+    /// [`Breadcrumbs`](crate::native::Breadcrumbs).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+    }
+}
@@ -6,6 +6,8 @@ use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background, Color};
 
+use crate::style::theme::Theme;
+
 /// The appearance of a [`Card`](crate::native::card::Card).
 #[derive(Clone, Copy, Debug)]
 pub struct Style {
@@ -41,6 +43,10 @@ pub struct Style {
 
     /// The color of the close icon of the [`Card`](crate::native::card::Card).
     pub close_color: Color,
+
+    /// The color of the scroller of a scrollable body of the
+    /// [`Card`](crate::native::card::Card).
+    pub scroller_color: Color,
 }
 
 /// The appearance of a [`Card`](crate::native::card::Card).
@@ -67,6 +73,31 @@ impl StyleSheet for Default {
             foot_background: Color::TRANSPARENT.into(),
             foot_text_color: Color::BLACK,
             close_color: Color::BLACK,
+            scroller_color: [0.0, 0.0, 0.0, 0.3].into(),
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: palette.background.into(),
+            border_radius: 10.0,
+            border_width: 1.0,
+            border_color: palette.surface,
+            head_background: Background::Color(palette.surface),
+            head_text_color: palette.text,
+            body_background: Color::TRANSPARENT.into(),
+            body_text_color: palette.text,
+            foot_background: Color::TRANSPARENT.into(),
+            foot_text_color: palette.text,
+            close_color: palette.text,
+            scroller_color: Color {
+                a: 0.3,
+                ..palette.text
+            },
         }
     }
 }
@@ -102,6 +133,7 @@ mod predefined {
     use crate::style::{
         card::{Style, StyleSheet},
         colors,
+        theme::Theme,
     };
 
     /// The appearance with the [`primary`](colors::PRIMARY) head background of
@@ -248,4 +280,15 @@ mod predefined {
             }
         }
     }
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`Card`](crate::native::card::Card).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+    }
 }
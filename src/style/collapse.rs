@@ -0,0 +1,130 @@
+//! Use a collapse to show or hide the bodies of sections by clicking on their
+//! headers.
+//!
+//! *This API requires the following crate features to be activated: `collapse`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::{Background, Color};
+#[cfg(target_arch = "wasm32")]
+use iced_web::{Background, Color};
+
+use crate::style::theme::Theme;
+
+/// The appearance of a section of a [`Collapse`](crate::native::Collapse).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The background of the header.
+    pub header_background: Background,
+
+    /// The background of the body.
+    pub body_background: Background,
+
+    /// The border radius of the section.
+    pub border_radius: f32,
+
+    /// The border width of the section.
+    pub border_width: f32,
+
+    /// The border color of the section.
+    pub border_color: Color,
+
+    /// The color of the expand indicator.
+    pub indicator_color: Color,
+}
+
+/// The appearance of the sections of a [`Collapse`](crate::native::Collapse).
+pub trait StyleSheet {
+    /// The normal appearance of a section.
+    fn active(&self) -> Style;
+
+    /// The appearance of a section whose header is hovered.
+    fn hovered(&self) -> Style;
+}
+
+/// The default appearance of a [`Collapse`](crate::native::Collapse).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            header_background: Background::Color([0.95, 0.95, 0.95].into()),
+            body_background: Background::Color(Color::WHITE),
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            indicator_color: [0.3, 0.3, 0.3].into(),
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            header_background: Background::Color([0.9, 0.9, 0.9].into()),
+            ..self.active()
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            header_background: Background::Color(palette.hovered),
+            body_background: Background::Color(palette.background),
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: palette.border,
+            indicator_color: palette.text,
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            header_background: Background::Color(self.palette().surface),
+            ..self.active()
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`Collapse`](crate::native::Collapse) widget.
+mod predefined {
+    use crate::style::{
+        collapse::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`Collapse`](crate::native::Collapse).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+
+        fn hovered(&self) -> Style {
+            Theme::Dark.hovered()
+        }
+    }
+}
@@ -6,6 +6,8 @@ use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background, Color};
 
+use crate::style::theme::Theme;
+
 /// The appearance of a [`ColorPicker`](crate::native::ColorPicker).
 #[derive(Clone, Copy, Debug)]
 pub struct Style {
@@ -83,6 +85,40 @@ impl StyleSheet for Default {
     }
 }
 
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: palette.background.into(),
+            border_radius: 15.0,
+            border_width: 1.0,
+            border_color: palette.text,
+            bar_border_radius: 5.0,
+            bar_border_width: 1.0,
+            bar_border_color: palette.text,
+        }
+    }
+
+    fn selected(&self) -> Style {
+        self.active()
+    }
+
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+
+    fn focused(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            border_color: palette.primary,
+            bar_border_color: palette.primary,
+            ..self.active()
+        }
+    }
+}
+
 #[allow(clippy::use_self)]
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
@@ -99,3 +135,38 @@ where
         Box::new(style)
     }
 }
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`ColorPicker`](crate::native::ColorPicker)
+/// widget.
+mod predefined {
+    use crate::style::{
+        color_picker::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of the
+    /// [`ColorPicker`](crate::native::ColorPicker).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+
+        fn selected(&self) -> Style {
+            Theme::Dark.selected()
+        }
+
+        fn hovered(&self) -> Style {
+            Theme::Dark.hovered()
+        }
+
+        fn focused(&self) -> Style {
+            Theme::Dark.focused()
+        }
+    }
+}
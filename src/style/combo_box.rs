@@ -0,0 +1,119 @@
+//! Use a combo box to select an option from a list filtered by the text typed
+//! into a text field.
+//!
+//! *This API requires the following crate features to be activated: `combo_box`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::{Background, Color};
+#[cfg(target_arch = "wasm32")]
+use iced_web::{Background, Color};
+
+use crate::style::theme::Theme;
+
+/// The appearance of the suggestions of a
+/// [`ComboBox`](crate::native::ComboBox).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The background of the suggestions.
+    pub background: Background,
+
+    /// The border radius of the suggestions.
+    pub border_radius: f32,
+
+    /// The border width of the suggestions.
+    pub border_width: f32,
+
+    /// The border color of the suggestions.
+    pub border_color: Color,
+
+    /// The text color of the suggestions.
+    pub text_color: Color,
+
+    /// The background of the hovered suggestion.
+    pub hovered_background: Background,
+
+    /// The text color of the hovered suggestion.
+    pub hovered_text_color: Color,
+}
+
+/// The appearance of the suggestions of a
+/// [`ComboBox`](crate::native::ComboBox).
+pub trait StyleSheet {
+    /// The normal appearance of the suggestions of a
+    /// [`ComboBox`](crate::native::ComboBox).
+    fn active(&self) -> Style;
+}
+
+/// The default appearance of the suggestions of a
+/// [`ComboBox`](crate::native::ComboBox).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            background: Background::Color(Color::WHITE),
+            border_radius: 0.0,
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            hovered_background: Background::Color([0.0, 0.5, 1.0].into()),
+            hovered_text_color: Color::WHITE,
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: Background::Color(palette.background),
+            border_radius: 0.0,
+            border_width: 1.0,
+            border_color: palette.border,
+            text_color: palette.text,
+            hovered_background: Background::Color(palette.primary),
+            hovered_text_color: palette.text_on_primary,
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the suggestions of a
+/// [`ComboBox`](crate::native::ComboBox) widget.
+mod predefined {
+    use crate::style::{
+        combo_box::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`ComboBox`](crate::native::ComboBox).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Use a context menu for showing actions on right click.
+//!
+//! *This API requires the following crate features to be activated: context_menu*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::{Background, Color};
+#[cfg(target_arch = "wasm32")]
+use iced_web::{Background, Color};
+
+use crate::style::theme::Theme;
+
+/// The appearance of a [`ContextMenu`](crate::native::ContextMenu).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The background of the [`ContextMenu`](crate::native::ContextMenu).
+    pub background: Background,
+
+    /// The border radius of the [`ContextMenu`](crate::native::ContextMenu).
+    pub border_radius: f32,
+
+    /// The border width of the [`ContextMenu`](crate::native::ContextMenu).
+    pub border_width: f32,
+
+    /// The border color of the [`ContextMenu`](crate::native::ContextMenu).
+    pub border_color: Color,
+}
+
+/// The appearance of a [`ContextMenu`](crate::native::ContextMenu).
+pub trait StyleSheet {
+    /// The normal appearance of a [`ContextMenu`](crate::native::ContextMenu).
+    fn active(&self) -> Style;
+}
+
+/// The default appearance of a [`ContextMenu`](crate::native::ContextMenu).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            background: Background::Color(Color::WHITE),
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: [0.87, 0.87, 0.87].into(),
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: Background::Color(palette.background),
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: palette.surface,
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`ContextMenu`](crate::native::ContextMenu)
+/// widget.
+mod predefined {
+    use crate::style::{
+        context_menu::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`ContextMenu`](crate::native::ContextMenu).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+    }
+}
@@ -6,6 +6,8 @@ use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background, Color};
 
+use crate::style::theme::Theme;
+
 /// The appearance of a [`DatePicker`](crate::native::DatePicker).
 #[derive(Clone, Copy, Debug)]
 pub struct Style {
@@ -31,6 +33,10 @@ pub struct Style {
     /// The background of the days in the calender of the
     /// [`DatePicker`](crate::native::DatePicker).
     pub day_background: Background,
+
+    /// The background of the days in between the start and the end date of
+    /// the range picked by a [`DatePicker`](crate::native::DatePicker).
+    pub day_range_background: Background,
 }
 
 /// The appearance of a [`DatePicker`](crate::native::DatePicker).
@@ -65,6 +71,7 @@ impl StyleSheet for Default {
             text_color: Color::BLACK,
             text_attenuated_color: [0.87, 0.87, 0.87].into(),
             day_background: Color::WHITE.into(),
+            day_range_background: Background::Color([0.94, 0.94, 0.94].into()),
         }
     }
 
@@ -90,6 +97,47 @@ impl StyleSheet for Default {
     }
 }
 
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: palette.background.into(),
+            border_radius: 15.0,
+            border_width: 1.0,
+            border_color: palette.text,
+            text_color: palette.text,
+            text_attenuated_color: palette.surface,
+            day_background: palette.background.into(),
+            day_range_background: Background::Color(palette.hovered),
+        }
+    }
+
+    fn selected(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            text_color: palette.text_on_primary,
+            day_background: Background::Color(palette.primary),
+            ..self.active()
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            day_background: Background::Color(self.palette().surface),
+            ..self.active()
+        }
+    }
+
+    fn focused(&self) -> Style {
+        Style {
+            border_color: self.palette().primary,
+            ..self.active()
+        }
+    }
+}
+
 #[allow(clippy::use_self)]
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
@@ -106,3 +154,37 @@ where
         Box::new(style)
     }
 }
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`DatePicker`](crate::native::DatePicker) widget.
+mod predefined {
+    use crate::style::{
+        date_picker::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of the
+    /// [`DatePicker`](crate::native::DatePicker).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+
+        fn selected(&self) -> Style {
+            Theme::Dark.selected()
+        }
+
+        fn hovered(&self) -> Style {
+            Theme::Dark.hovered()
+        }
+
+        fn focused(&self) -> Style {
+            Theme::Dark.focused()
+        }
+    }
+}
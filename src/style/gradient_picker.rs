@@ -0,0 +1,138 @@
+//! Use a gradient picker to edit the color stops of a linear gradient.
+//!
+//! *This API requires the following crate features to be activated: `gradient_picker`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::Color;
+#[cfg(target_arch = "wasm32")]
+use iced_web::Color;
+
+use crate::style::theme::Theme;
+
+/// The appearance of a [`GradientPicker`](crate::native::GradientPicker).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The border radius of the bar previewing the gradient.
+    pub bar_border_radius: f32,
+
+    /// The border width of the bar previewing the gradient.
+    pub bar_border_width: f32,
+
+    /// The border color of the bar previewing the gradient.
+    pub bar_border_color: Color,
+
+    /// The border radius of the handles of the stops.
+    pub handle_border_radius: f32,
+
+    /// The border width of the handles of the stops.
+    pub handle_border_width: f32,
+
+    /// The border color of the handles of the stops.
+    pub handle_border_color: Color,
+
+    /// The border color of the handle of the selected stop.
+    pub selected_handle_border_color: Color,
+}
+
+/// The appearance of a [`GradientPicker`](crate::native::GradientPicker).
+pub trait StyleSheet {
+    /// The normal appearance of a [`GradientPicker`](crate::native::GradientPicker).
+    fn active(&self) -> Style;
+
+    /// The appearance when a handle of the
+    /// [`GradientPicker`](crate::native::GradientPicker) is hovered.
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+}
+
+/// The default appearance of a [`GradientPicker`](crate::native::GradientPicker).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            bar_border_radius: 2.0,
+            bar_border_width: 1.0,
+            bar_border_color: [0.7, 0.7, 0.7].into(),
+            handle_border_radius: 2.0,
+            handle_border_width: 2.0,
+            handle_border_color: [0.7, 0.7, 0.7].into(),
+            selected_handle_border_color: [0.0, 0.5, 1.0].into(),
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            handle_border_color: [0.4, 0.4, 0.4].into(),
+            ..self.active()
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            bar_border_radius: 2.0,
+            bar_border_width: 1.0,
+            bar_border_color: palette.border,
+            handle_border_radius: 2.0,
+            handle_border_width: 2.0,
+            handle_border_color: palette.border,
+            selected_handle_border_color: palette.primary,
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            handle_border_color: self.palette().text,
+            ..self.active()
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`GradientPicker`](crate::native::GradientPicker)
+/// widget.
+mod predefined {
+    use crate::style::{
+        gradient_picker::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`GradientPicker`](crate::native::GradientPicker).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+
+        fn hovered(&self) -> Style {
+            Theme::Dark.hovered()
+        }
+    }
+}
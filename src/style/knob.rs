@@ -0,0 +1,160 @@
+//! Use a knob to adjust a value within a range by dragging or scrolling.
+//!
+//! *This API requires the following crate features to be activated: `knob`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::Color;
+#[cfg(target_arch = "wasm32")]
+use iced_web::Color;
+
+use crate::style::theme::Theme;
+
+/// The appearance of a [`Knob`](crate::native::Knob).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The color of the arc around the [`Knob`](crate::native::Knob) outside
+    /// of the value.
+    pub track_color: Color,
+
+    /// The color of the arc indicating the value.
+    pub value_color: Color,
+
+    /// The width of the arcs.
+    pub arc_width: f32,
+
+    /// The color of the body of the [`Knob`](crate::native::Knob).
+    pub knob_color: Color,
+
+    /// The border width of the body of the [`Knob`](crate::native::Knob).
+    pub knob_border_width: f32,
+
+    /// The border color of the body of the [`Knob`](crate::native::Knob).
+    pub knob_border_color: Color,
+
+    /// The color of the line on the body pointing at the value.
+    pub pointer_color: Color,
+}
+
+/// The appearance of a [`Knob`](crate::native::Knob).
+pub trait StyleSheet {
+    /// The normal appearance of a [`Knob`](crate::native::Knob).
+    fn active(&self) -> Style;
+
+    /// The appearance when the [`Knob`](crate::native::Knob) is hovered.
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+
+    /// The appearance while the [`Knob`](crate::native::Knob) is dragged.
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+}
+
+/// The default appearance of a [`Knob`](crate::native::Knob).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            track_color: [0.8, 0.8, 0.8].into(),
+            value_color: [0.0, 0.5, 1.0].into(),
+            arc_width: 4.0,
+            knob_color: [0.95, 0.95, 0.95].into(),
+            knob_border_width: 1.0,
+            knob_border_color: [0.6, 0.6, 0.6].into(),
+            pointer_color: [0.2, 0.2, 0.2].into(),
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            knob_border_color: [0.0, 0.5, 1.0].into(),
+            ..self.active()
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        Style {
+            knob_color: [0.9, 0.9, 0.9].into(),
+            ..self.hovered()
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            track_color: palette.surface,
+            value_color: palette.primary,
+            arc_width: 4.0,
+            knob_color: palette.hovered,
+            knob_border_width: 1.0,
+            knob_border_color: palette.border,
+            pointer_color: palette.text,
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            knob_border_color: self.palette().primary,
+            ..self.active()
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        Style {
+            knob_color: self.palette().surface,
+            ..self.hovered()
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`Knob`](crate::native::Knob) widget.
+mod predefined {
+    use crate::style::{
+        knob::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`Knob`](crate::native::Knob).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+
+        fn hovered(&self) -> Style {
+            Theme::Dark.hovered()
+        }
+
+        fn dragging(&self) -> Style {
+            Theme::Dark.dragging()
+        }
+    }
+}
@@ -2,29 +2,89 @@
 
 pub mod style_state;
 
+pub mod theme;
+pub use theme::{Palette, Theme};
+
 #[cfg(feature = "colors")]
 pub mod colors;
 
 #[cfg(feature = "badge")]
 pub mod badge;
 
+#[cfg(feature = "breadcrumbs")]
+pub mod breadcrumbs;
+
 #[cfg(feature = "button")]
 pub mod button;
 
 #[cfg(feature = "card")]
 pub mod card;
 
+#[cfg(feature = "collapse")]
+pub mod collapse;
+
 #[cfg(feature = "color_picker")]
 pub mod color_picker;
 
+#[cfg(feature = "combo_box")]
+pub mod combo_box;
+
+#[cfg(feature = "context_menu")]
+pub mod context_menu;
+
 #[cfg(feature = "date_picker")]
 pub mod date_picker;
 
+#[cfg(feature = "gradient_picker")]
+pub mod gradient_picker;
+
+#[cfg(feature = "knob")]
+pub mod knob;
+
 #[cfg(feature = "modal")]
 pub mod modal;
 
+#[cfg(feature = "pagination")]
+pub mod pagination;
+
+#[cfg(feature = "popover")]
+pub mod popover;
+
+#[cfg(feature = "progress_ring")]
+pub mod progress_ring;
+
+#[cfg(feature = "range_slider")]
+pub mod range_slider;
+
+#[cfg(feature = "reorderable_list")]
+pub mod reorderable_list;
+
+#[cfg(feature = "segmented_button")]
+pub mod segmented_button;
+
+#[cfg(feature = "selection_list")]
+pub mod selection_list;
+
+#[cfg(feature = "spinner")]
+pub mod spinner;
+
+#[cfg(feature = "split")]
+pub mod split;
+
+#[cfg(feature = "steps")]
+pub mod steps;
+
+#[cfg(feature = "table")]
+pub mod table;
+
 #[cfg(feature = "tab_bar")]
 pub mod tab_bar;
 
 #[cfg(feature = "time_picker")]
 pub mod time_picker;
+
+#[cfg(feature = "toast")]
+pub mod toast;
+
+#[cfg(feature = "tree_view")]
+pub mod tree_view;
@@ -3,9 +3,11 @@
 //! *This API requires the following crate features to be activated: badge*
 
 #[cfg(not(target_arch = "wasm32"))]
-use iced_native::Background;
+use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
-use iced_web::Background;
+use iced_web::{Background, Color};
+
+use crate::style::theme::Theme;
 
 /// The appearance of a [`Modal`](crate::native::Modal).
 #[derive(Clone, Copy, Debug)]
@@ -34,6 +36,17 @@ impl StyleSheet for Default {
     }
 }
 
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        Style {
+            background: Background::Color(Color {
+                a: 0.3,
+                ..self.palette().surface
+            }),
+        }
+    }
+}
+
 #[allow(clippy::use_self)]
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
@@ -50,3 +63,25 @@ where
         Box::new(style)
     }
 }
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`Modal`](crate::native::Modal) widget.
+mod predefined {
+    use crate::style::{
+        modal::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`Modal`](crate::native::Modal).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+    }
+}
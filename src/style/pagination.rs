@@ -0,0 +1,170 @@
+//! Use a pagination to navigate between the pages of some content.
+//!
+//! *This API requires the following crate features to be activated: `pagination`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::{Background, Color};
+#[cfg(target_arch = "wasm32")]
+use iced_web::{Background, Color};
+
+use crate::style::theme::{mix, Theme};
+
+/// The appearance of a button of a [`Pagination`](crate::native::Pagination).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The background of the button.
+    pub background: Background,
+
+    /// The border radius of the button.
+    pub border_radius: f32,
+
+    /// The border width of the button.
+    pub border_width: f32,
+
+    /// The border color of the button.
+    pub border_color: Color,
+
+    /// The text color of the button.
+    pub text_color: Color,
+}
+
+/// The appearance of a [`Pagination`](crate::native::Pagination).
+pub trait StyleSheet {
+    /// The normal appearance of a button.
+    ///
+    /// `is_selected` is true if the button shows the current page.
+    fn active(&self, is_selected: bool) -> Style;
+
+    /// The appearance of a hovered button.
+    ///
+    /// `is_selected` is true if the button shows the current page.
+    fn hovered(&self, is_selected: bool) -> Style {
+        self.active(is_selected)
+    }
+
+    /// The appearance of the previous or the next button if there is no
+    /// previous or next page.
+    fn disabled(&self) -> Style {
+        let active = self.active(false);
+
+        Style {
+            text_color: Color {
+                a: active.text_color.a * 0.5,
+                ..active.text_color
+            },
+            ..active
+        }
+    }
+}
+
+/// The default appearance of a [`Pagination`](crate::native::Pagination).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self, is_selected: bool) -> Style {
+        Style {
+            background: if is_selected {
+                Background::Color([0.0, 0.5, 1.0].into())
+            } else {
+                Background::Color(Color::WHITE)
+            },
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: [0.0, 0.5, 1.0].into(),
+            text_color: if is_selected {
+                Color::WHITE
+            } else {
+                Color::BLACK
+            },
+        }
+    }
+
+    fn hovered(&self, is_selected: bool) -> Style {
+        if is_selected {
+            self.active(is_selected)
+        } else {
+            Style {
+                background: Background::Color([0.9, 0.95, 1.0].into()),
+                ..self.active(is_selected)
+            }
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self, is_selected: bool) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: if is_selected {
+                Background::Color(palette.primary)
+            } else {
+                Background::Color(palette.background)
+            },
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: palette.primary,
+            text_color: if is_selected {
+                palette.text_on_primary
+            } else {
+                palette.text
+            },
+        }
+    }
+
+    fn hovered(&self, is_selected: bool) -> Style {
+        if is_selected {
+            self.active(is_selected)
+        } else {
+            let palette = self.palette();
+
+            Style {
+                background: Background::Color(mix(palette.background, palette.primary, 0.1)),
+                ..self.active(is_selected)
+            }
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`Pagination`](crate::native::Pagination) widget.
+mod predefined {
+    use crate::style::{
+        pagination::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`Pagination`](crate::native::Pagination).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self, is_selected: bool) -> Style {
+            Theme::Dark.active(is_selected)
+        }
+
+        fn hovered(&self, is_selected: bool) -> Style {
+            Theme::Dark.hovered(is_selected)
+        }
+    }
+}
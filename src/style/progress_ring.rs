@@ -0,0 +1,95 @@
+//! Use a progress ring to show the progress of some work as an arc around a
+//! circle.
+//!
+//! *This API requires the following crate features to be activated: `progress_ring`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::Color;
+#[cfg(target_arch = "wasm32")]
+use iced_web::Color;
+
+use crate::style::theme::Theme;
+
+/// The appearance of a [`ProgressRing`](crate::native::ProgressRing).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The color of the arc showing the progress.
+    pub color: Color,
+
+    /// The color of the full circle behind the arc.
+    pub track_color: Color,
+
+    /// The color of the text of the label.
+    pub text_color: Color,
+}
+
+/// The appearance of a [`ProgressRing`](crate::native::ProgressRing).
+pub trait StyleSheet {
+    /// The normal appearance of a [`ProgressRing`](crate::native::ProgressRing).
+    fn active(&self) -> Style;
+}
+
+/// The default appearance of a [`ProgressRing`](crate::native::ProgressRing).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            color: [0.0, 0.5, 1.0].into(),
+            track_color: [0.87, 0.87, 0.87].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            color: palette.primary,
+            track_color: palette.surface,
+            text_color: palette.text,
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`ProgressRing`](crate::native::ProgressRing)
+/// widget.
+mod predefined {
+    use crate::style::{
+        progress_ring::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`ProgressRing`](crate::native::ProgressRing).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+    }
+}
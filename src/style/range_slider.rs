@@ -0,0 +1,167 @@
+//! Use a range slider to select an interval of values with two handles.
+//!
+//! *This API requires the following crate features to be activated: `range_slider`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::Color;
+#[cfg(target_arch = "wasm32")]
+use iced_web::Color;
+
+use crate::style::theme::Theme;
+
+/// The appearance of a [`RangeSlider`](crate::native::RangeSlider).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The color of the rail outside of the selected interval.
+    pub rail_color: Color,
+
+    /// The color of the rail within the selected interval.
+    pub range_color: Color,
+
+    /// The width of the rail.
+    pub rail_width: f32,
+
+    /// The color of the handles.
+    pub handle_color: Color,
+
+    /// The radius of the handles.
+    pub handle_radius: f32,
+
+    /// The border width of the handles.
+    pub handle_border_width: f32,
+
+    /// The border color of the handles.
+    pub handle_border_color: Color,
+
+    /// The color of the tick marks.
+    pub tick_color: Color,
+}
+
+/// The appearance of a [`RangeSlider`](crate::native::RangeSlider).
+pub trait StyleSheet {
+    /// The normal appearance of a [`RangeSlider`](crate::native::RangeSlider).
+    fn active(&self) -> Style;
+
+    /// The appearance when the [`RangeSlider`](crate::native::RangeSlider) is
+    /// hovered.
+    fn hovered(&self) -> Style;
+
+    /// The appearance while a handle of the
+    /// [`RangeSlider`](crate::native::RangeSlider) is dragged.
+    fn dragging(&self) -> Style;
+}
+
+/// The default appearance of a [`RangeSlider`](crate::native::RangeSlider).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            rail_color: [0.8, 0.8, 0.8].into(),
+            range_color: [0.0, 0.5, 1.0].into(),
+            rail_width: 4.0,
+            handle_color: Color::WHITE,
+            handle_radius: 8.0,
+            handle_border_width: 1.0,
+            handle_border_color: [0.6, 0.6, 0.6].into(),
+            tick_color: [0.6, 0.6, 0.6].into(),
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            handle_color: [0.95, 0.95, 0.95].into(),
+            ..self.active()
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        Style {
+            handle_color: [0.9, 0.9, 0.9].into(),
+            handle_border_color: [0.0, 0.5, 1.0].into(),
+            ..self.active()
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            rail_color: palette.surface,
+            range_color: palette.primary,
+            rail_width: 4.0,
+            handle_color: palette.background,
+            handle_radius: 8.0,
+            handle_border_width: 1.0,
+            handle_border_color: palette.border,
+            tick_color: palette.border,
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            handle_color: self.palette().hovered,
+            ..self.active()
+        }
+    }
+
+    fn dragging(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            handle_color: palette.surface,
+            handle_border_color: palette.primary,
+            ..self.active()
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`RangeSlider`](crate::native::RangeSlider)
+/// widget.
+mod predefined {
+    use crate::style::{
+        range_slider::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`RangeSlider`](crate::native::RangeSlider).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+
+        fn hovered(&self) -> Style {
+            Theme::Dark.hovered()
+        }
+
+        fn dragging(&self) -> Style {
+            Theme::Dark.dragging()
+        }
+    }
+}
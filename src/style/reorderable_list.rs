@@ -0,0 +1,115 @@
+//! Use a reorderable list to let the user rearrange elements by dragging them.
+//!
+//! *This API requires the following crate features to be activated: `reorderable_list`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::{Background, Color};
+#[cfg(target_arch = "wasm32")]
+use iced_web::{Background, Color};
+
+use crate::style::theme::Theme;
+
+/// The appearance of a [`ReorderableList`](crate::native::ReorderableList).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The background of the space left by the dragged element.
+    pub placeholder_background: Background,
+
+    /// The color of the indicator showing where the dragged element will be
+    /// dropped.
+    pub indicator_color: Color,
+
+    /// The thickness of the drop indicator.
+    pub indicator_width: f32,
+
+    /// The background of the dragged element.
+    pub dragged_background: Background,
+
+    /// The border radius of the dragged element.
+    pub dragged_border_radius: f32,
+
+    /// The border width of the dragged element.
+    pub dragged_border_width: f32,
+
+    /// The border color of the dragged element.
+    pub dragged_border_color: Color,
+}
+
+/// The appearance of a [`ReorderableList`](crate::native::ReorderableList).
+pub trait StyleSheet {
+    /// The normal appearance of a [`ReorderableList`](crate::native::ReorderableList).
+    fn active(&self) -> Style;
+}
+
+/// The default appearance of a [`ReorderableList`](crate::native::ReorderableList).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            placeholder_background: Background::Color([0.9, 0.9, 0.9].into()),
+            indicator_color: [0.0, 0.5, 1.0].into(),
+            indicator_width: 2.0,
+            dragged_background: Background::Color(Color::WHITE),
+            dragged_border_radius: 2.0,
+            dragged_border_width: 1.0,
+            dragged_border_color: [0.7, 0.7, 0.7].into(),
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            placeholder_background: Background::Color(palette.hovered),
+            indicator_color: palette.primary,
+            indicator_width: 2.0,
+            dragged_background: Background::Color(palette.background),
+            dragged_border_radius: 2.0,
+            dragged_border_width: 1.0,
+            dragged_border_color: palette.border,
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the
+/// [`ReorderableList`](crate::native::ReorderableList) widget.
+mod predefined {
+    use crate::style::{
+        reorderable_list::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`ReorderableList`](crate::native::ReorderableList).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+    }
+}
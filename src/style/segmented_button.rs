@@ -0,0 +1,162 @@
+//! Use a segmented button to select one of a few mutually exclusive options.
+//!
+//! *This API requires the following crate features to be activated: `segmented_button`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::{Background, Color};
+#[cfg(target_arch = "wasm32")]
+use iced_web::{Background, Color};
+
+use crate::style::theme::{mix, Theme};
+
+/// The appearance of a [`SegmentedButton`](crate::native::SegmentedButton).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The background of the segment.
+    pub background: Background,
+
+    /// The border radius of the segmented button.
+    pub border_radius: f32,
+
+    /// The border width of the segmented button.
+    pub border_width: f32,
+
+    /// The border color of the segmented button.
+    pub border_color: Color,
+
+    /// The color of the dividers between the segments.
+    pub divider_color: Color,
+
+    /// The text color of the segment.
+    pub text_color: Color,
+}
+
+/// The appearance of a [`SegmentedButton`](crate::native::SegmentedButton).
+pub trait StyleSheet {
+    /// The normal appearance of a segment.
+    ///
+    /// `is_selected` is true if the segment is selected.
+    fn active(&self, is_selected: bool) -> Style;
+
+    /// The appearance of a hovered segment.
+    ///
+    /// `is_selected` is true if the segment is selected.
+    fn hovered(&self, is_selected: bool) -> Style {
+        self.active(is_selected)
+    }
+}
+
+/// The default appearance of a [`SegmentedButton`](crate::native::SegmentedButton).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self, is_selected: bool) -> Style {
+        Style {
+            background: if is_selected {
+                Background::Color([0.0, 0.5, 1.0].into())
+            } else {
+                Background::Color(Color::WHITE)
+            },
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: [0.0, 0.5, 1.0].into(),
+            divider_color: [0.0, 0.5, 1.0].into(),
+            text_color: if is_selected {
+                Color::WHITE
+            } else {
+                Color::BLACK
+            },
+        }
+    }
+
+    fn hovered(&self, is_selected: bool) -> Style {
+        if is_selected {
+            self.active(is_selected)
+        } else {
+            Style {
+                background: Background::Color([0.9, 0.95, 1.0].into()),
+                ..self.active(is_selected)
+            }
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self, is_selected: bool) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: if is_selected {
+                Background::Color(palette.primary)
+            } else {
+                Background::Color(palette.background)
+            },
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: palette.primary,
+            divider_color: palette.primary,
+            text_color: if is_selected {
+                palette.text_on_primary
+            } else {
+                palette.text
+            },
+        }
+    }
+
+    fn hovered(&self, is_selected: bool) -> Style {
+        if is_selected {
+            self.active(is_selected)
+        } else {
+            let palette = self.palette();
+
+            Style {
+                background: Background::Color(mix(palette.background, palette.primary, 0.1)),
+                ..self.active(is_selected)
+            }
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the
+/// [`SegmentedButton`](crate::native::SegmentedButton) widget.
+mod predefined {
+    use crate::style::{
+        segmented_button::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`SegmentedButton`](crate::native::SegmentedButton).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self, is_selected: bool) -> Style {
+            Theme::Dark.active(is_selected)
+        }
+
+        fn hovered(&self, is_selected: bool) -> Style {
+            Theme::Dark.hovered(is_selected)
+        }
+    }
+}
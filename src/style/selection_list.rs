@@ -0,0 +1,124 @@
+//! Use a selection list to select one or more entries of a scrollable list.
+//!
+//! *This API requires the following crate features to be activated: `selection_list`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::{Background, Color};
+#[cfg(target_arch = "wasm32")]
+use iced_web::{Background, Color};
+
+use crate::style::theme::Theme;
+
+/// The appearance of a [`SelectionList`](crate::native::SelectionList).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The background of the [`SelectionList`](crate::native::SelectionList).
+    pub background: Background,
+
+    /// The border width of the [`SelectionList`](crate::native::SelectionList).
+    pub border_width: f32,
+
+    /// The border color of the [`SelectionList`](crate::native::SelectionList).
+    pub border_color: Color,
+
+    /// The text color of the entries.
+    pub text_color: Color,
+
+    /// The background of the hovered entry.
+    pub hovered_background: Background,
+
+    /// The text color of the hovered entry.
+    pub hovered_text_color: Color,
+
+    /// The background of the selected entries.
+    pub selected_background: Background,
+
+    /// The text color of the selected entries.
+    pub selected_text_color: Color,
+
+    /// The color of the scrollbar.
+    pub scrollbar_color: Color,
+}
+
+/// The appearance of a [`SelectionList`](crate::native::SelectionList).
+pub trait StyleSheet {
+    /// The normal appearance of a [`SelectionList`](crate::native::SelectionList).
+    fn active(&self) -> Style;
+}
+
+/// The default appearance of a [`SelectionList`](crate::native::SelectionList).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            background: Background::Color(Color::WHITE),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            hovered_background: Background::Color([0.9, 0.9, 0.9].into()),
+            hovered_text_color: Color::BLACK,
+            selected_background: Background::Color([0.0, 0.5, 1.0].into()),
+            selected_text_color: Color::WHITE,
+            scrollbar_color: [0.7, 0.7, 0.7].into(),
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: Background::Color(palette.background),
+            border_width: 1.0,
+            border_color: palette.border,
+            text_color: palette.text,
+            hovered_background: Background::Color(palette.hovered),
+            hovered_text_color: palette.text,
+            selected_background: Background::Color(palette.primary),
+            selected_text_color: palette.text_on_primary,
+            scrollbar_color: palette.border,
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`SelectionList`](crate::native::SelectionList)
+/// widget.
+mod predefined {
+    use crate::style::{
+        selection_list::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`SelectionList`](crate::native::SelectionList).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+    }
+}
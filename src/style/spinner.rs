@@ -0,0 +1,88 @@
+//! Use a spinner to indicate that some work is running in the background.
+//!
+//! *This API requires the following crate features to be activated: `spinner`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::Color;
+#[cfg(target_arch = "wasm32")]
+use iced_web::Color;
+
+use crate::style::theme::Theme;
+
+/// The appearance of a [`Spinner`](crate::native::Spinner).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The color of the rotating arc.
+    pub color: Color,
+
+    /// The color of the full circle behind the rotating arc.
+    pub track_color: Color,
+}
+
+/// The appearance of a [`Spinner`](crate::native::Spinner).
+pub trait StyleSheet {
+    /// The normal appearance of a [`Spinner`](crate::native::Spinner).
+    fn active(&self) -> Style;
+}
+
+/// The default appearance of a [`Spinner`](crate::native::Spinner).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            color: [0.0, 0.5, 1.0].into(),
+            track_color: [0.87, 0.87, 0.87].into(),
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            color: palette.primary,
+            track_color: palette.surface,
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`Spinner`](crate::native::Spinner) widget.
+mod predefined {
+    use crate::style::{
+        spinner::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`Spinner`](crate::native::Spinner).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+    }
+}
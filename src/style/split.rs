@@ -0,0 +1,165 @@
+//! Use a split to split the available space in two parts to display two
+//! different elements separated by a draggable divider.
+//!
+//! *This API requires the following crate features to be activated: split*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::{Background, Color};
+#[cfg(target_arch = "wasm32")]
+use iced_web::{Background, Color};
+
+use crate::style::theme::{mix, Theme};
+
+/// The appearance of a [`Split`](crate::native::Split).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The optional background of the [`Split`](crate::native::Split).
+    pub background: Option<Background>,
+
+    /// The optional background of the first element of the
+    /// [`Split`](crate::native::Split).
+    pub first_background: Option<Background>,
+
+    /// The optional background of the second element of the
+    /// [`Split`](crate::native::Split).
+    pub second_background: Option<Background>,
+
+    /// The border width of the [`Split`](crate::native::Split).
+    pub border_width: f32,
+
+    /// The border color of the [`Split`](crate::native::Split).
+    pub border_color: Color,
+
+    /// The background of the divider of the [`Split`](crate::native::Split).
+    pub divider_background: Background,
+
+    /// The border width of the divider of the [`Split`](crate::native::Split).
+    pub divider_border_width: f32,
+
+    /// The border color of the divider of the [`Split`](crate::native::Split).
+    pub divider_border_color: Color,
+}
+
+/// The appearance of a [`Split`](crate::native::Split).
+pub trait StyleSheet {
+    /// The normal appearance of a [`Split`](crate::native::Split).
+    fn active(&self) -> Style;
+
+    /// The appearance when the divider of the [`Split`](crate::native::Split)
+    /// is hovered.
+    fn hovered(&self) -> Style;
+
+    /// The appearance when the divider of the [`Split`](crate::native::Split)
+    /// is dragged.
+    fn dragged(&self) -> Style;
+}
+
+/// The default appearance of a [`Split`](crate::native::Split).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            background: None,
+            first_background: None,
+            second_background: None,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            divider_background: Background::Color([0.87, 0.87, 0.87].into()),
+            divider_border_width: 0.0,
+            divider_border_color: Color::TRANSPARENT,
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            divider_background: Background::Color([0.8, 0.8, 0.8].into()),
+            ..self.active()
+        }
+    }
+
+    fn dragged(&self) -> Style {
+        Style {
+            divider_background: Background::Color([0.7, 0.7, 0.7].into()),
+            ..self.active()
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        Style {
+            background: None,
+            first_background: None,
+            second_background: None,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            divider_background: Background::Color(self.palette().surface),
+            divider_border_width: 0.0,
+            divider_border_color: Color::TRANSPARENT,
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            divider_background: Background::Color(mix(palette.surface, palette.border, 0.5)),
+            ..self.active()
+        }
+    }
+
+    fn dragged(&self) -> Style {
+        Style {
+            divider_background: Background::Color(self.palette().border),
+            ..self.active()
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`Split`](crate::native::Split) widget.
+mod predefined {
+    use crate::style::{
+        split::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`Split`](crate::native::Split).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+
+        fn hovered(&self) -> Style {
+            Theme::Dark.hovered()
+        }
+
+        fn dragged(&self) -> Style {
+            Theme::Dark.dragged()
+        }
+    }
+}
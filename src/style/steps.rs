@@ -0,0 +1,198 @@
+//! Use steps to show the progress through a sequence of numbered steps, like
+//! the pages of a wizard.
+//!
+//! *This API requires the following crate features to be activated: `steps`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::{Background, Color};
+#[cfg(target_arch = "wasm32")]
+use iced_web::{Background, Color};
+
+use crate::style::theme::{mix, Theme};
+
+/// The state of a step of [`Steps`](crate::native::Steps), determining its style.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum StepState {
+    /// The step is before the current step.
+    Completed,
+    /// The step is the current step.
+    Active,
+    /// The step is after the current step.
+    Pending,
+}
+
+impl StepState {
+    /// Returns the [`StepState`](StepState) of the step at the given index.
+    pub fn of(index: usize, current: usize) -> Self {
+        if index < current {
+            Self::Completed
+        } else if index == current {
+            Self::Active
+        } else {
+            Self::Pending
+        }
+    }
+}
+
+/// The appearance of a step of [`Steps`](crate::native::Steps).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The background of the indicator.
+    pub indicator_background: Background,
+
+    /// The border width of the indicator.
+    pub indicator_border_width: f32,
+
+    /// The border color of the indicator.
+    pub indicator_border_color: Color,
+
+    /// The color of the number or the check mark of the indicator.
+    pub indicator_text_color: Color,
+
+    /// The color of the label.
+    pub label_color: Color,
+
+    /// The color of the line connecting the indicator to the next one.
+    pub connector_color: Color,
+}
+
+/// The appearance of [`Steps`](crate::native::Steps).
+pub trait StyleSheet {
+    /// The normal appearance of a step in the given [`StepState`](StepState).
+    fn active(&self, state: StepState) -> Style;
+
+    /// The appearance of a hovered step in the given
+    /// [`StepState`](StepState) if the steps can be clicked.
+    fn hovered(&self, state: StepState) -> Style {
+        self.active(state)
+    }
+}
+
+/// The default appearance of [`Steps`](crate::native::Steps).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self, state: StepState) -> Style {
+        let primary: Color = [0.0, 0.5, 1.0].into();
+        let pending: Color = [0.7, 0.7, 0.7].into();
+
+        match state {
+            StepState::Completed => Style {
+                indicator_background: Background::Color(primary),
+                indicator_border_width: 1.0,
+                indicator_border_color: primary,
+                indicator_text_color: Color::WHITE,
+                label_color: Color::BLACK,
+                connector_color: primary,
+            },
+            StepState::Active => Style {
+                indicator_background: Background::Color(Color::WHITE),
+                indicator_border_width: 2.0,
+                indicator_border_color: primary,
+                indicator_text_color: primary,
+                label_color: Color::BLACK,
+                connector_color: pending,
+            },
+            StepState::Pending => Style {
+                indicator_background: Background::Color(Color::WHITE),
+                indicator_border_width: 1.0,
+                indicator_border_color: pending,
+                indicator_text_color: pending,
+                label_color: [0.5, 0.5, 0.5].into(),
+                connector_color: pending,
+            },
+        }
+    }
+
+    fn hovered(&self, state: StepState) -> Style {
+        Style {
+            indicator_border_color: [0.0, 0.4, 0.8].into(),
+            ..self.active(state)
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self, state: StepState) -> Style {
+        let palette = self.palette();
+
+        match state {
+            StepState::Completed => Style {
+                indicator_background: Background::Color(palette.primary),
+                indicator_border_width: 1.0,
+                indicator_border_color: palette.primary,
+                indicator_text_color: palette.text_on_primary,
+                label_color: palette.text,
+                connector_color: palette.primary,
+            },
+            StepState::Active => Style {
+                indicator_background: Background::Color(palette.background),
+                indicator_border_width: 2.0,
+                indicator_border_color: palette.primary,
+                indicator_text_color: palette.primary,
+                label_color: palette.text,
+                connector_color: palette.border,
+            },
+            StepState::Pending => Style {
+                indicator_background: Background::Color(palette.background),
+                indicator_border_width: 1.0,
+                indicator_border_color: palette.border,
+                indicator_text_color: palette.border,
+                label_color: mix(palette.text, palette.background, 0.5),
+                connector_color: palette.border,
+            },
+        }
+    }
+
+    fn hovered(&self, state: StepState) -> Style {
+        let palette = self.palette();
+
+        Style {
+            indicator_border_color: mix(palette.primary, palette.text, 0.2),
+            ..self.active(state)
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`Steps`](crate::native::Steps) widget.
+mod predefined {
+    use crate::style::{
+        steps::{StepState, Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of
+    /// [`Steps`](crate::native::Steps).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self, state: StepState) -> Style {
+            Theme::Dark.active(state)
+        }
+
+        fn hovered(&self, state: StepState) -> Style {
+            Theme::Dark.hovered(state)
+        }
+    }
+}
@@ -10,6 +10,8 @@ use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background, Color};
 
+use crate::style::theme::Theme;
+
 /// The appearance of a [`TabBar`](crate::native::tab_bar::TabBar).
 #[derive(Clone, Copy, Debug)]
 pub struct Style {
@@ -38,6 +40,36 @@ pub struct Style {
     pub text_color: Color,
 }
 
+/// The appearance of the content area of a [`Tabs`](crate::native::tabs::Tabs)
+/// widget.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ContentStyle {
+    /// The background of the content area.
+    pub background: Option<Background>,
+
+    /// The border color of the content area.
+    pub border_color: Option<Color>,
+
+    /// The border width of the content area.
+    pub border_width: f32,
+
+    /// The border radius of the content area.
+    pub border_radius: f32,
+}
+
+/// The appearance of the close icon of a tab label while it is hovered.
+#[derive(Clone, Copy, Debug)]
+pub struct CloseStyle {
+    /// The color of the close icon.
+    pub icon_color: Color,
+
+    /// The background behind the close icon.
+    pub background: Option<Background>,
+
+    /// The border radius of the background behind the close icon.
+    pub border_radius: f32,
+}
+
 /// The appearance of a [`TabBar`](crate::native::tab_bar::TabBar).
 pub trait StyleSheet {
     /// The normal appearance0of a tab bar and its tab labels.
@@ -49,6 +81,43 @@ pub trait StyleSheet {
     ///
     /// `is_active` is true if the tab is selected.
     fn hovered(&self, is_active: bool) -> Style;
+
+    /// The appearance of the tab bar and its selected tab label while the tab
+    /// bar has the keyboard focus.
+    ///
+    /// `is_active` is true if the tab is selected.
+    fn focused(&self, is_active: bool) -> Style {
+        self.hovered(is_active)
+    }
+
+    /// The appearance of the content area of a
+    /// [`Tabs`](crate::native::tabs::Tabs) widget.
+    ///
+    /// `index` is the index of the active tab. By default the content area
+    /// has neither a background nor a border.
+    fn content(&self, _index: usize) -> ContentStyle {
+        std::default::Default::default()
+    }
+
+    /// The appearance of the close icon of a tab label while it is hovered.
+    ///
+    /// `is_active` is true if the tab is selected. By default the close icon
+    /// keeps the icon color of the hovered tab label and has no background.
+    fn close_hovered(&self, is_active: bool) -> CloseStyle {
+        CloseStyle {
+            icon_color: self.hovered(is_active).icon_color,
+            background: None,
+            border_radius: 0.0,
+        }
+    }
+
+    /// The color of the indicator showing where a dragged tab label will be
+    /// inserted.
+    ///
+    /// By default it is the text color of the active tab label.
+    fn insertion_indicator_color(&self) -> Color {
+        self.active(true).text_color
+    }
 }
 
 /// The default appearance of a [`TabBar`](crate::native::TabBar).
@@ -79,6 +148,48 @@ impl StyleSheet for Default {
             ..self.active(is_active)
         }
     }
+
+    fn focused(&self, is_active: bool) -> Style {
+        Style {
+            tab_label_border_color: [0.0, 0.5, 1.0].into(),
+            ..self.hovered(is_active)
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self, is_active: bool) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: None,
+            border_color: None,
+            border_width: 0.0,
+            tab_label_background: if is_active {
+                Background::Color(palette.hovered)
+            } else {
+                Background::Color(palette.surface)
+            },
+            tab_label_border_color: palette.border,
+            tab_label_border_width: 1.0,
+            icon_color: palette.text,
+            text_color: palette.text,
+        }
+    }
+
+    fn hovered(&self, is_active: bool) -> Style {
+        Style {
+            tab_label_background: Background::Color(self.palette().hovered),
+            ..self.active(is_active)
+        }
+    }
+
+    fn focused(&self, is_active: bool) -> Style {
+        Style {
+            tab_label_border_color: self.palette().primary,
+            ..self.hovered(is_active)
+        }
+    }
 }
 
 #[allow(clippy::use_self)]
@@ -97,3 +208,33 @@ where
         Box::new(style)
     }
 }
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`TabBar`](crate::native::TabBar) widget.
+mod predefined {
+    use crate::style::{
+        tab_bar::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`TabBar`](crate::native::TabBar).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self, is_active: bool) -> Style {
+            Theme::Dark.active(is_active)
+        }
+
+        fn hovered(&self, is_active: bool) -> Style {
+            Theme::Dark.hovered(is_active)
+        }
+
+        fn focused(&self, is_active: bool) -> Style {
+            Theme::Dark.focused(is_active)
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Use a table to display rows of elements in columns below a header row.
+//!
+//! *This API requires the following crate features to be activated: `table`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::{Background, Color};
+#[cfg(target_arch = "wasm32")]
+use iced_web::{Background, Color};
+
+use crate::style::theme::{mix, Theme};
+
+/// The appearance of a [`Table`](crate::native::Table).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The background of the [`Table`](crate::native::Table).
+    pub background: Background,
+
+    /// The border width of the [`Table`](crate::native::Table).
+    pub border_width: f32,
+
+    /// The border color of the [`Table`](crate::native::Table).
+    pub border_color: Color,
+
+    /// The default text color of the cells.
+    pub text_color: Color,
+
+    /// The background of the header row.
+    pub header_background: Background,
+
+    /// The background of a hovered header if the columns can be sorted.
+    pub hovered_header_background: Background,
+
+    /// The text color of the headers and the sort indicator.
+    pub header_text_color: Color,
+
+    /// The color of the dividers between the headers and below the header
+    /// row.
+    pub divider_color: Color,
+
+    /// The background of every second row, if the rows are striped.
+    pub striped_row_background: Option<Background>,
+
+    /// The background of the hovered row, if the rows are highlighted.
+    pub hovered_row_background: Option<Background>,
+
+    /// The color of the scrollbar.
+    pub scrollbar_color: Color,
+}
+
+/// The appearance of a [`Table`](crate::native::Table).
+pub trait StyleSheet {
+    /// The normal appearance of a [`Table`](crate::native::Table).
+    fn active(&self) -> Style;
+}
+
+/// The default appearance of a [`Table`](crate::native::Table).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            background: Background::Color(Color::WHITE),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            header_background: Background::Color([0.87, 0.87, 0.87].into()),
+            hovered_header_background: Background::Color([0.8, 0.8, 0.8].into()),
+            header_text_color: Color::BLACK,
+            divider_color: [0.7, 0.7, 0.7].into(),
+            striped_row_background: Some(Background::Color([0.96, 0.96, 0.96].into())),
+            hovered_row_background: Some(Background::Color([0.9, 0.95, 1.0].into())),
+            scrollbar_color: [0.7, 0.7, 0.7].into(),
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: Background::Color(palette.background),
+            border_width: 1.0,
+            border_color: palette.border,
+            text_color: palette.text,
+            header_background: Background::Color(palette.surface),
+            hovered_header_background: Background::Color(mix(palette.surface, palette.border, 0.5)),
+            header_text_color: palette.text,
+            divider_color: palette.border,
+            striped_row_background: Some(Background::Color(mix(
+                palette.background,
+                palette.hovered,
+                0.5,
+            ))),
+            hovered_row_background: Some(Background::Color(mix(
+                palette.background,
+                palette.primary,
+                0.1,
+            ))),
+            scrollbar_color: palette.border,
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`Table`](crate::native::Table) widget.
+mod predefined {
+    use crate::style::{
+        table::{Style, StyleSheet},
+        theme::Theme,
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`Table`](crate::native::Table).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+    }
+}
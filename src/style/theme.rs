@@ -0,0 +1,110 @@
+//! A theme styling all widgets from a single palette.
+//!
+//! Every style sheet of the widgets is implemented for the [`Theme`](Theme),
+//! so passing the same theme to the `style` method of each widget gives them
+//! a consistent look.
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::Color;
+#[cfg(target_arch = "wasm32")]
+use iced_web::Color;
+
+/// The colors a [`Theme`](Theme) derives the appearance of the widgets from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Palette {
+    /// The background color of the widgets.
+    pub background: Color,
+
+    /// The background color of secondary surfaces, e.g. headers, tab labels
+    /// and tracks.
+    pub surface: Color,
+
+    /// The background color of hovered elements.
+    pub hovered: Color,
+
+    /// The color of the borders and dividers.
+    pub border: Color,
+
+    /// The color of the text.
+    pub text: Color,
+
+    /// The accent color of selected and active elements.
+    pub primary: Color,
+
+    /// The color of the text on the primary color.
+    pub text_on_primary: Color,
+
+    /// The color of warnings.
+    pub warning: Color,
+
+    /// The color of errors.
+    pub danger: Color,
+}
+
+impl Palette {
+    /// The palette of the [`Light`](Theme::Light) theme.
+    pub const LIGHT: Self = Self {
+        background: Color::WHITE,
+        surface: Color::from_rgb(0.87, 0.87, 0.87),
+        hovered: Color::from_rgb(0.93, 0.93, 0.93),
+        border: Color::from_rgb(0.7, 0.7, 0.7),
+        text: Color::BLACK,
+        primary: Color::from_rgb(0.0, 0.5, 1.0),
+        text_on_primary: Color::WHITE,
+        warning: Color::from_rgb(1.0, 0.7, 0.0),
+        danger: Color::from_rgb(0.85, 0.1, 0.1),
+    };
+
+    /// The palette of the [`Dark`](Theme::Dark) theme.
+    pub const DARK: Self = Self {
+        background: Color::from_rgb(0.16, 0.16, 0.16),
+        surface: Color::from_rgb(0.24, 0.24, 0.24),
+        hovered: Color::from_rgb(0.3, 0.3, 0.3),
+        border: Color::from_rgb(0.4, 0.4, 0.4),
+        text: Color::from_rgb(0.92, 0.92, 0.92),
+        primary: Color::from_rgb(0.2, 0.6, 1.0),
+        text_on_primary: Color::WHITE,
+        warning: Color::from_rgb(1.0, 0.75, 0.2),
+        danger: Color::from_rgb(0.95, 0.3, 0.3),
+    };
+}
+
+/// A theme styling all widgets from a single [`Palette`](Palette).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Theme {
+    /// Dark text on a light background.
+    Light,
+
+    /// Light text on a dark background.
+    Dark,
+
+    /// The colors of a custom [`Palette`](Palette).
+    Custom(Palette),
+}
+
+impl Theme {
+    /// Gets the [`Palette`](Palette) of the [`Theme`](Theme).
+    #[must_use]
+    pub const fn palette(&self) -> Palette {
+        match self {
+            Self::Light => Palette::LIGHT,
+            Self::Dark => Palette::DARK,
+            Self::Custom(palette) => *palette,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::Light
+    }
+}
+
+/// Mixes the color `a` with the given amount of the color `b`.
+pub(crate) fn mix(a: Color, b: Color, amount: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * amount,
+        g: a.g + (b.g - a.g) * amount,
+        b: a.b + (b.b - a.b) * amount,
+        a: a.a + (b.a - a.a) * amount,
+    }
+}
@@ -6,6 +6,8 @@ use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background, Color};
 
+use crate::style::theme::Theme;
+
 /// The appearance of a [`TimePicker`](crate::native::TimePicker).
 #[derive(Clone, Copy, Debug)]
 pub struct Style {
@@ -105,6 +107,50 @@ impl StyleSheet for Default {
     }
 }
 
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: palette.background.into(),
+            border_radius: 15.0,
+            border_width: 1.0,
+            border_color: palette.text,
+            text_color: palette.text,
+            clock_number_color: palette.text,
+            clock_number_background: palette.background,
+            clock_dots_color: palette.surface,
+            clock_hand_color: palette.surface,
+            clock_hand_width: 1.0,
+        }
+    }
+
+    fn selected(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            clock_number_color: palette.text_on_primary,
+            clock_number_background: palette.primary,
+            clock_hand_color: palette.primary,
+            ..self.active()
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            clock_number_background: self.palette().surface,
+            ..self.active()
+        }
+    }
+
+    fn focused(&self) -> Style {
+        Style {
+            border_color: self.palette().primary,
+            ..self.active()
+        }
+    }
+}
+
 #[allow(clippy::use_self)]
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
@@ -121,3 +167,37 @@ where
         Box::new(style)
     }
 }
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`TimePicker`](crate::native::TimePicker) widget.
+mod predefined {
+    use crate::style::{
+        theme::Theme,
+        time_picker::{Style, StyleSheet},
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of the
+    /// [`TimePicker`](crate::native::TimePicker).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+
+        fn selected(&self) -> Style {
+            Theme::Dark.selected()
+        }
+
+        fn hovered(&self) -> Style {
+            Theme::Dark.hovered()
+        }
+
+        fn focused(&self) -> Style {
+            Theme::Dark.focused()
+        }
+    }
+}
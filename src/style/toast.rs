@@ -0,0 +1,167 @@
+//! Use a toast to display short notifications stacked in a corner of the
+//! window.
+//!
+//! *This API requires the following crate features to be activated: `toast`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::{Background, Color};
+#[cfg(target_arch = "wasm32")]
+use iced_web::{Background, Color};
+
+use crate::style::theme::{mix, Theme};
+
+/// The appearance of a notification of a [`Toast`](crate::native::Toast).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The background of the notification.
+    pub background: Background,
+
+    /// The border radius of the notification.
+    pub border_radius: f32,
+
+    /// The border width of the notification.
+    pub border_width: f32,
+
+    /// The border color of the notification.
+    pub border_color: Color,
+
+    /// The text color of the title of the notification.
+    pub title_color: Color,
+
+    /// The text color of the body of the notification.
+    pub body_color: Color,
+
+    /// The color of the close button of the notification.
+    pub close_color: Color,
+}
+
+/// The appearance of the notifications of a [`Toast`](crate::native::Toast)
+/// depending on their severity.
+pub trait StyleSheet {
+    /// The appearance of an informational notification.
+    fn info(&self) -> Style;
+
+    /// The appearance of a warning notification.
+    fn warning(&self) -> Style;
+
+    /// The appearance of an error notification.
+    fn error(&self) -> Style;
+}
+
+/// The default appearance of the notifications of a
+/// [`Toast`](crate::native::Toast).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn info(&self) -> Style {
+        Style {
+            background: Background::Color([0.87, 0.93, 1.0].into()),
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: [0.0, 0.5, 1.0].into(),
+            title_color: Color::BLACK,
+            body_color: [0.2, 0.2, 0.2].into(),
+            close_color: [0.3, 0.3, 0.3].into(),
+        }
+    }
+
+    fn warning(&self) -> Style {
+        Style {
+            background: Background::Color([1.0, 0.96, 0.85].into()),
+            border_color: [1.0, 0.7, 0.0].into(),
+            ..self.info()
+        }
+    }
+
+    fn error(&self) -> Style {
+        Style {
+            background: Background::Color([1.0, 0.89, 0.89].into()),
+            border_color: [0.85, 0.1, 0.1].into(),
+            ..self.info()
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn info(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: Background::Color(mix(palette.background, palette.primary, 0.15)),
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: palette.primary,
+            title_color: palette.text,
+            body_color: mix(palette.text, palette.background, 0.2),
+            close_color: mix(palette.text, palette.background, 0.3),
+        }
+    }
+
+    fn warning(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: Background::Color(mix(palette.background, palette.warning, 0.15)),
+            border_color: palette.warning,
+            ..self.info()
+        }
+    }
+
+    fn error(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: Background::Color(mix(palette.background, palette.danger, 0.15)),
+            border_color: palette.danger,
+            ..self.info()
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the notifications of a
+/// [`Toast`](crate::native::Toast).
+mod predefined {
+    use crate::style::{
+        theme::Theme,
+        toast::{Style, StyleSheet},
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of the
+    /// notifications of a [`Toast`](crate::native::Toast).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn info(&self) -> Style {
+            Theme::Dark.info()
+        }
+
+        fn warning(&self) -> Style {
+            Theme::Dark.warning()
+        }
+
+        fn error(&self) -> Style {
+            Theme::Dark.error()
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Use a tree view to display hierarchical data with expandable nodes.
+//!
+//! *This API requires the following crate features to be activated: `tree_view`*
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::{Background, Color};
+#[cfg(target_arch = "wasm32")]
+use iced_web::{Background, Color};
+
+use crate::style::theme::{mix, Theme};
+
+/// The appearance of a [`TreeView`](crate::native::TreeView).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The optional background of the [`TreeView`](crate::native::TreeView).
+    pub background: Option<Background>,
+
+    /// The text color of the labels of the
+    /// [`TreeView`](crate::native::TreeView).
+    pub text_color: Color,
+
+    /// The color of the expand and collapse toggles of the
+    /// [`TreeView`](crate::native::TreeView).
+    pub toggle_color: Color,
+
+    /// The color of the indentation guides of the
+    /// [`TreeView`](crate::native::TreeView).
+    pub guide_color: Color,
+
+    /// The background of the hovered row of the
+    /// [`TreeView`](crate::native::TreeView).
+    pub hovered_background: Background,
+
+    /// The background of the selected row of the
+    /// [`TreeView`](crate::native::TreeView).
+    pub selected_background: Background,
+
+    /// The text color of the selected row of the
+    /// [`TreeView`](crate::native::TreeView).
+    pub selected_text_color: Color,
+}
+
+/// The appearance of a [`TreeView`](crate::native::TreeView).
+pub trait StyleSheet {
+    /// The normal appearance of a [`TreeView`](crate::native::TreeView).
+    fn active(&self) -> Style;
+}
+
+/// The default appearance of a [`TreeView`](crate::native::TreeView).
+#[derive(Clone, Copy, Debug)]
+pub struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            background: None,
+            text_color: Color::BLACK,
+            toggle_color: [0.4, 0.4, 0.4].into(),
+            guide_color: [0.87, 0.87, 0.87].into(),
+            hovered_background: Background::Color([0.95, 0.95, 0.95].into()),
+            selected_background: Background::Color([0.0, 0.5, 1.0].into()),
+            selected_text_color: Color::WHITE,
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.palette();
+
+        Style {
+            background: None,
+            text_color: palette.text,
+            toggle_color: mix(palette.text, palette.background, 0.4),
+            guide_color: palette.surface,
+            hovered_background: Background::Color(palette.hovered),
+            selected_background: Background::Color(palette.primary),
+            selected_text_color: palette.text_on_primary,
+        }
+    }
+}
+
+#[allow(clippy::use_self)]
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+#[allow(clippy::use_self)]
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
+
+#[cfg(feature = "colors")]
+pub use predefined::*;
+#[cfg(feature = "colors")]
+/// Predefined styles for the [`TreeView`](crate::native::TreeView) widget.
+mod predefined {
+    use crate::style::{
+        theme::Theme,
+        tree_view::{Style, StyleSheet},
+    };
+
+    /// The dark variant of the [`Default`](super::Default) appearance of a
+    /// [`TreeView`](crate::native::TreeView).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DefaultDark;
+
+    impl StyleSheet for DefaultDark {
+        fn active(&self) -> Style {
+            Theme::Dark.active()
+        }
+    }
+}
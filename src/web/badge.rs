@@ -23,6 +23,8 @@ pub struct Badge<'a, Message> {
     padding: u16,
     width: Length,
     height: Length,
+    max_width: u32,
+    tooltip: Option<String>,
     horizontal_alignment: Align,
     vertical_alignment: Align,
     style: Box<dyn StyleSheet>,
@@ -41,6 +43,8 @@ impl<'a, Message> Badge<'a, Message> {
             padding: 7,
             width: Length::Shrink,
             height: Length::Shrink,
+            max_width: u32::MAX,
+            tooltip: None,
             horizontal_alignment: Align::Center,
             vertical_alignment: Align::Center,
             style: Default::default(),
@@ -66,6 +70,26 @@ impl<'a, Message> Badge<'a, Message> {
         self
     }
 
+    /// Sets the maximum width of the [`Badge`](Badge).
+    ///
+    /// Text exceeding the maximum width is truncated with an ellipsis.
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the tooltip that is shown while the [`Badge`](Badge) is hovered.
+    ///
+    /// The [`TooltipState`](TooltipState) is only taken to match the native
+    /// [`Badge`](Badge), the browser shows the tooltip on the web.
+    pub fn tooltip<T>(mut self, _state: &'a mut TooltipState, tooltip: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
     /// Sets the horizontal alignment of the content of the [`Badge`](Badge).
     pub fn align_x(mut self, alignment: Align) -> Self {
         self.horizontal_alignment = alignment;
@@ -85,6 +109,19 @@ impl<'a, Message> Badge<'a, Message> {
     }
 }
 
+/// The state of the tooltip of a [`Badge`](Badge).
+///
+/// The browser keeps the state of the tooltip on the web.
+#[derive(Debug, Default)]
+pub struct TooltipState;
+
+impl TooltipState {
+    /// Creates a new [`TooltipState`](TooltipState).
+    pub fn new() -> Self {
+        TooltipState
+    }
+}
+
 impl<'a, Message> Widget<Message> for Badge<'a, Message>
 where
     Message: 'static + Clone,
@@ -114,14 +151,23 @@ where
             String::from_str_in(&padding_class, bump).into_bump_str()
         };
 
-        let node = div(bump)
+        let truncation = if self.max_width == u32::MAX {
+            String::new()
+        } else {
+            format!(
+                "max-width: {}px; overflow: hidden; text-overflow: ellipsis; white-space: nowrap",
+                self.max_width
+            )
+        };
+
+        let mut node = div(bump)
             .attr("class", class)
             .attr(
                 "style",
                 bumpalo::format!(
                     in bump,
                     "background: {}; border-radius: {}rem; width:{}; height: {} \
-                    border: {}px solid {}; display: inline-block; color: {}",
+                    border: {}px solid {}; display: inline-block; color: {}; {}",
                     //css::color(style.background)
                     match style.background {
                         Background::Color(color) => css::color(color),
@@ -131,12 +177,20 @@ where
                     css::length(self.height),
                     style.border_width,
                     border_color,
-                    css::color(style.text_color)
+                    css::color(style.text_color),
+                    truncation
                 )
                 .into_bump_str(),
             )
             .children(vec![self.content.node(bump, bus, style_sheet)]);
 
+        if let Some(tooltip) = &self.tooltip {
+            node = node.attr(
+                "title",
+                bumpalo::format!(in bump, "{}", tooltip).into_bump_str(),
+            );
+        }
+
         node.finish()
     }
 }
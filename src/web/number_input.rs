@@ -0,0 +1,182 @@
+//! A text field for entering numbers, with buttons to increase and decrease
+//! the value.
+//!
+//! *This API requires the following crate features to be activated: number_input*
+use std::{fmt::Display, rc::Rc, str::FromStr};
+
+use dodrio::bumpalo;
+use iced_web::{css, Bus, Css, Element, Length, Widget};
+use num_traits::{Bounded, Num};
+
+/// A text field for entering numbers, with buttons to increase and decrease
+/// the value by a step.
+///
+/// # Example
+/// ```
+/// # use iced_aw::{NumberInput, number_input};
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     VolumeChanged(u8),
+/// }
+///
+/// let mut state = number_input::State::new();
+///
+/// let number_input = NumberInput::new(
+///     &mut state,
+///     42,
+///     Message::VolumeChanged,
+/// )
+/// .max(100)
+/// .step(5);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message> {
+    _state: &'a mut State,
+    value: T,
+    step: T,
+    min: T,
+    max: T,
+    width: Length,
+    on_change: Rc<dyn Fn(T) -> Message>,
+}
+
+impl<'a, T, Message> NumberInput<'a, T, Message>
+where
+    T: Num + Bounded + PartialOrd + Display + FromStr + Copy,
+{
+    /// Creates a new [`NumberInput`](NumberInput).
+    ///
+    /// It expects:
+    ///     * a mutable reference to the [`NumberInput`](NumberInput)'s
+    ///         [`State`](State).
+    ///     * the current value.
+    ///     * a function that will be called when a new valid value is typed
+    ///         into the text field or set by the buttons.
+    pub fn new<F>(_state: &'a mut State, value: T, on_change: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        Self {
+            _state,
+            value,
+            step: T::one(),
+            min: T::min_value(),
+            max: T::max_value(),
+            width: Length::Fill,
+            on_change: Rc::new(on_change),
+        }
+    }
+
+    /// Sets the step by which the buttons change the value of the
+    /// [`NumberInput`](NumberInput).
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the minimum value of the [`NumberInput`](NumberInput).
+    pub fn min(mut self, min: T) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`](NumberInput).
+    pub fn max(mut self, max: T) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`](NumberInput).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+/// The state of the [`NumberInput`](NumberInput).
+///
+/// The browser keeps the state of the number input on the web.
+#[derive(Debug, Default)]
+pub struct State;
+
+impl State {
+    /// Creates a new [`State`](State).
+    pub fn new() -> Self {
+        State
+    }
+}
+
+impl<'a, T, Message> Widget<Message> for NumberInput<'a, T, Message>
+where
+    T: 'static + Num + Bounded + PartialOrd + Display + FromStr + Copy,
+    Message: 'static + Clone,
+{
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        _style_sheet: &mut Css<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+        use wasm_bindgen::JsCast;
+
+        let on_change = self.on_change.clone();
+        let input_event_bus = bus.clone();
+        let min = self.min;
+        let max = self.max;
+        let value = self.value;
+
+        // The browser renders the buttons to increase and decrease the value.
+        // https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/number
+        let node = input(bump)
+            .attr("type", "number")
+            .attr(
+                "value",
+                bumpalo::format!(in bump, "{}", self.value).into_bump_str(),
+            )
+            .attr(
+                "min",
+                bumpalo::format!(in bump, "{}", self.min).into_bump_str(),
+            )
+            .attr(
+                "max",
+                bumpalo::format!(in bump, "{}", self.max).into_bump_str(),
+            )
+            .attr(
+                "step",
+                bumpalo::format!(in bump, "{}", self.step).into_bump_str(),
+            )
+            .attr(
+                "style",
+                bumpalo::format!(in bump, "width: {}", css::length(self.width)).into_bump_str(),
+            )
+            .on("input", move |_root, _vdom, event| {
+                let number_input = match event
+                    .target()
+                    .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
+                {
+                    None => return,
+                    Some(number_input) => number_input,
+                };
+
+                if let Ok(new_value) = number_input.value().parse::<T>() {
+                    if new_value >= min && new_value <= max && new_value != value {
+                        input_event_bus.publish(on_change(new_value));
+                    }
+                }
+            })
+            .finish();
+
+        node
+    }
+}
+
+impl<'a, T, Message> From<NumberInput<'a, T, Message>> for Element<'a, Message>
+where
+    T: 'static + Num + Bounded + PartialOrd + Display + FromStr + Copy,
+    Message: 'static + Clone,
+{
+    fn from(number_input: NumberInput<'a, T, Message>) -> Element<'a, Message> {
+        Element::new(number_input)
+    }
+}
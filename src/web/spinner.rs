@@ -0,0 +1,131 @@
+//! Use a spinner to indicate that some work is running in the background.
+//!
+//! *This API requires the following crate features to be activated: spinner*
+use std::time::Duration;
+
+use dodrio::bumpalo;
+use iced_web::{css, Bus, Css, Element, Widget};
+
+pub use crate::style::spinner::{Style, StyleSheet};
+
+/// The name of the CSS animation rotating the [`Spinner`](Spinner).
+const ANIMATION: &str = "iced-aw-spinner";
+
+/// An indeterminate loading indicator drawing a rotating arc.
+///
+/// The rotation is animated by the browser.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use iced_aw::Spinner;
+/// let spinner = Spinner::new()
+///     .size(32)
+///     .stroke_width(3.0)
+///     .cycle_duration(Duration::from_millis(800));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Spinner {
+    size: u16,
+    stroke_width: f32,
+    cycle_duration: Duration,
+    style: Box<dyn StyleSheet>,
+}
+
+impl Spinner {
+    /// Creates a new [`Spinner`](Spinner).
+    pub fn new() -> Self {
+        Spinner {
+            size: 40,
+            stroke_width: 4.0,
+            cycle_duration: Duration::from_secs(1),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width and height of the [`Spinner`](Spinner).
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the width of the arc of the [`Spinner`](Spinner).
+    pub fn stroke_width(mut self, stroke_width: f32) -> Self {
+        self.stroke_width = stroke_width;
+        self
+    }
+
+    /// Sets the duration of a full rotation of the [`Spinner`](Spinner).
+    pub fn cycle_duration(mut self, cycle_duration: Duration) -> Self {
+        self.cycle_duration = cycle_duration;
+        self
+    }
+
+    /// Sets the style of the [`Spinner`](Spinner).
+    pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message> Widget<Message> for Spinner {
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        _bus: &Bus<Message>,
+        _style_sheet: &mut Css<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        let appearance = self.style.active();
+
+        let keyframes = bumpalo::format!(
+            in bump,
+            "@keyframes {} {{ to {{ transform: rotate(360deg); }} }}",
+            ANIMATION
+        )
+        .into_bump_str();
+
+        div(bump)
+            .attr("style", "display: inline-block")
+            .children(vec![
+                style(bump).children(vec![text(keyframes)]).finish(),
+                div(bump)
+                    .attr(
+                        "style",
+                        bumpalo::format!(
+                            in bump,
+                            "width: {}px; height: {}px; box-sizing: border-box; \
+                            border-radius: 50%; border: {}px solid {}; \
+                            border-top-color: {}; \
+                            animation: {} {}ms linear infinite",
+                            self.size,
+                            self.size,
+                            self.stroke_width,
+                            css::color(appearance.track_color),
+                            css::color(appearance.color),
+                            ANIMATION,
+                            self.cycle_duration.as_millis(),
+                        )
+                        .into_bump_str(),
+                    )
+                    .finish(),
+            ])
+            .finish()
+    }
+}
+
+impl<'a, Message> From<Spinner> for Element<'a, Message>
+where
+    Message: 'static,
+{
+    fn from(spinner: Spinner) -> Element<'a, Message> {
+        Element::new(spinner)
+    }
+}